@@ -3,7 +3,7 @@ use http_body::{combinators::UnsyncBoxBody, Body};
 use tonic::{
     body::BoxBody as ReqBody,
     codegen::http as grpc,
-    transport::{self, Endpoint},
+    transport::{self, Channel, Endpoint},
 };
 use tower::{util::BoxCloneService, Service, ServiceBuilder};
 
@@ -18,12 +18,17 @@ pub(crate) type RspBody = UnsyncBoxBody<Bytes, BoxError>;
 
 /// Connects to the provided tonic [`Endpoint`], returning a [`BoxGrpcService`].
 pub(crate) async fn connect(ep: Endpoint) -> anyhow::Result<BoxGrpcService> {
-    let conn = ep.connect().await?;
+    Ok(channel(ep.connect().await?))
+}
+
+/// Erases the type of an already-connected tonic [`Channel`], returning a
+/// [`BoxGrpcService`].
+pub(crate) fn channel(conn: Channel) -> BoxGrpcService {
     let svc = ServiceBuilder::new()
         .map_response(|rsp: grpc::Response<transport::Body>| rsp.map(box_rsp_body))
         .map_err(BoxError::from)
         .service(conn);
-    Ok(BoxCloneService::new(svc))
+    BoxCloneService::new(svc)
 }
 
 /// Constructs a [`BoxGrpcService`] by erasing the type of an `S`-typed local
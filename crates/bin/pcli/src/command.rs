@@ -1,3 +1,6 @@
+use camino::Utf8PathBuf;
+use penumbra_view::SelectionStrategy;
+
 pub use debug::DebugCmd;
 pub use init::InitCmd;
 pub use query::QueryCmd;
@@ -51,8 +54,20 @@ pub enum Command {
     #[clap(subcommand, display_order = 300, visible_alias = "v")]
     View(ViewCmd),
     /// Create and broadcast a transaction.
-    #[clap(subcommand, display_order = 400, visible_alias = "tx")]
-    Transaction(TxCmd),
+    #[clap(display_order = 400, visible_alias = "tx")]
+    Transaction {
+        /// Instead of signing and broadcasting the transaction, write its plan to this file.
+        ///
+        /// The plan can then be signed on an offline machine with `pcli tx sign`, and
+        /// broadcast with `pcli tx broadcast`.
+        #[clap(long, global = true, value_name = "PLAN_FILE")]
+        plan_only: Option<Utf8PathBuf>,
+        /// How to select the notes spent to fund the transaction.
+        #[clap(long, global = true, value_enum, default_value = "greedy")]
+        selection_strategy: SelectionStrategy,
+        #[clap(subcommand)]
+        cmd: TxCmd,
+    },
     /// Manage a validator.
     #[clap(subcommand, display_order = 900)]
     Validator(ValidatorCmd),
@@ -72,7 +87,7 @@ impl Command {
     pub fn offline(&self) -> bool {
         match self {
             Command::Init(_) => true,
-            Command::Transaction(cmd) => cmd.offline(),
+            Command::Transaction { cmd, .. } => cmd.offline(),
             Command::View(cmd) => cmd.offline(),
             Command::Validator(cmd) => cmd.offline(),
             Command::Query(cmd) => cmd.offline(),
@@ -1,12 +1,19 @@
 use anyhow::Result;
+use camino::Utf8Path;
 use serde::Serialize;
 use std::path::PathBuf;
 use std::process::Command;
 
+mod sct;
+pub use sct::SctCmd;
+
 #[derive(Debug, clap::Subcommand)]
 pub enum DebugCmd {
     /// Emit debugging info, useful for requesting support
     Info,
+    /// Inspect the wallet's state commitment tree, to debug witnesses for notes
+    #[clap(subcommand)]
+    Sct(SctCmd),
 }
 
 impl DebugCmd {
@@ -14,16 +21,17 @@ impl DebugCmd {
         true
     }
 
-    pub fn exec(&self, data_dir: PathBuf) -> Result<()> {
+    pub async fn exec(&self, home: &Utf8Path) -> Result<()> {
         match self {
             DebugCmd::Info => {
-                let debug_info = DebugInfo::new(data_dir);
+                let debug_info = DebugInfo::new(home.as_std_path().to_path_buf());
                 // Using derived serialization as a cheap Display impl for formatting
                 // the output. It's human-readable enough when pretty, plus we can parse it.
                 let d = serde_json::to_string_pretty(&debug_info)?;
                 println!("{d}");
                 Ok(())
             }
+            DebugCmd::Sct(cmd) => cmd.exec(home).await,
         }
     }
 }
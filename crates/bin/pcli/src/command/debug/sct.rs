@@ -0,0 +1,208 @@
+use std::{fs::File, io::BufWriter};
+
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use penumbra_proto::crypto::tct::v1alpha1 as pb;
+use penumbra_tct::{Position, Proof, Root, StateCommitment, Tree};
+use penumbra_view::Storage;
+
+use crate::config::PcliConfig;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum SctCmd {
+    /// Print the position, forgotten version, and root of the wallet's state commitment tree.
+    Status,
+    /// Check the witness for a note commitment against an anchor.
+    Witness {
+        /// The note commitment to witness, as hex.
+        #[clap(parse(try_from_str = StateCommitment::parse_hex))]
+        commitment: StateCommitment,
+        /// The anchor to verify the witness against, as hex [default: the root of the wallet's
+        /// state commitment tree].
+        #[clap(long, parse(try_from_str = parse_root))]
+        anchor: Option<Root>,
+    },
+    /// Render the portion of the state commitment tree witnessed by the wallet as a DOT graph.
+    Render {
+        /// The file to write the rendering to.
+        output: Utf8PathBuf,
+        /// Render an SVG image instead, using the `dot` command from Graphviz.
+        #[clap(long)]
+        svg: bool,
+        /// Indent the DOT graph to be human-readable.
+        #[clap(long, conflicts_with = "svg")]
+        pretty: bool,
+    },
+}
+
+impl SctCmd {
+    pub async fn exec(&self, home: &Utf8Path) -> Result<()> {
+        let tree = load_tree(home).await?;
+
+        match self {
+            SctCmd::Status => {
+                match tree.position() {
+                    Some(position) => println!("Position:  {}", display_position(position)),
+                    None => println!("Position:  full"),
+                }
+                println!("Forgotten: {}", u64::from(tree.forgotten()));
+                println!("Root:      {}", tree.root());
+                println!("Witnessed: {} commitments", tree.witnessed_count());
+            }
+            SctCmd::Witness { commitment, anchor } => {
+                let proof = tree.witness(*commitment).with_context(|| {
+                    format!("note commitment {commitment} is not witnessed by the wallet")
+                })?;
+                let anchor = anchor.unwrap_or_else(|| tree.root());
+                println!("Position: {}", display_position(proof.position()));
+                println!("Anchor:   {anchor}");
+                match diagnose(&proof, anchor, tree.root()) {
+                    Diagnosis::Verifies => println!("Witness verifies against the anchor."),
+                    Diagnosis::StaleAnchor => println!(
+                        "Witness does NOT verify against the anchor.\nIt does verify against the wallet's current root {}, so the anchor may be from a different block, or the wallet may not be synced.",
+                        tree.root()
+                    ),
+                    Diagnosis::Corrupted => println!(
+                        "Witness does NOT verify against the anchor.\nIt does not verify against the wallet's current root {} either, so the wallet's state commitment tree may be corrupted.",
+                        tree.root()
+                    ),
+                }
+            }
+            SctCmd::Render {
+                output,
+                svg,
+                pretty,
+            } => {
+                let mut writer = BufWriter::new(
+                    File::create(output).with_context(|| format!("could not create {output}"))?,
+                );
+                if *svg {
+                    penumbra_tct_visualize::render::witnessed_svg(&tree, &mut writer)
+                        .context("could not render SVG; is Graphviz installed?")?;
+                } else {
+                    penumbra_tct_visualize::render::witnessed_dot(&tree, *pretty, &mut writer)?;
+                }
+                println!("Wrote {output}");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Load the state commitment tree from the wallet's local view database.
+async fn load_tree(home: &Utf8Path) -> Result<Tree> {
+    let config = PcliConfig::load(home.join(crate::CONFIG_FILE_NAME))?;
+    if let Some(view_url) = config.view_url {
+        anyhow::bail!(
+            "the wallet uses the remote view service at {view_url}, so there is no local state commitment tree to inspect"
+        );
+    }
+
+    let path = home.join(crate::VIEW_FILE_NAME);
+    if !path.exists() {
+        anyhow::bail!("no view database found at {path}; sync the wallet first");
+    }
+    let storage = Storage::load(path, config.view_database_key()?).await?;
+    storage.state_commitment_tree().await
+}
+
+/// What checking a witness against an anchor says about it.
+#[derive(Debug, PartialEq, Eq)]
+enum Diagnosis {
+    /// The witness verifies against the anchor.
+    Verifies,
+    /// The witness only verifies against the wallet's current root, so the anchor is out of date.
+    StaleAnchor,
+    /// The witness verifies against neither, so the wallet's tree may be corrupted.
+    Corrupted,
+}
+
+fn diagnose(proof: &Proof, anchor: Root, current_root: Root) -> Diagnosis {
+    if proof.verify(anchor).is_ok() {
+        Diagnosis::Verifies
+    } else if proof.verify(current_root).is_ok() {
+        Diagnosis::StaleAnchor
+    } else {
+        Diagnosis::Corrupted
+    }
+}
+
+fn display_position(position: Position) -> String {
+    format!(
+        "{} (epoch {}, block {}, commitment {})",
+        u64::from(position),
+        position.epoch(),
+        position.block(),
+        position.commitment()
+    )
+}
+
+fn parse_root(s: &str) -> Result<Root> {
+    Ok(Root::try_from(pb::MerkleRoot {
+        inner: hex::decode(s)?,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_tct::Witness;
+
+    use super::*;
+
+    fn commitment(byte: u8) -> StateCommitment {
+        StateCommitment::try_from([byte; 32]).unwrap()
+    }
+
+    #[test]
+    fn parse_root_round_trips() {
+        let mut tree = Tree::new();
+        tree.insert(Witness::Keep, commitment(1)).unwrap();
+        let root = tree.root();
+        assert_eq!(parse_root(&root.to_string()).unwrap(), root);
+
+        assert!(parse_root("not hex").is_err());
+        assert!(parse_root("abcd").is_err());
+    }
+
+    #[test]
+    fn display_position_shows_its_parts() {
+        let mut tree = Tree::new();
+        tree.end_epoch().unwrap();
+        tree.end_block().unwrap();
+        tree.insert(Witness::Forget, commitment(1)).unwrap();
+        let position = tree.insert(Witness::Keep, commitment(2)).unwrap();
+        assert_eq!(
+            display_position(position),
+            format!("{} (epoch 1, block 1, commitment 1)", u64::from(position))
+        );
+    }
+
+    #[test]
+    fn diagnose_tells_stale_anchors_from_corruption() {
+        let mut tree = Tree::new();
+        tree.insert(Witness::Keep, commitment(1)).unwrap();
+        let old_root = tree.root();
+        tree.insert(Witness::Keep, commitment(2)).unwrap();
+        let proof = tree.witness(commitment(1)).unwrap();
+
+        assert_eq!(
+            diagnose(&proof, tree.root(), tree.root()),
+            Diagnosis::Verifies
+        );
+        assert_eq!(
+            diagnose(&proof, old_root, tree.root()),
+            Diagnosis::StaleAnchor
+        );
+
+        // A witness for the same commitment at a different position verifies against neither.
+        let mut other = Tree::new();
+        other.insert(Witness::Forget, commitment(3)).unwrap();
+        other.insert(Witness::Keep, commitment(1)).unwrap();
+        let misplaced = other.witness(commitment(1)).unwrap();
+        assert_eq!(
+            diagnose(&misplaced, old_root, tree.root()),
+            Diagnosis::Corrupted
+        );
+    }
+}
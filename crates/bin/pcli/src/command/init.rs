@@ -1,9 +1,16 @@
 use std::{io::Read, str::FromStr};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
-use penumbra_custody::threshold;
-use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+use penumbra_custody::{external, threshold};
+use penumbra_keys::{
+    keys::{Bip44Path, SeedPhrase, SpendKey},
+    FullViewingKey,
+};
+use penumbra_proto::custody::v1alpha1::{
+    self as pb, custody_protocol_service_client::CustodyProtocolServiceClient,
+};
+use penumbra_view::{ChaffPolicy, DatabaseEncryption, DatabaseKey};
 use rand_core::OsRng;
 use url::Url;
 
@@ -28,6 +35,29 @@ pub struct InitCmd {
             parse(try_from_str = Url::parse),
         )]
     grpc_url: Url,
+    /// The block height before which the wallet cannot have received any funds.
+    ///
+    /// If set, the view database will start synchronizing from the last epoch
+    /// boundary before this height, rather than scanning the chain from genesis.
+    #[clap(long)]
+    birthday: Option<u64>,
+    /// The fraction, between 0 and 1, of blocks irrelevant to the wallet whose
+    /// transactions are downloaded anyway during sync, to hide the relevant
+    /// ones from the node.
+    #[clap(long)]
+    chaff_rate: Option<f64>,
+    /// Encrypt the view database at rest with a passphrase.
+    ///
+    /// The passphrase is read from the `PENUMBRA_VIEW_PASSPHRASE` environment
+    /// variable if it is set, and is otherwise asked for whenever the view
+    /// database is opened.
+    #[clap(long, conflicts_with = "view_key_file")]
+    encrypt_view_with_passphrase: bool,
+    /// Encrypt the view database at rest with a key read from this file.
+    ///
+    /// If the file doesn't exist, a new random key is written to it.
+    #[clap(long)]
+    view_key_file: Option<Utf8PathBuf>,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -38,6 +68,23 @@ pub enum InitSubCmd {
     /// Initialize `pcli` with a manual threshold signing backend.
     #[clap(subcommand, display_order = 150)]
     Threshold(ThresholdInitCmd),
+    /// Initialize `pcli` with an external signer, such as a hardware wallet.
+    ///
+    /// The signer must implement the custody protocol, either listening on a
+    /// Unix socket or speaking it over the stdin and stdout of a command.  The
+    /// wallet's full viewing key is requested from the signer.
+    #[clap(display_order = 175)]
+    External {
+        /// The path of the Unix socket the signer listens on.
+        #[clap(long, conflicts_with = "command", required_unless_present = "command")]
+        socket: Option<Utf8PathBuf>,
+        /// A command that runs the signer, speaking the custody protocol over its stdio.
+        #[clap(long)]
+        command: Option<String>,
+        /// Arguments to pass to the signer command, after a `--`.
+        #[clap(last = true, requires = "command")]
+        args: Vec<String>,
+    },
     /// Initialize `pcli` in view-only mode, without spending keys.
     #[clap(display_order = 200)]
     ViewOnly {
@@ -141,9 +188,29 @@ pub enum ThresholdInitCmd {
         #[clap(short, long)]
         num_participants: u16,
     },
+    /// Join the set of signers for an existing threshold key, which is being
+    /// reshared with `pcli threshold reshare`.
+    Reshare {
+        /// The number of current signers dealing new shares.
+        #[clap(short, long)]
+        dealers: u16,
+        /// The minimum number of signers in the new set required to make a signature (>= 2).
+        #[clap(short, long)]
+        threshold: u16,
+        /// The number of signers in the new set.
+        #[clap(short, long)]
+        num_participants: u16,
+    },
 }
 
-fn exec_deal(threshold: u16, home: Vec<Utf8PathBuf>, grpc_url: Url) -> Result<()> {
+fn exec_deal(
+    threshold: u16,
+    home: Vec<Utf8PathBuf>,
+    grpc_url: Url,
+    birthday: Option<u64>,
+    chaff_rate: Option<f64>,
+    view_encryption: Option<DatabaseEncryption>,
+) -> Result<()> {
     if threshold < 2 {
         anyhow::bail!("threshold must be >= 2");
     }
@@ -159,6 +226,9 @@ fn exec_deal(threshold: u16, home: Vec<Utf8PathBuf>, grpc_url: Url) -> Result<()
             grpc_url: grpc_url.clone(),
             view_url: None,
             disable_warning: false,
+            birthday,
+            chaff_rate,
+            view_encryption: view_encryption.clone(),
         };
         println!("  Writing signer {} config to {}", i, path);
         std::fs::create_dir_all(path)?;
@@ -167,10 +237,51 @@ fn exec_deal(threshold: u16, home: Vec<Utf8PathBuf>, grpc_url: Url) -> Result<()
     Ok(())
 }
 
+/// Request the full viewing key of the wallet held by an external signer.
+async fn external_full_viewing_key(config: &external::Config) -> Result<FullViewingKey> {
+    println!("Requesting the full viewing key from the external signer...");
+    let mut client = CustodyProtocolServiceClient::new(external::connect(config));
+    let full_viewing_key = client
+        .export_full_viewing_key(pb::ExportFullViewingKeyRequest {})
+        .await
+        .context("could not get the full viewing key from the external signer")?
+        .into_inner()
+        .full_viewing_key
+        .ok_or_else(|| anyhow::anyhow!("external signer returned no full viewing key"))?
+        .try_into()?;
+    Ok(full_viewing_key)
+}
+
 impl InitCmd {
+    /// How the view database should be encrypted, generating its key file if needed.
+    fn view_encryption(&self) -> Result<Option<DatabaseEncryption>> {
+        if self.encrypt_view_with_passphrase {
+            return Ok(Some(DatabaseEncryption::Passphrase));
+        }
+        let Some(path) = &self.view_key_file else {
+            return Ok(None);
+        };
+        if !path.exists() {
+            println!("Writing a new view database key to {}", path);
+            DatabaseKey::generate_key_file(path)?;
+        }
+        Ok(Some(DatabaseEncryption::KeyFile { path: path.clone() }))
+    }
+
     pub async fn exec(&self, home_dir: impl AsRef<camino::Utf8Path>) -> Result<()> {
+        // Check that the chaff rate is valid before writing it to the config.
+        if let Some(chaff_rate) = self.chaff_rate {
+            ChaffPolicy::new(chaff_rate)?;
+        }
         if let InitSubCmd::Threshold(ThresholdInitCmd::Deal { threshold, home }) = &self.subcmd {
-            exec_deal(threshold.clone(), home.clone(), self.grpc_url.clone())?;
+            exec_deal(
+                threshold.clone(),
+                home.clone(),
+                self.grpc_url.clone(),
+                self.birthday,
+                self.chaff_rate,
+                self.view_encryption()?,
+            )?;
             return Ok(());
         }
         let home_dir = home_dir.as_ref();
@@ -208,9 +319,41 @@ impl InitCmd {
                 let config = threshold::dkg(*threshold, *num_participants, &ActualTerminal).await?;
                 (config.fvk().clone(), CustodyConfig::Threshold(config))
             }
+            InitSubCmd::Threshold(ThresholdInitCmd::Reshare {
+                dealers,
+                threshold,
+                num_participants,
+            }) => {
+                let parameters = threshold::ReshareParameters {
+                    dealers: *dealers,
+                    participants: *num_participants,
+                    threshold: *threshold,
+                };
+                let config = threshold::reshare(None, true, &parameters, &ActualTerminal)
+                    .await?
+                    .expect("joining a reshare produces a config");
+                (config.fvk().clone(), CustodyConfig::Threshold(config))
+            }
             InitSubCmd::Threshold(ThresholdInitCmd::Deal { .. }) => {
                 panic!("this should already have been handled above")
             }
+            InitSubCmd::External {
+                socket,
+                command,
+                args,
+            } => {
+                let signer = match (socket, command) {
+                    (Some(socket), _) => external::Signer::Socket(socket.clone().into()),
+                    (None, Some(program)) => external::Signer::Command {
+                        program: program.clone(),
+                        args: args.clone(),
+                    },
+                    (None, None) => unreachable!("clap requires one of --socket or --command"),
+                };
+                let config = external::Config { signer };
+                let full_viewing_key = external_full_viewing_key(&config).await?;
+                (full_viewing_key, CustodyConfig::External(config))
+            }
             InitSubCmd::ViewOnly { full_viewing_key } => {
                 let full_viewing_key = full_viewing_key.parse()?;
                 (full_viewing_key, CustodyConfig::ViewOnly)
@@ -223,6 +366,9 @@ impl InitCmd {
             grpc_url: self.grpc_url.clone(),
             view_url: None,
             disable_warning: false,
+            birthday: self.birthday,
+            chaff_rate: self.chaff_rate,
+            view_encryption: self.view_encryption()?,
         };
 
         // Create the config directory, if
@@ -2,8 +2,12 @@ use crate::App;
 use anyhow::{Context, Result};
 use futures::TryStreamExt;
 use penumbra_asset::Value;
+use penumbra_community_pool::VestingSchedule;
+use penumbra_num::Amount;
 use penumbra_proto::{
-    core::component::community_pool::v1alpha1::CommunityPoolAssetBalancesRequest,
+    core::component::community_pool::v1alpha1::{
+        CommunityPoolAssetBalancesRequest, VestingSchedulesRequest,
+    },
     penumbra::core::component::community_pool::v1alpha1::query_service_client::QueryServiceClient as CommunityPoolQueryServiceClient,
 };
 use penumbra_view::ViewClient;
@@ -16,12 +20,15 @@ pub enum CommunityPoolCmd {
         /// Get only the balance of the specified asset.
         asset: Option<String>,
     },
+    /// List the active vesting schedules paying out of the Community Pool.
+    Vesting,
 }
 
 impl CommunityPoolCmd {
     pub async fn exec(&self, app: &mut App) -> Result<()> {
         match self {
             CommunityPoolCmd::Balance { asset } => self.print_balance(app, asset).await,
+            CommunityPoolCmd::Vesting => self.print_vesting(app).await,
         }
     }
 
@@ -65,4 +72,52 @@ impl CommunityPoolCmd {
 
         Ok(())
     }
+
+    pub async fn print_vesting(&self, app: &mut App) -> Result<()> {
+        let mut client = CommunityPoolQueryServiceClient::new(app.pd_channel().await?);
+        let chain_id = app.view().app_params().await?.chain_params.chain_id;
+        let schedules = client
+            .vesting_schedules(VestingSchedulesRequest { chain_id })
+            .await?
+            .into_inner()
+            .try_collect::<Vec<_>>()
+            .await
+            .context("cannot process Community Pool vesting data")?;
+
+        let asset_cache = app.view().assets().await?;
+        let mut writer = stdout();
+        for response in schedules {
+            let schedule: VestingSchedule = response
+                .schedule
+                .context("schedule should always be set")?
+                .try_into()
+                .context("cannot parse vesting schedule")?;
+            let paid: Amount = response
+                .paid
+                .context("paid amount should always be set")?
+                .try_into()
+                .context("cannot parse paid amount")?;
+            let format = |amount| {
+                Value {
+                    amount,
+                    asset_id: schedule.total.asset_id,
+                }
+                .format(&asset_cache)
+            };
+
+            writeln!(
+                writer,
+                "#{}: {} of {} paid to {}, vesting {} per epoch from epoch {} (cliff at epoch {})",
+                response.id,
+                format(paid),
+                format(schedule.total.amount),
+                schedule.recipient,
+                format(schedule.per_epoch),
+                schedule.start_epoch,
+                schedule.cliff_epoch,
+            )?;
+        }
+
+        Ok(())
+    }
 }
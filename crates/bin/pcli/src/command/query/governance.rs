@@ -5,7 +5,7 @@ use std::{
 
 use anyhow::{Context, Result};
 use futures::TryStreamExt;
-use penumbra_governance::Vote;
+use penumbra_governance::Ballot;
 use penumbra_proto::core::component::governance::v1alpha1::{
     query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
     AllTalliedDelegatorVotesForProposalRequest, ProposalDataRequest, ProposalListRequest,
@@ -142,17 +142,17 @@ impl GovernanceCmd {
                             .try_collect::<Vec<_>>()
                             .await?;
 
-                        let mut validator_votes_and_power: BTreeMap<IdentityKey, (Vote, u64)> =
+                        let mut validator_votes_and_power: BTreeMap<IdentityKey, (Ballot, u64)> =
                             BTreeMap::new();
                         for vote_response in validator_votes {
                             let identity_key: IdentityKey = vote_response
                                 .identity_key
                                 .expect("identity key must be set for vote response")
                                 .try_into()?;
-                            let vote: Vote = vote_response
-                                .vote
-                                .expect("vote must be set for vote response")
-                                .try_into()?;
+                            let vote = Ballot::try_from_parts(
+                                vote_response.vote,
+                                vote_response.weighted_vote,
+                            )?;
                             let power: u64 = client
                                 .voting_power_at_proposal_start(VotingPowerAtProposalStartRequest {
                                     proposal_id: *proposal_id,
@@ -209,9 +209,7 @@ impl GovernanceCmd {
                                 let mut map = serde_json::Map::new();
                                 map.insert(
                                     "validator".to_string(),
-                                    json!({
-                                        vote.to_string(): power,
-                                    }),
+                                    json_tally(&penumbra_governance::Tally::from((vote, power))),
                                 );
                                 let delegator_tally =
                                     if let Some(tally) = delegator_tallies.remove(&identity_key) {
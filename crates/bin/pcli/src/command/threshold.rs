@@ -1,17 +1,30 @@
 use anyhow::Result;
+use camino::Utf8Path;
+use penumbra_custody::threshold::{self, ReshareParameters};
 
-use crate::{terminal::ActualTerminal, App};
+use crate::{
+    config::{CustodyConfig, PcliConfig},
+    terminal::ActualTerminal,
+    App,
+};
 
 #[derive(Debug, clap::Subcommand)]
 pub enum ThresholdCmd {
     /// Contribute to signing a transaction with threshold custody
     Sign,
+    /// Reshare the threshold key to a new set of signers, or with a new threshold.
+    ///
+    /// Every signer dealing new shares runs this command, along with `pcli init
+    /// threshold reshare` for each signer joining the new set.  The full viewing
+    /// key stays the same, so no funds need to move.
+    Reshare(ReshareCmd),
 }
 
 impl ThresholdCmd {
     pub fn offline(&self) -> bool {
         match self {
             ThresholdCmd::Sign => true,
+            ThresholdCmd::Reshare(_) => true,
         }
     }
 
@@ -25,6 +38,66 @@ impl ThresholdCmd {
             ThresholdCmd::Sign => {
                 penumbra_custody::threshold::follow(config, &ActualTerminal).await
             }
+            ThresholdCmd::Reshare(_) => {
+                unreachable!("reshare command already executed")
+            }
         }
     }
 }
+
+#[derive(Debug, clap::Parser)]
+pub struct ReshareCmd {
+    /// The number of current signers dealing new shares, which must be at least
+    /// the current threshold.
+    #[clap(short, long)]
+    dealers: u16,
+    /// The minimum number of signers in the new set required to make a signature (>= 2).
+    #[clap(short, long)]
+    threshold: u16,
+    /// The number of signers in the new set.
+    #[clap(short, long)]
+    num_participants: u16,
+    /// Deal new shares without keeping one, leaving the set of signers.
+    ///
+    /// The share in this config is removed once every signer in the new set has
+    /// confirmed their share, leaving a view-only config.
+    #[clap(long)]
+    leave: bool,
+}
+
+impl ReshareCmd {
+    pub async fn exec(&self, home: impl AsRef<Utf8Path>) -> Result<()> {
+        let config_path = home.as_ref().join(crate::CONFIG_FILE_NAME);
+        let mut config = PcliConfig::load(&config_path)?;
+        let threshold_config = match &config.custody {
+            CustodyConfig::Threshold(config) => config.clone(),
+            _ => anyhow::bail!("this command can only be used with the threshold custody backend"),
+        };
+        let parameters = ReshareParameters {
+            dealers: self.dealers,
+            participants: self.num_participants,
+            threshold: self.threshold,
+        };
+
+        let new_config = threshold::reshare(
+            Some(threshold_config),
+            !self.leave,
+            &parameters,
+            &ActualTerminal,
+        )
+        .await?;
+
+        // Resharing only returns once every new participant has confirmed their
+        // share, so the old config is untouched if it fails at any point.  After
+        // that, the old share is overwritten, rather than kept around, since the
+        // old shares remain able to sign until enough of them are destroyed.
+        config.custody = match new_config {
+            Some(new_config) => CustodyConfig::Threshold(new_config),
+            None => CustodyConfig::ViewOnly,
+        };
+        config.save(&config_path)?;
+        println!("Updated config at {config_path}");
+
+        Ok(())
+    }
+}
@@ -26,13 +26,20 @@ use rand_core::OsRng;
 use regex::Regex;
 
 use liquidity_position::PositionCmd;
+use offline::{BroadcastCmd, SignCmd};
 use penumbra_asset::{asset, asset::DenomMetadata, Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_dex::{lp::position, swap_claim::SwapClaimPlan};
 use penumbra_fee::Fee;
-use penumbra_governance::{proposal::ProposalToml, proposal_state::State as ProposalState, Vote};
-use penumbra_keys::keys::AddressIndex;
+use penumbra_governance::{
+    proposal::ProposalToml, proposal_state::State as ProposalState, Ballot, Proposal, Vote,
+    WeightedVote,
+};
+use penumbra_keys::{keys::AddressIndex, Address};
 use penumbra_num::Amount;
 use penumbra_proto::{
+    core::app::v1alpha1::{
+        query_service_client::QueryServiceClient as AppQueryServiceClient, SimulateProposalRequest,
+    },
     core::component::{
         chain::v1alpha1::{
             query_service_client::QueryServiceClient as ChainQueryServiceClient,
@@ -56,8 +63,10 @@ use penumbra_proto::{
 };
 use penumbra_shielded_pool::Ics20Withdrawal;
 use penumbra_stake::rate::RateData;
-use penumbra_stake::{DelegationToken, IdentityKey, Penalty, UnbondingToken, UndelegateClaimPlan};
-use penumbra_transaction::{gas::swap_claim_gas_cost, memo::MemoPlaintext};
+use penumbra_stake::{
+    DelegationToken, IdentityKey, Penalty, RedelegationToken, UnbondingToken, UndelegateClaimPlan,
+};
+use penumbra_transaction::{gas::swap_claim_gas_cost, memo::MemoPlaintext, PaymentRequest};
 use penumbra_view::ViewClient;
 use penumbra_wallet::plan::{self, Planner};
 use proposal::ProposalCmd;
@@ -65,8 +74,10 @@ use proposal::ProposalCmd;
 use crate::App;
 
 mod liquidity_position;
+mod offline;
 mod proposal;
 mod replicate;
+mod simulate;
 
 #[derive(Debug, clap::Subcommand)]
 pub enum TxCmd {
@@ -74,8 +85,8 @@ pub enum TxCmd {
     #[clap(display_order = 100)]
     Send {
         /// The destination address to send funds to.
-        #[clap(long, display_order = 100)]
-        to: String,
+        #[clap(long, display_order = 100, required_unless_present = "uri")]
+        to: Option<String>,
         /// The amounts to send, written as typed values 1.87penumbra, 12cubes, etc.
         values: Vec<String>,
         /// Only spend funds originally received by the given account.
@@ -84,6 +95,11 @@ pub enum TxCmd {
         /// Optional. Set the transaction's memo field to the provided text.
         #[clap(long)]
         memo: Option<String>,
+        /// Pay a `penumbra:` payment request URI, instead of giving the destination and memo.
+        ///
+        /// The amounts to send may only be given if the request doesn't specify one.
+        #[clap(long, display_order = 200, conflicts_with_all = &["to", "memo"])]
+        uri: Option<String>,
     },
     /// Deposit stake into a validator's delegation pool.
     #[clap(display_order = 200)]
@@ -106,7 +122,19 @@ pub enum TxCmd {
         #[clap(long, default_value = "0", display_order = 300)]
         source: u32,
     },
-    /// Claim any undelegations that have finished unbonding.
+    /// Move stake from one validator's delegation pool to another's without waiting for unbonding.
+    #[clap(display_order = 200)]
+    Redelegate {
+        /// The identity key of the validator to redelegate to.
+        #[clap(long, display_order = 100)]
+        to: String,
+        /// The amount of delegation tokens to redelegate.
+        amount: String,
+        /// Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0", display_order = 300)]
+        source: u32,
+    },
+    /// Claim any undelegations and redelegations that have finished unbonding.
     #[clap(display_order = 200)]
     UndelegateClaim {},
     /// Swap tokens of one denomination for another using the DEX.
@@ -202,6 +230,12 @@ pub enum TxCmd {
         #[clap(long, default_value = "0", display_order = 200)]
         source: u32,
     },
+    /// Sign a transaction plan made with `--plan-only`, on a machine with only custody access.
+    #[clap(display_order = 950)]
+    Sign(SignCmd),
+    /// Build and broadcast a transaction from a plan signed offline with `pcli tx sign`.
+    #[clap(display_order = 960)]
+    Broadcast(BroadcastCmd),
 }
 
 /// Vote on a governance proposal.
@@ -228,15 +262,53 @@ pub enum VoteCmd {
         #[clap(long = "on")]
         proposal_id: u64,
     },
+    /// Split your voting power between the options on a proposal.
+    ///
+    /// Voting power is divided in proportion to the given weights: for example, `--yes 3 --no 1`
+    /// votes yes with three quarters of your voting power and no with the remaining quarter.
+    #[clap(display_order = 400)]
+    Split {
+        /// The proposal ID to vote on.
+        #[clap(long = "on")]
+        proposal_id: u64,
+        /// The weight to give to voting yes.
+        #[clap(long, default_value = "0")]
+        yes: u64,
+        /// The weight to give to voting no.
+        #[clap(long, default_value = "0")]
+        no: u64,
+        /// The weight to give to abstaining.
+        #[clap(long, default_value = "0")]
+        abstain: u64,
+    },
 }
 
-impl From<VoteCmd> for (u64, Vote) {
-    fn from(cmd: VoteCmd) -> (u64, Vote) {
-        match cmd {
-            VoteCmd::Yes { proposal_id } => (proposal_id, Vote::Yes),
-            VoteCmd::No { proposal_id } => (proposal_id, Vote::No),
-            VoteCmd::Abstain { proposal_id } => (proposal_id, Vote::Abstain),
-        }
+impl TryFrom<VoteCmd> for (u64, Ballot) {
+    type Error = anyhow::Error;
+
+    fn try_from(cmd: VoteCmd) -> Result<(u64, Ballot)> {
+        Ok(match cmd {
+            VoteCmd::Yes { proposal_id } => (proposal_id, Vote::Yes.into()),
+            VoteCmd::No { proposal_id } => (proposal_id, Vote::No.into()),
+            VoteCmd::Abstain { proposal_id } => (proposal_id, Vote::Abstain.into()),
+            VoteCmd::Split {
+                proposal_id,
+                yes,
+                no,
+                abstain,
+            } => {
+                anyhow::ensure!(
+                    yes > 0 || no > 0 || abstain > 0,
+                    "at least one of --yes, --no, or --abstain must be given a nonzero weight"
+                );
+                let weighted_vote = WeightedVote {
+                    yes: yes.into(),
+                    no: no.into(),
+                    abstain: abstain.into(),
+                };
+                (proposal_id, weighted_vote.into())
+            }
+        })
     }
 }
 
@@ -249,16 +321,26 @@ impl TxCmd {
             TxCmd::Swap { .. } => false,
             TxCmd::Delegate { .. } => false,
             TxCmd::Undelegate { .. } => false,
+            TxCmd::Redelegate { .. } => false,
             TxCmd::UndelegateClaim { .. } => false,
             TxCmd::Vote { .. } => false,
             TxCmd::Proposal(proposal_cmd) => proposal_cmd.offline(),
             TxCmd::CommunityPoolDeposit { .. } => false,
             TxCmd::Position(lp_cmd) => lp_cmd.offline(),
             TxCmd::Withdraw { .. } => false,
+            TxCmd::Sign(sign_cmd) => sign_cmd.offline(),
+            TxCmd::Broadcast(broadcast_cmd) => broadcast_cmd.offline(),
         }
     }
 
     pub async fn exec(&self, app: &mut App) -> Result<()> {
+        // These commands work with an existing plan, so they don't need to plan anything.
+        match self {
+            TxCmd::Sign(sign_cmd) => return sign_cmd.exec(app).await,
+            TxCmd::Broadcast(broadcast_cmd) => return broadcast_cmd.exec(app).await,
+            _ => {}
+        }
+
         let gas_prices = app
             .view
             .as_mut()
@@ -276,15 +358,46 @@ impl TxCmd {
                 to,
                 source: from,
                 memo,
+                uri,
             } => {
                 // Parse all of the values provided.
-                let values = values
+                let mut values = values
                     .iter()
                     .map(|v| v.parse())
                     .collect::<Result<Vec<Value>, _>>()?;
-                let to = to
-                    .parse()
-                    .map_err(|_| anyhow::anyhow!("address is invalid"))?;
+                let mut memo = memo.clone();
+                let mut expiry_height = None;
+                let to: Address = match (to, uri) {
+                    (_, Some(uri)) => {
+                        let request: PaymentRequest = uri.parse()?;
+                        if let Some(value) = request.value {
+                            if !values.is_empty() {
+                                anyhow::bail!(
+                                    "the payment request already specifies the amount to send"
+                                );
+                            }
+                            values.push(value);
+                        }
+                        if let Some(height) = request.expiry_height {
+                            let current_height = app.view().status().await?.full_sync_height;
+                            if request.is_expired(current_height) {
+                                anyhow::bail!(
+                                    "the payment request expired after height {height}, and the current height is {current_height}"
+                                );
+                            }
+                        }
+                        memo = request.memo;
+                        expiry_height = request.expiry_height;
+                        request.address
+                    }
+                    (Some(to), None) => to
+                        .parse()
+                        .map_err(|_| anyhow::anyhow!("address is invalid"))?,
+                    (None, None) => anyhow::bail!("a destination address or URI is required"),
+                };
+                if values.is_empty() {
+                    anyhow::bail!("no amount to send was given");
+                }
 
                 let return_address = app
                     .config
@@ -292,11 +405,15 @@ impl TxCmd {
                     .payment_address((*from).into())
                     .0;
 
-                let memo_plaintext =
-                    MemoPlaintext::new(return_address, memo.clone().unwrap_or_default())?;
+                let memo_plaintext = MemoPlaintext::new(return_address, memo.unwrap_or_default())?;
 
                 let mut planner = Planner::new(OsRng);
-                planner.set_gas_prices(gas_prices);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .selection_strategy(app.selection_strategy);
+                if let Some(expiry_height) = expiry_height {
+                    planner.expiry_height(expiry_height);
+                }
                 for value in values.iter().cloned() {
                     planner.output(value, to);
                 }
@@ -319,7 +436,9 @@ impl TxCmd {
                     .collect::<Result<Vec<Value>, _>>()?;
 
                 let mut planner = Planner::new(OsRng);
-                planner.set_gas_prices(gas_prices);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .selection_strategy(app.selection_strategy);
                 for value in values {
                     planner.community_pool_deposit(value);
                 }
@@ -333,6 +452,24 @@ impl TxCmd {
                     .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Sweep if app.plan_only.is_some() => {
+                // The notes made by one round of sweeps aren't spendable until its transactions
+                // are broadcast, so only the first round can be planned.
+                let plans = plan::sweep(
+                    app.view
+                        .as_mut()
+                        .context("view service must be initialized")?,
+                    OsRng,
+                )
+                .await?;
+                let num_plans = plans.len();
+                for plan in plans {
+                    app.build_and_submit_transaction(plan).await?;
+                }
+                println!(
+                    "planned {num_plans} sweeps; run the sweep again once they are broadcast to plan any remaining ones"
+                );
+            }
             TxCmd::Sweep => loop {
                 let plans = plan::sweep(
                     app.view
@@ -372,7 +509,9 @@ impl TxCmd {
                     fvk.incoming().payment_address(AddressIndex::new(*source));
 
                 let mut planner = Planner::new(OsRng);
-                planner.set_gas_prices(gas_prices.clone());
+                planner
+                    .set_gas_prices(gas_prices.clone())
+                    .selection_strategy(app.selection_strategy);
                 // The swap claim requires a pre-paid fee, however gas costs might change in the meantime.
                 // This shouldn't be an issue, since the planner will account for the difference and add additional
                 // spends alongside the swap claim transaction as necessary.
@@ -403,7 +542,10 @@ impl TxCmd {
 
                 // Submit the `Swap` transaction, waiting for confirmation,
                 // at which point the swap will be available for claiming.
-                app.build_and_submit_transaction(plan).await?;
+                if app.build_and_submit_transaction(plan).await?.is_none() {
+                    // Only the swap itself was planned, so there's nothing to claim yet.
+                    return Ok(());
+                }
 
                 // Fetch the SwapRecord with the claimable swap.
                 let swap_record = app
@@ -439,7 +581,9 @@ impl TxCmd {
                     .await?;
 
                 let mut planner = Planner::new(OsRng);
-                planner.set_gas_prices(gas_prices);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .selection_strategy(app.selection_strategy);
                 let plan = planner
                     .swap_claim(SwapClaimPlan {
                         swap_plaintext,
@@ -477,7 +621,9 @@ impl TxCmd {
                     .try_into()?;
 
                 let mut planner = Planner::new(OsRng);
-                planner.set_gas_prices(gas_prices);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .selection_strategy(app.selection_strategy);
                 let plan = planner
                     .delegate(unbonded_amount.value(), rate_data)
                     .plan(app.view(), AddressIndex::new(*source))
@@ -513,7 +659,9 @@ impl TxCmd {
                     .try_into()?;
 
                 let mut planner = Planner::new(OsRng);
-                planner.set_gas_prices(gas_prices);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .selection_strategy(app.selection_strategy);
 
                 let plan = planner
                     .undelegate(delegation_value.amount, rate_data)
@@ -528,6 +676,59 @@ impl TxCmd {
 
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Redelegate { to, amount, source } => {
+                let to = to.parse::<IdentityKey>()?;
+
+                let delegation_value @ Value {
+                    amount: _,
+                    asset_id,
+                } = amount.parse::<Value>()?;
+
+                let delegation_token: DelegationToken = app
+                    .view()
+                    .assets()
+                    .await?
+                    .get(&asset_id)
+                    .ok_or_else(|| anyhow::anyhow!("unknown asset id {}", asset_id))?
+                    .clone()
+                    .try_into()
+                    .context("could not parse supplied denomination as a delegation token")?;
+
+                let from = delegation_token.validator();
+                if from == to {
+                    anyhow::bail!("cannot redelegate to the same validator");
+                }
+
+                let mut client = StakeQueryServiceClient::new(app.pd_channel().await?);
+                let from_rate_data: RateData = client
+                    .current_validator_rate(tonic::Request::new(from.into()))
+                    .await?
+                    .into_inner()
+                    .try_into()?;
+                let to_rate_data: RateData = client
+                    .current_validator_rate(tonic::Request::new(to.into()))
+                    .await?
+                    .into_inner()
+                    .try_into()?;
+
+                let mut planner = Planner::new(OsRng);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .selection_strategy(app.selection_strategy);
+
+                let plan = planner
+                    .redelegate(delegation_value.amount, from_rate_data, to_rate_data)
+                    .plan(
+                        app.view
+                            .as_mut()
+                            .context("view service must be initialized")?,
+                        AddressIndex::new(*source),
+                    )
+                    .await
+                    .context("can't build redelegate plan")?;
+
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::UndelegateClaim {} => {
                 let channel = app.pd_channel().await?;
                 let view: &mut dyn ViewClient = app
@@ -597,7 +798,10 @@ impl TxCmd {
                             .try_into()?;
 
                         let mut planner = Planner::new(OsRng);
-                        planner.set_gas_prices(gas_prices.clone());
+                        planner
+                            .set_gas_prices(gas_prices.clone())
+                            .selection_strategy(app.selection_strategy)
+                            .exclude_notes(app.planned_spends.iter().copied());
                         let unbonding_amount = notes.iter().map(|n| n.note.amount()).sum();
                         for note in notes {
                             planner.spend(note.note, note.position);
@@ -623,6 +827,96 @@ impl TxCmd {
                         app.build_and_submit_transaction(plan).await?;
                     }
                 }
+
+                // Redelegation tokens are claimed for the destination validator's delegation
+                // tokens, once the redelegated stake can no longer be slashed by the source
+                // validator.
+                let params = app
+                    .view
+                    .as_mut()
+                    .context("view service must be initialized")?
+                    .app_params()
+                    .await?;
+                let notes = app
+                    .view
+                    .as_mut()
+                    .context("view service must be initialized")?
+                    .unspent_notes_by_address_and_asset()
+                    .await?;
+                for (address_index, notes_by_asset) in notes.into_iter() {
+                    for (token, notes) in
+                        notes_by_asset.into_iter().filter_map(|(asset_id, notes)| {
+                            let denom = asset_cache
+                                .get(&asset_id)
+                                .expect("asset ID should exist in asset cache")
+                                .clone();
+                            match RedelegationToken::try_from(denom) {
+                                Ok(token) => Some((token, notes)),
+                                Err(_) => None,
+                            }
+                        })
+                    {
+                        let start_epoch_index = token.start_epoch_index();
+                        let end_epoch_index =
+                            start_epoch_index + params.stake_params.unbonding_epochs;
+                        if end_epoch_index > current_epoch.index {
+                            println!(
+                                "{} can be claimed in epoch {end_epoch_index}",
+                                token.denom().default_unit()
+                            );
+                            continue;
+                        }
+
+                        println!("claiming {}", token.denom().default_unit());
+                        let from_validator_identity = token.from_validator();
+
+                        let mut client = StakeQueryServiceClient::new(channel.clone());
+                        let penalty: Penalty = client
+                            .validator_penalty(tonic::Request::new(ValidatorPenaltyRequest {
+                                chain_id: params.chain_params.chain_id.to_string(),
+                                identity_key: Some(from_validator_identity.clone().into()),
+                                start_epoch_index,
+                                end_epoch_index,
+                            }))
+                            .await?
+                            .into_inner()
+                            .penalty
+                            .ok_or_else(|| {
+                                anyhow::anyhow!(
+                                    "no penalty returned for validator {}",
+                                    from_validator_identity
+                                )
+                            })?
+                            .try_into()?;
+
+                        let mut planner = Planner::new(OsRng);
+                        planner
+                            .set_gas_prices(gas_prices.clone())
+                            .selection_strategy(app.selection_strategy)
+                            .exclude_notes(app.planned_spends.iter().copied());
+                        let escrowed_amount = notes.iter().map(|n| n.note.amount()).sum();
+                        for note in notes {
+                            planner.spend(note.note, note.position);
+                        }
+
+                        let plan = planner
+                            .redelegate_claim(
+                                from_validator_identity,
+                                token.to_validator(),
+                                start_epoch_index,
+                                penalty,
+                                escrowed_amount,
+                            )
+                            .plan(
+                                app.view
+                                    .as_mut()
+                                    .context("view service must be initialized")?,
+                                address_index,
+                            )
+                            .await?;
+                        app.build_and_submit_transaction(plan).await?;
+                    }
+                }
             }
             TxCmd::Proposal(ProposalCmd::Submit {
                 file,
@@ -641,7 +935,9 @@ impl TxCmd {
                     .context("can't parse proposal file")?;
 
                 let mut planner = Planner::new(OsRng);
-                planner.set_gas_prices(gas_prices);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .selection_strategy(app.selection_strategy);
                 let plan = planner
                     .proposal_submit(proposal, Amount::from(*deposit_amount))
                     .plan(
@@ -653,13 +949,38 @@ impl TxCmd {
                     .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Proposal(ProposalCmd::Simulate { file }) => {
+                let mut proposal_file = File::open(file).context("can't open proposal file")?;
+                let mut proposal_string = String::new();
+                proposal_file
+                    .read_to_string(&mut proposal_string)
+                    .context("can't read proposal file")?;
+                let proposal_toml: ProposalToml =
+                    toml::from_str(&proposal_string).context("can't parse proposal file")?;
+                let proposal: Proposal = proposal_toml
+                    .try_into()
+                    .context("can't parse proposal file")?;
+
+                let mut client = AppQueryServiceClient::new(app.pd_channel().await?);
+                let simulation = client
+                    .simulate_proposal(SimulateProposalRequest {
+                        chain_id: app.view().app_params().await?.chain_params.chain_id,
+                        proposal: Some(proposal.into()),
+                    })
+                    .await?
+                    .into_inner();
+
+                println!("{}", simulate::render_simulation(simulation)?);
+            }
             TxCmd::Proposal(ProposalCmd::Withdraw {
                 proposal_id,
                 reason,
                 source,
             }) => {
                 let mut planner = Planner::new(OsRng);
-                planner.set_gas_prices(gas_prices);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .selection_strategy(app.selection_strategy);
                 let plan = planner
                     .proposal_withdraw(*proposal_id, reason.clone())
                     .plan(
@@ -740,6 +1061,7 @@ impl TxCmd {
                 };
 
                 let plan = Planner::new(OsRng)
+                    .selection_strategy(app.selection_strategy)
                     .proposal_deposit_claim(*proposal_id, deposit_amount, outcome)
                     .plan(
                         app.view
@@ -752,7 +1074,7 @@ impl TxCmd {
                 app.build_and_submit_transaction(plan).await?;
             }
             TxCmd::Vote { vote, source } => {
-                let (proposal_id, vote): (u64, Vote) = (*vote).into();
+                let (proposal_id, vote): (u64, Ballot) = (*vote).try_into()?;
 
                 // Before we vote on the proposal, we have to gather some information about it so
                 // that we can prepare our vote:
@@ -797,6 +1119,7 @@ impl TxCmd {
                 }
 
                 let plan = Planner::new(OsRng)
+                    .selection_strategy(app.selection_strategy)
                     .set_gas_prices(gas_prices)
                     .delegator_vote(
                         proposal_id,
@@ -825,6 +1148,7 @@ impl TxCmd {
                 tracing::info!(?position);
 
                 let plan = Planner::new(OsRng)
+                    .selection_strategy(app.selection_strategy)
                     .position_open(position)
                     .fee(fee)
                     .plan(
@@ -836,6 +1160,9 @@ impl TxCmd {
                     .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Sign(_) | TxCmd::Broadcast(_) => {
+                unreachable!("commands working with existing plans are handled above")
+            }
             TxCmd::Withdraw {
                 to,
                 value,
@@ -959,6 +1286,7 @@ impl TxCmd {
                 };
 
                 let plan = Planner::new(OsRng)
+                    .selection_strategy(app.selection_strategy)
                     .ics20_withdrawal(withdrawal)
                     .fee(fee)
                     .plan(
@@ -978,6 +1306,7 @@ impl TxCmd {
                 let fee = Fee::from_staking_token_amount((*fee).into());
 
                 let plan = Planner::new(OsRng)
+                    .selection_strategy(app.selection_strategy)
                     .position_close(*position_id)
                     .fee(fee)
                     .plan(
@@ -1011,7 +1340,9 @@ impl TxCmd {
                 let fee = Fee::from_staking_token_amount((*fee).into());
 
                 let mut planner = Planner::new(OsRng);
-                planner.set_gas_prices(gas_prices);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .selection_strategy(app.selection_strategy);
 
                 for position_id in owned_position_ids {
                     // Close the position
@@ -1051,7 +1382,9 @@ impl TxCmd {
                 let fee = Fee::from_staking_token_amount((*fee).into());
 
                 let mut planner = Planner::new(OsRng);
-                planner.set_gas_prices(gas_prices);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .selection_strategy(app.selection_strategy);
 
                 let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
 
@@ -1145,6 +1478,7 @@ impl TxCmd {
                 let fee = Fee::from_staking_token_amount((*fee).into());
 
                 let plan = Planner::new(OsRng)
+                    .selection_strategy(app.selection_strategy)
                     .set_gas_prices(gas_prices)
                     .position_withdraw(*position_id, reserves.try_into()?, pair.try_into()?)
                     .fee(fee)
@@ -0,0 +1,153 @@
+use std::fs::File;
+
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use dialoguer::Confirm;
+use penumbra_asset::asset;
+use penumbra_custody::{AuthorizeRequest, CustodyClient};
+use penumbra_proto::core::transaction::v1alpha1 as pb;
+use penumbra_transaction::{plan::TransactionPlan, AuthorizationData, TransactionPerspective};
+use penumbra_view::ViewClient;
+
+use crate::{
+    command::view::{format_transaction_plan, render_transaction_view},
+    App,
+};
+
+/// Sign a transaction plan made with `--plan-only`, without access to the network.
+///
+/// Only the custody backend is used, so this can run on an offline machine.
+#[derive(Debug, clap::Args)]
+pub struct SignCmd {
+    /// The transaction plan file to sign.
+    plan: Utf8PathBuf,
+    /// The file to write the authorization data to.
+    #[clap(long, short)]
+    output: Utf8PathBuf,
+    /// Sign without asking for confirmation after showing the transaction.
+    #[clap(long)]
+    yes: bool,
+}
+
+impl SignCmd {
+    pub fn offline(&self) -> bool {
+        true
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let plan = load_plan(&self.plan)?;
+
+        let fvk = &app.config.full_viewing_key;
+        let asset_cache = asset::Cache::with_known_assets();
+        println!("{}", format_transaction_plan(fvk, &asset_cache, &plan));
+        println!(
+            "effect hash: {}",
+            hex::encode(plan.effect_hash(fvk)?.as_bytes())
+        );
+
+        if !self.yes
+            && !Confirm::new()
+                .with_prompt("Do you want to sign this transaction?")
+                .interact()?
+        {
+            return Ok(());
+        }
+
+        let auth_data: AuthorizationData = CustodyClient::authorize(
+            &mut app.custody,
+            AuthorizeRequest {
+                plan,
+                pre_authorizations: Vec::new(),
+            },
+        )
+        .await?
+        .data
+        .ok_or_else(|| anyhow::anyhow!("empty AuthorizeResponse message"))?
+        .try_into()?;
+
+        let file = File::options()
+            .write(true)
+            .create_new(true)
+            .open(&self.output)
+            .with_context(|| format!("could not create authorization data file {}", self.output))?;
+        serde_json::to_writer_pretty(file, &pb::AuthorizationData::from(auth_data))?;
+        println!(
+            "wrote authorization data to {}; broadcast it with `pcli tx broadcast {} {}`",
+            self.output, self.plan, self.output
+        );
+
+        Ok(())
+    }
+}
+
+/// Build and broadcast a transaction from a plan and the authorization data signed offline.
+#[derive(Debug, clap::Args)]
+pub struct BroadcastCmd {
+    /// The transaction plan file, as made with `--plan-only`.
+    plan: Utf8PathBuf,
+    /// The authorization data file, as made with `pcli tx sign`.
+    authorization: Utf8PathBuf,
+}
+
+impl BroadcastCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let plan = load_plan(&self.plan)?;
+        let auth_data: AuthorizationData = serde_json::from_reader::<_, pb::AuthorizationData>(
+            File::open(&self.authorization).with_context(|| {
+                format!(
+                    "could not open authorization data file {}",
+                    self.authorization
+                )
+            })?,
+        )
+        .with_context(|| {
+            format!(
+                "could not parse authorization data file {}",
+                self.authorization
+            )
+        })?
+        .try_into()?;
+
+        let fvk = app.config.full_viewing_key.clone();
+        anyhow::ensure!(
+            plan.effect_hash(&fvk)? == auth_data.effect_hash,
+            "the authorization data was not made for this transaction plan"
+        );
+
+        // The spent notes are known from the plan, so they can be shown in the summary.
+        let spend_nullifiers = plan
+            .spend_plans()
+            .map(|spend| (spend.nullifier(&fvk), spend.note.clone()))
+            .collect();
+
+        println!("building transaction...");
+        let transaction = app.view().witness_and_build(plan, auth_data).await?;
+
+        let perspective = TransactionPerspective {
+            payload_keys: transaction.payload_keys(&fvk)?,
+            spend_nullifiers,
+            ..Default::default()
+        };
+        let asset_cache = app.view().assets().await?;
+        render_transaction_view(
+            &fvk,
+            &asset_cache,
+            transaction.view_from_perspective(&perspective),
+        )?;
+
+        app.submit_transaction(transaction).await?;
+
+        Ok(())
+    }
+}
+
+fn load_plan(path: &Utf8Path) -> Result<TransactionPlan> {
+    serde_json::from_reader(
+        File::open(path).with_context(|| format!("could not open transaction plan file {path}"))?,
+    )
+    .with_context(|| format!("could not parse transaction plan file {path}"))
+}
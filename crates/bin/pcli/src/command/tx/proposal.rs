@@ -1,7 +1,11 @@
 use anyhow::{Context, Result};
+use rand_core::OsRng;
 
 use penumbra_app::params::AppParameters;
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_community_pool::VestingSchedule;
 use penumbra_governance::{proposal::ChangedAppParameters, Proposal, ProposalPayload};
+use penumbra_keys::Address;
 use penumbra_proto::DomainType;
 use penumbra_transaction::plan::TransactionPlan;
 
@@ -12,7 +16,7 @@ pub enum ProposalCmd {
         /// The file to output the template to.
         #[clap(long, global = true)]
         file: Option<camino::Utf8PathBuf>,
-        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, or community-pool-spend].
+        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, community-pool-spend, community-pool-vesting-grant, or community-pool-vesting-cancel].
         #[clap(subcommand)]
         kind: ProposalKindCmd,
     },
@@ -28,6 +32,15 @@ pub enum ProposalCmd {
         #[clap(long)]
         deposit_amount: u64,
     },
+    /// Simulate the enactment of a proposal against the current chain state, without submitting it.
+    ///
+    /// Reports the parameter changes and events the proposal would produce if it passed now, or
+    /// the reason it would fail to be enacted.
+    Simulate {
+        /// The proposal to simulate, in TOML format.
+        #[clap(long)]
+        file: camino::Utf8PathBuf,
+    },
     /// Withdraw a governance proposal that you previously submitted.
     Withdraw {
         /// The proposal id to withdraw.
@@ -73,6 +86,14 @@ pub enum ProposalKindCmd {
     },
     /// Generate a template for an upgrade propopsal,
     UpgradePlan,
+    /// Generate a template for a Community Pool vesting grant proposal.
+    CommunityPoolVestingGrant,
+    /// Generate a template for a Community Pool vesting cancellation proposal.
+    CommunityPoolVestingCancel {
+        /// The ID of the vesting schedule to cancel, i.e. of the proposal that registered it.
+        #[clap(long, default_value = "0")]
+        schedule_id: u64,
+    },
 }
 
 impl ProposalKindCmd {
@@ -114,6 +135,26 @@ impl ProposalKindCmd {
                 }
             }
             ProposalKindCmd::UpgradePlan { .. } => ProposalPayload::UpgradePlan { height: 0 },
+            ProposalKindCmd::CommunityPoolVestingGrant => {
+                ProposalPayload::CommunityPoolVestingGrant {
+                    schedule: VestingSchedule {
+                        // A placeholder, to be replaced in the template before submission.
+                        recipient: Address::dummy(&mut OsRng),
+                        total: Value {
+                            amount: 0u64.into(),
+                            asset_id: *STAKING_TOKEN_ASSET_ID,
+                        },
+                        start_epoch: 0,
+                        cliff_epoch: 0,
+                        per_epoch: 0u64.into(),
+                    },
+                }
+            }
+            ProposalKindCmd::CommunityPoolVestingCancel { schedule_id } => {
+                ProposalPayload::CommunityPoolVestingCancel {
+                    schedule_id: *schedule_id,
+                }
+            }
         };
 
         Ok(Proposal {
@@ -130,6 +171,7 @@ impl ProposalCmd {
         match self {
             ProposalCmd::Template { .. } => false,
             ProposalCmd::Submit { .. } => false,
+            ProposalCmd::Simulate { .. } => false,
             ProposalCmd::Withdraw { .. } => false,
             ProposalCmd::DepositClaim { .. } => false,
         }
@@ -160,7 +160,9 @@ impl ConstantProduct {
             .try_into()?;
 
         let mut planner = Planner::new(OsRng);
-        planner.set_gas_prices(gas_prices);
+        planner
+            .set_gas_prices(gas_prices)
+            .selection_strategy(app.selection_strategy);
         positions.iter().for_each(|position| {
             planner.position_open(position.clone());
         });
@@ -173,8 +175,9 @@ impl ConstantProduct {
                 AddressIndex::new(self.source),
             )
             .await?;
-        let tx_id = app.build_and_submit_transaction(plan).await?;
-        println!("posted with transaction id: {tx_id}");
+        if let Some(tx_id) = app.build_and_submit_transaction(plan).await? {
+            println!("posted with transaction id: {tx_id}");
+        }
 
         Ok(())
     }
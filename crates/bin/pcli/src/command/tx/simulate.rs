@@ -0,0 +1,183 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{Context, Result};
+use penumbra_app::params::AppParameters;
+use penumbra_proto::core::app::v1alpha1::SimulateProposalResponse;
+
+/// Renders the outcome of a proposal simulation for display, or returns an error with the reason
+/// the proposal would fail to be enacted.
+pub(super) fn render_simulation(simulation: SimulateProposalResponse) -> Result<String> {
+    if !simulation.error.is_empty() {
+        anyhow::bail!("proposal would fail: {}", simulation.error);
+    }
+
+    let mut lines = vec!["proposal would be enacted successfully".to_string()];
+
+    if let Some(new_app_parameters) = simulation.new_app_parameters {
+        let old: AppParameters = simulation
+            .old_app_parameters
+            .context("missing old app parameters in simulation response")?
+            .try_into()?;
+        let new: AppParameters = new_app_parameters.try_into()?;
+
+        lines.push(String::new());
+        lines.push("parameter changes:".to_string());
+        let changes = parameter_diff(&old, &new)?;
+        if changes.is_empty() {
+            lines.push("  (none)".to_string());
+        }
+        lines.extend(changes.into_iter().map(|change| format!("  {change}")));
+    }
+
+    if !simulation.events.is_empty() {
+        lines.push(String::new());
+        lines.push("events:".to_string());
+        for event in simulation.events {
+            lines.push(format!("  {}", event.kind));
+            for attribute in event.attributes {
+                lines.push(format!("    {}: {}", attribute.key, attribute.value));
+            }
+        }
+    }
+
+    Ok(lines.join("\n"))
+}
+
+/// Lists every app parameter that differs between `old` and `new`, keyed by its path in the JSON
+/// representation of the parameters.
+fn parameter_diff(old: &AppParameters, new: &AppParameters) -> Result<Vec<String>> {
+    fn flatten(prefix: String, value: serde_json::Value, out: &mut BTreeMap<String, String>) {
+        match value {
+            serde_json::Value::Object(fields) => {
+                for (key, value) in fields {
+                    let path = if prefix.is_empty() {
+                        key
+                    } else {
+                        format!("{prefix}.{key}")
+                    };
+                    flatten(path, value, out);
+                }
+            }
+            value => {
+                out.insert(prefix, value.to_string());
+            }
+        }
+    }
+
+    let mut old_fields = BTreeMap::new();
+    flatten(String::new(), serde_json::to_value(old)?, &mut old_fields);
+    let mut new_fields = BTreeMap::new();
+    flatten(String::new(), serde_json::to_value(new)?, &mut new_fields);
+
+    let paths: BTreeSet<_> = old_fields.keys().chain(new_fields.keys()).collect();
+    let mut changes = Vec::new();
+    for path in paths {
+        let old_value = old_fields
+            .get(path)
+            .map(String::as_str)
+            .unwrap_or("<unset>");
+        let new_value = new_fields
+            .get(path)
+            .map(String::as_str)
+            .unwrap_or("<unset>");
+        if old_value != new_value {
+            changes.push(format!("{path}: {old_value} -> {new_value}"));
+        }
+    }
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_proto::core::app::v1alpha1::simulate_proposal_response::{event, Event};
+
+    use super::*;
+
+    fn params_with_epoch_duration(epoch_duration: u64) -> AppParameters {
+        let mut params = AppParameters::default();
+        params.chain_params.epoch_duration = epoch_duration;
+        params
+    }
+
+    #[test]
+    fn failed_simulation_is_an_error() {
+        let simulation = SimulateProposalResponse {
+            error: "proposal failed stateful checks".to_string(),
+            ..Default::default()
+        };
+        let error = render_simulation(simulation).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "proposal would fail: proposal failed stateful checks"
+        );
+    }
+
+    #[test]
+    fn successful_simulation_without_changes() {
+        let rendered = render_simulation(SimulateProposalResponse::default()).unwrap();
+        assert_eq!(rendered, "proposal would be enacted successfully");
+    }
+
+    #[test]
+    fn parameter_changes_are_listed() {
+        let simulation = SimulateProposalResponse {
+            old_app_parameters: Some(params_with_epoch_duration(719).into()),
+            new_app_parameters: Some(params_with_epoch_duration(100).into()),
+            ..Default::default()
+        };
+        let rendered = render_simulation(simulation).unwrap();
+        let lines: Vec<_> = rendered.lines().collect();
+
+        assert_eq!(
+            lines[..3],
+            [
+                "proposal would be enacted successfully",
+                "",
+                "parameter changes:"
+            ]
+        );
+        assert_eq!(lines.len(), 4, "only the changed parameter is listed");
+        assert!(lines[3].starts_with("  chainParams.epochDuration: "));
+        assert!(lines[3].contains("719"));
+        assert!(lines[3].ends_with("\"100\""));
+    }
+
+    #[test]
+    fn unchanged_parameters_are_noted() {
+        let simulation = SimulateProposalResponse {
+            old_app_parameters: Some(params_with_epoch_duration(719).into()),
+            new_app_parameters: Some(params_with_epoch_duration(719).into()),
+            ..Default::default()
+        };
+        let rendered = render_simulation(simulation).unwrap();
+        assert!(rendered.ends_with("parameter changes:\n  (none)"));
+    }
+
+    #[test]
+    fn new_parameters_require_old_parameters() {
+        let simulation = SimulateProposalResponse {
+            new_app_parameters: Some(params_with_epoch_duration(100).into()),
+            ..Default::default()
+        };
+        assert!(render_simulation(simulation).is_err());
+    }
+
+    #[test]
+    fn events_are_listed_with_attributes() {
+        let simulation = SimulateProposalResponse {
+            events: vec![Event {
+                kind: "proposal_passed".to_string(),
+                attributes: vec![event::Attribute {
+                    key: "proposal_id".to_string(),
+                    value: "3".to_string(),
+                }],
+            }],
+            ..Default::default()
+        };
+        let rendered = render_simulation(simulation).unwrap();
+        assert_eq!(
+            rendered,
+            "proposal would be enacted successfully\n\nevents:\n  proposal_passed\n    proposal_id: 3"
+        );
+    }
+}
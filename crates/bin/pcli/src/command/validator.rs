@@ -9,7 +9,7 @@ use serde_json::Value;
 
 use penumbra_fee::Fee;
 use penumbra_governance::{
-    ValidatorVote, ValidatorVoteBody, ValidatorVoteReason, Vote, MAX_VALIDATOR_VOTE_REASON_LENGTH,
+    Ballot, ValidatorVote, ValidatorVoteBody, ValidatorVoteReason, MAX_VALIDATOR_VOTE_REASON_LENGTH,
 };
 use penumbra_keys::keys::AddressIndex;
 use penumbra_proto::{
@@ -183,7 +183,7 @@ impl ValidatorCmd {
                 // Currently this is always just copied from the identity key
                 let governance_key = GovernanceKey(identity_key.0);
 
-                let (proposal, vote): (u64, Vote) = (*vote).into();
+                let (proposal, vote): (u64, Ballot) = (*vote).try_into()?;
 
                 if reason.len() > MAX_VALIDATOR_VOTE_REASON_LENGTH {
                     anyhow::bail!("validator vote reason is too long, max 1024 bytes");
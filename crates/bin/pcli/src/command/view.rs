@@ -2,15 +2,22 @@ use anyhow::Result;
 
 use address::AddressCmd;
 use balance::BalanceCmd;
+use detection_key::DetectionKeyCmd;
+pub use encrypt::EncryptCmd;
+use history::HistoryCmd;
 use staked::StakedCmd;
 use transaction_hashes::TransactionHashesCmd;
 use tx::TxCmd;
+pub use tx::{format_transaction_plan, render_transaction_view};
 use wallet_id::WalletIdCmd;
 
 use crate::App;
 
 mod address;
 mod balance;
+mod detection_key;
+mod encrypt;
+mod history;
 mod staked;
 mod wallet_id;
 
@@ -23,12 +30,17 @@ pub enum ViewCmd {
     WalletId(WalletIdCmd),
     /// View one of your addresses, either by numerical index, or a random ephemeral one.
     Address(AddressCmd),
+    /// View the root detection key for your addresses, which can be given to a detection server
+    /// to detect transactions sent to any address made with `pcli view address --root-detection`.
+    DetectionKey(DetectionKeyCmd),
     /// View your account balances.
     Balance(BalanceCmd),
     /// View your staked delegation tokens.
     Staked(StakedCmd),
     /// Deletes all scanned data and local state, while leaving keys untouched.
     Reset(Reset),
+    /// Encrypts the view database at rest, or changes the key it's encrypted with.
+    Encrypt(EncryptCmd),
     /// Synchronizes the client, privately scanning the chain state.
     ///
     /// `pcli` syncs automatically prior to any action requiring chain state,
@@ -39,6 +51,8 @@ pub enum ViewCmd {
     ListTransactionHashes(TransactionHashesCmd),
     /// Displays a transaction's details by hash.
     Tx(TxCmd),
+    /// Export the history of your transactions, with the change each made to your balances.
+    History(HistoryCmd),
 }
 
 impl ViewCmd {
@@ -46,12 +60,15 @@ impl ViewCmd {
         match self {
             ViewCmd::WalletId(wallet_id_cmd) => wallet_id_cmd.offline(),
             ViewCmd::Address(address_cmd) => address_cmd.offline(),
+            ViewCmd::DetectionKey(detection_key_cmd) => detection_key_cmd.offline(),
             ViewCmd::Balance(balance_cmd) => balance_cmd.offline(),
             ViewCmd::Staked(staked_cmd) => staked_cmd.offline(),
             ViewCmd::Reset(_) => true,
+            ViewCmd::Encrypt(_) => true,
             ViewCmd::Sync => false,
             ViewCmd::ListTransactionHashes(transactions_cmd) => transactions_cmd.offline(),
             ViewCmd::Tx(tx_cmd) => tx_cmd.offline(),
+            ViewCmd::History(history_cmd) => history_cmd.offline(),
         }
     }
 
@@ -66,6 +83,9 @@ impl ViewCmd {
             ViewCmd::Tx(tx_cmd) => {
                 tx_cmd.exec(app).await?;
             }
+            ViewCmd::History(history_cmd) => {
+                history_cmd.exec(app).await?;
+            }
             ViewCmd::ListTransactionHashes(transactions_cmd) => {
                 let view_client = app.view();
                 transactions_cmd
@@ -79,9 +99,15 @@ impl ViewCmd {
             ViewCmd::Reset(_reset) => {
                 // The wallet has already been reset by a short-circuiting path.
             }
+            ViewCmd::Encrypt(_encrypt) => {
+                // The view database has already been encrypted by a short-circuiting path.
+            }
             ViewCmd::Address(address_cmd) => {
                 address_cmd.exec(&full_viewing_key)?;
             }
+            ViewCmd::DetectionKey(detection_key_cmd) => {
+                detection_key_cmd.exec(&full_viewing_key)?;
+            }
             ViewCmd::Balance(balance_cmd) => {
                 let view_client = app.view();
                 balance_cmd.exec(&full_viewing_key, view_client).await?;
@@ -2,7 +2,9 @@ use anyhow::Result;
 use base64::Engine;
 use rand_core::OsRng;
 
-use penumbra_keys::{Address, FullViewingKey};
+use penumbra_asset::Value;
+use penumbra_keys::{keys::DetectionKeyDerivation, Address, FullViewingKey};
+use penumbra_transaction::PaymentRequest;
 
 #[derive(Debug, clap::Parser)]
 pub struct AddressCmd {
@@ -12,9 +14,29 @@ pub struct AddressCmd {
     /// Generate an ephemeral address instead of an indexed one.
     #[clap(short, long)]
     ephemeral: bool,
+    /// Derive the address's clue key from the wallet's root detection key, so that a detection
+    /// server given `pcli view detection-key` can detect transactions sent to it.
+    ///
+    /// This gives a different address than the default one with the same index, though both
+    /// belong to the wallet.
+    #[clap(long)]
+    root_detection: bool,
     /// Output in base64 format, instead of the default bech32.
     #[clap(long)]
     base64: bool,
+    /// Output a `penumbra:` payment request URI for the address, to be paid with
+    /// `pcli tx send --uri`.
+    #[clap(long, conflicts_with = "base64")]
+    request: bool,
+    /// The amount to request, written as a typed value 1.87penumbra, 12cubes, etc.
+    #[clap(long, requires = "request")]
+    amount: Option<String>,
+    /// The memo text to request.
+    #[clap(long, requires = "request")]
+    memo: Option<String>,
+    /// The height after which the payment request should no longer be paid.
+    #[clap(long, requires = "request")]
+    expiry_height: Option<u64>,
 }
 
 impl AddressCmd {
@@ -29,12 +51,30 @@ impl AddressCmd {
         if let Ok(index) = index {
             //index provided
 
+            let derivation = if self.root_detection {
+                DetectionKeyDerivation::Root
+            } else {
+                DetectionKeyDerivation::Legacy
+            };
             let (address, _dtk) = match self.ephemeral {
-                false => fvk.incoming().payment_address(index.into()),
-                true => fvk.incoming().ephemeral_address(OsRng, index.into()),
+                false => fvk
+                    .incoming()
+                    .payment_address_with(index.into(), derivation),
+                true => fvk
+                    .incoming()
+                    .ephemeral_address_with(OsRng, index.into(), derivation),
             };
 
-            if self.base64 {
+            if self.request {
+                let amount: Option<Value> = self.amount.as_deref().map(str::parse).transpose()?;
+                let request = PaymentRequest {
+                    address,
+                    value: amount,
+                    memo: self.memo.clone(),
+                    expiry_height: self.expiry_height,
+                };
+                println!("{}", request);
+            } else if self.base64 {
                 println!(
                     "{}",
                     base64::engine::general_purpose::STANDARD.encode(address.to_vec()),
@@ -45,6 +85,10 @@ impl AddressCmd {
         } else {
             //address or nothing provided
 
+            if self.request {
+                anyhow::bail!("a payment request can only be made for an address index");
+            }
+
             let address: Address = self
                 .address_or_index
                 .parse()
@@ -0,0 +1,20 @@
+use anyhow::Result;
+
+use penumbra_keys::FullViewingKey;
+
+#[derive(Debug, clap::Parser)]
+pub struct DetectionKeyCmd {}
+
+impl DetectionKeyCmd {
+    /// Determine if this command requires a network sync before it executes.
+    pub fn offline(&self) -> bool {
+        true
+    }
+
+    pub fn exec(&self, fvk: &FullViewingKey) -> Result<()> {
+        let detection_key = fvk.root_detection_key();
+        println!("{}", hex::encode(detection_key.to_bytes()));
+
+        Ok(())
+    }
+}
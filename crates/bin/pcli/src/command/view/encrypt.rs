@@ -0,0 +1,69 @@
+use anyhow::Result;
+use camino::{Utf8Path, Utf8PathBuf};
+use penumbra_view::{DatabaseEncryption, DatabaseKey};
+
+use crate::config::PcliConfig;
+
+/// Encrypt the view database at rest, change the key it is encrypted with, or decrypt it.
+#[derive(Debug, clap::Parser)]
+pub struct EncryptCmd {
+    /// Encrypt the view database with a key read from this file, rather than with a passphrase.
+    ///
+    /// If the file doesn't exist, a new random key is written to it.
+    #[clap(long)]
+    key_file: Option<Utf8PathBuf>,
+    /// Decrypt the view database, so that it is stored unencrypted.
+    #[clap(long, conflicts_with = "key_file")]
+    decrypt: bool,
+}
+
+impl EncryptCmd {
+    pub fn exec(&self, home: impl AsRef<Utf8Path>) -> Result<()> {
+        let config_path = home.as_ref().join(crate::CONFIG_FILE_NAME);
+        let mut config = PcliConfig::load(&config_path)?;
+        let view_path = home.as_ref().join(crate::VIEW_FILE_NAME);
+
+        let encryption = match &self.key_file {
+            _ if self.decrypt => None,
+            Some(path) => {
+                if !path.exists() {
+                    println!("Writing a new view database key to {path}");
+                    DatabaseKey::generate_key_file(path)?;
+                }
+                Some(DatabaseEncryption::KeyFile { path: path.clone() })
+            }
+            None => Some(DatabaseEncryption::Passphrase),
+        };
+
+        if view_path.exists() {
+            let from = config.view_database_key()?;
+            let to = encryption
+                .as_ref()
+                .map(|encryption| {
+                    encryption.key(|| {
+                        let passphrase =
+                            rpassword::prompt_password("Enter new view database passphrase: ")?;
+                        if rpassword::prompt_password("Confirm new view database passphrase: ")?
+                            != passphrase
+                        {
+                            anyhow::bail!("passphrases do not match");
+                        }
+                        Ok(passphrase)
+                    })
+                })
+                .transpose()?;
+            penumbra_view::migrate_encryption(&view_path, from.as_ref(), to.as_ref())?;
+            if to.is_some() {
+                println!("Encrypted view data at {view_path}");
+            } else {
+                println!("Decrypted view data at {view_path}");
+            }
+        }
+
+        config.view_encryption = encryption;
+        config.save(&config_path)?;
+        println!("Updated config at {config_path}");
+
+        Ok(())
+    }
+}
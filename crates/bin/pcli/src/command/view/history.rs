@@ -0,0 +1,209 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use penumbra_asset::{asset, Value};
+use penumbra_proto::util::tendermint_proxy::v1alpha1::GetBlockByHeightRequest;
+use penumbra_view::{TransactionHistoryEntry, ViewClient};
+use serde::Serialize;
+
+use crate::App;
+
+/// The format to export the transaction history in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum HistoryFormat {
+    Csv,
+    Json,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct HistoryCmd {
+    /// Only export transactions from this height (inclusive).
+    #[clap(short, long)]
+    pub start_height: Option<u64>,
+    /// Only export transactions up to this height (inclusive).
+    #[clap(short, long)]
+    pub end_height: Option<u64>,
+    /// Only export the changes to the balance of this account.
+    #[clap(long)]
+    pub account: Option<u32>,
+    /// Only export the changes to the balance of this asset, given as a denom or an asset id.
+    #[clap(long)]
+    pub asset: Option<String>,
+    /// The format to export the history in.
+    #[clap(long, value_enum, default_value = "csv")]
+    pub format: HistoryFormat,
+    /// Include the time of the block each transaction was included in.
+    ///
+    /// The times are fetched from the node, which learns the heights of the wallet's
+    /// transactions.
+    #[clap(long)]
+    pub timestamps: bool,
+}
+
+/// A row of the exported history: the change a transaction made to the balance of one asset in
+/// one account, along with the details of the transaction.
+#[derive(Debug, Serialize)]
+struct HistoryRow {
+    height: u64,
+    timestamp: Option<String>,
+    transaction_id: String,
+    account: Option<u32>,
+    asset: Option<String>,
+    received: Option<String>,
+    sent: Option<String>,
+    fee: String,
+    return_address: Option<String>,
+    counterparties: Vec<String>,
+    memo: Option<String>,
+    actions: Vec<String>,
+}
+
+impl HistoryCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let asset_cache = app.view().assets().await?;
+        let asset_id = self
+            .asset
+            .as_deref()
+            .map(|asset| {
+                asset
+                    .parse::<asset::Id>()
+                    .ok()
+                    .or_else(|| asset_cache.get_unit(asset).map(|unit| unit.id()))
+                    .with_context(|| format!("unknown asset {asset}"))
+            })
+            .transpose()?;
+
+        let entries = app
+            .view()
+            .transaction_history(self.start_height, self.end_height, self.account, asset_id)
+            .await?;
+
+        let mut timestamps = BTreeMap::new();
+        if self.timestamps {
+            let mut client = app.tendermint_proxy_client().await?;
+            for entry in &entries {
+                if timestamps.contains_key(&entry.height) {
+                    continue;
+                }
+                let time = client
+                    .get_block_by_height(GetBlockByHeightRequest {
+                        height: entry.height.try_into()?,
+                    })
+                    .await?
+                    .into_inner()
+                    .block
+                    .and_then(|block| block.header)
+                    .and_then(|header| header.time)
+                    .with_context(|| format!("missing time for block {}", entry.height))?;
+                let time = tendermint::Time::from_unix_timestamp(time.seconds, time.nanos as u32)?;
+                timestamps.insert(entry.height, time.to_rfc3339());
+            }
+        }
+
+        let rows: Vec<HistoryRow> = entries
+            .iter()
+            .flat_map(|entry| rows(entry, &asset_cache, timestamps.get(&entry.height).cloned()))
+            .collect();
+
+        match self.format {
+            HistoryFormat::Json => println!("{}", serde_json::to_string_pretty(&rows)?),
+            HistoryFormat::Csv => {
+                println!(
+                    "height,timestamp,transaction_id,account,asset,received,sent,fee,return_address,counterparties,memo,actions"
+                );
+                for row in rows {
+                    let fields = [
+                        row.height.to_string(),
+                        row.timestamp.unwrap_or_default(),
+                        row.transaction_id,
+                        row.account.map(|a| a.to_string()).unwrap_or_default(),
+                        row.asset.unwrap_or_default(),
+                        row.received.unwrap_or_default(),
+                        row.sent.unwrap_or_default(),
+                        row.fee,
+                        row.return_address.unwrap_or_default(),
+                        row.counterparties.join(" "),
+                        row.memo.unwrap_or_default(),
+                        row.actions.join(" "),
+                    ];
+                    let fields: Vec<_> = fields.iter().map(|field| csv_field(field)).collect();
+                    println!("{}", fields.join(","));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits a history entry into one row per account and asset whose balance it changed, or a
+/// single row if it changed none.
+fn rows(
+    entry: &TransactionHistoryEntry,
+    asset_cache: &asset::Cache,
+    timestamp: Option<String>,
+) -> Vec<HistoryRow> {
+    let row = |account: Option<u32>, asset: Option<String>, received, sent| HistoryRow {
+        height: entry.height,
+        timestamp: timestamp.clone(),
+        transaction_id: hex::encode(entry.id),
+        account,
+        asset,
+        received,
+        sent,
+        fee: entry.fee.value().format(asset_cache),
+        return_address: entry.return_address.as_ref().map(ToString::to_string),
+        counterparties: entry
+            .counterparties
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+        memo: entry.memo_text.clone(),
+        actions: entry.action_kinds.clone(),
+    };
+
+    let mut rows = Vec::new();
+    for delta in &entry.balance_deltas {
+        let mut by_asset: BTreeMap<asset::Id, (Option<Value>, Option<Value>)> = BTreeMap::new();
+        for value in &delta.received {
+            by_asset.entry(value.asset_id).or_default().0 = Some(*value);
+        }
+        for value in &delta.sent {
+            by_asset.entry(value.asset_id).or_default().1 = Some(*value);
+        }
+        for (asset_id, (received, sent)) in by_asset {
+            // Write amounts in the asset's default unit, so that a column has a single unit.
+            let unit = asset_cache.get(&asset_id).map(|denom| denom.default_unit());
+            let asset = unit
+                .as_ref()
+                .map_or_else(|| asset_id.to_string(), ToString::to_string);
+            let format_amount = |value: Value| match &unit {
+                Some(unit) => unit.format_value(value.amount),
+                None => value.amount.to_string(),
+            };
+            rows.push(row(
+                Some(delta.account),
+                Some(asset),
+                received.map(&format_amount),
+                sent.map(&format_amount),
+            ));
+        }
+    }
+    if rows.is_empty() {
+        rows.push(row(None, None, None, None));
+    }
+    rows
+}
+
+/// Quotes a CSV field if needed, per RFC 4180.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -1,20 +1,43 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, time::Duration};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use comfy_table::{presets, Table};
 use futures::TryStreamExt;
+use tendermint::Time;
 use tonic::transport::Channel;
 
-use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_chain::Epoch;
 use penumbra_keys::FullViewingKey;
-use penumbra_proto::core::component::stake::v1alpha1::{
-    query_service_client::QueryServiceClient as StakeQueryServiceClient, ValidatorInfoRequest,
+use penumbra_proto::{
+    core::component::{
+        chain::v1alpha1::{
+            query_service_client::QueryServiceClient as ChainQueryServiceClient,
+            EpochByHeightRequest,
+        },
+        stake::v1alpha1::{
+            query_service_client::QueryServiceClient as StakeQueryServiceClient,
+            ValidatorInfoRequest, ValidatorRateHistoryRequest,
+        },
+    },
+    util::tendermint_proxy::v1alpha1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, GetBlockByHeightRequest,
+        GetStatusRequest,
+    },
 };
-use penumbra_stake::{validator, DelegationToken};
-use penumbra_view::ViewClient;
+use penumbra_stake::{
+    rate::{BaseRateData, RateData},
+    validator, DelegationToken, IdentityKey,
+};
+use penumbra_view::{SpendableNoteRecord, ViewClient};
 
 #[derive(Debug, clap::Parser)]
-pub struct StakedCmd {}
+pub struct StakedCmd {
+    /// Also show the realized returns of each delegation note, computed from
+    /// the validators' historical exchange rates.
+    #[clap(long)]
+    history: bool,
+}
 
 impl StakedCmd {
     pub fn offline(&self) -> bool {
@@ -132,6 +155,274 @@ impl StakedCmd {
         ]);
         println!("{table}");
 
+        if self.history {
+            let mut delegation_notes = Vec::new();
+            for (asset_id, notes_by_address) in notes.iter() {
+                let Some(Ok(dt)) = asset_cache
+                    .get(asset_id)
+                    .map(|denom| DelegationToken::try_from(denom.clone()))
+                else {
+                    continue;
+                };
+                let info = validators
+                    .iter()
+                    .find(|v| v.validator.identity_key == dt.validator())
+                    .expect("validator info exists in returned data");
+                for note in notes_by_address.values().flatten() {
+                    delegation_notes.push((info, note));
+                }
+            }
+
+            println!();
+            self.print_history(&delegation_notes, &asset_cache, pd_channel)
+                .await?;
+        }
+
         Ok(())
     }
+
+    /// Prints the realized return of each delegation note, from the epoch in
+    /// which it was created to the current epoch, alongside the return of
+    /// the base rate over the same period for comparison.
+    ///
+    /// Returns are also annualized, using the times of the first blocks of
+    /// the two epochs.
+    async fn print_history(
+        &self,
+        delegation_notes: &[(&validator::Info, &SpendableNoteRecord)],
+        asset_cache: &asset::Cache,
+        pd_channel: Channel,
+    ) -> Result<()> {
+        let mut chain_client = ChainQueryServiceClient::new(pd_channel.clone());
+        let mut stake_client = StakeQueryServiceClient::new(pd_channel.clone());
+        let mut tendermint_client = TendermintProxyServiceClient::new(pd_channel);
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec![
+            "Name",
+            "Tokens",
+            "Since",
+            "Epochs",
+            "Entry",
+            "Current",
+            "Return",
+            "Annualized",
+            "Base",
+        ]);
+        for column in [1, 4, 5, 6, 7, 8] {
+            table
+                .get_column_mut(column)
+                .expect("column exists")
+                .set_cell_alignment(comfy_table::CellAlignment::Right);
+        }
+
+        let latest_height = tendermint_client
+            .get_status(GetStatusRequest::default())
+            .await?
+            .into_inner()
+            .sync_info
+            .context("missing sync info in status")?
+            .latest_block_height;
+        let current_epoch = epoch_by_height(&mut chain_client, latest_height).await?;
+        let current_time = block_time(&mut tendermint_client, current_epoch.start_height).await?;
+
+        // Only the rates at either end of the period are needed.  Several
+        // notes are often created in the same epoch, so cache the lookups.
+        let mut entries: BTreeMap<(String, u64), Option<(RateData, BaseRateData, Time)>> =
+            BTreeMap::new();
+        let mut current_base: Option<BaseRateData> = None;
+
+        for (info, note) in delegation_notes {
+            let current_rate = &info.rate_data;
+            let start_epoch = epoch_by_height(&mut chain_client, note.height_created).await?;
+            let start_epoch_index = start_epoch.index;
+
+            if current_base.is_none() {
+                current_base = rate_in_epoch(
+                    &mut stake_client,
+                    &info.validator.identity_key,
+                    current_rate.epoch_index,
+                )
+                .await?
+                .map(|(_, base)| base);
+            }
+
+            let key = (info.validator.identity_key.to_string(), start_epoch_index);
+            if !entries.contains_key(&key) {
+                // Rate data may not have been recorded for old epochs.
+                let entry = match rate_in_epoch(
+                    &mut stake_client,
+                    &info.validator.identity_key,
+                    start_epoch_index,
+                )
+                .await?
+                {
+                    Some((rate, base)) => Some((
+                        rate,
+                        base,
+                        block_time(&mut tendermint_client, start_epoch.start_height).await?,
+                    )),
+                    None => None,
+                };
+                entries.insert(key.clone(), entry);
+            }
+
+            let delegation = Value {
+                amount: note.note.amount(),
+                asset_id: note.note.asset_id(),
+            };
+            let current = Value {
+                amount: current_rate
+                    .unbonded_amount(delegation.amount.value())
+                    .into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            };
+
+            let (entry, realized, annualized, base) = match (&entries[&key], &current_base) {
+                (Some((entry_rate, entry_base, entry_time)), Some(current_base)) => {
+                    let entry = Value {
+                        amount: entry_rate.unbonded_amount(delegation.amount.value()).into(),
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                    };
+                    let realized = current_rate.validator_exchange_rate as f64
+                        / entry_rate.validator_exchange_rate as f64
+                        - 1.0;
+                    let base = current_base.base_exchange_rate as f64
+                        / entry_base.base_exchange_rate as f64
+                        - 1.0;
+                    let annualized = current_time
+                        .duration_since(*entry_time)
+                        .ok()
+                        .and_then(|elapsed| annualize(realized, elapsed))
+                        .map(|annualized| format!("{:.4}%", annualized * 100.0))
+                        .unwrap_or_else(|| "n/a".to_string());
+                    (
+                        entry.format(asset_cache),
+                        format!("{:.4}%", realized * 100.0),
+                        annualized,
+                        format!("{:.4}%", base * 100.0),
+                    )
+                }
+                _ => (
+                    "n/a".to_string(),
+                    "n/a".to_string(),
+                    "n/a".to_string(),
+                    "n/a".to_string(),
+                ),
+            };
+
+            table.add_row(vec![
+                info.validator.name.clone(),
+                delegation.format(asset_cache),
+                format!("{start_epoch_index}"),
+                format!("{}", current_rate.epoch_index - start_epoch_index),
+                entry,
+                current.format(asset_cache),
+                realized,
+                annualized,
+                base,
+            ]);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+}
+
+/// Fetches the epoch containing the given height.
+async fn epoch_by_height(
+    client: &mut ChainQueryServiceClient<Channel>,
+    height: u64,
+) -> Result<Epoch> {
+    Ok(client
+        .epoch_by_height(EpochByHeightRequest { height })
+        .await?
+        .into_inner()
+        .epoch
+        .context("failed to find EpochByHeight message")?
+        .into())
+}
+
+/// Fetches the validator's rate data, and the base rate data, recorded for a
+/// single epoch, if any.
+async fn rate_in_epoch(
+    client: &mut StakeQueryServiceClient<Channel>,
+    identity_key: &IdentityKey,
+    epoch_index: u64,
+) -> Result<Option<(RateData, BaseRateData)>> {
+    client
+        .validator_rate_history(ValidatorRateHistoryRequest {
+            identity_key: Some(identity_key.clone().into()),
+            start_epoch_index: epoch_index,
+            end_epoch_index: epoch_index,
+            ..Default::default()
+        })
+        .await?
+        .into_inner()
+        .message()
+        .await?
+        .map(|r| -> Result<(RateData, BaseRateData)> {
+            Ok((
+                r.rate_data
+                    .context("missing rate data in rate history")?
+                    .try_into()?,
+                r.base_rate_data
+                    .context("missing base rate data in rate history")?
+                    .try_into()?,
+            ))
+        })
+        .transpose()
+}
+
+/// Fetches the time of the block at the given height.
+async fn block_time(
+    client: &mut TendermintProxyServiceClient<Channel>,
+    height: u64,
+) -> Result<Time> {
+    let time = client
+        .get_block_by_height(GetBlockByHeightRequest {
+            height: height.try_into()?,
+        })
+        .await?
+        .into_inner()
+        .block
+        .and_then(|block| block.header)
+        .and_then(|header| header.time)
+        .with_context(|| format!("missing time for block {height}"))?;
+    Ok(Time::from_unix_timestamp(time.seconds, time.nanos as u32)?)
+}
+
+/// Annualizes a return realized over the `elapsed` time, assuming it
+/// compounds at the same rate for the rest of the year.
+///
+/// Returns `None` if no time has elapsed, since nothing can be extrapolated.
+fn annualize(realized: f64, elapsed: Duration) -> Option<f64> {
+    const SECONDS_PER_YEAR: f64 = 365.25 * 24.0 * 60.0 * 60.0;
+
+    let elapsed = elapsed.as_secs_f64();
+    if elapsed <= 0.0 {
+        return None;
+    }
+    Some((1.0 + realized).powf(SECONDS_PER_YEAR / elapsed) - 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn annualize_compounds_over_a_year() {
+        let day = Duration::from_secs(24 * 60 * 60);
+
+        let year = annualize(0.05, day * 365 + day / 4).expect("time elapsed");
+        assert!((year - 0.05).abs() < 1e-9);
+
+        let half = annualize(0.05, (day * 365 + day / 4) / 2).expect("time elapsed");
+        assert!((half - (1.05f64 * 1.05 - 1.0)).abs() < 1e-9);
+
+        assert!(annualize(0.0, day).expect("time elapsed").abs() < 1e-12);
+        assert_eq!(annualize(0.05, Duration::ZERO), None);
+    }
 }
@@ -8,12 +8,14 @@ use penumbra_dex::{
     swap_claim::{SwapClaim, SwapClaimView},
     DirectedUnitPair,
 };
-use penumbra_keys::{keys::IncomingViewingKey, Address};
+use penumbra_fee::Fee;
+use penumbra_keys::{keys::IncomingViewingKey, Address, FullViewingKey};
 use penumbra_proto::{util::tendermint_proxy::v1alpha1::GetTxRequest, DomainType};
 use penumbra_shielded_pool::{Note, NoteView};
 use penumbra_transaction::{
+    plan::{ActionPlan, TransactionPlan},
     view::action_view::{OutputView, SpendView},
-    Transaction,
+    Transaction, TransactionPerspective, TransactionView,
 };
 use penumbra_view::{TransactionInfo, ViewClient};
 
@@ -119,15 +121,15 @@ fn format_opaque_swap_claim_row(asset_cache: &Cache, swap: &SwapClaim) -> String
 
 fn format_visible_swap_claim_row(
     asset_cache: &Cache,
-    swap: &SwapClaim,
+    fee: &Fee,
     note_1: &Note,
     note_2: &Note,
 ) -> String {
     // Typical swap claims only have a single output note with value, but we can't know that for sure.
 
     let value_fee = Value {
-        amount: swap.body.fee.amount(),
-        asset_id: swap.body.fee.asset_id(),
+        amount: fee.amount(),
+        asset_id: fee.asset_id(),
     }
     .format(asset_cache);
 
@@ -238,6 +240,336 @@ fn format_position_row(asset_cache: &Cache, position: Position) -> String {
     )
 }
 
+/// Prints a human-readable summary of a transaction, as seen by the given full viewing key.
+pub fn render_transaction_view(
+    fvk: &FullViewingKey,
+    asset_cache: &Cache,
+    view: TransactionView,
+) -> Result<()> {
+    // Initialize the tables
+    let mut actions_table = Table::new();
+    actions_table.load_preset(presets::NOTHING);
+    actions_table.set_header(vec!["Action Type", "Description"]);
+
+    let mut metadata_table = Table::new();
+    metadata_table.load_preset(presets::NOTHING);
+    metadata_table.set_header(vec!["", ""]);
+
+    // Iterate over the ActionViews in the TxV & display as appropriate
+
+    for av in view.body_view.action_views {
+        actions_table.add_row(match av {
+            penumbra_transaction::ActionView::Swap(SwapView::Visible {
+                swap: _,
+                swap_plaintext,
+            }) => [
+                "Swap".to_string(),
+                format_visible_swap_row(asset_cache, &swap_plaintext),
+            ],
+            penumbra_transaction::ActionView::Swap(SwapView::Opaque { swap }) => {
+                ["Swap".to_string(), format_opaque_swap_row(&swap)]
+            }
+            penumbra_transaction::ActionView::SwapClaim(SwapClaimView::Visible {
+                swap_claim,
+                output_1,
+                output_2,
+            }) => [
+                "Swap Claim".to_string(),
+                format_visible_swap_claim_row(
+                    asset_cache,
+                    &swap_claim.body.fee,
+                    &output_1.note()?,
+                    &output_2.note()?,
+                ),
+            ],
+            penumbra_transaction::ActionView::SwapClaim(SwapClaimView::Opaque { swap_claim }) => [
+                "Swap Claim".to_string(),
+                format_opaque_swap_claim_row(asset_cache, &swap_claim),
+            ],
+
+            penumbra_transaction::ActionView::Output(OutputView::Visible {
+                output: _,
+                note,
+                payload_key: _,
+            }) => [
+                "Output".to_string(),
+                format_visible_output_row(asset_cache, fvk.incoming(), &note),
+            ],
+            penumbra_transaction::ActionView::Output(OutputView::Opaque { output: _ }) => {
+                ["Output".to_string(), "[?] to [?]".to_string()]
+            }
+            penumbra_transaction::ActionView::Spend(SpendView::Visible { spend: _, note }) => [
+                "Spend".to_string(),
+                format_visible_spend_row(asset_cache, fvk.incoming(), &note),
+            ],
+            penumbra_transaction::ActionView::Spend(SpendView::Opaque { spend: _ }) => {
+                ["Spend".to_string(), "[?] spent [?]".to_string()]
+            }
+            penumbra_transaction::ActionView::Delegate(_) => {
+                ["Delegation".to_string(), "".to_string()]
+            }
+            penumbra_transaction::ActionView::Undelegate(_) => {
+                ["Undelegation".to_string(), "".to_string()]
+            }
+            penumbra_transaction::ActionView::UndelegateClaim(_) => {
+                ["Undelegation Claim".to_string(), "".to_string()]
+            }
+            penumbra_transaction::ActionView::Redelegate(_) => {
+                ["Redelegation".to_string(), "".to_string()]
+            }
+            penumbra_transaction::ActionView::RedelegateClaim(_) => {
+                ["Redelegation Claim".to_string(), "".to_string()]
+            }
+            penumbra_transaction::ActionView::ValidatorDefinition(_) => {
+                ["Upload Validator Definition".to_string(), "".to_string()]
+            }
+            penumbra_transaction::ActionView::IbcRelay(_) => {
+                ["IBC Action".to_string(), "".to_string()]
+            }
+            penumbra_transaction::ActionView::ProposalSubmit(prop_submit) => [
+                format!("Submit Governance Proposal #{}", prop_submit.proposal.id),
+                "".to_string(),
+            ],
+            penumbra_transaction::ActionView::ProposalWithdraw(prop_withdraw) => [
+                format!("Withdraw Governance Proposal #{}", prop_withdraw.proposal),
+                "".to_string(),
+            ],
+            penumbra_transaction::ActionView::ProposalDepositClaim(prop_deposit_claim) => [
+                format!(
+                    "Claim Deposit for Governance Proposal #{}",
+                    prop_deposit_claim.proposal
+                ),
+                "".to_string(),
+            ],
+            penumbra_transaction::ActionView::ValidatorVote(_) => {
+                ["Validator Vote".to_string(), "".to_string()]
+            }
+            penumbra_transaction::ActionView::DelegatorVote(_) => {
+                ["Delegator Vote".to_string(), "".to_string()]
+            }
+            penumbra_transaction::ActionView::PositionOpen(position_open) => [
+                "Open Liquidity Position".to_string(),
+                format_position_row(asset_cache, position_open.position),
+            ],
+            penumbra_transaction::ActionView::PositionClose(_) => {
+                ["Close Liquidity Position".to_string(), "".to_string()]
+            }
+            penumbra_transaction::ActionView::PositionWithdraw(_) => {
+                ["Withdraw Liquidity Position".to_string(), "".to_string()]
+            }
+            penumbra_transaction::ActionView::PositionRewardClaim(_) => [
+                "Claim Liquidity Position Reward".to_string(),
+                "".to_string(),
+            ],
+            penumbra_transaction::ActionView::Ics20Withdrawal(w) => {
+                let unit = w.denom.best_unit_for(w.amount);
+                [
+                    "Ics20 Withdrawal".to_string(),
+                    // TODO: why doesn't format_value include the unit?
+                    format!(
+                        "{}{} via {} to {}",
+                        unit.format_value(w.amount),
+                        unit,
+                        w.source_channel,
+                        w.destination_chain_address,
+                    ),
+                ]
+            }
+            penumbra_transaction::ActionView::CommunityPoolDeposit(_) => {
+                ["CommunityPool Deposit".to_string(), "".to_string()]
+            }
+            penumbra_transaction::ActionView::CommunityPoolSpend(_) => {
+                ["CommunityPool Spend".to_string(), "".to_string()]
+            }
+            penumbra_transaction::ActionView::CommunityPoolOutput(_) => {
+                ["CommunityPool Output".to_string(), "".to_string()]
+            }
+        });
+    }
+
+    metadata_table.add_row(vec![
+        "Transaction Fee",
+        &view
+            .body_view
+            .transaction_parameters
+            .fee
+            .value()
+            .format(asset_cache),
+    ]);
+
+    let memo_view = view.body_view.memo_view;
+
+    if let Some(memo_view) = memo_view {
+        match memo_view {
+            penumbra_transaction::MemoView::Visible {
+                plaintext,
+                ciphertext: _,
+            } => {
+                metadata_table.add_row(vec![
+                    "Transaction Memo Return Address",
+                    &format_full_address(fvk.incoming(), &plaintext.return_address.address()),
+                ]);
+                metadata_table.add_row(vec!["Transaction Memo Text", &plaintext.text]);
+            }
+            penumbra_transaction::MemoView::Opaque { ciphertext: _ } => (),
+        }
+    }
+
+    metadata_table.add_row(vec![
+        "Transaction Expiration Height",
+        &format!("{}", view.body_view.transaction_parameters.expiry_height),
+    ]);
+
+    // Print table of actions and their descriptions
+    println!("{actions_table}");
+
+    // Print transaction metadata
+    println!("{metadata_table}");
+
+    Ok(())
+}
+
+/// Formats the actions and metadata of a transaction plan as tables, in the same way as
+/// [`render_transaction_view`] formats the view of the transaction it describes.
+///
+/// The plan holds the plaintexts of its notes, so they can be shown without the transaction
+/// being built.
+pub fn format_transaction_plan(
+    fvk: &FullViewingKey,
+    asset_cache: &Cache,
+    plan: &TransactionPlan,
+) -> String {
+    let mut actions_table = Table::new();
+    actions_table.load_preset(presets::NOTHING);
+    actions_table.set_header(vec!["Action Type", "Description"]);
+
+    let mut metadata_table = Table::new();
+    metadata_table.load_preset(presets::NOTHING);
+    metadata_table.set_header(vec!["", ""]);
+
+    let perspective = TransactionPerspective::default();
+    for action in &plan.actions {
+        actions_table.add_row(match action {
+            ActionPlan::Swap(swap) => [
+                "Swap".to_string(),
+                format_visible_swap_row(asset_cache, &swap.swap_plaintext),
+            ],
+            ActionPlan::SwapClaim(swap_claim) => {
+                let (note_1, note_2) = swap_claim
+                    .swap_plaintext
+                    .output_notes(&swap_claim.output_data);
+                [
+                    "Swap Claim".to_string(),
+                    format_visible_swap_claim_row(
+                        asset_cache,
+                        &swap_claim.swap_plaintext.claim_fee,
+                        &note_1,
+                        &note_2,
+                    ),
+                ]
+            }
+            ActionPlan::Output(output) => [
+                "Output".to_string(),
+                format_visible_output_row(
+                    asset_cache,
+                    fvk.incoming(),
+                    &perspective.view_note(output.output_note()),
+                ),
+            ],
+            ActionPlan::Spend(spend) => [
+                "Spend".to_string(),
+                format_visible_spend_row(
+                    asset_cache,
+                    fvk.incoming(),
+                    &perspective.view_note(spend.note.clone()),
+                ),
+            ],
+            ActionPlan::Delegate(_) => ["Delegation".to_string(), "".to_string()],
+            ActionPlan::Undelegate(_) => ["Undelegation".to_string(), "".to_string()],
+            ActionPlan::UndelegateClaim(_) => ["Undelegation Claim".to_string(), "".to_string()],
+            ActionPlan::Redelegate(_) => ["Redelegation".to_string(), "".to_string()],
+            ActionPlan::RedelegateClaim(_) => ["Redelegation Claim".to_string(), "".to_string()],
+            ActionPlan::ValidatorDefinition(_) => {
+                ["Upload Validator Definition".to_string(), "".to_string()]
+            }
+            ActionPlan::IbcAction(_) => ["IBC Action".to_string(), "".to_string()],
+            ActionPlan::ProposalSubmit(prop_submit) => [
+                format!("Submit Governance Proposal #{}", prop_submit.proposal.id),
+                "".to_string(),
+            ],
+            ActionPlan::ProposalWithdraw(prop_withdraw) => [
+                format!("Withdraw Governance Proposal #{}", prop_withdraw.proposal),
+                "".to_string(),
+            ],
+            ActionPlan::ProposalDepositClaim(prop_deposit_claim) => [
+                format!(
+                    "Claim Deposit for Governance Proposal #{}",
+                    prop_deposit_claim.proposal
+                ),
+                "".to_string(),
+            ],
+            ActionPlan::ValidatorVote(_) => ["Validator Vote".to_string(), "".to_string()],
+            ActionPlan::DelegatorVote(_) => ["Delegator Vote".to_string(), "".to_string()],
+            ActionPlan::PositionOpen(position_open) => [
+                "Open Liquidity Position".to_string(),
+                format_position_row(asset_cache, position_open.position.clone()),
+            ],
+            ActionPlan::PositionClose(_) => {
+                ["Close Liquidity Position".to_string(), "".to_string()]
+            }
+            ActionPlan::PositionWithdraw(_) => {
+                ["Withdraw Liquidity Position".to_string(), "".to_string()]
+            }
+            ActionPlan::PositionRewardClaim(_) => [
+                "Claim Liquidity Position Reward".to_string(),
+                "".to_string(),
+            ],
+            ActionPlan::Withdrawal(w) => {
+                let unit = w.denom.best_unit_for(w.amount);
+                [
+                    "Ics20 Withdrawal".to_string(),
+                    format!(
+                        "{}{} via {} to {}",
+                        unit.format_value(w.amount),
+                        unit,
+                        w.source_channel,
+                        w.destination_chain_address,
+                    ),
+                ]
+            }
+            ActionPlan::CommunityPoolDeposit(_) => {
+                ["CommunityPool Deposit".to_string(), "".to_string()]
+            }
+            ActionPlan::CommunityPoolSpend(_) => {
+                ["CommunityPool Spend".to_string(), "".to_string()]
+            }
+            ActionPlan::CommunityPoolOutput(_) => {
+                ["CommunityPool Output".to_string(), "".to_string()]
+            }
+        });
+    }
+
+    metadata_table.add_row(vec![
+        "Transaction Fee",
+        &plan.transaction_parameters.fee.value().format(asset_cache),
+    ]);
+
+    if let Some(memo) = &plan.memo {
+        metadata_table.add_row(vec![
+            "Transaction Memo Return Address",
+            &format_full_address(fvk.incoming(), &memo.plaintext.return_address()),
+        ]);
+        metadata_table.add_row(vec!["Transaction Memo Text", memo.plaintext.text()]);
+    }
+
+    metadata_table.add_row(vec![
+        "Transaction Expiration Height",
+        &format!("{}", plan.transaction_parameters.expiry_height),
+    ]);
+
+    format!("{actions_table}\n{metadata_table}")
+}
+
 impl TxCmd {
     pub fn offline(&self) -> bool {
         false
@@ -290,194 +622,58 @@ impl TxCmd {
                 serde_json::to_string_pretty(&tx_info.view)?.to_colored_json_auto()?
             );
         } else {
-            // Initialize the tables
-            let mut actions_table = Table::new();
-            actions_table.load_preset(presets::NOTHING);
-            actions_table.set_header(vec!["Action Type", "Description"]);
-
-            let mut metadata_table = Table::new();
-            metadata_table.load_preset(presets::NOTHING);
-            metadata_table.set_header(vec!["", ""]);
-
             let asset_cache = app.view().assets().await?;
-            // Iterate over the ActionViews in the TxV & display as appropriate
-
-            for av in tx_info.view.body_view.action_views {
-                actions_table.add_row(match av {
-                    penumbra_transaction::ActionView::Swap(SwapView::Visible {
-                        swap: _,
-                        swap_plaintext,
-                    }) => [
-                        "Swap".to_string(),
-                        format_visible_swap_row(&asset_cache, &swap_plaintext),
-                    ],
-                    penumbra_transaction::ActionView::Swap(SwapView::Opaque { swap }) => {
-                        ["Swap".to_string(), format_opaque_swap_row(&swap)]
-                    }
-                    penumbra_transaction::ActionView::SwapClaim(SwapClaimView::Visible {
-                        swap_claim,
-                        output_1,
-                        output_2,
-                    }) => [
-                        "Swap Claim".to_string(),
-                        format_visible_swap_claim_row(
-                            &asset_cache,
-                            &swap_claim,
-                            &output_1.note()?,
-                            &output_2.note()?,
-                        ),
-                    ],
-                    penumbra_transaction::ActionView::SwapClaim(SwapClaimView::Opaque {
-                        swap_claim,
-                    }) => [
-                        "Swap Claim".to_string(),
-                        format_opaque_swap_claim_row(&asset_cache, &swap_claim),
-                    ],
-
-                    penumbra_transaction::ActionView::Output(OutputView::Visible {
-                        output: _,
-                        note,
-                        payload_key: _,
-                    }) => [
-                        "Output".to_string(),
-                        format_visible_output_row(&asset_cache, fvk.incoming(), &note),
-                    ],
-                    penumbra_transaction::ActionView::Output(OutputView::Opaque { output: _ }) => {
-                        ["Output".to_string(), "[?] to [?]".to_string()]
-                    }
-                    penumbra_transaction::ActionView::Spend(SpendView::Visible {
-                        spend: _,
-                        note,
-                    }) => [
-                        "Spend".to_string(),
-                        format_visible_spend_row(&asset_cache, fvk.incoming(), &note),
-                    ],
-                    penumbra_transaction::ActionView::Spend(SpendView::Opaque { spend: _ }) => {
-                        ["Spend".to_string(), "[?] spent [?]".to_string()]
-                    }
-                    penumbra_transaction::ActionView::Delegate(_) => {
-                        ["Delegation".to_string(), "".to_string()]
-                    }
-                    penumbra_transaction::ActionView::Undelegate(_) => {
-                        ["Undelegation".to_string(), "".to_string()]
-                    }
-                    penumbra_transaction::ActionView::UndelegateClaim(_) => {
-                        ["Undelegation Claim".to_string(), "".to_string()]
-                    }
-                    penumbra_transaction::ActionView::ValidatorDefinition(_) => {
-                        ["Upload Validator Definition".to_string(), "".to_string()]
-                    }
-                    penumbra_transaction::ActionView::IbcRelay(_) => {
-                        ["IBC Action".to_string(), "".to_string()]
-                    }
-                    penumbra_transaction::ActionView::ProposalSubmit(prop_submit) => [
-                        format!("Submit Governance Proposal #{}", prop_submit.proposal.id),
-                        "".to_string(),
-                    ],
-                    penumbra_transaction::ActionView::ProposalWithdraw(prop_withdraw) => [
-                        format!("Withdraw Governance Proposal #{}", prop_withdraw.proposal),
-                        "".to_string(),
-                    ],
-                    penumbra_transaction::ActionView::ProposalDepositClaim(prop_deposit_claim) => [
-                        format!(
-                            "Claim Deposit for Governance Proposal #{}",
-                            prop_deposit_claim.proposal
-                        ),
-                        "".to_string(),
-                    ],
-                    penumbra_transaction::ActionView::ValidatorVote(_) => {
-                        ["Validator Vote".to_string(), "".to_string()]
-                    }
-                    penumbra_transaction::ActionView::DelegatorVote(_) => {
-                        ["Delegator Vote".to_string(), "".to_string()]
-                    }
-                    penumbra_transaction::ActionView::PositionOpen(position_open) => [
-                        "Open Liquidity Position".to_string(),
-                        format_position_row(&asset_cache, position_open.position),
-                    ],
-                    penumbra_transaction::ActionView::PositionClose(_) => {
-                        ["Close Liquidity Position".to_string(), "".to_string()]
-                    }
-                    penumbra_transaction::ActionView::PositionWithdraw(_) => {
-                        ["Withdraw Liquidity Position".to_string(), "".to_string()]
-                    }
-                    penumbra_transaction::ActionView::PositionRewardClaim(_) => [
-                        "Claim Liquidity Position Reward".to_string(),
-                        "".to_string(),
-                    ],
-                    penumbra_transaction::ActionView::Ics20Withdrawal(w) => {
-                        let unit = w.denom.best_unit_for(w.amount);
-                        [
-                            "Ics20 Withdrawal".to_string(),
-                            // TODO: why doesn't format_value include the unit?
-                            format!(
-                                "{}{} via {} to {}",
-                                unit.format_value(w.amount),
-                                unit,
-                                w.source_channel,
-                                w.destination_chain_address,
-                            ),
-                        ]
-                    }
-                    penumbra_transaction::ActionView::CommunityPoolDeposit(_) => {
-                        ["CommunityPool Deposit".to_string(), "".to_string()]
-                    }
-                    penumbra_transaction::ActionView::CommunityPoolSpend(_) => {
-                        ["CommunityPool Spend".to_string(), "".to_string()]
-                    }
-                    penumbra_transaction::ActionView::CommunityPoolOutput(_) => {
-                        ["CommunityPool Output".to_string(), "".to_string()]
-                    }
-                });
-            }
-
-            metadata_table.add_row(vec![
-                "Transaction Fee",
-                &tx_info
-                    .view
-                    .body_view
-                    .transaction_parameters
-                    .fee
-                    .value()
-                    .format(&asset_cache),
-            ]);
-
-            let memo_view = tx_info.view.body_view.memo_view;
-
-            if let Some(memo_view) = memo_view {
-                match memo_view {
-                    penumbra_transaction::MemoView::Visible {
-                        plaintext,
-                        ciphertext: _,
-                    } => {
-                        metadata_table.add_row(vec![
-                            "Transaction Memo Return Address",
-                            &format_full_address(
-                                fvk.incoming(),
-                                &plaintext.return_address.address(),
-                            ),
-                        ]);
-                        metadata_table.add_row(vec!["Transaction Memo Text", &plaintext.text]);
-                    }
-                    penumbra_transaction::MemoView::Opaque { ciphertext: _ } => (),
-                }
-            }
-
-            metadata_table.add_row(vec![
-                "Transaction Expiration Height",
-                &format!(
-                    "{}",
-                    tx_info.view.body_view.transaction_parameters.expiry_height
-                ),
-            ]);
-
-            // Print table of actions and their descriptions
-            println!("{actions_table}");
-
-            // Print transaction metadata
-            println!("{metadata_table}");
+            render_transaction_view(&fvk, &asset_cache, tx_info.view)?;
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_keys::test_keys;
+    use penumbra_shielded_pool::{OutputPlan, SpendPlan};
+    use penumbra_transaction::memo::MemoPlaintext;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn plan_shows_spends_outputs_and_metadata() {
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+        let asset_cache = Cache::with_known_assets();
+        let value = |amount: u64| Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        };
+
+        let (address_0, _) = fvk.payment_address(0u32.into());
+        let (address_1, _) = fvk.payment_address(1u32.into());
+
+        let note = Note::generate(&mut OsRng, &address_0, value(5_000_000));
+        let mut plan = TransactionPlan::default();
+        plan.actions
+            .push(SpendPlan::new(&mut OsRng, note, 0u64.into()).into());
+        plan.actions
+            .push(OutputPlan::new(&mut OsRng, value(3_000_000), address_1).into());
+        plan.transaction_parameters.expiry_height = 1234;
+        plan.memo = Some(
+            penumbra_transaction::plan::MemoPlan::new(
+                &mut OsRng,
+                MemoPlaintext::new(address_0, "hello".to_string()).unwrap(),
+            )
+            .unwrap(),
+        );
+
+        let rendered = format_transaction_plan(fvk, &asset_cache, &plan);
+        assert!(
+            rendered.contains("[account 0] spent 5penumbra"),
+            "{rendered}"
+        );
+        assert!(rendered.contains("3penumbra to [account 1]"), "{rendered}");
+        assert!(rendered.contains("hello"), "{rendered}");
+        assert!(rendered.contains("1234"), "{rendered}");
+    }
+}
@@ -5,12 +5,16 @@ use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use url::Url;
 
-use penumbra_custody::{soft_kms::Config as SoftKmsConfig, threshold::Config as ThresholdConfig};
+use penumbra_custody::{
+    external::Config as ExternalConfig, soft_kms::Config as SoftKmsConfig,
+    threshold::Config as ThresholdConfig,
+};
 use penumbra_keys::FullViewingKey;
+use penumbra_view::{ChaffPolicy, DatabaseEncryption, DatabaseKey};
 
 /// Configuration data for `pcli`.
 #[serde_as]
-#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct PcliConfig {
     /// The URL of the gRPC endpoint used to talk to pd.
     pub grpc_url: Url,
@@ -24,6 +28,17 @@ pub struct PcliConfig {
     pub full_viewing_key: FullViewingKey,
     /// The custody backend to use.
     pub custody: CustodyConfig,
+    /// If set, the block height before which the wallet cannot have received any funds, used to
+    /// skip scanning earlier blocks when initializing the view database.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birthday: Option<u64>,
+    /// If set, how the view database is encrypted at rest.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_encryption: Option<DatabaseEncryption>,
+    /// If set, the fraction of blocks irrelevant to the wallet whose transactions are downloaded
+    /// anyway during sync, to hide the relevant ones from the node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chaff_rate: Option<f64>,
 }
 
 impl PcliConfig {
@@ -40,6 +55,29 @@ impl PcliConfig {
         std::fs::write(path, contents)?;
         Ok(())
     }
+
+    /// The key the view database is encrypted with, if it is, prompting for its passphrase if
+    /// needed.
+    pub fn view_database_key(&self) -> Result<Option<DatabaseKey>> {
+        self.view_encryption
+            .as_ref()
+            .map(|encryption| {
+                encryption.key(|| {
+                    Ok(rpassword::prompt_password(
+                        "Enter view database passphrase: ",
+                    )?)
+                })
+            })
+            .transpose()
+    }
+
+    /// The chaff policy the view worker should use during sync.
+    pub fn chaff_policy(&self) -> Result<ChaffPolicy> {
+        self.chaff_rate
+            .map(ChaffPolicy::new)
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
 }
 
 /// The custody backend to use.
@@ -53,6 +91,8 @@ pub enum CustodyConfig {
     SoftKms(SoftKmsConfig),
     /// A manual threshold custody service.
     Threshold(ThresholdConfig),
+    /// An external signer, such as a hardware wallet, running out of process.
+    External(ExternalConfig),
 }
 
 impl Default for CustodyConfig {
@@ -82,6 +122,9 @@ mod tests {
             grpc_url: Url::parse("https://grpc.testnet.penumbra.zone").unwrap(),
             disable_warning: false,
             view_url: None,
+            birthday: None,
+            view_encryption: None,
+            chaff_rate: None,
             full_viewing_key: penumbra_keys::test_keys::FULL_VIEWING_KEY.clone(),
             custody: CustodyConfig::SoftKms(SoftKmsConfig::from(
                 penumbra_keys::test_keys::SPEND_KEY.clone(),
@@ -91,11 +134,25 @@ mod tests {
         let mut config2 = config.clone();
         config2.custody = CustodyConfig::ViewOnly;
         config2.disable_warning = true;
+        config2.chaff_rate = Some(0.1);
+        config2.view_encryption = Some(DatabaseEncryption::KeyFile {
+            path: "/run/credentials/pcli/view-key".into(),
+        });
+
+        let mut config3 = config.clone();
+        config3.custody = CustodyConfig::External(ExternalConfig {
+            signer: penumbra_custody::external::Signer::Socket("/run/penumbra/signer.sock".into()),
+        });
 
         let toml_config = toml::to_string_pretty(&config).unwrap();
         let toml_config2 = toml::to_string_pretty(&config2).unwrap();
+        let toml_config3 = toml::to_string_pretty(&config3).unwrap();
 
         println!("{}", toml_config);
         println!("{}", toml_config2);
+        println!("{}", toml_config3);
+
+        let config3_2: PcliConfig = toml::from_str(&toml_config3).unwrap();
+        assert_eq!(config3, config3_2);
     }
 }
@@ -4,6 +4,7 @@
 use std::fs;
 
 use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
 use clap::Parser;
 use futures::StreamExt;
 
@@ -15,7 +16,7 @@ use penumbra_proto::{
     custody::v1alpha1::custody_protocol_service_client::CustodyProtocolServiceClient,
     view::v1alpha1::view_protocol_service_client::ViewProtocolServiceClient,
 };
-use penumbra_view::ViewClient;
+use penumbra_view::{SelectionStrategy, ViewClient};
 
 mod box_grpc_svc;
 mod command;
@@ -37,6 +38,16 @@ pub struct App {
     pub view: Option<ViewProtocolServiceClient<BoxGrpcService>>,
     pub custody: CustodyProtocolServiceClient<BoxGrpcService>,
     pub config: PcliConfig,
+    /// If set, transactions are not built and broadcast: their plans are written to this file
+    /// instead, to be signed offline.
+    pub plan_only: Option<Utf8PathBuf>,
+    /// The number of transaction plans written so far under `--plan-only`.
+    pub plans_written: usize,
+    /// The positions of the notes spent by the plans written so far under `--plan-only`, which
+    /// later plans must not spend again.
+    pub planned_spends: Vec<penumbra_tct::Position>,
+    /// The strategy used to select the notes spent to fund transactions.
+    pub selection_strategy: SelectionStrategy,
 }
 
 impl App {
@@ -113,10 +124,22 @@ async fn main() -> Result<()> {
         reset.exec(opt.home.as_path())?;
         return Ok(());
     }
+
+    // Likewise, the view database must not be open while it's being encrypted.
+    if let Command::View(ViewCmd::Encrypt(encrypt)) = &opt.cmd {
+        encrypt.exec(opt.home.as_path())?;
+        return Ok(());
+    }
+
+    // Resharing replaces the threshold key share in the config, so it takes the home dir directly.
+    if let Command::Threshold(ThresholdCmd::Reshare(reshare)) = &opt.cmd {
+        reshare.exec(opt.home.as_path()).await?;
+        return Ok(());
+    }
+
     // The debug command takes the home dir directly
     if let Command::Debug(debug_cmd) = &opt.cmd {
-        let dd = opt.home.into_std_path_buf();
-        debug_cmd.exec(dd)?;
+        debug_cmd.exec(opt.home.as_path()).await?;
         return Ok(());
     }
 
@@ -133,7 +156,7 @@ async fn main() -> Result<()> {
     match &cmd {
         Command::Init(_) => unreachable!("init command already executed"),
         Command::Debug(_) => unreachable!("debug command already executed"),
-        Command::Transaction(tx_cmd) => tx_cmd.exec(&mut app).await?,
+        Command::Transaction { cmd, .. } => cmd.exec(&mut app).await?,
         Command::View(view_cmd) => view_cmd.exec(&mut app).await?,
         Command::Validator(cmd) => cmd.exec(&mut app).await?,
         Command::Query(cmd) => cmd.exec(&mut app).await?,
@@ -1,4 +1,5 @@
 use anyhow::Context;
+use camino::{Utf8Path, Utf8PathBuf};
 use penumbra_proto::{
     util::tendermint_proxy::v1alpha1::tendermint_proxy_service_client::TendermintProxyServiceClient,
     DomainType,
@@ -12,12 +13,25 @@ use tracing::instrument;
 use crate::App;
 
 impl App {
+    /// Builds and submits the transaction described by `plan`, returning its ID.
+    ///
+    /// If `--plan-only` was given, the plan is written to that file instead, to be signed offline,
+    /// and `None` is returned. Commands making several transactions write their later plans to
+    /// numbered files next to it, and must exclude [`App::planned_spends`] from each later plan,
+    /// since the notes spent by earlier plans still appear unspent until those are broadcast.
     pub async fn build_and_submit_transaction(
         &mut self,
         plan: TransactionPlan,
-    ) -> anyhow::Result<TransactionId> {
+    ) -> anyhow::Result<Option<TransactionId>> {
+        if let Some(path) = &self.plan_only {
+            save_plan(&numbered_plan_path(path, self.plans_written), &plan)?;
+            self.plans_written += 1;
+            self.planned_spends
+                .extend(plan.spend_plans().map(|spend| spend.position));
+            return Ok(None);
+        }
         let transaction = self.build_transaction(plan).await?;
-        self.submit_transaction(transaction).await
+        self.submit_transaction(transaction).await.map(Some)
     }
 
     pub fn build_transaction(
@@ -102,3 +116,55 @@ impl App {
         Ok(TendermintProxyServiceClient::new(channel))
     }
 }
+
+/// The file the plan with the given zero-based `index` is written to under `--plan-only path`.
+///
+/// The first plan is written to `path` itself, and later ones to `path` with `-2`, `-3`, and so on
+/// appended to the file stem, so `plan.json` is followed by `plan-2.json`.
+fn numbered_plan_path(path: &Utf8Path, index: usize) -> Utf8PathBuf {
+    if index == 0 {
+        return path.to_owned();
+    }
+    let stem = path.file_stem().unwrap_or_default();
+    let file_name = match path.extension() {
+        Some(extension) => format!("{stem}-{}.{extension}", index + 1),
+        None => format!("{stem}-{}", index + 1),
+    };
+    path.with_file_name(file_name)
+}
+
+/// Writes a transaction plan to `path` as JSON, refusing to overwrite an existing file.
+fn save_plan(path: &Utf8Path, plan: &TransactionPlan) -> anyhow::Result<()> {
+    let file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .with_context(|| format!("could not create transaction plan file {path}"))?;
+    serde_json::to_writer_pretty(file, plan)?;
+    println!("wrote transaction plan to {path}; sign it offline with `pcli tx sign {path}`");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plan_paths_are_numbered_after_the_first() {
+        let path = Utf8Path::new("/tmp/plans/plan.json");
+        assert_eq!(numbered_plan_path(path, 0), path);
+        assert_eq!(numbered_plan_path(path, 1), "/tmp/plans/plan-2.json");
+        assert_eq!(numbered_plan_path(path, 2), "/tmp/plans/plan-3.json");
+        assert_eq!(numbered_plan_path(Utf8Path::new("plan"), 1), "plan-2");
+    }
+
+    #[test]
+    fn save_plan_refuses_to_overwrite() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = Utf8PathBuf::from_path_buf(dir.path().join("plan.json")).unwrap();
+        let plan = TransactionPlan::default();
+        save_plan(&path, &plan).unwrap();
+        assert!(save_plan(&path, &plan).is_err());
+        save_plan(&numbered_plan_path(&path, 1), &plan).unwrap();
+    }
+}
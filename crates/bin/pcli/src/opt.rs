@@ -19,7 +19,7 @@ use penumbra_proto::{
         view_protocol_service_server::ViewProtocolServiceServer,
     },
 };
-use penumbra_view::ViewService;
+use penumbra_view::{SelectionStrategy, ViewService};
 use tracing_subscriber::EnvFilter;
 
 #[derive(Debug, Parser)]
@@ -78,6 +78,11 @@ impl Opt {
                 let custody_svc = CustodyProtocolServiceServer::new(threshold_kms);
                 CustodyProtocolServiceClient::new(box_grpc_svc::local(custody_svc))
             }
+            CustodyConfig::External(config) => {
+                tracing::info!(signer = ?config.signer, "using external signer custody service");
+                let channel = penumbra_custody::external::connect(config);
+                CustodyProtocolServiceClient::new(box_grpc_svc::channel(channel))
+            }
         };
 
         // ...and the view service...
@@ -100,8 +105,11 @@ impl Opt {
 
                 let svc = ViewService::load_or_initialize(
                     Some(path),
+                    config.view_database_key()?,
                     &config.full_viewing_key,
                     config.grpc_url.clone(),
+                    config.birthday,
+                    config.chaff_policy()?,
                 )
                 .await?;
 
@@ -111,10 +119,23 @@ impl Opt {
             }
         };
 
+        let (plan_only, selection_strategy) = match &self.cmd {
+            Command::Transaction {
+                plan_only,
+                selection_strategy,
+                ..
+            } => (plan_only.clone(), *selection_strategy),
+            _ => (None, SelectionStrategy::default()),
+        };
+
         let app = App {
             view,
             custody,
             config,
+            plan_only,
+            plans_written: 0,
+            planned_spends: Vec::new(),
+            selection_strategy,
         };
         Ok((app, self.cmd))
     }
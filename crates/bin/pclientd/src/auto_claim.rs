@@ -0,0 +1,485 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use ark_ff::UniformRand;
+use decaf377::{Fq, Fr};
+use penumbra_custody::{
+    policy::{ActionKind, AuthPolicy},
+    soft_kms::SoftKms,
+};
+use penumbra_keys::{
+    keys::{AddressIndex, SpendKey},
+    FullViewingKey,
+};
+use penumbra_proto::{
+    core::component::{
+        chain::v1alpha1::{
+            query_service_client::QueryServiceClient as ChainQueryServiceClient,
+            EpochByHeightRequest,
+        },
+        stake::v1alpha1::{
+            query_service_client::QueryServiceClient as StakeQueryServiceClient,
+            ValidatorPenaltyRequest,
+        },
+    },
+    custody::v1alpha1::{
+        custody_protocol_service_client::CustodyProtocolServiceClient,
+        custody_protocol_service_server::CustodyProtocolServiceServer,
+    },
+    view::v1alpha1::{
+        view_protocol_service_client::ViewProtocolServiceClient,
+        view_protocol_service_server::ViewProtocolServiceServer,
+    },
+};
+use penumbra_stake::{Penalty, RedelegationToken, UnbondingToken, UndelegateClaimPlan};
+use penumbra_tct::StateCommitment;
+use penumbra_transaction::plan::TransactionPlan;
+use penumbra_view::{Planner, ViewClient, ViewService};
+use rand_core::OsRng;
+use tonic::transport::Channel;
+
+/// How often to look for claimable swaps, undelegations and redelegations.
+const CLAIM_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Periodically claims the outputs of the custodied wallet's swaps and its matured
+/// undelegations and redelegations.
+///
+/// The claims are authorized by `custody`, so they are subject to its configured authorization
+/// policy: claims it rejects are logged and retried at the next interval. Since the generated
+/// [`auth_policy`] only allows outputs to the wallet's first address, undelegations and
+/// redelegations held by other accounts are left unclaimed.
+pub async fn claim_periodically(
+    view_service: ViewService,
+    custody: SoftKms,
+    fvk: FullViewingKey,
+    pd: Channel,
+) {
+    let mut view = ViewProtocolServiceClient::new(ViewProtocolServiceServer::new(view_service));
+    let mut custody = CustodyProtocolServiceClient::new(CustodyProtocolServiceServer::new(custody));
+    let mut interval = tokio::time::interval(CLAIM_INTERVAL);
+    let mut in_flight = InFlightClaims::default();
+    let mut skipped_accounts = BTreeSet::new();
+
+    loop {
+        interval.tick().await;
+
+        let plans = match claim_plans(&mut view, pd.clone(), &mut skipped_accounts).await {
+            Ok(plans) => plans,
+            Err(e) => {
+                tracing::warn!(?e, "failed to plan claims");
+                continue;
+            }
+        };
+
+        for plan in plans {
+            // All the plans are made before any is submitted, so a later one may spend the same
+            // notes as an earlier one, and a claim that failed to confirm may still be included.
+            if !in_flight.insert(&plan, Instant::now()) {
+                tracing::debug!("skipping claim of notes that are already being claimed");
+                continue;
+            }
+            let result = async {
+                let transaction =
+                    penumbra_wallet::build_transaction(&fvk, &mut view, &mut custody, plan).await?;
+                ViewClient::broadcast_transaction(&mut view, transaction, true).await
+            }
+            .await;
+            match result {
+                Ok((id, height)) => tracing::info!(%id, ?height, "submitted claim"),
+                Err(e) => tracing::warn!(?e, "failed to submit claim"),
+            }
+        }
+    }
+}
+
+/// How long the inputs of a submitted claim are skipped before they can be claimed again, if they
+/// are still unspent.
+const IN_FLIGHT_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// The notes and swaps spent by recently submitted claims, so that they aren't claimed twice.
+#[derive(Debug, Default)]
+struct InFlightClaims {
+    submitted: BTreeMap<StateCommitment, Instant>,
+}
+
+impl InFlightClaims {
+    /// Records the inputs of the claim `plan`, submitted `now`, returning `false` without
+    /// recording anything if any of them are already being claimed.
+    fn insert(&mut self, plan: &TransactionPlan, now: Instant) -> bool {
+        self.submitted
+            .retain(|_, submitted| now.duration_since(*submitted) < IN_FLIGHT_TIMEOUT);
+
+        let inputs = plan
+            .spend_plans()
+            .map(|spend| spend.note.commit())
+            .chain(
+                plan.swap_claim_plans()
+                    .map(|claim| claim.swap_plaintext.swap_commitment()),
+            )
+            .collect::<Vec<_>>();
+        if inputs
+            .iter()
+            .any(|input| self.submitted.contains_key(input))
+        {
+            return false;
+        }
+        self.submitted
+            .extend(inputs.into_iter().map(|input| (input, now)));
+        true
+    }
+}
+
+/// The authorization policy generated by `pclientd init --auto-claim`.
+///
+/// Claims are made without pre-authorization, so this allows them, but denies every action that
+/// isn't needed to claim, and only allows outputs to the wallet's first address, into which the
+/// claims of its first account are made.
+pub fn auth_policy(spend_key: &SpendKey) -> Vec<AuthPolicy> {
+    let denied_actions = vec![
+        ActionKind::Delegate,
+        ActionKind::Undelegate,
+        ActionKind::Redelegate,
+        ActionKind::ValidatorDefinition,
+        ActionKind::Swap,
+        ActionKind::IbcAction,
+        ActionKind::ProposalSubmit,
+        ActionKind::ProposalWithdraw,
+        ActionKind::DelegatorVote,
+        ActionKind::ValidatorVote,
+        ActionKind::ProposalDepositClaim,
+        ActionKind::PositionOpen,
+        ActionKind::PositionClose,
+        ActionKind::PositionWithdraw,
+        ActionKind::PositionRewardClaim,
+        ActionKind::CommunityPoolSpend,
+        ActionKind::CommunityPoolOutput,
+        ActionKind::CommunityPoolDeposit,
+        ActionKind::Withdrawal,
+    ];
+    vec![
+        AuthPolicy::ActionDenyList { denied_actions },
+        AuthPolicy::DestinationAllowList {
+            allowed_destination_addresses: vec![
+                spend_key
+                    .incoming_viewing_key()
+                    .payment_address(Default::default())
+                    .0,
+            ],
+        },
+    ]
+}
+
+/// Plans the claims of all the unclaimed swaps and matured undelegations and redelegations, once
+/// the view service has caught up with the chain.
+///
+/// Undelegations and redelegations held by accounts other than the first are skipped, warning
+/// once for each account not yet in `skipped_accounts`. A claim that can't be planned is logged
+/// and skipped, without stopping the others from being planned.
+async fn claim_plans<V: ViewClient>(
+    view: &mut V,
+    pd: Channel,
+    skipped_accounts: &mut BTreeSet<u32>,
+) -> anyhow::Result<Vec<TransactionPlan>> {
+    let status = view.status().await?;
+    if status.catching_up {
+        return Ok(Vec::new());
+    }
+
+    let mut plans = penumbra_wallet::plan::claim_unclaimed_swaps(view, OsRng).await?;
+
+    let params = view.app_params().await?;
+    let current_epoch = ChainQueryServiceClient::new(pd.clone())
+        .epoch_by_height(EpochByHeightRequest {
+            height: status.full_sync_height,
+        })
+        .await?
+        .into_inner()
+        .epoch
+        .context("unable to get epoch for current height")?
+        .index;
+    let gas_prices = view.gas_prices().await?;
+    let asset_cache = view.assets().await?;
+
+    // Claim the unbonding tokens into the default address of the account that currently holds
+    // them.
+    for (address_index, notes_by_asset) in view.unspent_notes_by_address_and_asset().await? {
+        for (asset_id, notes) in notes_by_asset {
+            let Some(token) = asset_cache
+                .get(&asset_id)
+                .and_then(|denom| UnbondingToken::try_from(denom.clone()).ok())
+            else {
+                continue;
+            };
+            let start_epoch_index = token.start_epoch_index();
+            if start_epoch_index + params.stake_params.unbonding_epochs > current_epoch {
+                continue;
+            }
+            if !is_claimable_by(address_index.account, skipped_accounts) {
+                continue;
+            }
+
+            let validator_identity = token.validator();
+            let plan = async {
+                let penalty: Penalty = StakeQueryServiceClient::new(pd.clone())
+                    .validator_penalty(ValidatorPenaltyRequest {
+                        chain_id: params.chain_params.chain_id.clone(),
+                        identity_key: Some(validator_identity.into()),
+                        start_epoch_index,
+                        end_epoch_index: current_epoch,
+                    })
+                    .await?
+                    .into_inner()
+                    .penalty
+                    .with_context(|| {
+                        format!("no penalty returned for validator {validator_identity}")
+                    })?
+                    .try_into()?;
+
+                let mut planner = Planner::new(OsRng);
+                planner.set_gas_prices(gas_prices.clone());
+                let unbonding_amount = notes.iter().map(|record| record.note.amount()).sum();
+                for record in notes {
+                    planner.spend(record.note, record.position);
+                }
+                planner
+                    .undelegate_claim(UndelegateClaimPlan {
+                        validator_identity,
+                        start_epoch_index,
+                        penalty,
+                        unbonding_amount,
+                        balance_blinding: Fr::rand(&mut OsRng),
+                        proof_blinding_r: Fq::rand(&mut OsRng),
+                        proof_blinding_s: Fq::rand(&mut OsRng),
+                    })
+                    .plan(view, AddressIndex::new(address_index.account))
+                    .await
+                    .context("can't build undelegate claim plan")
+            }
+            .await;
+            match plan {
+                Ok(plan) => plans.push(plan),
+                Err(e) => {
+                    tracing::warn!(?e, %validator_identity, "failed to plan undelegation claim")
+                }
+            }
+        }
+    }
+
+    // Likewise, claim the redelegation tokens for the destination validator's delegation tokens.
+    for (address_index, notes_by_asset) in view.unspent_notes_by_address_and_asset().await? {
+        for (asset_id, notes) in notes_by_asset {
+            let Some(token) = asset_cache
+                .get(&asset_id)
+                .and_then(|denom| RedelegationToken::try_from(denom.clone()).ok())
+            else {
+                continue;
+            };
+            let start_epoch_index = token.start_epoch_index();
+            let end_epoch_index = start_epoch_index + params.stake_params.unbonding_epochs;
+            if end_epoch_index > current_epoch {
+                continue;
+            }
+            if !is_claimable_by(address_index.account, skipped_accounts) {
+                continue;
+            }
+
+            let from_validator_identity = token.from_validator();
+            let plan = async {
+                let penalty: Penalty = StakeQueryServiceClient::new(pd.clone())
+                    .validator_penalty(ValidatorPenaltyRequest {
+                        chain_id: params.chain_params.chain_id.clone(),
+                        identity_key: Some(from_validator_identity.into()),
+                        start_epoch_index,
+                        end_epoch_index,
+                    })
+                    .await?
+                    .into_inner()
+                    .penalty
+                    .with_context(|| {
+                        format!("no penalty returned for validator {from_validator_identity}")
+                    })?
+                    .try_into()?;
+
+                let mut planner = Planner::new(OsRng);
+                planner.set_gas_prices(gas_prices.clone());
+                let escrowed_amount = notes.iter().map(|record| record.note.amount()).sum();
+                for record in notes {
+                    planner.spend(record.note, record.position);
+                }
+                planner
+                    .redelegate_claim(
+                        from_validator_identity,
+                        token.to_validator(),
+                        start_epoch_index,
+                        penalty,
+                        escrowed_amount,
+                    )
+                    .plan(view, AddressIndex::new(address_index.account))
+                    .await
+                    .context("can't build redelegate claim plan")
+            }
+            .await;
+            match plan {
+                Ok(plan) => plans.push(plan),
+                Err(e) => tracing::warn!(
+                    ?e,
+                    %from_validator_identity,
+                    "failed to plan redelegation claim"
+                ),
+            }
+        }
+    }
+
+    Ok(plans)
+}
+
+/// Whether the generated [`auth_policy`] allows claims into `account`, which is only the case for
+/// the first account, warning the first time `account` is found not to be.
+fn is_claimable_by(account: u32, skipped_accounts: &mut BTreeSet<u32>) -> bool {
+    if account == 0 {
+        return true;
+    }
+    if skipped_accounts.insert(account) {
+        tracing::warn!(
+            account,
+            "not claiming undelegations or redelegations held by an account other than the first, as the auto-claim policy only allows outputs to the first account"
+        );
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_custody::{policy::Policy, AuthorizeRequest};
+    use penumbra_keys::test_keys;
+    use penumbra_shielded_pool::{Note, OutputPlan, SpendPlan};
+    use penumbra_stake::{Delegate, IdentityKey};
+    use penumbra_transaction::plan::ActionPlan;
+
+    use super::*;
+
+    fn value(amount: u64) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    fn spend(note: &Note) -> ActionPlan {
+        SpendPlan::new(&mut OsRng, note.clone(), 0u64.into()).into()
+    }
+
+    fn plan(actions: Vec<ActionPlan>) -> TransactionPlan {
+        TransactionPlan {
+            actions,
+            ..Default::default()
+        }
+    }
+
+    fn validator() -> IdentityKey {
+        IdentityKey(*test_keys::FULL_VIEWING_KEY.spend_verification_key())
+    }
+
+    fn undelegate_claim() -> ActionPlan {
+        ActionPlan::UndelegateClaim(UndelegateClaimPlan {
+            validator_identity: validator(),
+            start_epoch_index: 1,
+            penalty: Penalty::from_percent(0),
+            unbonding_amount: 10u64.into(),
+            balance_blinding: Fr::rand(&mut OsRng),
+            proof_blinding_r: Fq::rand(&mut OsRng),
+            proof_blinding_s: Fq::rand(&mut OsRng),
+        })
+    }
+
+    fn check(policy: &[AuthPolicy], plan: TransactionPlan) -> anyhow::Result<()> {
+        let request = AuthorizeRequest {
+            plan,
+            pre_authorizations: Vec::new(),
+        };
+        policy.iter().try_for_each(|policy| policy.check(&request))
+    }
+
+    #[test]
+    fn claims_of_notes_already_being_claimed_are_skipped() {
+        let address = test_keys::FULL_VIEWING_KEY
+            .payment_address(Default::default())
+            .0;
+        let note_1 = Note::generate(&mut OsRng, &address, value(1));
+        let note_2 = Note::generate(&mut OsRng, &address, value(2));
+
+        let mut in_flight = InFlightClaims::default();
+        let now = Instant::now();
+        assert!(in_flight.insert(&plan(vec![spend(&note_1)]), now));
+        assert!(!in_flight.insert(&plan(vec![spend(&note_1), spend(&note_2)]), now));
+        // The rejected claim's other inputs weren't recorded.
+        assert!(in_flight.insert(&plan(vec![spend(&note_2)]), now));
+
+        // Claims which never confirmed are retried eventually.
+        let later = now + IN_FLIGHT_TIMEOUT;
+        assert!(in_flight.insert(&plan(vec![spend(&note_1)]), later));
+        assert!(!in_flight.insert(&plan(vec![spend(&note_1)]), later));
+    }
+
+    #[test]
+    fn only_the_first_accounts_claims_are_made() {
+        let mut skipped_accounts = BTreeSet::new();
+        assert!(is_claimable_by(0, &mut skipped_accounts));
+        assert!(!is_claimable_by(1, &mut skipped_accounts));
+        assert!(!is_claimable_by(1, &mut skipped_accounts));
+        assert_eq!(skipped_accounts, BTreeSet::from([1]));
+    }
+
+    #[test]
+    fn generated_policy_allows_claims_into_the_wallet() {
+        let spend_key = &*test_keys::SPEND_KEY;
+        let policy = auth_policy(spend_key);
+        let fvk = spend_key.full_viewing_key();
+        let (address, _) = fvk.payment_address(Default::default());
+        let unbonding = Note::generate(&mut OsRng, &address, value(10));
+
+        let claim = plan(vec![
+            spend(&unbonding),
+            undelegate_claim(),
+            OutputPlan::new(&mut OsRng, value(10), address).into(),
+        ]);
+        check(&policy, claim).unwrap();
+    }
+
+    #[test]
+    fn generated_policy_denies_sending_funds_elsewhere() {
+        let spend_key = &*test_keys::SPEND_KEY;
+        let policy = auth_policy(spend_key);
+        let (address, _) = spend_key
+            .full_viewing_key()
+            .payment_address(Default::default());
+        let note = Note::generate(&mut OsRng, &address, value(10));
+
+        let (other_address, _) = penumbra_keys::keys::SpendKey::from_seed_phrase_bip44(
+            penumbra_keys::keys::SeedPhrase::generate(OsRng),
+            &penumbra_keys::keys::Bip44Path::new(0),
+        )
+        .full_viewing_key()
+        .payment_address(Default::default());
+        let send = plan(vec![
+            spend(&note),
+            OutputPlan::new(&mut OsRng, value(10), other_address).into(),
+        ]);
+        assert!(check(&policy, send).is_err());
+
+        let delegate = plan(vec![
+            spend(&note),
+            ActionPlan::Delegate(Delegate {
+                validator_identity: validator(),
+                epoch_index: 1,
+                unbonded_amount: 10u64.into(),
+                delegation_amount: 10u64.into(),
+            }),
+        ]);
+        assert!(check(&policy, delegate).is_err());
+    }
+}
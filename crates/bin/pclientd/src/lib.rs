@@ -5,18 +5,18 @@ use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
 use clap::Parser;
 use directories::ProjectDirs;
+use metrics_exporter_prometheus::PrometheusBuilder;
 use penumbra_custody::policy::{AuthPolicy, PreAuthorizationPolicy};
 use penumbra_custody::soft_kms::{self, SoftKms};
 use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
 use penumbra_keys::FullViewingKey;
 use penumbra_proto::{
-    core::app::v1alpha1::{
-        query_service_client::QueryServiceClient as AppQueryServiceClient, AppParametersRequest,
-    },
     custody::v1alpha1::custody_protocol_service_server::CustodyProtocolServiceServer,
     view::v1alpha1::view_protocol_service_server::ViewProtocolServiceServer,
 };
-use penumbra_view::{Storage, ViewService};
+use penumbra_view::{
+    ChaffPolicy, DatabaseEncryption, DatabaseKey, Storage, ViewService, PASSPHRASE_ENV_VAR,
+};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 
@@ -27,6 +27,9 @@ use std::str::FromStr;
 use tonic::transport::Server;
 use url::Url;
 
+mod auto_claim;
+mod webhook;
+
 mod proxy;
 pub use proxy::{
     AppQueryProxy, ChainQueryProxy, CompactBlockQueryProxy, DexQueryProxy, DexSimulationProxy,
@@ -40,10 +43,44 @@ pub struct PclientdConfig {
     /// FVK for both view and custody modes
     #[serde_as(as = "DisplayFromStr")]
     pub full_viewing_key: FullViewingKey,
+    /// FVKs of additional wallets to track in view mode.
+    ///
+    /// View requests must name the wallet they concern when these are set.
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_full_viewing_keys: Vec<FullViewingKey>,
     /// The URL of the gRPC endpoint used to talk to pd.
     pub grpc_url: Url,
     /// The address to bind to serve gRPC.
     pub bind_addr: SocketAddr,
+    /// If set, view service notifications are POSTed to this URL as JSON.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_webhook: Option<Url>,
+    /// If set, the block height before which none of the tracked wallets can have received
+    /// funds, used to skip scanning earlier blocks when initializing the view database.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birthday: Option<u64>,
+    /// If set, the fraction of blocks irrelevant to the tracked wallets whose transactions are
+    /// downloaded anyway during sync, to hide the relevant ones from the node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chaff_rate: Option<f64>,
+    /// If set, the address to bind to serve Prometheus metrics.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics_bind_addr: Option<SocketAddr>,
+    /// If set, in custody mode, swap outputs and matured undelegations are claimed automatically
+    /// in the background, subject to the KMS authorization policy.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub auto_claim: bool,
+    /// If set, how the view database is encrypted at rest.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_encryption: Option<DatabaseEncryption>,
+    /// Whether the custody service enforces the authorization policy in the KMS config.
+    ///
+    /// The policy was once not enforced, so configs written before this setting existed, which
+    /// lack it, keep signing any transaction, with a warning, until it's set after reviewing the
+    /// policy. Configs written by `pclientd init` set it.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub enforce_auth_policy: bool,
     /// Optional KMS config for custody mode
     pub kms_config: Option<soft_kms::Config>,
 }
@@ -59,6 +96,60 @@ impl PclientdConfig {
         std::fs::write(path, contents)?;
         Ok(())
     }
+
+    /// All the FVKs tracked by the view service, starting with the primary one.
+    pub fn full_viewing_keys(&self) -> Vec<FullViewingKey> {
+        std::iter::once(self.full_viewing_key.clone())
+            .chain(self.additional_full_viewing_keys.iter().cloned())
+            .collect()
+    }
+
+    /// The key the view database is encrypted with, if it is.
+    ///
+    /// As `pclientd` runs unattended, a passphrase must be set in the environment.
+    pub fn view_database_key(&self) -> Result<Option<DatabaseKey>> {
+        self.view_encryption
+            .as_ref()
+            .map(|encryption| {
+                encryption.key(|| {
+                    anyhow::bail!(
+                        "the view database is encrypted with a passphrase, which must be set in {PASSPHRASE_ENV_VAR}"
+                    )
+                })
+            })
+            .transpose()
+    }
+
+    /// The KMS config the custody service should use, if any, with its authorization policy
+    /// dropped unless [`enforce_auth_policy`](Self::enforce_auth_policy) is set.
+    pub fn custody_config(&self) -> Option<soft_kms::Config> {
+        let mut kms_config = self.kms_config.clone()?;
+        if !self.enforce_auth_policy && !kms_config.auth_policy.is_empty() {
+            tracing::warn!(
+                "the KMS authorization policy is not enforced; review it and set `enforce_auth_policy = true` in the config to enforce it"
+            );
+            kms_config.auth_policy.clear();
+        }
+        Some(kms_config)
+    }
+
+    /// The chaff policy the view worker should use during sync.
+    pub fn chaff_policy(&self) -> Result<ChaffPolicy> {
+        self.chaff_rate
+            .map(ChaffPolicy::new)
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+}
+
+/// Encrypt the view database with a key read from `path`, generating the key if the file
+/// doesn't exist.
+fn view_key_file_encryption(path: &Utf8PathBuf) -> Result<DatabaseEncryption> {
+    if !path.exists() {
+        println!("Writing a new view database key to: {:?}", path);
+        DatabaseKey::generate_key_file(path)?;
+    }
+    Ok(DatabaseEncryption::KeyFile { path: path.clone() })
 }
 
 fn default_home() -> Utf8PathBuf {
@@ -85,8 +176,11 @@ pub enum Command {
     /// Generate configs for `pclientd` in view or custody mode.
     Init {
         /// If provided, initialize in view mode with the given full viewing key.
+        ///
+        /// May be repeated to track several wallets; in custody mode, the additional
+        /// keys are tracked alongside the custodied wallet.
         #[clap(long, display_order = 100)]
-        view: Option<String>,
+        view: Vec<String>,
         /// If provided, initialize in custody mode with the given seed phrase.
         ///
         /// If the value '-' is provided, the seed phrase will be read from stdin.
@@ -103,11 +197,60 @@ pub enum Command {
         /// Sets the address to bind to to serve gRPC.
         #[clap(long, display_order = 900, default_value = "127.0.0.1:8081")]
         bind_addr: SocketAddr,
+        /// If provided, POST notifications of detected notes, swaps, and other events to this
+        /// URL as JSON.
+        #[clap(long, display_order = 900, parse(try_from_str = Url::parse))]
+        notification_webhook: Option<Url>,
+        /// If provided, the block height before which none of the tracked wallets can have
+        /// received funds, so that synchronization can skip the blocks before it.
+        #[clap(long, display_order = 900)]
+        birthday: Option<u64>,
+        /// If provided, the fraction (between 0 and 1) of blocks irrelevant to the tracked wallets
+        /// whose transactions are downloaded anyway during sync, to hide the relevant ones from
+        /// the node.
+        #[clap(long, display_order = 900)]
+        chaff_rate: Option<f64>,
+        /// If provided, serve Prometheus metrics, such as the number of chaff downloads made
+        /// during sync, on this address.
+        #[clap(long, display_order = 900)]
+        metrics_bind_addr: Option<SocketAddr>,
+        /// If set, in custody mode, automatically claim swap outputs and matured undelegations in
+        /// the background.
+        ///
+        /// The claims must be allowed by the KMS authorization policy. The generated config's
+        /// policy allows them, and only transactions which spend and claim funds into the
+        /// wallet's first address.
+        #[clap(long, display_order = 900, requires = "custody")]
+        auto_claim: bool,
+        /// If set, encrypt the view database at rest with a passphrase, which must be set in the
+        /// `PENUMBRA_VIEW_PASSPHRASE` environment variable when `pclientd` starts.
+        #[clap(long, display_order = 900, conflicts_with = "view_key_file")]
+        encrypt_view_with_passphrase: bool,
+        /// If provided, encrypt the view database at rest with a key read from this file.
+        ///
+        /// If the file doesn't exist, a new random key is written to it.
+        #[clap(long, display_order = 900)]
+        view_key_file: Option<Utf8PathBuf>,
     },
     /// Start running `pclientd`.
     Start {},
     /// Delete `pclientd` storage to reset local state.
     Reset {},
+    /// Encrypt the view database at rest, change the key it is encrypted with, or decrypt it.
+    ///
+    /// `pclientd` must not be running. The current key is taken from the config, and the new
+    /// passphrase, if any, is read from stdin.
+    EncryptView {
+        /// Encrypt the view database with a key read from this file, rather than with a
+        /// passphrase.
+        ///
+        /// If the file doesn't exist, a new random key is written to it.
+        #[clap(long)]
+        key_file: Option<Utf8PathBuf>,
+        /// Decrypt the view database, so that it is stored unencrypted.
+        #[clap(long, conflicts_with = "key_file")]
+        decrypt: bool,
+    },
 }
 
 impl Opt {
@@ -144,27 +287,21 @@ impl Opt {
         Ok(())
     }
 
-    async fn init_sqlite(&self, fvk: &FullViewingKey, grpc_url: &Url) -> Result<Storage> {
-        // Initialize client and storage
-        let mut client = AppQueryServiceClient::connect(grpc_url.to_string()).await?;
-
-        let params = client
-            .app_parameters(tonic::Request::new(AppParametersRequest {
-                chain_id: String::new(),
-            }))
-            .await?
-            .into_inner()
-            .try_into()?;
-
-        Storage::initialize(Some(self.sqlite_path()), fvk.clone(), params).await
-    }
-
-    async fn load_or_init_sqlite(&self, fvk: &FullViewingKey, grpc_url: &Url) -> Result<Storage> {
-        if self.sqlite_path().exists() {
-            Ok(Storage::load(self.sqlite_path()).await?)
-        } else {
-            self.init_sqlite(fvk, grpc_url).await
-        }
+    async fn load_or_init_sqlite(
+        &self,
+        key: Option<DatabaseKey>,
+        fvks: &[FullViewingKey],
+        grpc_url: &Url,
+        birthday: Option<u64>,
+    ) -> Result<Storage> {
+        Storage::load_or_initialize(
+            Some(self.sqlite_path()),
+            key,
+            fvks,
+            grpc_url.clone(),
+            birthday,
+        )
+        .await
     }
 
     pub async fn exec(self) -> Result<()> {
@@ -180,15 +317,70 @@ impl Opt {
 
                 Ok(())
             }
+            Command::EncryptView { key_file, decrypt } => {
+                let mut config = PclientdConfig::load(opt.config_path())
+                    .context("Failed to load pclientd config file")?;
+
+                let encryption = match key_file {
+                    _ if *decrypt => None,
+                    Some(path) => Some(view_key_file_encryption(path)?),
+                    None => Some(DatabaseEncryption::Passphrase),
+                };
+
+                if opt.sqlite_path().exists() {
+                    let from = config.view_database_key()?;
+                    let to = encryption
+                        .as_ref()
+                        .map(|encryption| {
+                            encryption.key(|| {
+                                println!("Enter the new view database passphrase: ");
+                                let line = io::stdin()
+                                    .lock()
+                                    .lines()
+                                    .next()
+                                    .context("no passphrase was entered")??;
+                                Ok(line)
+                            })
+                        })
+                        .transpose()?;
+                    penumbra_view::migrate_encryption(
+                        opt.sqlite_path(),
+                        from.as_ref(),
+                        to.as_ref(),
+                    )?;
+                    if to.is_some() {
+                        println!("Encrypted local storage at: {:?}", opt.sqlite_path());
+                    } else {
+                        println!("Decrypted local storage at: {:?}", opt.sqlite_path());
+                    }
+                }
+
+                config.view_encryption = encryption;
+                config.save(opt.config_path())?;
+
+                Ok(())
+            }
             Command::Init {
                 view,
                 custody,
                 grpc_url,
                 bind_addr,
+                notification_webhook,
+                birthday,
+                chaff_rate,
+                metrics_bind_addr,
+                auto_claim,
+                encrypt_view_with_passphrase,
+                view_key_file,
             } => {
                 // Check that the home directory is empty.
                 opt.check_home_nonempty()?;
 
+                // Check that the chaff rate is valid before writing it to the config.
+                if let Some(chaff_rate) = chaff_rate {
+                    ChaffPolicy::new(*chaff_rate)?;
+                }
+
                 let seed_phrase = match custody {
                     None => None,
                     Some(seed_phrase) => {
@@ -211,8 +403,13 @@ impl Opt {
                     }
                 };
 
-                let (spend_key, full_viewing_key) = match (seed_phrase, view) {
-                    (Some(seed_phrase), None) => {
+                let mut view_keys = view
+                    .iter()
+                    .map(|fvk| fvk.parse())
+                    .collect::<Result<Vec<FullViewingKey>>>()?;
+
+                let (spend_key, full_viewing_key) = match seed_phrase {
+                    Some(seed_phrase) => {
                         let spend_key = SpendKey::from_seed_phrase_bip44(
                             SeedPhrase::from_str(seed_phrase.as_str())?,
                             &Bip44Path::new(0),
@@ -220,18 +417,15 @@ impl Opt {
                         let full_viewing_key = spend_key.full_viewing_key().clone();
                         (Some(spend_key), full_viewing_key)
                     }
-                    (None, Some(view)) => (None, view.parse()?),
-                    (None, None) => {
+                    None if !view_keys.is_empty() => (None, view_keys.remove(0)),
+                    None => {
                         return Err(anyhow::anyhow!(
                             "Must provide either a seed phrase or a full viewing key."
                         ))
                     }
-                    (Some(_), Some(_)) => {
-                        return Err(anyhow::anyhow!(
-                            "Cannot provide both a seed phrase and a full viewing key."
-                        ))
-                    }
                 };
+                // Any remaining viewing keys are tracked alongside the primary wallet.
+                let additional_full_viewing_keys = view_keys;
 
                 println!(
                     "Initializing configuration at: {:?}",
@@ -240,37 +434,59 @@ impl Opt {
 
                 // Create config file with example authorization policy.
                 let kms_config: Option<soft_kms::Config> = spend_key.map(|spend_key| {
-                    // It's important that we throw away the signing key here, so that
-                    // by default the config is "cannot spend funds" without manual editing.
-                    let pak = ed25519_consensus::SigningKey::new(rand_core::OsRng);
-                    let pvk = pak.verification_key();
-
-                    let auth_policy = vec![
-                        AuthPolicy::DestinationAllowList {
-                            allowed_destination_addresses: vec![
-                                spend_key
-                                    .incoming_viewing_key()
-                                    .payment_address(Default::default())
-                                    .0,
-                            ],
-                        },
-                        AuthPolicy::OnlyIbcRelay,
-                        AuthPolicy::PreAuthorization(PreAuthorizationPolicy::Ed25519 {
-                            required_signatures: 1,
-                            allowed_signers: vec![pvk],
-                        }),
-                    ];
+                    let auth_policy = if *auto_claim {
+                        // Claims can't be pre-authorized, so they are allowed by policy instead,
+                        // along with nothing that could send funds out of the wallet.
+                        auto_claim::auth_policy(&spend_key)
+                    } else {
+                        // It's important that we throw away the signing key here, so that
+                        // by default the config is "cannot spend funds" without manual editing.
+                        let pak = ed25519_consensus::SigningKey::new(rand_core::OsRng);
+                        let pvk = pak.verification_key();
+
+                        vec![
+                            AuthPolicy::DestinationAllowList {
+                                allowed_destination_addresses: vec![
+                                    spend_key
+                                        .incoming_viewing_key()
+                                        .payment_address(Default::default())
+                                        .0,
+                                ],
+                            },
+                            AuthPolicy::OnlyIbcRelay,
+                            AuthPolicy::PreAuthorization(PreAuthorizationPolicy::Ed25519 {
+                                required_signatures: 1,
+                                allowed_signers: vec![pvk],
+                            }),
+                        ]
+                    };
                     soft_kms::Config {
                         spend_key,
                         auth_policy,
+                        spend_limits: Vec::new(),
+                        spend_ledger: None,
                     }
                 });
 
+                let view_encryption = match view_key_file {
+                    Some(path) => Some(view_key_file_encryption(path)?),
+                    None if *encrypt_view_with_passphrase => Some(DatabaseEncryption::Passphrase),
+                    None => None,
+                };
+
                 let client_config = PclientdConfig {
                     kms_config,
                     full_viewing_key,
+                    additional_full_viewing_keys,
                     grpc_url: grpc_url.clone(),
                     bind_addr: *bind_addr,
+                    notification_webhook: notification_webhook.clone(),
+                    birthday: *birthday,
+                    chaff_rate: *chaff_rate,
+                    metrics_bind_addr: *metrics_bind_addr,
+                    auto_claim: *auto_claim,
+                    view_encryption,
+                    enforce_auth_policy: true,
                 };
 
                 let encoded = toml::to_string_pretty(&client_config)
@@ -292,8 +508,23 @@ impl Opt {
                 )?;
 
                 tracing::info!(?opt.home, ?config.bind_addr, %config.grpc_url, "starting pclientd");
+                if let Some(metrics_bind_addr) = config.metrics_bind_addr {
+                    tracing::info!(%metrics_bind_addr, "serving metrics");
+                    PrometheusBuilder::new()
+                        .with_http_listener(metrics_bind_addr)
+                        .install()
+                        .with_context(|| {
+                            format!("failed to serve metrics on {metrics_bind_addr}")
+                        })?;
+                }
+                penumbra_view::register_metrics();
                 let storage = opt
-                    .load_or_init_sqlite(&config.full_viewing_key, &config.grpc_url)
+                    .load_or_init_sqlite(
+                        config.view_database_key()?,
+                        &config.full_viewing_keys(),
+                        &config.grpc_url,
+                        config.birthday,
+                    )
                     .await?;
 
                 let proxy_channel =
@@ -313,14 +544,32 @@ impl Opt {
                 let compact_block_query_proxy = CompactBlockQueryProxy(proxy_channel.clone());
                 let tendermint_proxy_proxy = TendermintProxyProxy(proxy_channel.clone());
 
-                let view_service = ViewProtocolServiceServer::new(
-                    ViewService::new(storage, config.grpc_url).await?,
-                );
-                let custody_service = config.kms_config.as_ref().map(|kms_config| {
-                    CustodyProtocolServiceServer::new(SoftKms::new(
-                        kms_config.spend_key.clone().into(),
-                    ))
-                });
+                let view_service =
+                    ViewService::new(storage, config.grpc_url, config.chaff_policy()?).await?;
+                if let Some(webhook) = config.notification_webhook {
+                    tracing::info!(%webhook, "forwarding notifications to webhook");
+                    tokio::spawn(webhook::forward_notifications(
+                        view_service.clone(),
+                        webhook,
+                    ));
+                }
+                // Auto-claiming and the custody service share a soft KMS, so that they share its
+                // record of spending under any spend limits.
+                let soft_kms = config.custody_config().map(SoftKms::new);
+                if config.auto_claim {
+                    let soft_kms = soft_kms
+                        .clone()
+                        .context("auto-claiming requires pclientd to run in custody mode")?;
+                    tracing::info!("automatically claiming swaps and undelegations");
+                    tokio::spawn(auto_claim::claim_periodically(
+                        view_service.for_wallet(config.full_viewing_key.wallet_id())?,
+                        soft_kms,
+                        config.full_viewing_key.clone(),
+                        proxy_channel.clone(),
+                    ));
+                }
+                let view_service = ViewProtocolServiceServer::new(view_service);
+                let custody_service = soft_kms.map(CustodyProtocolServiceServer::new);
 
                 let server = Server::builder()
                     .accept_http1(true)
@@ -353,3 +602,54 @@ impl Opt {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_keys::test_keys;
+
+    use super::*;
+
+    fn config(enforce_auth_policy: bool) -> PclientdConfig {
+        PclientdConfig {
+            full_viewing_key: test_keys::FULL_VIEWING_KEY.clone(),
+            additional_full_viewing_keys: Vec::new(),
+            grpc_url: "http://127.0.0.1:8080".parse().expect("valid url"),
+            bind_addr: "127.0.0.1:8081".parse().expect("valid address"),
+            notification_webhook: None,
+            birthday: None,
+            chaff_rate: None,
+            metrics_bind_addr: None,
+            auto_claim: false,
+            view_encryption: None,
+            enforce_auth_policy,
+            kms_config: Some(soft_kms::Config {
+                spend_key: test_keys::SPEND_KEY.clone(),
+                auth_policy: vec![AuthPolicy::OnlyIbcRelay],
+                spend_limits: Vec::new(),
+                spend_ledger: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn configs_without_enforcement_setting_do_not_enforce_policy() {
+        // A config written before the setting existed doesn't mention it.
+        let encoded = toml::to_string_pretty(&config(false)).expect("can encode config");
+        assert!(!encoded.contains("enforce_auth_policy"));
+        let decoded: PclientdConfig = toml::from_str(&encoded).expect("can decode config");
+
+        assert!(!decoded.enforce_auth_policy);
+        let custody_config = decoded.custody_config().expect("custody mode");
+        assert!(custody_config.auth_policy.is_empty());
+    }
+
+    #[test]
+    fn enforced_policy_is_kept() {
+        let encoded = toml::to_string_pretty(&config(true)).expect("can encode config");
+        let decoded: PclientdConfig = toml::from_str(&encoded).expect("can decode config");
+
+        assert!(decoded.enforce_auth_policy);
+        let custody_config = decoded.custody_config().expect("custody mode");
+        assert_eq!(custody_config.auth_policy.len(), 1);
+    }
+}
@@ -0,0 +1,199 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use penumbra_proto::view::v1alpha1::{
+    self as pb, view_protocol_service_client::ViewProtocolServiceClient,
+    view_protocol_service_server::ViewProtocolServiceServer,
+};
+use penumbra_view::{ViewClient, ViewService};
+use url::Url;
+
+/// How long to wait before resubscribing after the notification stream fails.
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(5);
+
+/// How many times delivering a notification is attempted before it is dropped.
+const DELIVERY_ATTEMPTS: u32 = 5;
+
+/// How long to wait before retrying a failed delivery, doubled after each further failure.
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Forwards the view service's notifications to `webhook`, as JSON-encoded `Notification`s
+/// POSTed one at a time.
+///
+/// Delivery is best-effort: a failed request is retried with exponential backoff, and the
+/// notification is dropped if the last attempt fails too. Notifications detected while a delivery
+/// is being retried wait for it, and may be skipped if too many pile up.
+pub async fn forward_notifications(view_service: ViewService, webhook: Url) {
+    let mut view = ViewProtocolServiceClient::new(ViewProtocolServiceServer::new(view_service));
+    let http = reqwest::Client::new();
+
+    loop {
+        let mut notifications = match ViewClient::notifications(&mut view, None).await {
+            Ok(notifications) => notifications,
+            Err(e) => {
+                tracing::warn!(?e, "failed to subscribe to view service notifications");
+                tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+                continue;
+            }
+        };
+
+        while let Some(notification) = notifications.next().await {
+            let notification = match notification {
+                Ok(notification) => pb::Notification::from(notification),
+                Err(e) => {
+                    tracing::warn!(?e, "view service notification stream failed");
+                    break;
+                }
+            };
+
+            if let Err(e) = deliver(&http, &webhook, &notification, RETRY_DELAY).await {
+                tracing::warn!(?e, %webhook, "failed to deliver notification to webhook, dropping it");
+            }
+        }
+
+        tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+    }
+}
+
+/// POSTs `notification` to `webhook`, making up to [`DELIVERY_ATTEMPTS`] attempts, the first retry
+/// after `retry_delay`.
+async fn deliver(
+    http: &reqwest::Client,
+    webhook: &Url,
+    notification: &pb::Notification,
+    retry_delay: Duration,
+) -> anyhow::Result<()> {
+    let mut delay = retry_delay;
+    for attempt in 1.. {
+        let result = http
+            .post(webhook.clone())
+            .json(notification)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        match result {
+            Ok(_) => break,
+            Err(e) if attempt < DELIVERY_ATTEMPTS => {
+                tracing::debug!(?e, attempt, %webhook, "failed to deliver notification, retrying");
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    /// Serves an HTTP endpoint answering each request with the next of `statuses`, returning its
+    /// URL and the bodies of the requests it received.
+    async fn webhook(statuses: Vec<u16>) -> (Url, Arc<Mutex<Vec<serde_json::Value>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/notify", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        let bodies = Arc::new(Mutex::new(Vec::new()));
+
+        let received = bodies.clone();
+        tokio::spawn(async move {
+            for status in statuses {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut stream = BufReader::new(stream);
+
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    stream.read_line(&mut line).await.unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut body = vec![0; content_length];
+                stream.read_exact(&mut body).await.unwrap();
+                received
+                    .lock()
+                    .unwrap()
+                    .push(serde_json::from_slice(&body).unwrap());
+
+                let response = format!(
+                    "HTTP/1.1 {status} Status\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (url, bodies)
+    }
+
+    fn notification() -> pb::Notification {
+        pb::Notification {
+            wallet_id: None,
+            height: 7,
+            event: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn notifications_are_posted_as_json() {
+        let (url, bodies) = webhook(vec![200]).await;
+
+        deliver(
+            &reqwest::Client::new(),
+            &url,
+            &notification(),
+            Duration::ZERO,
+        )
+        .await
+        .unwrap();
+
+        let expected = serde_json::to_value(notification()).unwrap();
+        assert_eq!(*bodies.lock().unwrap(), vec![expected]);
+    }
+
+    #[tokio::test]
+    async fn failed_deliveries_are_retried() {
+        let (url, bodies) = webhook(vec![500, 503, 200]).await;
+
+        deliver(
+            &reqwest::Client::new(),
+            &url,
+            &notification(),
+            Duration::ZERO,
+        )
+        .await
+        .unwrap();
+
+        let expected = serde_json::to_value(notification()).unwrap();
+        assert_eq!(*bodies.lock().unwrap(), vec![expected; 3]);
+    }
+
+    #[tokio::test]
+    async fn deliveries_are_dropped_after_the_last_attempt() {
+        let (url, bodies) = webhook(vec![500; DELIVERY_ATTEMPTS as usize]).await;
+
+        assert!(deliver(
+            &reqwest::Client::new(),
+            &url,
+            &notification(),
+            Duration::ZERO
+        )
+        .await
+        .is_err());
+        assert_eq!(bodies.lock().unwrap().len(), DELIVERY_ATTEMPTS as usize);
+    }
+}
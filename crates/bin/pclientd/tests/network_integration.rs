@@ -32,6 +32,7 @@ use penumbra_view::ViewClient;
 fn generate_config() -> anyhow::Result<PclientdConfig> {
     Ok(PclientdConfig {
         full_viewing_key: test_keys::FULL_VIEWING_KEY.clone(),
+        additional_full_viewing_keys: Vec::new(),
         grpc_url: std::env::var("PENUMBRA_NODE_PD_URL")
             .unwrap_or_else(|_| "http://127.0.0.1:8080".to_owned())
             .parse()?,
@@ -39,7 +40,16 @@ fn generate_config() -> anyhow::Result<PclientdConfig> {
         kms_config: Some(soft_kms::Config {
             spend_key: test_keys::SPEND_KEY.clone(),
             auth_policy: Vec::new(),
+            spend_limits: Vec::new(),
+            spend_ledger: None,
         }),
+        notification_webhook: None,
+        birthday: None,
+        chaff_rate: None,
+        metrics_bind_addr: None,
+        auto_claim: false,
+        enforce_auth_policy: true,
+        view_encryption: None,
     })
 }
 
@@ -151,6 +161,7 @@ async fn transaction_send_flow() -> anyhow::Result<()> {
         .witness_and_build(WitnessAndBuildRequest {
             transaction_plan: Some(plan),
             authorization_data: Some(auth_data),
+            wallet_id: None,
         })
         .await?
         .into_inner()
@@ -162,6 +173,7 @@ async fn transaction_send_flow() -> anyhow::Result<()> {
         .broadcast_transaction(BroadcastTransactionRequest {
             transaction: Some(tx),
             await_detection: true,
+            wallet_id: None,
         })
         .await?
         .into_inner()
@@ -292,6 +304,7 @@ async fn swap_claim_flow() -> anyhow::Result<()> {
         .witness_and_build(WitnessAndBuildRequest {
             transaction_plan: Some(plan),
             authorization_data: Some(auth_data),
+            wallet_id: None,
         })
         .await?
         .into_inner()
@@ -303,6 +316,7 @@ async fn swap_claim_flow() -> anyhow::Result<()> {
         .broadcast_transaction(BroadcastTransactionRequest {
             transaction: Some(tx),
             await_detection: true,
+            wallet_id: None,
         })
         .await?
         .into_inner()
@@ -360,6 +374,7 @@ async fn swap_claim_flow() -> anyhow::Result<()> {
         .witness_and_build(WitnessAndBuildRequest {
             transaction_plan: Some(plan),
             authorization_data: Some(auth_data),
+            wallet_id: None,
         })
         .await?
         .into_inner()
@@ -371,6 +386,7 @@ async fn swap_claim_flow() -> anyhow::Result<()> {
         .broadcast_transaction(BroadcastTransactionRequest {
             transaction: Some(tx),
             await_detection: true,
+            wallet_id: None,
         })
         .await?
         .into_inner()
@@ -388,6 +388,7 @@ async fn main() -> anyhow::Result<()> {
                 app::v1alpha1::query_service_server::QueryServiceServer as AppQueryServiceServer,
                 component::{
                     chain::v1alpha1::query_service_server::QueryServiceServer as ChainQueryServiceServer,
+                    community_pool::v1alpha1::query_service_server::QueryServiceServer as CommunityPoolQueryServiceServer,
                     compact_block::v1alpha1::query_service_server::QueryServiceServer as CompactBlockQueryServiceServer,
                     dex::v1alpha1::query_service_server::QueryServiceServer as DexQueryServiceServer,
                     governance::v1alpha1::query_service_server::QueryServiceServer as GovernanceQueryServiceServer,
@@ -401,6 +402,7 @@ async fn main() -> anyhow::Result<()> {
             use cnidarium::rpc::Server as StorageServer;
             use penumbra_app::rpc::Server as AppServer;
             use penumbra_chain::component::rpc::Server as ChainServer;
+            use penumbra_community_pool::component::rpc::Server as CommunityPoolServer;
             use penumbra_compact_block::component::rpc::Server as CompactBlockServer;
             use penumbra_dex::component::rpc::Server as DexServer;
             use penumbra_governance::component::rpc::Server as GovernanceServer;
@@ -445,6 +447,9 @@ async fn main() -> anyhow::Result<()> {
                 .add_service(we(ChainQueryServiceServer::new(ChainServer::new(
                     storage.clone(),
                 ))))
+                .add_service(we(CommunityPoolQueryServiceServer::new(
+                    CommunityPoolServer::new(storage.clone()),
+                )))
                 .add_service(we(CompactBlockQueryServiceServer::new(
                     CompactBlockServer::new(storage.clone()),
                 )))
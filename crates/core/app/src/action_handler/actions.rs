@@ -27,6 +27,8 @@ impl ActionHandler for Action {
             Action::Delegate(action) => action.check_stateless(()).await,
             Action::Undelegate(action) => action.check_stateless(()).await,
             Action::UndelegateClaim(action) => action.check_stateless(()).await,
+            Action::Redelegate(action) => action.check_stateless(()).await,
+            Action::RedelegateClaim(action) => action.check_stateless(()).await,
             Action::ValidatorDefinition(action) => action.check_stateless(()).await,
             Action::ValidatorVote(action) => action.check_stateless(()).await,
             Action::PositionClose(action) => action.check_stateless(()).await,
@@ -57,6 +59,8 @@ impl ActionHandler for Action {
             Action::Delegate(action) => action.check_stateful(state).await,
             Action::Undelegate(action) => action.check_stateful(state).await,
             Action::UndelegateClaim(action) => action.check_stateful(state).await,
+            Action::Redelegate(action) => action.check_stateful(state).await,
+            Action::RedelegateClaim(action) => action.check_stateful(state).await,
             Action::ValidatorDefinition(action) => action.check_stateful(state).await,
             Action::DelegatorVote(action) => action.check_stateful(state).await,
             Action::ValidatorVote(action) => action.check_stateful(state).await,
@@ -94,6 +98,8 @@ impl ActionHandler for Action {
             Action::Delegate(action) => action.execute(state).await,
             Action::Undelegate(action) => action.execute(state).await,
             Action::UndelegateClaim(action) => action.execute(state).await,
+            Action::Redelegate(action) => action.execute(state).await,
+            Action::RedelegateClaim(action) => action.execute(state).await,
             Action::ValidatorDefinition(action) => action.execute(state).await,
             Action::DelegatorVote(action) => action.execute(state).await,
             Action::ValidatorVote(action) => action.execute(state).await,
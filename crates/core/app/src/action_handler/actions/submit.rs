@@ -107,8 +107,8 @@ impl ActionHandler for ProposalSubmit {
                                 "invalid action in Community Pool spend proposal (would require proving)"
                             )
                         }
-                        Delegate(_) | Undelegate(_) => {
-                            // Delegation and undelegation is disallowed due to Undelegateclaim requiring proving.
+                        Delegate(_) | Undelegate(_) | Redelegate(_) | RedelegateClaim(_) => {
+                            // Delegation, undelegation and redelegation is disallowed due to Undelegateclaim requiring proving.
                             anyhow::bail!(
                                 "invalid action in Community Pool spend proposal (can't claim outputs of undelegation)"
                             )
@@ -144,6 +144,12 @@ impl ActionHandler for ProposalSubmit {
                 let _ = &ClientId::from_str(client_id)
                     .context("can't decode client id from IBC proposal")?;
             }
+            CommunityPoolVestingGrant { schedule } => {
+                schedule
+                    .check_valid()
+                    .context("invalid Community Pool vesting schedule")?;
+            }
+            CommunityPoolVestingCancel { .. } => {}
         }
 
         Ok(())
@@ -230,6 +236,22 @@ impl ActionHandler for ProposalSubmit {
                     .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
                 let _ = state.get_client_state(client_id).await?;
             }
+            ProposalPayload::CommunityPoolVestingGrant { .. } => {
+                // Vesting grants are paid out of the Community Pool, so they're subject to the
+                // same restriction as Community Pool spend proposals.
+                let community_pool_parameters = state.get_community_pool_params().await?;
+                anyhow::ensure!(
+                    community_pool_parameters.community_pool_spend_proposals_enabled,
+                    "Community Pool spend proposals are not enabled",
+                );
+            }
+            ProposalPayload::CommunityPoolVestingCancel { schedule_id } => {
+                // Check that there's an active schedule to cancel.
+                anyhow::ensure!(
+                    state.vesting_schedule(*schedule_id).await?.is_some(),
+                    "no active Community Pool vesting schedule with id {schedule_id}",
+                );
+            }
         }
 
         Ok(())
@@ -7,7 +7,7 @@ use cnidarium_component::Component;
 use jmt::RootHash;
 use penumbra_chain::component::{StateReadExt as _, StateWriteExt as _};
 use penumbra_chain::params::FmdParameters;
-use penumbra_community_pool::component::StateWriteExt as _;
+use penumbra_community_pool::component::{CommunityPool, StateWriteExt as _};
 use penumbra_community_pool::StateReadExt as _;
 use penumbra_compact_block::component::CompactBlockManager;
 use penumbra_dex::component::Dex;
@@ -438,6 +438,9 @@ impl App {
             Governance::end_epoch(&mut arc_state_tx)
                 .await
                 .expect("able to call end_epoch on Governance component");
+            CommunityPool::end_epoch(&mut arc_state_tx)
+                .await
+                .expect("able to call end_epoch on Community Pool component");
             ShieldedPool::end_epoch(&mut arc_state_tx)
                 .await
                 .expect("able to call end_epoch on shielded pool component");
@@ -27,6 +27,7 @@ pub mod params;
 
 pub mod metrics;
 pub mod rpc;
+pub mod simulate;
 pub use self::metrics::register_metrics;
 
 #[cfg(test)]
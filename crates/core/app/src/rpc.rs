@@ -1,13 +1,15 @@
 use cnidarium::Storage;
 use penumbra_chain::component::StateReadExt as _;
+use penumbra_governance::Proposal;
 use penumbra_proto::core::app::v1alpha1::{
-    query_service_server::QueryService, AppParametersRequest, AppParametersResponse,
+    query_service_server::QueryService, simulate_proposal_response, AppParametersRequest,
+    AppParametersResponse, SimulateProposalRequest, SimulateProposalResponse,
     TransactionsByHeightRequest, TransactionsByHeightResponse,
 };
 use tonic::Status;
 use tracing::instrument;
 
-use crate::app::StateReadExt as _;
+use crate::{app::StateReadExt as _, simulate::simulate_proposal};
 
 // TODO: Hide this and only expose a Router?
 pub struct Server {
@@ -68,4 +70,54 @@ impl QueryService for Server {
             app_parameters: Some(app_parameters.into()),
         }))
     }
+
+    #[instrument(skip(self, request))]
+    async fn simulate_proposal(
+        &self,
+        request: tonic::Request<SimulateProposalRequest>,
+    ) -> Result<tonic::Response<SimulateProposalResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let proposal: Proposal = request
+            .into_inner()
+            .proposal
+            .ok_or_else(|| Status::invalid_argument("missing proposal"))?
+            .try_into()
+            .map_err(|e| Status::invalid_argument(format!("invalid proposal: {e}")))?;
+
+        // A proposal that would fail is a successful simulation, so failures are reported in
+        // the response rather than as an error status.
+        let response = match simulate_proposal(state, proposal).await {
+            Ok(simulation) => SimulateProposalResponse {
+                error: String::new(),
+                old_app_parameters: Some(simulation.old_app_params.into()),
+                new_app_parameters: simulation.new_app_params.map(Into::into),
+                events: simulation
+                    .events
+                    .into_iter()
+                    .map(|event| simulate_proposal_response::Event {
+                        kind: event.kind,
+                        attributes: event
+                            .attributes
+                            .into_iter()
+                            .map(|attribute| simulate_proposal_response::event::Attribute {
+                                key: attribute.key,
+                                value: attribute.value,
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            },
+            Err(error) => SimulateProposalResponse {
+                error: format!("{error:#}"),
+                ..Default::default()
+            },
+        };
+
+        Ok(tonic::Response::new(response))
+    }
 }
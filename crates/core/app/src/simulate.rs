@@ -0,0 +1,113 @@
+//! Dry runs of governance proposal enactment against a fork of the chain state.
+
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use cnidarium::{Snapshot, StateDelta};
+use penumbra_governance::{
+    component::{StateReadExt as _, StateWriteExt as _},
+    state_key, Proposal, ProposalPayload, ProposalSubmit,
+};
+use penumbra_proto::StateReadProto as _;
+use penumbra_transaction::Transaction;
+use tendermint::abci;
+
+use crate::{action_handler::ActionHandler, app::StateReadExt as _, params::AppParameters};
+
+/// The outcome of a successful dry run of a proposal's enactment.
+#[derive(Debug, Clone)]
+pub struct ProposalSimulation {
+    /// The app parameters before the proposal was submitted.
+    pub old_app_params: AppParameters,
+    /// The app parameters the proposal would enact, if it is a parameter change.
+    pub new_app_params: Option<AppParameters>,
+    /// The events emitted while submitting and enacting the proposal.
+    pub events: Vec<abci::Event>,
+}
+
+/// Submits `proposal` on top of `snapshot` and enacts it as if it had passed immediately.
+///
+/// Nothing is written back to storage. Follow-up work that the app would perform in the
+/// blocks after enactment is performed immediately, so that failures which would only
+/// surface then (an invalid parameter change, an undeliverable Community Pool spend) are
+/// reported as errors here.
+pub async fn simulate_proposal(
+    snapshot: Snapshot,
+    mut proposal: Proposal,
+) -> Result<ProposalSimulation> {
+    let mut state = StateDelta::new(snapshot);
+    let old_app_params = state.get_app_params().await?;
+
+    proposal.id = state.next_proposal_id().await?;
+    let proposal_id = proposal.id;
+    let submit = ProposalSubmit {
+        proposal,
+        deposit_amount: old_app_params.governance_params.proposal_deposit_amount,
+    };
+    submit
+        .check_stateless(())
+        .await
+        .context("proposal failed stateless checks")?;
+    submit
+        .check_stateful(Arc::new(state.fork()))
+        .await
+        .context("proposal failed stateful checks")?;
+    submit
+        .execute(&mut state)
+        .await
+        .context("proposal submission failed")?;
+
+    state
+        .enact_proposal(proposal_id, &submit.proposal.payload)
+        .await?
+        .context("proposal enactment failed")?;
+
+    let mut new_app_params = None;
+    match &submit.proposal.payload {
+        ProposalPayload::ParameterChange { .. } => {
+            // Mirror the validation the app performs at the end of the block in which a
+            // parameter change passes.
+            let params = state
+                .next_block_pending_app_parameters()
+                .await?
+                .context("parameter change was not scheduled")?;
+            let old = AppParameters::from_changed_params(&params.old, None)?;
+            let new = AppParameters::from_changed_params(&params.new, Some(&old))?;
+            old.check_valid_update(&new)
+                .context("parameter change failed validation")?;
+            anyhow::ensure!(
+                old == old_app_params,
+                "current app parameters do not match the old parameters in the proposal"
+            );
+            new_app_params = Some(new);
+        }
+        ProposalPayload::CommunityPoolSpend { .. } => {
+            // Mirror the delivery of the Community Pool transaction that the app performs at the
+            // start of the next block.
+            let transaction: Transaction = state
+                .get(&state_key::community_pool_transaction(proposal_id))
+                .await?
+                .context("no Community Pool transaction was built for the proposal")?;
+            transaction
+                .check_stateless(())
+                .await
+                .context("Community Pool transaction failed stateless checks")?;
+            transaction
+                .check_stateful(Arc::new(state.fork()))
+                .await
+                .context("Community Pool transaction failed stateful checks")?;
+            transaction
+                .execute(&mut state)
+                .await
+                .context("Community Pool transaction failed to execute")?;
+        }
+        _ => {}
+    }
+
+    let (_, mut changes) = state.flatten();
+    Ok(ProposalSimulation {
+        old_app_params,
+        new_app_params,
+        events: changes.take_events(),
+    })
+}
@@ -1,8 +1,206 @@
-/// The Community Pool isn't really a "component" because it doesn't really execute anything by itself. It's
-/// just a collection of state that is modified by CommunityPoolSpend and CommunityPoolDeposit actions.
+/// The Community Pool is mostly a collection of state that is modified by CommunityPoolSpend and
+/// CommunityPoolDeposit actions. The only thing it executes by itself is paying out vesting
+/// schedules registered by passed proposals, at the end of each epoch.
+pub mod rpc;
 pub mod state_key;
 
 mod action_handler;
 mod view;
 
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use cnidarium_component::Component;
+use penumbra_asset::Value;
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_sct::CommitmentSource;
+use penumbra_shielded_pool::component::NoteManager;
+use tendermint::v0_37::abci;
+use tracing::instrument;
+
 pub use view::{StateReadExt, StateWriteExt};
+
+pub struct CommunityPool {}
+
+#[async_trait]
+impl Component for CommunityPool {
+    // Community Pool parameters are written to state by the app during `init_chain`.
+    type AppState = ();
+
+    #[instrument(name = "community_pool", skip(_state, _app_state))]
+    async fn init_chain<S: StateWrite>(_state: S, _app_state: Option<&Self::AppState>) {}
+
+    #[instrument(name = "community_pool", skip(_state, _begin_block))]
+    async fn begin_block<S: StateWrite + 'static>(
+        _state: &mut Arc<S>,
+        _begin_block: &abci::request::BeginBlock,
+    ) {
+    }
+
+    #[instrument(name = "community_pool", skip(_state, _end_block))]
+    async fn end_block<S: StateWrite + 'static>(
+        _state: &mut Arc<S>,
+        _end_block: &abci::request::EndBlock,
+    ) {
+    }
+
+    #[instrument(name = "community_pool", skip(state))]
+    async fn end_epoch<S: StateWrite + 'static>(state: &mut Arc<S>) -> Result<()> {
+        let state = Arc::get_mut(state).context("state should be unique")?;
+        let epoch_index = state.get_current_epoch().await?.index;
+        state.pay_vesting_schedules(epoch_index).await
+    }
+}
+
+#[async_trait]
+trait VestingManager: StateWriteExt {
+    /// Pays out everything vested by the end of `epoch_index` that has not
+    /// already been paid out, for every active vesting schedule.
+    async fn pay_vesting_schedules(&mut self, epoch_index: u64) -> Result<()> {
+        for (id, schedule) in self.vesting_schedules().await? {
+            let paid = self.vesting_paid(id).await?;
+            let Some(due) = schedule
+                .vested_by_end_of(epoch_index)
+                .checked_sub(&paid)
+                .filter(|due| due.value() > 0)
+            else {
+                continue;
+            };
+
+            let payout = Value {
+                amount: due,
+                asset_id: schedule.total.asset_id,
+            };
+
+            // If the Community Pool can't currently cover the payout, leave it
+            // owing: it will be paid out at the end of a later epoch, once the
+            // Community Pool has been replenished.
+            if let Err(error) = self.community_pool_withdraw(payout).await {
+                tracing::warn!(id, ?error, "unable to pay out vesting schedule this epoch");
+                continue;
+            }
+            self.mint_note(
+                payout,
+                &schedule.recipient,
+                CommitmentSource::CommunityPoolOutput,
+            )
+            .await?;
+            tracing::debug!(id, ?payout, "paid out vesting schedule");
+
+            let paid = paid + due;
+            if paid >= schedule.total.amount {
+                tracing::info!(id, "vesting schedule completed");
+                self.remove_vesting_schedule(id);
+            } else {
+                self.put_vesting_paid(id, paid);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> VestingManager for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{Snapshot, StateDelta, TempStorage};
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_keys::test_keys;
+    use penumbra_num::Amount;
+
+    use super::*;
+    use crate::VestingSchedule;
+
+    fn staking_tokens(amount: u64) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    /// Returns a state with a funded Community Pool and a single vesting
+    /// schedule of 1000 tokens, vesting 100 per epoch from epoch 10 with a
+    /// cliff at epoch 12.
+    async fn state_with_schedule(
+        storage: &TempStorage,
+        pool_balance: u64,
+    ) -> Result<StateDelta<Snapshot>> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state
+            .community_pool_deposit(staking_tokens(pool_balance))
+            .await?;
+        state.put_vesting_schedule(
+            0,
+            VestingSchedule {
+                recipient: *test_keys::ADDRESS_0,
+                total: staking_tokens(1000),
+                start_epoch: 10,
+                cliff_epoch: 12,
+                per_epoch: 100u64.into(),
+            },
+        );
+        Ok(state)
+    }
+
+    #[tokio::test]
+    async fn pays_vested_amounts_until_complete() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = state_with_schedule(&storage, 10_000).await?;
+
+        state.pay_vesting_schedules(11).await?;
+        assert_eq!(state.vesting_paid(0).await?, Amount::zero());
+        assert_eq!(
+            state
+                .community_pool_asset_balance(*STAKING_TOKEN_ASSET_ID)
+                .await?,
+            Amount::from(10_000u64)
+        );
+
+        state.pay_vesting_schedules(12).await?;
+        assert_eq!(state.vesting_paid(0).await?, Amount::from(300u64));
+
+        state.pay_vesting_schedules(13).await?;
+        assert_eq!(state.vesting_paid(0).await?, Amount::from(400u64));
+
+        // Paying out twice for the same epoch pays nothing more.
+        state.pay_vesting_schedules(13).await?;
+        assert_eq!(state.vesting_paid(0).await?, Amount::from(400u64));
+
+        state.pay_vesting_schedules(25).await?;
+        assert!(state.vesting_schedule(0).await?.is_none());
+        assert_eq!(
+            state
+                .community_pool_asset_balance(*STAKING_TOKEN_ASSET_ID)
+                .await?,
+            Amount::from(9_000u64)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn underfunded_payouts_are_deferred() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = state_with_schedule(&storage, 50).await?;
+
+        state.pay_vesting_schedules(12).await?;
+        assert_eq!(state.vesting_paid(0).await?, Amount::zero());
+        assert!(state.vesting_schedule(0).await?.is_some());
+
+        // Once the pool is replenished, everything owed so far is paid out.
+        state.community_pool_deposit(staking_tokens(1000)).await?;
+        state.pay_vesting_schedules(13).await?;
+        assert_eq!(state.vesting_paid(0).await?, Amount::from(400u64));
+        assert_eq!(
+            state
+                .community_pool_asset_balance(*STAKING_TOKEN_ASSET_ID)
+                .await?,
+            Amount::from(650u64)
+        );
+
+        Ok(())
+    }
+}
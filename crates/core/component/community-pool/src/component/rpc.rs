@@ -0,0 +1,119 @@
+use std::pin::Pin;
+
+use cnidarium::Storage;
+use futures::StreamExt;
+use penumbra_asset::{asset, Value};
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_proto::core::component::community_pool::v1alpha1::{
+    query_service_server::QueryService, CommunityPoolAssetBalancesRequest,
+    CommunityPoolAssetBalancesResponse, VestingSchedulesRequest, VestingSchedulesResponse,
+};
+use tonic::Status;
+use tracing::instrument;
+
+use super::StateReadExt;
+
+// TODO: Hide this and only expose a Router?
+pub struct Server {
+    storage: Storage,
+}
+
+impl Server {
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+#[tonic::async_trait]
+impl QueryService for Server {
+    type CommunityPoolAssetBalancesStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<CommunityPoolAssetBalancesResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
+    type VestingSchedulesStream = Pin<
+        Box<dyn futures::Stream<Item = Result<VestingSchedulesResponse, tonic::Status>> + Send>,
+    >;
+
+    #[instrument(skip(self, request))]
+    async fn community_pool_asset_balances(
+        &self,
+        request: tonic::Request<CommunityPoolAssetBalancesRequest>,
+    ) -> Result<tonic::Response<Self::CommunityPoolAssetBalancesStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let asset_ids = request
+            .into_inner()
+            .asset_ids
+            .into_iter()
+            .map(asset::Id::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| Status::invalid_argument(format!("invalid asset id: {e}")))?;
+
+        let balances = if asset_ids.is_empty() {
+            state
+                .community_pool_balance()
+                .await
+                .map_err(|e| Status::unavailable(format!("error getting balances: {e}")))?
+                .into_iter()
+                .collect::<Vec<_>>()
+        } else {
+            let mut balances = Vec::with_capacity(asset_ids.len());
+            for asset_id in asset_ids {
+                let amount = state
+                    .community_pool_asset_balance(asset_id)
+                    .await
+                    .map_err(|e| Status::unavailable(format!("error getting balance: {e}")))?;
+                balances.push((asset_id, amount));
+            }
+            balances
+        };
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(balances.into_iter().map(|(asset_id, amount)| {
+                Ok(CommunityPoolAssetBalancesResponse {
+                    balance: Some(Value { amount, asset_id }.into()),
+                })
+            }))
+            .boxed(),
+        ))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn vesting_schedules(
+        &self,
+        request: tonic::Request<VestingSchedulesRequest>,
+    ) -> Result<tonic::Response<Self::VestingSchedulesStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let schedules = state
+            .vesting_schedules()
+            .await
+            .map_err(|e| Status::unavailable(format!("error getting vesting schedules: {e}")))?;
+
+        let mut responses = Vec::with_capacity(schedules.len());
+        for (id, schedule) in schedules {
+            let paid = state.vesting_paid(id).await.map_err(|e| {
+                Status::unavailable(format!("error getting vesting schedules: {e}"))
+            })?;
+            responses.push(Ok(VestingSchedulesResponse {
+                id,
+                schedule: Some(schedule.into()),
+                paid: Some(paid.into()),
+            }));
+        }
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(responses).boxed(),
+        ))
+    }
+}
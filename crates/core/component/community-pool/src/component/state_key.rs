@@ -16,3 +16,18 @@ pub fn all_assets_balance() -> &'static str {
     // note: this must be the prefix of the above.
     "community_pool/asset/"
 }
+
+pub fn vesting_schedule(id: u64) -> String {
+    // Load-bearing format string: we need to pad with 0s to ensure that
+    // the lex order agrees with the numeric order on schedule IDs.
+    format!("community_pool/vesting/schedule/{id:020}")
+}
+
+pub fn all_vesting_schedules() -> &'static str {
+    // note: this must be the prefix of the above.
+    "community_pool/vesting/schedule/"
+}
+
+pub fn vesting_paid(id: u64) -> String {
+    format!("community_pool/vesting/paid/{id:020}")
+}
@@ -9,7 +9,7 @@ use penumbra_asset::{asset, Value};
 use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
 
-use crate::params::CommunityPoolParameters;
+use crate::{params::CommunityPoolParameters, VestingSchedule};
 
 use super::state_key;
 
@@ -47,6 +47,32 @@ pub trait StateReadExt: StateRead {
             .try_collect()
             .await
     }
+
+    /// Gets the vesting schedule with the given ID, if it is active.
+    async fn vesting_schedule(&self, id: u64) -> Result<Option<VestingSchedule>> {
+        self.get(&state_key::vesting_schedule(id)).await
+    }
+
+    /// Gets the amount paid out so far under the vesting schedule with the given ID.
+    async fn vesting_paid(&self, id: u64) -> Result<Amount> {
+        Ok(self
+            .get(&state_key::vesting_paid(id))
+            .await?
+            .unwrap_or_else(|| Amount::from(0u64)))
+    }
+
+    /// Gets all active vesting schedules, ordered by ID.
+    async fn vesting_schedules(&self) -> Result<Vec<(u64, VestingSchedule)>> {
+        let prefix = state_key::all_vesting_schedules();
+        self.prefix(prefix)
+            .map(|result| {
+                let (key, schedule) = result?;
+                let id = key.rsplit('/').next().expect("key is well-formed");
+                Ok((id.parse()?, schedule))
+            })
+            .try_collect()
+            .await
+    }
 }
 
 impl<T> StateReadExt for T where T: StateRead + ?Sized {}
@@ -87,6 +113,22 @@ pub trait StateWriteExt: StateWrite {
         }
         Ok(())
     }
+
+    /// Registers a vesting schedule under the given ID.
+    fn put_vesting_schedule(&mut self, id: u64, schedule: VestingSchedule) {
+        self.put(state_key::vesting_schedule(id), schedule);
+    }
+
+    /// Records the amount paid out so far under the vesting schedule with the given ID.
+    fn put_vesting_paid(&mut self, id: u64, paid: Amount) {
+        self.put(state_key::vesting_paid(id), paid);
+    }
+
+    /// Removes the vesting schedule with the given ID, stopping any further payouts.
+    fn remove_vesting_schedule(&mut self, id: u64) {
+        self.delete(state_key::vesting_schedule(id));
+        self.delete(state_key::vesting_paid(id));
+    }
 }
 
 impl<T> StateWriteExt for T where T: StateWrite + ?Sized {}
@@ -10,9 +10,12 @@ pub mod event;
 mod action;
 pub use action::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
 
+mod vesting;
+pub use vesting::VestingSchedule;
+
 pub mod genesis;
 pub mod params;
 
 #[cfg_attr(docsrs, doc(cfg(feature = "component")))]
 #[cfg(feature = "component")]
-pub use component::{StateReadExt, StateWriteExt};
+pub use component::{CommunityPool, StateReadExt, StateWriteExt};
@@ -0,0 +1,170 @@
+use anyhow::{Context, Error};
+use serde::{Deserialize, Serialize};
+use std::convert::{TryFrom, TryInto};
+
+use penumbra_asset::Value;
+use penumbra_keys::Address;
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::community_pool::v1alpha1 as pb, DomainType};
+
+/// A schedule of payouts from the Community Pool to a single recipient.
+///
+/// Funds vest at `per_epoch` per epoch starting with `start_epoch`, until
+/// `total` has vested. Nothing is paid out before `cliff_epoch`; from the
+/// cliff onwards, everything vested so far is paid out at the end of each
+/// epoch.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::VestingSchedule", into = "pb::VestingSchedule")]
+pub struct VestingSchedule {
+    pub recipient: Address,
+    pub total: Value,
+    pub start_epoch: u64,
+    pub cliff_epoch: u64,
+    pub per_epoch: Amount,
+}
+
+impl VestingSchedule {
+    /// Checks that the schedule is internally consistent.
+    pub fn check_valid(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.total.amount > Amount::zero(),
+            "vesting schedule must pay out a nonzero total"
+        );
+        anyhow::ensure!(
+            self.per_epoch > Amount::zero(),
+            "vesting schedule must vest a nonzero amount per epoch"
+        );
+        anyhow::ensure!(
+            self.per_epoch <= self.total.amount,
+            "vesting schedule cannot vest more than its total per epoch"
+        );
+        anyhow::ensure!(
+            self.cliff_epoch >= self.start_epoch,
+            "vesting schedule cliff must not precede its start"
+        );
+        Ok(())
+    }
+
+    /// The total amount that has vested by the end of the given epoch, and
+    /// may therefore have been paid out.
+    pub fn vested_by_end_of(&self, epoch_index: u64) -> Amount {
+        if epoch_index < self.cliff_epoch {
+            return Amount::zero();
+        }
+        let epochs = u128::from(epoch_index - self.start_epoch + 1);
+        let vested = epochs.saturating_mul(self.per_epoch.value());
+        Amount::from(vested.min(self.total.amount.value()))
+    }
+}
+
+impl DomainType for VestingSchedule {
+    type Proto = pb::VestingSchedule;
+}
+
+impl From<VestingSchedule> for pb::VestingSchedule {
+    fn from(msg: VestingSchedule) -> Self {
+        pb::VestingSchedule {
+            recipient: Some(msg.recipient.into()),
+            total: Some(msg.total.into()),
+            start_epoch: msg.start_epoch,
+            cliff_epoch: msg.cliff_epoch,
+            per_epoch: Some(msg.per_epoch.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::VestingSchedule> for VestingSchedule {
+    type Error = Error;
+
+    fn try_from(proto: pb::VestingSchedule) -> anyhow::Result<Self, Self::Error> {
+        Ok(VestingSchedule {
+            recipient: proto
+                .recipient
+                .ok_or_else(|| anyhow::anyhow!("missing recipient"))?
+                .try_into()
+                .context("malformed recipient")?,
+            total: proto
+                .total
+                .ok_or_else(|| anyhow::anyhow!("missing total"))?
+                .try_into()
+                .context("malformed total")?,
+            start_epoch: proto.start_epoch,
+            cliff_epoch: proto.cliff_epoch,
+            per_epoch: proto
+                .per_epoch
+                .ok_or_else(|| anyhow::anyhow!("missing per-epoch amount"))?
+                .try_into()
+                .context("malformed per-epoch amount")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_keys::test_keys;
+
+    use super::*;
+
+    fn schedule(total: u64, start_epoch: u64, cliff_epoch: u64, per_epoch: u64) -> VestingSchedule {
+        VestingSchedule {
+            recipient: *test_keys::ADDRESS_0,
+            total: Value {
+                amount: total.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+            start_epoch,
+            cliff_epoch,
+            per_epoch: per_epoch.into(),
+        }
+    }
+
+    #[test]
+    fn nothing_vests_before_cliff() {
+        let schedule = schedule(1000, 10, 12, 100);
+        assert_eq!(schedule.vested_by_end_of(10), Amount::zero());
+        assert_eq!(schedule.vested_by_end_of(11), Amount::zero());
+        // At the cliff, everything vested since the start is released at once.
+        assert_eq!(schedule.vested_by_end_of(12), Amount::from(300u64));
+    }
+
+    #[test]
+    fn vests_linearly_from_start() {
+        let schedule = schedule(1000, 10, 10, 100);
+        for epoch in 10..20 {
+            assert_eq!(
+                schedule.vested_by_end_of(epoch),
+                Amount::from((epoch - 9) * 100)
+            );
+        }
+        assert_eq!(schedule.vested_by_end_of(19), Amount::from(1000u64));
+    }
+
+    #[test]
+    fn final_epoch_vests_remainder() {
+        let schedule = schedule(1000, 0, 0, 300);
+        assert_eq!(schedule.vested_by_end_of(2), Amount::from(900u64));
+        // The last epoch only vests what remains of the total.
+        assert_eq!(schedule.vested_by_end_of(3), Amount::from(1000u64));
+        assert_eq!(schedule.vested_by_end_of(4), Amount::from(1000u64));
+        assert_eq!(schedule.vested_by_end_of(u64::MAX), Amount::from(1000u64));
+    }
+
+    #[test]
+    fn valid_schedules_are_accepted() {
+        assert!(schedule(1000, 10, 12, 100).check_valid().is_ok());
+        assert!(schedule(1000, 10, 10, 1000).check_valid().is_ok());
+    }
+
+    #[test]
+    fn invalid_schedules_are_rejected() {
+        // Nothing to pay out.
+        assert!(schedule(0, 10, 12, 100).check_valid().is_err());
+        // Never vests anything.
+        assert!(schedule(1000, 10, 12, 0).check_valid().is_err());
+        // Vests more than the total in a single epoch.
+        assert!(schedule(1000, 10, 12, 1001).check_valid().is_err());
+        // Cliff before the start.
+        assert!(schedule(1000, 10, 9, 100).check_valid().is_err());
+    }
+}
@@ -5,11 +5,12 @@ use ark_ff::Zero;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use decaf377::Fr;
+use penumbra_num::Amount;
 use penumbra_proof_params::DELEGATOR_VOTE_PROOF_VERIFICATION_KEY;
 use penumbra_txhash::TransactionContext;
 
 use crate::{
-    DelegatorVote, DelegatorVoteBody, DelegatorVoteProofPublic,
+    Ballot, DelegatorVote, DelegatorVoteBody, DelegatorVoteProofPublic,
     {component::StateWriteExt, StateReadExt},
 };
 use cnidarium_component::ActionHandler;
@@ -28,9 +29,8 @@ impl ActionHandler for DelegatorVote {
                     nullifier,
                     rk,
                     value,
-                    // Unused in stateless checks:
-                    unbonded_amount: _,
-                    vote: _,     // Only used when executing the vote
+                    unbonded_amount,
+                    vote,
                     proposal: _, // Checked against the current open proposals statefully
                 },
         } = self;
@@ -51,6 +51,10 @@ impl ActionHandler for DelegatorVote {
             .verify(&DELEGATOR_VOTE_PROOF_VERIFICATION_KEY, public)
             .context("a delegator vote proof did not verify")?;
 
+        // 3. Check that a weighted vote splits exactly the unbonded amount of the note. The
+        // unbonded amount itself is checked against the note's value statefully.
+        check_weights(vote, *unbonded_amount)?;
+
         Ok(())
     }
 
@@ -59,7 +63,7 @@ impl ActionHandler for DelegatorVote {
             body:
                 DelegatorVoteBody {
                     proposal,
-                    vote: _, // All votes are valid, so we don't need to do anything with this
+                    vote: _, // Weights were checked against the unbonded amount statelessly
                     start_position,
                     value,
                     unbonded_amount,
@@ -113,3 +117,44 @@ impl ActionHandler for DelegatorVote {
         Ok(())
     }
 }
+
+/// Checks that a weighted vote splits exactly the `unbonded_amount` of the note voting.
+fn check_weights(vote: &Ballot, unbonded_amount: Amount) -> Result<()> {
+    if let Ballot::Weighted(weighted_vote) = vote {
+        anyhow::ensure!(
+            weighted_vote.total() == unbonded_amount,
+            "delegator vote weights sum to {}, but the unbonded amount is {}",
+            weighted_vote.total(),
+            unbonded_amount,
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Vote, WeightedVote};
+
+    use super::*;
+
+    fn weighted(yes: u64, no: u64, abstain: u64) -> Ballot {
+        Ballot::Weighted(WeightedVote {
+            yes: yes.into(),
+            no: no.into(),
+            abstain: abstain.into(),
+        })
+    }
+
+    #[test]
+    fn weights_must_sum_to_the_unbonded_amount() {
+        assert!(check_weights(&weighted(60, 30, 10), 100u64.into()).is_ok());
+        assert!(check_weights(&weighted(60, 30, 9), 100u64.into()).is_err());
+        assert!(check_weights(&weighted(60, 30, 11), 100u64.into()).is_err());
+        assert!(check_weights(&weighted(0, 0, 0), 100u64.into()).is_err());
+    }
+
+    #[test]
+    fn single_votes_use_the_whole_unbonded_amount() {
+        assert!(check_weights(&Ballot::Single(Vote::Yes), 100u64.into()).is_ok());
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use penumbra_num::Amount;
 use penumbra_proto::DomainType;
 
 use crate::component::StateWriteExt;
@@ -10,7 +11,7 @@ use crate::{action_handler::ActionHandler, StateReadExt};
 use crate::{
     proposal_state::Outcome,
     proposal_state::State as ProposalState,
-    {ValidatorVote, ValidatorVoteBody, MAX_VALIDATOR_VOTE_REASON_LENGTH},
+    {Ballot, ValidatorVote, ValidatorVoteBody, MAX_VALIDATOR_VOTE_REASON_LENGTH},
 };
 
 #[async_trait]
@@ -31,6 +32,14 @@ impl ActionHandler for ValidatorVote {
             anyhow::bail!("validator vote reason is too long");
         }
 
+        // Check that a weighted vote gives some weight to at least one option, so that the
+        // validator's voting power can be divided in proportion to the weights.
+        if let Ballot::Weighted(weighted_vote) = body.vote {
+            if weighted_vote.total() == Amount::zero() {
+                anyhow::bail!("validator vote weights must not all be zero");
+            }
+        }
+
         // This is stateless verification, so we still need to check that the proposal being voted
         // on exists, and that this validator hasn't voted on it already.
 
@@ -42,7 +51,7 @@ impl ActionHandler for ValidatorVote {
             body:
                 ValidatorVoteBody {
                     proposal,
-                    vote: _, // All votes are valid, so we don't need to do anything with this
+                    vote: _, // Weights were checked statelessly
                     identity_key,
                     governance_key,
                     reason: _, // Checked the length in the stateless verification
@@ -29,7 +29,6 @@ use tracing::instrument;
 
 use crate::state_key;
 use crate::Tally;
-use crate::Vote;
 
 use super::StateReadExt;
 
@@ -322,29 +321,20 @@ impl QueryService for Server {
 
         let proposal_id = request.into_inner().proposal_id;
 
-        let s = state
-            .prefix::<Vote>(&state_key::all_validator_votes_for_proposal(proposal_id))
-            .and_then(|r| async move {
-                Ok((
-                    IdentityKey::from_str(r.0.rsplit('/').next().context("invalid key")?)?,
-                    r.1,
-                ))
-            })
-            .map_ok(|i: (IdentityKey, Vote)| ValidatorVotesResponse {
-                vote: Some(i.1.into()),
-                identity_key: Some(i.0.into()),
-            });
+        let votes = state.validator_votes(proposal_id).await.map_err(|e| {
+            tonic::Status::unavailable(format!("error getting validator votes from storage: {e}"))
+        })?;
 
-        Ok(tonic::Response::new(
-            s.map_err(|e: anyhow::Error| {
-                tonic::Status::unavailable(format!(
-                    "error getting validator votes from storage: {e}"
-                ))
+        let s = futures::stream::iter(votes.into_iter().map(|(identity_key, vote)| {
+            let (vote, weighted_vote) = vote.into_parts();
+            Ok(ValidatorVotesResponse {
+                vote,
+                identity_key: Some(identity_key.into()),
+                weighted_vote,
             })
-            // TODO: how do we instrument a Stream
-            //.instrument(Span::current())
-            .boxed(),
-        ))
+        }));
+
+        Ok(tonic::Response::new(s.boxed()))
     }
 
     #[instrument(skip(self, request))]
@@ -10,6 +10,7 @@ use futures::StreamExt;
 use ibc_types::core::client::ClientId;
 use penumbra_asset::{asset, Value, STAKING_TOKEN_DENOM};
 use penumbra_chain::component::{StateReadExt as _, StateWriteExt as _};
+use penumbra_community_pool::component::{StateReadExt as _, StateWriteExt as _};
 use penumbra_ibc::component::ClientStateReadExt as _;
 use penumbra_ibc::component::ClientStateWriteExt as _;
 use penumbra_num::Amount;
@@ -28,7 +29,7 @@ use crate::{
     proposal::{ChangedAppParameters, ChangedAppParametersSet, Proposal, ProposalPayload},
     proposal_state::State as ProposalState,
     validator_vote::action::ValidatorVoteReason,
-    vote::Vote,
+    vote::{Ballot, Vote, WeightedVote},
 };
 use crate::{state_key, tally::Tally};
 
@@ -104,10 +105,20 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
         &self,
         proposal_id: u64,
         identity_key: IdentityKey,
-    ) -> Result<Option<Vote>> {
-        Ok(self
+    ) -> Result<Option<Ballot>> {
+        if let Some(vote) = self
             .get::<Vote>(&state_key::validator_vote(proposal_id, identity_key))
-            .await?)
+            .await?
+        {
+            return Ok(Some(vote.into()));
+        }
+        Ok(self
+            .get::<WeightedVote>(&state_key::validator_weighted_vote(
+                proposal_id,
+                identity_key,
+            ))
+            .await?
+            .map(Into::into))
     }
 
     /// Get the proposal voting start block for a given proposal.
@@ -455,11 +466,24 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
     }
 
     /// Get all the validator votes for the proposal.
-    async fn validator_votes(&self, proposal_id: u64) -> Result<BTreeMap<IdentityKey, Vote>> {
+    async fn validator_votes(&self, proposal_id: u64) -> Result<BTreeMap<IdentityKey, Ballot>> {
         let mut votes = BTreeMap::new();
 
         let prefix = state_key::all_validator_votes_for_proposal(proposal_id);
-        let mut stream = self.prefix(&prefix);
+        let mut stream = self.prefix::<Vote>(&prefix);
+
+        while let Some((key, vote)) = stream.next().await.transpose()? {
+            let identity_key = key
+                .rsplit('/')
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("incorrect key format for validator vote"))?
+                .parse()?;
+            votes.insert(identity_key, vote.into());
+        }
+
+        // Weighted votes are stored separately, so that single-option votes keep their format.
+        let prefix = state_key::all_validator_weighted_votes_for_proposal(proposal_id);
+        let mut stream = self.prefix::<WeightedVote>(&prefix);
 
         while let Some((key, vote)) = stream.next().await.transpose()? {
             let identity_key = key
@@ -467,7 +491,7 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
                 .next()
                 .ok_or_else(|| anyhow::anyhow!("incorrect key format for validator vote"))?
                 .parse()?;
-            votes.insert(identity_key, vote);
+            votes.insert(identity_key, vote.into());
         }
 
         Ok(votes)
@@ -685,11 +709,19 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
         &mut self,
         proposal_id: u64,
         identity_key: IdentityKey,
-        vote: Vote,
+        vote: Ballot,
         reason: ValidatorVoteReason,
     ) {
         // Record the vote
-        self.put(state_key::validator_vote(proposal_id, identity_key), vote);
+        match vote {
+            Ballot::Single(vote) => {
+                self.put(state_key::validator_vote(proposal_id, identity_key), vote)
+            }
+            Ballot::Weighted(weighted_vote) => self.put(
+                state_key::validator_weighted_vote(proposal_id, identity_key),
+                weighted_vote,
+            ),
+        }
         // Record the vote justification
         self.put(
             state_key::validator_vote_reason(proposal_id, identity_key),
@@ -734,7 +766,7 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
         &mut self,
         proposal_id: u64,
         identity_key: IdentityKey,
-        vote: Vote,
+        vote: Ballot,
         nullifier: &Nullifier,
         unbonded_amount: Amount,
     ) -> Result<()> {
@@ -895,6 +927,23 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
                 let unfrozen_client = client_state.unfrozen();
                 self.put_client(client_id, unfrozen_client);
             }
+            ProposalPayload::CommunityPoolVestingGrant { schedule } => {
+                tracing::info!(?schedule, "registering Community Pool vesting schedule");
+                // The schedule is identified by the ID of the proposal that registered it.
+                self.put_vesting_schedule(proposal_id, schedule.clone());
+            }
+            ProposalPayload::CommunityPoolVestingCancel { schedule_id } => {
+                if self.vesting_schedule(*schedule_id).await?.is_some() {
+                    tracing::info!(schedule_id, "cancelling Community Pool vesting schedule");
+                    self.remove_vesting_schedule(*schedule_id);
+                } else {
+                    // The schedule may have completed since the proposal was submitted.
+                    tracing::info!(
+                        schedule_id,
+                        "Community Pool vesting schedule already inactive"
+                    );
+                }
+            }
         }
         Ok(Ok(()))
     }
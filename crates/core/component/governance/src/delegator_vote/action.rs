@@ -1,6 +1,6 @@
 use anyhow::Context;
 
-use crate::{vote::Vote, DelegatorVoteProof};
+use crate::{vote::Ballot, DelegatorVoteProof};
 use decaf377_rdsa::{Signature, SpendAuth, VerificationKey};
 use penumbra_asset::Value;
 use penumbra_num::Amount;
@@ -30,7 +30,9 @@ pub struct DelegatorVoteBody {
     /// The start position of the proposal in the TCT.
     pub start_position: tct::Position,
     /// The vote on the proposal.
-    pub vote: Vote, // With flow encryption, this will be a triple of flow ciphertexts
+    ///
+    /// If this is a weighted vote, its weights must sum to the unbonded amount.
+    pub vote: Ballot, // With flow encryption, this will be a triple of flow ciphertexts
     /// The value of the staked note being used to vote.
     pub value: Value, // With flow encryption, this will be a triple of balance commitments, and a public denomination
     /// The unbonded amount equivalent to the value above
@@ -49,10 +51,12 @@ impl EffectingData for DelegatorVoteBody {
 
 impl From<DelegatorVoteBody> for pb::DelegatorVoteBody {
     fn from(value: DelegatorVoteBody) -> Self {
+        let (vote, weighted_vote) = value.vote.into_parts();
         pb::DelegatorVoteBody {
             proposal: value.proposal,
             start_position: value.start_position.into(),
-            vote: Some(value.vote.into()),
+            vote,
+            weighted_vote,
             value: Some(value.value.into()),
             unbonded_amount: Some(value.unbonded_amount.into()),
             nullifier: Some(value.nullifier.into()),
@@ -71,10 +75,8 @@ impl TryFrom<pb::DelegatorVoteBody> for DelegatorVoteBody {
                 .start_position
                 .try_into()
                 .context("invalid start position in `DelegatorVote`")?,
-            vote: msg
-                .vote
-                .ok_or_else(|| anyhow::anyhow!("missing vote in `DelegatorVote`"))?
-                .try_into()?,
+            vote: Ballot::try_from_parts(msg.vote, msg.weighted_vote)
+                .context("invalid vote in `DelegatorVote`")?,
             value: msg
                 .value
                 .ok_or_else(|| anyhow::anyhow!("missing value in `DelegatorVote`"))?
@@ -1,3 +1,4 @@
+use anyhow::Context;
 use ark_ff::UniformRand;
 use ark_ff::Zero;
 use decaf377::{FieldExt, Fq, Fr};
@@ -17,7 +18,7 @@ use crate::delegator_vote::action::{DelegatorVote, DelegatorVoteBody};
 use crate::delegator_vote::proof::DelegatorVoteProof;
 use crate::DelegatorVoteProofPrivate;
 use crate::DelegatorVoteProofPublic;
-use crate::{vote::Vote, VotingReceiptToken};
+use crate::{vote::Ballot, VotingReceiptToken};
 
 /// A plan to vote as a delegator.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,7 +29,9 @@ pub struct DelegatorVotePlan {
     /// The start position of the proposal.
     pub start_position: tct::Position,
     /// The vote to cast.
-    pub vote: Vote,
+    ///
+    /// If this is a weighted vote, its weights sum to the unbonded amount.
+    pub vote: Ballot,
     /// A staked note that was spendable before the proposal started.
     pub staked_note: Note,
     /// The unbonded amount corresponding to the staked note.
@@ -45,12 +48,14 @@ pub struct DelegatorVotePlan {
 
 impl DelegatorVotePlan {
     /// Create a new [`DelegatorVotePlan`] that votes using the given positioned `note`.
+    ///
+    /// If `vote` is a weighted vote, its weights are scaled to sum to the `unbonded_amount`.
     #[allow(clippy::too_many_arguments)]
     pub fn new<R: CryptoRng + RngCore>(
         rng: &mut R,
         proposal: u64,
         start_position: tct::Position,
-        vote: Vote,
+        vote: Ballot,
         staked_note: Note,
         position: tct::Position,
         unbonded_amount: Amount,
@@ -58,7 +63,7 @@ impl DelegatorVotePlan {
         DelegatorVotePlan {
             proposal,
             start_position,
-            vote,
+            vote: vote.for_power(unbonded_amount),
             staked_note,
             unbonded_amount,
             position,
@@ -155,9 +160,11 @@ impl DelegatorVotePlan {
 
 impl From<DelegatorVotePlan> for pb::DelegatorVotePlan {
     fn from(inner: DelegatorVotePlan) -> Self {
+        let (vote, weighted_vote) = inner.vote.into_parts();
         pb::DelegatorVotePlan {
             proposal: inner.proposal,
-            vote: Some(inner.vote.into()),
+            vote,
+            weighted_vote,
             start_position: inner.start_position.into(),
             staked_note: Some(inner.staked_note.into()),
             unbonded_amount: Some(inner.unbonded_amount.into()),
@@ -185,10 +192,8 @@ impl TryFrom<pb::DelegatorVotePlan> for DelegatorVotePlan {
         Ok(DelegatorVotePlan {
             proposal: value.proposal,
             start_position: value.start_position.into(),
-            vote: value
-                .vote
-                .ok_or_else(|| anyhow::anyhow!("missing vote in `DelegatorVotePlan`"))?
-                .try_into()?,
+            vote: Ballot::try_from_parts(value.vote, value.weighted_vote)
+                .context("invalid vote in `DelegatorVotePlan`")?,
             staked_note: value
                 .staked_note
                 .ok_or_else(|| anyhow::anyhow!("missing staked note in `DelegatorVotePlan`"))?
@@ -55,7 +55,7 @@ mod action_handler;
 pub use component::{StateReadExt, StateWriteExt};
 
 pub mod vote;
-pub use vote::Vote;
+pub use vote::{Ballot, Vote, WeightedVote};
 
 pub mod genesis;
 pub mod params;
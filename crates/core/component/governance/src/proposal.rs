@@ -4,8 +4,9 @@ use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 use crate::params::GovernanceParameters;
+use penumbra_asset::Value;
 use penumbra_chain::params::ChainParameters;
-use penumbra_community_pool::params::CommunityPoolParameters;
+use penumbra_community_pool::{params::CommunityPoolParameters, VestingSchedule};
 use penumbra_distributions::params::DistributionsParameters;
 use penumbra_fee::params::FeeParameters;
 use penumbra_ibc::params::IBCParameters;
@@ -89,6 +90,16 @@ impl From<Proposal> for pb::Proposal {
                     client_id: client_id.into(),
                 });
             }
+            ProposalPayload::CommunityPoolVestingGrant { schedule } => {
+                proposal.community_pool_vesting_grant =
+                    Some(pb::proposal::CommunityPoolVestingGrant {
+                        schedule: Some(schedule.into()),
+                    });
+            }
+            ProposalPayload::CommunityPoolVestingCancel { schedule_id } => {
+                proposal.community_pool_vesting_cancel =
+                    Some(pb::proposal::CommunityPoolVestingCancel { schedule_id });
+            }
         }
         proposal
     }
@@ -148,6 +159,17 @@ impl TryFrom<pb::Proposal> for Proposal {
                 ProposalPayload::UpgradePlan {
                     height: upgrade_plan.height,
                 }
+            } else if let Some(vesting_grant) = inner.community_pool_vesting_grant {
+                ProposalPayload::CommunityPoolVestingGrant {
+                    schedule: vesting_grant
+                        .schedule
+                        .ok_or_else(|| anyhow::anyhow!("missing vesting schedule"))?
+                        .try_into()?,
+                }
+            } else if let Some(vesting_cancel) = inner.community_pool_vesting_cancel {
+                ProposalPayload::CommunityPoolVestingCancel {
+                    schedule_id: vesting_cancel.schedule_id,
+                }
             } else {
                 anyhow::bail!("missing proposal payload or unknown proposal type");
             },
@@ -218,6 +240,12 @@ pub enum ProposalKind {
     /// A proposal to unfreeze an IBC client.
     #[cfg_attr(feature = "clap", clap(display_order = 700))]
     UnfreezeIbcClient,
+    /// A Community Pool vesting grant proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 800))]
+    CommunityPoolVestingGrant,
+    /// A Community Pool vesting cancellation proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 900))]
+    CommunityPoolVestingCancel,
 }
 
 impl FromStr for ProposalKind {
@@ -230,6 +258,8 @@ impl FromStr for ProposalKind {
             "parameterchange" => Ok(ProposalKind::ParameterChange),
             "communitypoolspend" => Ok(ProposalKind::CommunityPoolSpend),
             "upgrade_plan" => Ok(ProposalKind::UpgradePlan),
+            "communitypoolvestinggrant" => Ok(ProposalKind::CommunityPoolVestingGrant),
+            "communitypoolvestingcancel" => Ok(ProposalKind::CommunityPoolVestingCancel),
             _ => Err(anyhow::anyhow!("invalid proposal kind: {}", s)),
         }
     }
@@ -246,6 +276,12 @@ impl Proposal {
             ProposalPayload::UpgradePlan { .. } => ProposalKind::UpgradePlan,
             ProposalPayload::FreezeIbcClient { .. } => ProposalKind::FreezeIbcClient,
             ProposalPayload::UnfreezeIbcClient { .. } => ProposalKind::UnfreezeIbcClient,
+            ProposalPayload::CommunityPoolVestingGrant { .. } => {
+                ProposalKind::CommunityPoolVestingGrant
+            }
+            ProposalPayload::CommunityPoolVestingCancel { .. } => {
+                ProposalKind::CommunityPoolVestingCancel
+            }
         }
     }
 }
@@ -304,6 +340,18 @@ pub enum ProposalPayload {
         /// The identifier of the client to unfreeze.
         client_id: String,
     },
+    /// A Community Pool vesting grant proposal registers a schedule of payouts from the Community
+    /// Pool, made at the end of each epoch, identified by the ID of the proposal.
+    CommunityPoolVestingGrant {
+        /// The vesting schedule to register when the proposal is passed.
+        schedule: VestingSchedule,
+    },
+    /// A Community Pool vesting cancellation proposal stops any further payouts from a vesting
+    /// schedule registered by a previous proposal.
+    CommunityPoolVestingCancel {
+        /// The ID of the proposal that registered the vesting schedule.
+        schedule_id: u64,
+    },
 }
 
 /// A TOML-serializable version of `ProposalPayload`, meant for human consumption.
@@ -332,6 +380,16 @@ pub enum ProposalPayloadToml {
     UnfreezeIbcClient {
         client_id: String,
     },
+    CommunityPoolVestingGrant {
+        recipient: String,
+        total: String,
+        per_epoch: String,
+        start_epoch: u64,
+        cliff_epoch: u64,
+    },
+    CommunityPoolVestingCancel {
+        schedule_id: u64,
+    },
 }
 
 impl TryFrom<ProposalPayloadToml> for ProposalPayload {
@@ -365,6 +423,36 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
             ProposalPayloadToml::UnfreezeIbcClient { client_id } => {
                 ProposalPayload::UnfreezeIbcClient { client_id }
             }
+            ProposalPayloadToml::CommunityPoolVestingGrant {
+                recipient,
+                total,
+                per_epoch,
+                start_epoch,
+                cliff_epoch,
+            } => {
+                let total: Value = total.parse().context("couldn't parse vesting total")?;
+                let per_epoch: Value = per_epoch
+                    .parse()
+                    .context("couldn't parse vesting amount per epoch")?;
+                anyhow::ensure!(
+                    per_epoch.asset_id == total.asset_id,
+                    "vesting amount per epoch must be of the same asset as the total"
+                );
+                ProposalPayload::CommunityPoolVestingGrant {
+                    schedule: VestingSchedule {
+                        recipient: recipient
+                            .parse()
+                            .context("couldn't parse vesting recipient address")?,
+                        total,
+                        start_epoch,
+                        cliff_epoch,
+                        per_epoch: per_epoch.amount,
+                    },
+                }
+            }
+            ProposalPayloadToml::CommunityPoolVestingCancel { schedule_id } => {
+                ProposalPayload::CommunityPoolVestingCancel { schedule_id }
+            }
         })
     }
 }
@@ -394,6 +482,21 @@ impl From<ProposalPayload> for ProposalPayloadToml {
             ProposalPayload::UnfreezeIbcClient { client_id } => {
                 ProposalPayloadToml::UnfreezeIbcClient { client_id }
             }
+            ProposalPayload::CommunityPoolVestingGrant { schedule } => {
+                // Amounts are rendered in base units, tagged with the asset ID, so that they
+                // round-trip exactly.
+                let asset_id = schedule.total.asset_id;
+                ProposalPayloadToml::CommunityPoolVestingGrant {
+                    recipient: schedule.recipient.to_string(),
+                    total: format!("{}{}", schedule.total.amount, asset_id),
+                    per_epoch: format!("{}{}", schedule.per_epoch, asset_id),
+                    start_epoch: schedule.start_epoch,
+                    cliff_epoch: schedule.cliff_epoch,
+                }
+            }
+            ProposalPayload::CommunityPoolVestingCancel { schedule_id } => {
+                ProposalPayloadToml::CommunityPoolVestingCancel { schedule_id }
+            }
         }
     }
 }
@@ -8,7 +8,7 @@ use penumbra_proto::{penumbra::core::component::governance::v1alpha1 as pb, Doma
 use crate::{
     params::GovernanceParameters,
     proposal_state::{Outcome as StateOutcome, Withdrawn},
-    vote::Vote,
+    vote::{Ballot, Vote, WeightedVote},
 };
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
@@ -79,6 +79,28 @@ impl From<(u64, Vote)> for Tally {
     }
 }
 
+impl From<WeightedVote> for Tally {
+    fn from(weighted_vote: WeightedVote) -> Self {
+        Self {
+            yes: weighted_vote.yes.value() as u64,
+            no: weighted_vote.no.value() as u64,
+            abstain: weighted_vote.abstain.value() as u64,
+        }
+    }
+}
+
+impl From<(Ballot, u64)> for Tally {
+    fn from((ballot, power): (Ballot, u64)) -> Self {
+        ballot.weights(power.into()).into()
+    }
+}
+
+impl From<(u64, Ballot)> for Tally {
+    fn from((power, ballot): (u64, Ballot)) -> Self {
+        Self::from((ballot, power))
+    }
+}
+
 impl Add for Tally {
     type Output = Self;
 
@@ -187,3 +209,43 @@ impl Tally {
         Ratio::new(self.yes, total_voting_power) > Ratio::new(2, 3)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weighted(yes: u64, no: u64, abstain: u64) -> Ballot {
+        Ballot::Weighted(WeightedVote {
+            yes: yes.into(),
+            no: no.into(),
+            abstain: abstain.into(),
+        })
+    }
+
+    #[test]
+    fn weighted_ballot_splits_voting_power() {
+        let tally = Tally::from((weighted(1, 1, 2), 100));
+        assert_eq!((tally.yes(), tally.no(), tally.abstain()), (25, 25, 50));
+    }
+
+    #[test]
+    fn weighted_ballot_rounding_remainder_abstains() {
+        let tally = Tally::from((weighted(1, 1, 1), 100));
+        assert_eq!((tally.yes(), tally.no(), tally.abstain()), (33, 33, 34));
+        assert_eq!(tally.total(), 100);
+    }
+
+    #[test]
+    fn weighted_validator_tally_excludes_delegator_overrides() {
+        // A validator with 1000 voting power splits its vote 3:1, while delegators holding 200 of
+        // its power override it by voting no.
+        let delegator_tally = Tally::from(WeightedVote {
+            no: 200u64.into(),
+            ..Default::default()
+        });
+        let effective_power = 1000 - delegator_tally.total();
+        let tally = Tally::from((weighted(3, 1, 0), effective_power)) + delegator_tally;
+        assert_eq!((tally.yes(), tally.no(), tally.abstain()), (600, 400, 0));
+        assert_eq!(tally.total(), 1000);
+    }
+}
@@ -1,10 +1,11 @@
+use anyhow::Context;
 use decaf377_rdsa::{Signature, SpendAuth};
 use penumbra_proto::{penumbra::core::component::governance::v1alpha1 as pb, DomainType};
 use penumbra_stake::{GovernanceKey, IdentityKey};
 use penumbra_txhash::{EffectHash, EffectingData};
 use serde::{Deserialize, Serialize};
 
-use crate::vote::Vote;
+use crate::vote::Ballot;
 
 /// A vote by a validator.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,7 +69,10 @@ pub struct ValidatorVoteBody {
     /// The proposal ID to vote on.
     pub proposal: u64,
     /// The vote to cast.
-    pub vote: Vote,
+    ///
+    /// If this is a weighted vote, the validator's voting power is divided between the options in
+    /// proportion to its weights.
+    pub vote: Ballot,
     /// The identity of the validator who is voting.
     pub identity_key: IdentityKey,
     /// The governance key for the validator who is voting.
@@ -79,9 +83,11 @@ pub struct ValidatorVoteBody {
 
 impl From<ValidatorVoteBody> for pb::ValidatorVoteBody {
     fn from(value: ValidatorVoteBody) -> Self {
+        let (vote, weighted_vote) = value.vote.into_parts();
         pb::ValidatorVoteBody {
             proposal: value.proposal,
-            vote: Some(value.vote.into()),
+            vote,
+            weighted_vote,
             identity_key: Some(value.identity_key.into()),
             governance_key: Some(value.governance_key.into()),
             reason: Some(value.reason.into()),
@@ -95,10 +101,8 @@ impl TryFrom<pb::ValidatorVoteBody> for ValidatorVoteBody {
     fn try_from(msg: pb::ValidatorVoteBody) -> Result<Self, Self::Error> {
         Ok(ValidatorVoteBody {
             proposal: msg.proposal,
-            vote: msg
-                .vote
-                .ok_or_else(|| anyhow::anyhow!("missing vote in `ValidatorVote`"))?
-                .try_into()?,
+            vote: Ballot::try_from_parts(msg.vote, msg.weighted_vote)
+                .context("invalid vote in `ValidatorVote`")?,
             identity_key: msg
                 .identity_key
                 .ok_or_else(|| anyhow::anyhow!("missing validator identity in `ValidatorVote`"))?
@@ -3,7 +3,8 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::anyhow;
+use anyhow::{anyhow, Context};
+use penumbra_num::Amount;
 use penumbra_proto::{penumbra::core::component::governance::v1alpha1 as pb, DomainType};
 use serde::{Deserialize, Serialize};
 
@@ -84,6 +85,188 @@ impl TryFrom<pb::Vote> for Vote {
     }
 }
 
+/// A vote whose voting power is split between the options, according to the given weights.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(try_from = "pb::WeightedVote", into = "pb::WeightedVote")]
+pub struct WeightedVote {
+    /// The weight given to voting yes.
+    pub yes: Amount,
+    /// The weight given to voting no.
+    pub no: Amount,
+    /// The weight given to abstaining.
+    pub abstain: Amount,
+}
+
+impl WeightedVote {
+    /// The sum of the weights given to each option, saturating at the maximum amount.
+    pub fn total(&self) -> Amount {
+        self.yes
+            .value()
+            .saturating_add(self.no.value())
+            .saturating_add(self.abstain.value())
+            .into()
+    }
+
+    /// Scales the weights proportionally so that they sum to exactly `power`.
+    ///
+    /// The yes and no weights are rounded down, and any remainder is assigned to abstain, so
+    /// that rounding can never increase the weight of a yes or no vote.
+    pub fn scaled_to(&self, power: Amount) -> WeightedVote {
+        let total = self.total().value();
+        if total == 0 {
+            return WeightedVote {
+                abstain: power,
+                ..Default::default()
+            };
+        }
+        let scale = |weight: Amount| -> Amount {
+            // Saturating only ever rounds the scaled weight down further, so the sum of the scaled
+            // yes and no weights can never exceed `power`.
+            (weight.value().saturating_mul(power.value()) / total).into()
+        };
+        let yes = scale(self.yes);
+        let no = scale(self.no);
+        WeightedVote {
+            yes,
+            no,
+            abstain: power - yes - no,
+        }
+    }
+}
+
+impl Display for WeightedVote {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "yes: {}, no: {}, abstain: {}",
+            self.yes, self.no, self.abstain
+        )
+    }
+}
+
+impl From<WeightedVote> for pb::WeightedVote {
+    fn from(value: WeightedVote) -> Self {
+        pb::WeightedVote {
+            yes: Some(value.yes.into()),
+            no: Some(value.no.into()),
+            abstain: Some(value.abstain.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::WeightedVote> for WeightedVote {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::WeightedVote) -> Result<Self, Self::Error> {
+        Ok(WeightedVote {
+            yes: msg
+                .yes
+                .ok_or_else(|| anyhow!("missing yes weight"))?
+                .try_into()
+                .context("invalid yes weight")?,
+            no: msg
+                .no
+                .ok_or_else(|| anyhow!("missing no weight"))?
+                .try_into()
+                .context("invalid no weight")?,
+            abstain: msg
+                .abstain
+                .ok_or_else(|| anyhow!("missing abstain weight"))?
+                .try_into()
+                .context("invalid abstain weight")?,
+        })
+    }
+}
+
+impl DomainType for WeightedVote {
+    type Proto = pb::WeightedVote;
+}
+
+/// A vote as cast in a validator or delegator vote: either entirely for a single option, or split
+/// between the options by weight.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Ballot {
+    /// All of the voter's power is used to vote for a single option.
+    Single(Vote),
+    /// The voter's power is split between the options.
+    Weighted(WeightedVote),
+}
+
+impl Ballot {
+    /// Restates this ballot for a voter with exactly `power` voting power.
+    ///
+    /// Single-option ballots are unchanged, and weighted ballots have their weights scaled to sum
+    /// to `power`.
+    pub fn for_power(&self, power: Amount) -> Ballot {
+        match self {
+            Ballot::Single(vote) => Ballot::Single(*vote),
+            Ballot::Weighted(weights) => Ballot::Weighted(weights.scaled_to(power)),
+        }
+    }
+
+    /// Splits `power` between the options according to this ballot.
+    pub fn weights(&self, power: Amount) -> WeightedVote {
+        match self {
+            Ballot::Single(Vote::Yes) => WeightedVote {
+                yes: power,
+                ..Default::default()
+            },
+            Ballot::Single(Vote::No) => WeightedVote {
+                no: power,
+                ..Default::default()
+            },
+            Ballot::Single(Vote::Abstain) => WeightedVote {
+                abstain: power,
+                ..Default::default()
+            },
+            Ballot::Weighted(weights) => weights.scaled_to(power),
+        }
+    }
+
+    /// Converts the pair of proto fields used to carry a ballot into a [`Ballot`], checking that
+    /// exactly one of them is set.
+    pub fn try_from_parts(
+        vote: Option<pb::Vote>,
+        weighted_vote: Option<pb::WeightedVote>,
+    ) -> anyhow::Result<Ballot> {
+        match (vote, weighted_vote) {
+            (Some(vote), None) => Ok(Ballot::Single(vote.try_into()?)),
+            (None, Some(weighted_vote)) => Ok(Ballot::Weighted(weighted_vote.try_into()?)),
+            (None, None) => Err(anyhow!("missing vote")),
+            (Some(_), Some(_)) => Err(anyhow!("both a vote and a weighted vote were given")),
+        }
+    }
+
+    /// Converts a [`Ballot`] into the pair of proto fields used to carry it.
+    pub fn into_parts(self) -> (Option<pb::Vote>, Option<pb::WeightedVote>) {
+        match self {
+            Ballot::Single(vote) => (Some(vote.into()), None),
+            Ballot::Weighted(weighted_vote) => (None, Some(weighted_vote.into())),
+        }
+    }
+}
+
+impl From<Vote> for Ballot {
+    fn from(vote: Vote) -> Self {
+        Ballot::Single(vote)
+    }
+}
+
+impl From<WeightedVote> for Ballot {
+    fn from(weighted_vote: WeightedVote) -> Self {
+        Ballot::Weighted(weighted_vote)
+    }
+}
+
+impl Display for Ballot {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Ballot::Single(vote) => write!(f, "{vote}"),
+            Ballot::Weighted(weighted_vote) => write!(f, "split ({weighted_vote})"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use proptest::proptest;
@@ -95,6 +278,67 @@ mod test {
             let vote2 = super::Vote::try_from(pb_vote).unwrap();
             assert_eq!(vote, vote2);
         }
+
+        #[test]
+        fn scaled_weights_sum_to_power(
+            yes in 0u64..1_000_000,
+            no in 0u64..1_000_000,
+            abstain in 0u64..1_000_000,
+            power in 0u64..u64::MAX,
+        ) {
+            let weights = super::WeightedVote {
+                yes: yes.into(),
+                no: no.into(),
+                abstain: abstain.into(),
+            };
+            let scaled = weights.scaled_to(power.into());
+            assert_eq!(scaled.total(), power.into());
+        }
+    }
+
+    fn weighted_vote() -> super::WeightedVote {
+        super::WeightedVote {
+            yes: 3u64.into(),
+            no: 2u64.into(),
+            abstain: 1u64.into(),
+        }
+    }
+
+    #[test]
+    fn ballot_roundtrips_through_parts() {
+        for ballot in [
+            super::Ballot::Single(super::Vote::No),
+            super::Ballot::Weighted(weighted_vote()),
+        ] {
+            let (vote, weighted_vote) = ballot.into_parts();
+            let ballot2 = super::Ballot::try_from_parts(vote, weighted_vote).unwrap();
+            assert_eq!(ballot, ballot2);
+        }
+    }
+
+    #[test]
+    fn ballot_requires_exactly_one_part() {
+        assert!(super::Ballot::try_from_parts(None, None).is_err());
+        assert!(super::Ballot::try_from_parts(
+            Some(super::Vote::Yes.into()),
+            Some(weighted_vote().into()),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn ballot_rejects_missing_weights() {
+        let mut weighted_vote = super::pb::WeightedVote::from(weighted_vote());
+        weighted_vote.no = None;
+        assert!(super::Ballot::try_from_parts(None, Some(weighted_vote)).is_err());
+    }
+
+    #[test]
+    fn ballot_rejects_unspecified_vote() {
+        let vote = super::pb::Vote {
+            vote: super::pb::vote::Vote::Unspecified as i32,
+        };
+        assert!(super::Ballot::try_from_parts(Some(vote), None).is_err());
     }
 }
 
@@ -1,5 +1,7 @@
+mod tree_store;
 mod view;
 
+pub use tree_store::TreeStore;
 pub use view::{SctManager, SourceContext, StateReadExt};
 
 pub mod rpc;
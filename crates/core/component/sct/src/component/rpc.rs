@@ -1,16 +1,60 @@
 use cnidarium::Storage;
-use penumbra_proto::core::component::sct::v1alpha1::query_service_server::QueryService;
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_proto::core::component::sct::v1alpha1::{
+    query_service_server::QueryService, SctFrontierRequest, SctFrontierResponse,
+};
+use tonic::Status;
+use tracing::instrument;
+
+use super::StateReadExt as _;
 
 // TODO: Hide this and only expose a Router?
 pub struct Server {
-    _storage: Storage,
+    storage: Storage,
 }
 
 impl Server {
-    pub fn new(_storage: Storage) -> Self {
-        Self { _storage }
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
     }
 }
 
 #[tonic::async_trait]
-impl QueryService for Server {}
+impl QueryService for Server {
+    #[instrument(skip(self, request))]
+    async fn sct_frontier(
+        &self,
+        request: tonic::Request<SctFrontierRequest>,
+    ) -> Result<tonic::Response<SctFrontierResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let epoch = state
+            .epoch_by_height(request.get_ref().height)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("could not get epoch for height: {e}")))?;
+
+        // The frontier must precede the requested height, so it is the one taken at the end of
+        // the previous epoch.
+        let Some(previous_epoch) = epoch.index.checked_sub(1) else {
+            return Err(tonic::Status::not_found(
+                "no epoch ended before the requested height",
+            ));
+        };
+
+        let frontier = state
+            .sct_frontier_by_epoch(previous_epoch)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("could not get frontier: {e}")))?
+            .ok_or_else(|| {
+                tonic::Status::not_found(format!(
+                    "no frontier was recorded at the end of epoch {previous_epoch}"
+                ))
+            })?;
+
+        Ok(tonic::Response::new(frontier))
+    }
+}
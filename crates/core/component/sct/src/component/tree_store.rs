@@ -0,0 +1,412 @@
+use std::{
+    ops::{Deref, DerefMut, Range},
+    pin::Pin,
+};
+
+use anyhow::{Context as _, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use futures::{Stream, StreamExt, TryStreamExt};
+use penumbra_tct::{
+    storage::{AsyncRead, AsyncWrite, StoredPosition},
+    structure::Hash,
+    Forgotten, Position, StateCommitment,
+};
+
+/// A [`penumbra_tct`] storage backend over the nonverifiable keyspace of a cnidarium state.
+///
+/// This can be made from a shared reference to a [`StateRead`] to read a tree, or from a mutable
+/// reference to a [`StateWrite`] to write one.  Every key is stored under a prefix, so that several trees can be stored in the same state.
+/// Since the tree is written incrementally with [`Tree::to_async_writer`], only the changes made
+/// since it was last written are persisted, rather than the whole tree each time, and it can be
+/// reloaded with [`Tree::from_async_reader`].
+///
+/// [`Tree::to_async_writer`]: penumbra_tct::Tree::to_async_writer
+/// [`Tree::from_async_reader`]: penumbra_tct::Tree::from_async_reader
+pub struct TreeStore<S> {
+    state: S,
+    prefix: Vec<u8>,
+}
+
+impl<S> TreeStore<S> {
+    /// Store a tree in `state`, under the given key `prefix`.
+    pub fn new(state: S, prefix: impl Into<Vec<u8>>) -> Self {
+        Self {
+            state,
+            prefix: prefix.into(),
+        }
+    }
+
+    fn key(&self, suffix: &[u8]) -> Vec<u8> {
+        [self.prefix.as_slice(), suffix].concat()
+    }
+
+    fn position_key(&self) -> Vec<u8> {
+        self.key(b"position")
+    }
+
+    fn forgotten_key(&self) -> Vec<u8> {
+        self.key(b"forgotten")
+    }
+
+    fn hashes_prefix(&self) -> Vec<u8> {
+        self.key(b"hash/")
+    }
+
+    fn commitments_prefix(&self) -> Vec<u8> {
+        self.key(b"commitment/")
+    }
+
+    // Positions are big-endian, so that keys sort in the order of their positions.
+    fn hash_key(&self, position: Position, height: u8) -> Vec<u8> {
+        let mut key = self.hashes_prefix();
+        key.extend_from_slice(&u64::from(position).to_be_bytes());
+        key.push(height);
+        key
+    }
+
+    fn commitment_key(&self, position: Position) -> Vec<u8> {
+        let mut key = self.commitments_prefix();
+        key.extend_from_slice(&u64::from(position).to_be_bytes());
+        key
+    }
+}
+
+fn decode_hash_key(prefix: &[u8], key: &[u8]) -> Result<(Position, u8)> {
+    let suffix: [u8; 9] = key
+        .strip_prefix(prefix)
+        .and_then(|suffix| suffix.try_into().ok())
+        .context("malformed state commitment tree hash key")?;
+    let [position @ .., height] = suffix;
+    Ok((Position::from(u64::from_be_bytes(position)), height))
+}
+
+fn decode_commitment_key(prefix: &[u8], key: &[u8]) -> Result<Position> {
+    let suffix: [u8; 8] = key
+        .strip_prefix(prefix)
+        .and_then(|suffix| suffix.try_into().ok())
+        .context("malformed state commitment tree commitment key")?;
+    Ok(Position::from(u64::from_be_bytes(suffix)))
+}
+
+fn decode_hash(bytes: Vec<u8>) -> Result<Hash> {
+    let bytes =
+        <[u8; 32]>::try_from(bytes).map_err(|_| anyhow::anyhow!("hash was of incorrect length"))?;
+    Ok(Hash::from_bytes(bytes)?)
+}
+
+fn decode_commitment(bytes: Vec<u8>) -> Result<StateCommitment> {
+    let bytes = <[u8; 32]>::try_from(bytes)
+        .map_err(|_| anyhow::anyhow!("commitment was of incorrect length"))?;
+    Ok(StateCommitment::try_from(bytes)?)
+}
+
+#[async_trait]
+impl<S> AsyncRead for TreeStore<S>
+where
+    S: Deref + Send + Sync,
+    S::Target: StateRead,
+{
+    type Error = anyhow::Error;
+
+    type HashesStream<'a>
+        = Pin<Box<dyn Stream<Item = Result<(Position, u8, Hash)>> + Send + 'a>>
+    where
+        Self: 'a;
+
+    type CommitmentsStream<'a>
+        = Pin<Box<dyn Stream<Item = Result<(Position, StateCommitment)>> + Send + 'a>>
+    where
+        Self: 'a;
+
+    async fn position(&mut self) -> Result<StoredPosition> {
+        match self
+            .state
+            .nonverifiable_get_raw(&self.position_key())
+            .await?
+        {
+            Some(bytes) => bincode::deserialize(&bytes)
+                .context("could not decode state commitment tree position"),
+            None => Ok(StoredPosition::default()),
+        }
+    }
+
+    async fn forgotten(&mut self) -> Result<Forgotten> {
+        match self
+            .state
+            .nonverifiable_get_raw(&self.forgotten_key())
+            .await?
+        {
+            Some(bytes) => bincode::deserialize(&bytes)
+                .context("could not decode state commitment tree forgotten version"),
+            None => Ok(Forgotten::default()),
+        }
+    }
+
+    async fn hash(&mut self, position: Position, height: u8) -> Result<Option<Hash>> {
+        self.state
+            .nonverifiable_get_raw(&self.hash_key(position, height))
+            .await?
+            .map(decode_hash)
+            .transpose()
+    }
+
+    fn hashes(&mut self) -> Self::HashesStream<'_> {
+        let prefix = self.hashes_prefix();
+        self.state
+            .nonverifiable_prefix_raw(&prefix)
+            .map(move |entry| {
+                let (key, value) = entry?;
+                let (position, height) = decode_hash_key(&prefix, &key)?;
+                Ok((position, height, decode_hash(value)?))
+            })
+            .boxed()
+    }
+
+    async fn commitment(&mut self, position: Position) -> Result<Option<StateCommitment>> {
+        self.state
+            .nonverifiable_get_raw(&self.commitment_key(position))
+            .await?
+            .map(decode_commitment)
+            .transpose()
+    }
+
+    fn commitments(&mut self) -> Self::CommitmentsStream<'_> {
+        let prefix = self.commitments_prefix();
+        self.state
+            .nonverifiable_prefix_raw(&prefix)
+            .map(move |entry| {
+                let (key, value) = entry?;
+                let position = decode_commitment_key(&prefix, &key)?;
+                Ok((position, decode_commitment(value)?))
+            })
+            .boxed()
+    }
+}
+
+#[async_trait]
+impl<S> AsyncWrite for TreeStore<S>
+where
+    S: DerefMut + Send + Sync,
+    S::Target: StateWrite,
+{
+    async fn add_hash(
+        &mut self,
+        position: Position,
+        height: u8,
+        hash: Hash,
+        _essential: bool,
+    ) -> Result<()> {
+        let key = self.hash_key(position, height);
+        self.state
+            .nonverifiable_put_raw(key, hash.to_bytes().to_vec());
+        Ok(())
+    }
+
+    async fn add_commitment(
+        &mut self,
+        position: Position,
+        commitment: StateCommitment,
+    ) -> Result<()> {
+        let key = self.commitment_key(position);
+        if self.state.nonverifiable_get_raw(&key).await?.is_some() {
+            anyhow::bail!("refusing to overwrite existing commitment at position {position:?}");
+        }
+        self.state
+            .nonverifiable_put_raw(key, <[u8; 32]>::from(commitment).to_vec());
+        Ok(())
+    }
+
+    async fn delete_range(&mut self, below_height: u8, positions: Range<Position>) -> Result<()> {
+        if positions.is_empty() {
+            return Ok(());
+        }
+        // Keys sort in the order of their positions, so only the keys in the range are scanned.
+        let range = u64::from(positions.start).to_be_bytes().to_vec()
+            ..u64::from(positions.end).to_be_bytes().to_vec();
+
+        let hashes_prefix = self.hashes_prefix();
+        let hash_keys: Vec<Vec<u8>> = self
+            .state
+            .nonverifiable_range_raw(Some(&hashes_prefix), range.clone())?
+            .map_ok(|(key, _)| key)
+            .try_filter(|key| {
+                let delete = decode_hash_key(&hashes_prefix, key)
+                    .is_ok_and(|(_, height)| height < below_height);
+                async move { delete }
+            })
+            .try_collect()
+            .await?;
+
+        let commitment_keys: Vec<Vec<u8>> = self
+            .state
+            .nonverifiable_range_raw(Some(&self.commitments_prefix()), range)?
+            .map_ok(|(key, _)| key)
+            .try_collect()
+            .await?;
+
+        for key in hash_keys.into_iter().chain(commitment_keys) {
+            self.state.nonverifiable_delete(key);
+        }
+        Ok(())
+    }
+
+    async fn set_position(&mut self, position: StoredPosition) -> Result<()> {
+        if position < self.position().await? {
+            anyhow::bail!("refusing to move the state commitment tree position backwards");
+        }
+        let bytes = bincode::serialize(&position)?;
+        let key = self.position_key();
+        self.state.nonverifiable_put_raw(key, bytes);
+        Ok(())
+    }
+
+    async fn set_forgotten(&mut self, forgotten: Forgotten) -> Result<()> {
+        if forgotten < self.forgotten().await? {
+            anyhow::bail!("refusing to move the state commitment tree forgotten version backwards");
+        }
+        let bytes = bincode::serialize(&forgotten)?;
+        let key = self.forgotten_key();
+        self.state.nonverifiable_put_raw(key, bytes);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use penumbra_tct::{Tree, Witness};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn tree_store_round_trips_incrementally() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        // The empty store holds the empty tree:
+        let loaded = Tree::from_async_reader(&mut TreeStore::new(&state, "test/")).await?;
+        assert_eq!(loaded, Tree::new());
+
+        let mut tree = Tree::new();
+        tree.insert(Witness::Keep, StateCommitment::try_from([0; 32])?)?;
+        tree.end_block()?;
+        tree.insert(Witness::Forget, StateCommitment::try_from([1; 32])?)?;
+        tree.to_async_writer(&mut TreeStore::new(&mut state, "test/"))
+            .await?;
+        storage.commit(state).await?;
+
+        // Writing again only writes what changed since the last write:
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        tree.end_epoch()?;
+        tree.insert(Witness::Keep, StateCommitment::try_from([2; 32])?)?;
+        tree.forget(StateCommitment::try_from([0; 32])?);
+        tree.to_async_writer(&mut TreeStore::new(&mut state, "test/"))
+            .await?;
+        storage.commit(state).await?;
+
+        let snapshot = storage.latest_snapshot();
+        let loaded = Tree::from_async_reader(&mut TreeStore::new(&snapshot, "test/")).await?;
+        assert_eq!(loaded, tree);
+
+        // Trees under other prefixes are unaffected:
+        let other = Tree::from_async_reader(&mut TreeStore::new(&snapshot, "other/")).await?;
+        assert_eq!(other, Tree::new());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn delete_range_only_deletes_in_range() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        let hash = Hash::zero();
+        let commitment = StateCommitment::try_from([0; 32])?;
+        {
+            let mut store = TreeStore::new(&mut state, "test/");
+            for p in 0..6 {
+                for height in [0, 1, 2] {
+                    store
+                        .add_hash(Position::from(p), height, hash, false)
+                        .await?;
+                }
+                store.add_commitment(Position::from(p), commitment).await?;
+            }
+            // A tree under a prefix extending this one is unaffected.
+            let mut other = TreeStore::new(&mut state, "test/hash/");
+            other.add_hash(Position::from(2), 0, hash, false).await?;
+        }
+        // Some of the entries are committed, and some are only pending in the delta.
+        storage.commit(state).await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        {
+            let mut store = TreeStore::new(&mut state, "test/");
+            for p in 6..8 {
+                store.add_hash(Position::from(p), 0, hash, false).await?;
+                store.add_commitment(Position::from(p), commitment).await?;
+            }
+            store
+                .delete_range(2, Position::from(2)..Position::from(7))
+                .await?;
+            // An empty range deletes nothing.
+            store
+                .delete_range(2, Position::from(1)..Position::from(1))
+                .await?;
+        }
+
+        let mut store = TreeStore::new(&state, "test/");
+        for p in 0..8 {
+            let in_range = (2..7).contains(&p);
+            for height in [0, 1, 2] {
+                let expected = match p {
+                    0..=5 => !(in_range && height < 2),
+                    _ => height == 0 && !in_range,
+                };
+                assert_eq!(
+                    store.hash(Position::from(p), height).await?.is_some(),
+                    expected,
+                    "hash at position {p}, height {height}"
+                );
+            }
+            assert_eq!(
+                store.commitment(Position::from(p)).await?.is_some(),
+                !in_range,
+                "commitment at position {p}"
+            );
+        }
+        let mut other = TreeStore::new(&state, "test/hash/");
+        assert!(other.hash(Position::from(2), 0).await?.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn forgotten_commitments_are_deleted() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        let kept = StateCommitment::try_from([0; 32])?;
+        let forgotten = StateCommitment::try_from([1; 32])?;
+        let mut tree = Tree::new();
+        let forgotten_position = tree.insert(Witness::Keep, forgotten)?;
+        let kept_position = tree.insert(Witness::Keep, kept)?;
+        tree.to_async_writer(&mut TreeStore::new(&mut state, "test/"))
+            .await?;
+        storage.commit(state).await?;
+
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        tree.forget(forgotten);
+        tree.to_async_writer(&mut TreeStore::new(&mut state, "test/"))
+            .await?;
+        storage.commit(state).await?;
+
+        let snapshot = storage.latest_snapshot();
+        let mut store = TreeStore::new(&snapshot, "test/");
+        assert_eq!(store.commitment(kept_position).await?, Some(kept));
+        assert_eq!(store.commitment(forgotten_position).await?, None);
+        assert_eq!(Tree::from_async_reader(&mut store).await?, tree);
+
+        Ok(())
+    }
+}
@@ -2,14 +2,23 @@ use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use penumbra_chain::component::StateReadExt as _;
-use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_fee::component::StateReadExt as _;
+use penumbra_proto::{
+    core::component::sct::v1alpha1::SctFrontierResponse, Message, StateReadProto, StateWriteProto,
+};
 use penumbra_tct as tct;
-use tct::builder::{block, epoch};
+use tct::{
+    builder::{block, epoch},
+    storage::StoredPosition,
+    Forgotten,
+};
 use tracing::instrument;
 
 // TODO: make epoch management the responsibility of this component
 
-use crate::{event, state_key, CommitmentSource, NullificationInfo, Nullifier};
+use crate::{
+    component::TreeStore, event, state_key, CommitmentSource, NullificationInfo, Nullifier,
+};
 
 /// A helper trait for placing a `CommitmentSource` as ambient context during execution.
 #[async_trait]
@@ -52,22 +61,36 @@ pub trait StateReadExt: StateRead {
             return tree;
         }
 
-        match self
+        // If the tree was stored whole, before it was stored incrementally, use that.
+        if let Some(bytes) = self
             .nonverifiable_get_raw(state_key::state_commitment_tree().as_bytes())
             .await
             .expect("able to retrieve state commitment tree from nonverifiable storage")
         {
-            Some(bytes) => bincode::deserialize(&bytes).expect(
+            return bincode::deserialize(&bytes).expect(
                 "able to deserialize stored state commitment tree from nonverifiable storage",
-            ),
-            None => tct::Tree::new(),
+            );
         }
+
+        let mut store = TreeStore::new(self, state_key::state_commitment_tree_prefix());
+        tct::Tree::from_async_reader(&mut store)
+            .await
+            .expect("able to load state commitment tree from nonverifiable storage")
     }
 
     async fn anchor_by_height(&self, height: u64) -> Result<Option<tct::Root>> {
         self.get(&state_key::anchor_by_height(height)).await
     }
 
+    /// Gets the frontier of the state commitment tree as of the end of the given epoch.
+    async fn sct_frontier_by_epoch(&self, epoch_index: u64) -> Result<Option<SctFrontierResponse>> {
+        self.nonverifiable_get_raw(state_key::frontier_by_epoch(epoch_index).as_bytes())
+            .await?
+            .map(|bytes| SctFrontierResponse::decode(bytes.as_slice()))
+            .transpose()
+            .map_err(Into::into)
+    }
+
     async fn check_claimed_anchor(&self, anchor: tct::Root) -> Result<()> {
         if anchor.is_empty() {
             return Ok(());
@@ -202,21 +225,60 @@ trait StateWriteExt: StateWrite {
         self.object_put(state_key::cached_state_commitment_tree(), tree);
     }
 
-    // Serialize the current state commitment tree to storage (slightly more expensive, should only
-    // happen once a block).
+    // Write the changes to the current state commitment tree to storage (slightly more expensive,
+    // should only happen once a block).
     async fn write_state_commitment_tree(&mut self) {
         // If the cached tree is dirty, flush it to storage
         if let Some(tree) = self.object_get::<tct::Tree>(state_key::cached_state_commitment_tree())
         {
-            let bytes = bincode::serialize(&tree)
-                .expect("able to serialize state commitment tree to bincode");
-            self.nonverifiable_put_raw(
-                state_key::state_commitment_tree().as_bytes().to_vec(),
-                bytes,
-            );
+            // If the tree was stored whole, before it was stored incrementally, delete that, so
+            // that the whole tree is written incrementally below.
+            let legacy_key = state_key::state_commitment_tree().as_bytes();
+            if self
+                .nonverifiable_get_raw(legacy_key)
+                .await
+                .expect("able to retrieve state commitment tree from nonverifiable storage")
+                .is_some()
+            {
+                self.nonverifiable_delete(legacy_key.to_vec());
+            }
+
+            let mut store = TreeStore::new(self, state_key::state_commitment_tree_prefix());
+            tree.to_async_writer(&mut store)
+                .await
+                .expect("able to write state commitment tree to nonverifiable storage");
         }
     }
 
+    // Serialize the frontier of the state commitment tree at the end of an epoch to storage, so
+    // that clients can start synchronizing from it rather than from genesis.
+    async fn write_sct_frontier(&mut self, epoch_index: u64, height: u64, sct: &tct::Tree) {
+        // The node forgets every commitment as soon as it's inserted, so this is just the frontier.
+        let updates = sct
+            .updates(StoredPosition::default(), Forgotten::default())
+            .collect::<tct::storage::Updates>();
+        // Clients starting from the frontier won't see the blocks which set the gas prices and
+        // FMD parameters in effect at that height, so they are recorded alongside it.
+        let gas_prices = self.get_gas_prices().await.expect("gas prices must be set");
+        let fmd_parameters = self
+            .get_current_fmd_parameters()
+            .await
+            .expect("fmd parameters must be set");
+        let frontier = SctFrontierResponse {
+            height,
+            frontier: bincode::serialize(&updates)
+                .expect("able to serialize state commitment tree frontier to bincode"),
+            gas_prices: Some(gas_prices.into()),
+            fmd_parameters: Some(fmd_parameters.into()),
+        };
+        self.nonverifiable_put_raw(
+            state_key::frontier_by_epoch(epoch_index)
+                .as_bytes()
+                .to_vec(),
+            frontier.encode_to_vec(),
+        );
+    }
+
     async fn write_sct(
         &mut self,
         height: u64,
@@ -238,6 +300,7 @@ trait StateWriteExt: StateWrite {
         if let Some(epoch_root) = epoch_root {
             let index = self.epoch().await.expect("epoch must be set").index;
             self.record_proto(event::epoch_root(index, epoch_root));
+            self.write_sct_frontier(index, height, &sct).await;
         }
 
         self.put_state_commitment_tree(sct);
@@ -246,3 +309,99 @@ trait StateWriteExt: StateWrite {
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use penumbra_chain::{component::StateWriteExt as _, params::FmdParameters};
+    use penumbra_fee::{component::StateWriteExt as _, GasPrices};
+    use tct::{
+        storage::{InMemory, Updates},
+        StateCommitment, Witness,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn frontier_records_chain_state_at_its_height() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        let gas_prices = GasPrices {
+            block_space_price: 1,
+            compact_block_space_price: 2,
+            verification_price: 3,
+            execution_price: 4,
+        };
+        let fmd_parameters = FmdParameters {
+            precision_bits: 4,
+            as_of_block_height: 7,
+        };
+        state.put_gas_prices(gas_prices);
+        state.put_current_fmd_parameters(fmd_parameters.clone());
+
+        let mut tree = tct::Tree::new();
+        tree.insert(Witness::Forget, StateCommitment::try_from([1; 32])?)?;
+        tree.end_block()?;
+        tree.end_epoch()?;
+        state.write_sct_frontier(3, 42, &tree).await;
+
+        let frontier = state
+            .sct_frontier_by_epoch(3)
+            .await?
+            .expect("frontier was recorded");
+        assert_eq!(frontier.height, 42);
+        assert_eq!(frontier.gas_prices, Some(gas_prices.into()));
+        assert_eq!(frontier.fmd_parameters, Some(fmd_parameters.into()));
+
+        // The recorded frontier loads into a tree with the same root.
+        let updates: Updates = bincode::deserialize(&frontier.frontier)?;
+        let mut store = InMemory::new();
+        for update in updates {
+            update.apply(&mut store)?;
+        }
+        assert_eq!(tct::Tree::from_reader(&mut store)?.root(), tree.root());
+
+        assert!(state.sct_frontier_by_epoch(4).await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn legacy_state_commitment_tree_is_migrated() -> Result<()> {
+        let storage = TempStorage::new().await?;
+
+        let mut tree = tct::Tree::new();
+        tree.insert(Witness::Keep, StateCommitment::try_from([0; 32])?)?;
+        tree.end_block()?;
+        tree.insert(Witness::Forget, StateCommitment::try_from([1; 32])?)?;
+
+        // State from before the tree was stored incrementally holds it whole:
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.nonverifiable_put_raw(
+            state_key::state_commitment_tree().as_bytes().to_vec(),
+            bincode::serialize(&tree)?,
+        );
+        storage.commit(state).await?;
+
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        assert_eq!(state.state_commitment_tree().await, tree);
+
+        // The next write stores the whole tree incrementally, and removes the legacy key:
+        tree.insert(Witness::Keep, StateCommitment::try_from([2; 32])?)?;
+        state.put_state_commitment_tree(tree.clone());
+        state.write_state_commitment_tree().await;
+        storage.commit(state).await?;
+
+        let snapshot = storage.latest_snapshot();
+        assert!(snapshot
+            .nonverifiable_get_raw(state_key::state_commitment_tree().as_bytes())
+            .await?
+            .is_none());
+        assert_eq!(snapshot.state_commitment_tree().await, tree);
+        let mut store = TreeStore::new(&snapshot, state_key::state_commitment_tree_prefix());
+        assert_eq!(tct::Tree::from_async_reader(&mut store).await?, tree);
+
+        Ok(())
+    }
+}
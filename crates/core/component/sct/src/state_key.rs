@@ -20,10 +20,20 @@ pub fn anchor_lookup(anchor: Root) -> String {
     format!("sct/valid_anchors/{anchor}")
 }
 
+// The whole state commitment tree, as it was stored before being stored incrementally.
 pub fn state_commitment_tree() -> &'static str {
     "sct/state_commitment_tree"
 }
 
+// The prefix under which the state commitment tree is stored incrementally.
+pub fn state_commitment_tree_prefix() -> &'static str {
+    "sct/tree/"
+}
+
+pub fn frontier_by_epoch(epoch_index: u64) -> String {
+    format!("sct/frontier/{epoch_index}")
+}
+
 pub fn note_source(note_commitment: &StateCommitment) -> String {
     format!("sct/note_source/{note_commitment}")
 }
@@ -1,6 +1,8 @@
 use cnidarium_component::ActionHandler;
 
 mod delegate;
+mod redelegate;
+mod redelegate_claim;
 mod undelegate;
 mod undelegate_claim;
 mod validator_definition;
@@ -0,0 +1,277 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use penumbra_shielded_pool::component::SupplyWrite;
+
+use crate::{
+    action_handler::ActionHandler, component::StateWriteExt as _, event, validator, Redelegate,
+    StateReadExt as _,
+};
+
+#[async_trait]
+impl ActionHandler for Redelegate {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        ensure!(
+            self.from_validator_identity != self.to_validator_identity,
+            "cannot redelegate from validator {} to itself",
+            self.from_validator_identity,
+        );
+
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        let r = self;
+        let from_rate_data = state
+            .current_validator_rate(&r.from_validator_identity)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("unknown validator identity {}", r.from_validator_identity)
+            })?;
+        let to_rate_data = state
+            .current_validator_rate(&r.to_validator_identity)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("unknown validator identity {}", r.to_validator_identity)
+            })?;
+
+        // Check whether the epoch is correct first, to give a more helpful
+        // error message if it's wrong.  Both rates are updated together at
+        // the epoch boundary, so checking the source rate is sufficient.
+        if r.epoch_index != from_rate_data.epoch_index {
+            anyhow::bail!(
+                "redelegation was prepared for epoch {} but the next epoch is {}",
+                r.epoch_index,
+                from_rate_data.epoch_index
+            );
+        }
+
+        // The destination validator must be accepting delegations, exactly as
+        // for a `Delegate` action.
+        let to_validator = state
+            .validator(&r.to_validator_identity)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing definition for validator"))?;
+        let to_validator_state = state
+            .validator_state(&r.to_validator_identity)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing state for validator"))?;
+
+        use validator::State::*;
+        if !to_validator.enabled {
+            anyhow::bail!(
+                "redelegations are only allowed to enabled validators, but {} is disabled",
+                r.to_validator_identity,
+            );
+        }
+        if !matches!(to_validator_state, Inactive | Active) {
+            anyhow::bail!(
+                "redelegations are only allowed to active or inactive validators, but {} is in state {:?}",
+                r.to_validator_identity,
+                to_validator_state,
+            );
+        }
+
+        // For redelegations, we enforce correct computation (with rounding)
+        // of the *unbonded amount based on the source delegation amount*,
+        // and then of the *destination delegation amount based on the
+        // unbonded amount*, mirroring an undelegation followed by a
+        // delegation.  See the comments on the `Delegate` and `Undelegate`
+        // handlers for why the direction of the computation matters.
+        let expected_unbonded_amount =
+            from_rate_data.unbonded_amount(r.from_delegation_amount.value());
+        ensure!(
+            r.unbonded_amount.value() == expected_unbonded_amount,
+            "redelegation unbonded amount {} does not match expected amount {}",
+            r.unbonded_amount,
+            expected_unbonded_amount,
+        );
+
+        let expected_to_delegation_amount =
+            to_rate_data.delegation_amount(r.unbonded_amount.value());
+        ensure!(
+            r.to_delegation_amount.value() == expected_to_delegation_amount,
+            "given {} unbonded stake, expected {} delegation tokens but description produces {}",
+            r.unbonded_amount,
+            expected_to_delegation_amount,
+            r.to_delegation_amount,
+        );
+
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        tracing::debug!(?self, "queuing redelegation for next epoch");
+        state.stub_push_redelegation(self.clone());
+        // Register the redelegation's denom, so clients can look it up later.
+        state
+            .register_denom(&self.redelegation_token().denom())
+            .await?;
+
+        state.record(event::redelegate(self));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{Snapshot, StateDelta, TempStorage};
+    use decaf377_rdsa::{SigningKey, SpendAuth, VerificationKey};
+    use penumbra_asset::Value;
+    use penumbra_num::Amount;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{
+        component::StateWriteExt as _, rate::RateData, state_key, validator::Validator,
+        DelegationToken, FundingStreams, GovernanceKey, IdentityKey,
+    };
+
+    /// Adds a validator with the given exchange rate for epoch 3, returning its rate data.
+    fn add_validator(
+        state: &mut StateDelta<Snapshot>,
+        enabled: bool,
+        validator_state: validator::State,
+        exchange_rate: u64,
+    ) -> RateData {
+        let identity_sk = SigningKey::<SpendAuth>::new(OsRng);
+        let identity_key = IdentityKey((&identity_sk).into());
+        let governance_vk: VerificationKey<SpendAuth> =
+            (&SigningKey::<SpendAuth>::new(OsRng)).into();
+        let consensus_sk = ed25519_consensus::SigningKey::new(OsRng);
+        let validator = Validator {
+            identity_key: identity_key.clone(),
+            governance_key: GovernanceKey(governance_vk),
+            consensus_key: tendermint::PublicKey::from_raw_ed25519(
+                &consensus_sk.verification_key().to_bytes(),
+            )
+            .expect("valid ed25519 key"),
+            name: String::new(),
+            website: String::new(),
+            description: String::new(),
+            enabled,
+            funding_streams: FundingStreams::default(),
+            sequence_number: 0,
+        };
+        let rate_data = RateData {
+            identity_key: identity_key.clone(),
+            epoch_index: 3,
+            validator_reward_rate: 0,
+            validator_exchange_rate: exchange_rate,
+        };
+
+        state.put(state_key::validators::by_id(&identity_key), validator);
+        state.put(
+            state_key::state_by_validator(&identity_key),
+            validator_state,
+        );
+        state.set_validator_rates(&identity_key, rate_data.clone());
+
+        rate_data
+    }
+
+    #[tokio::test]
+    async fn check_stateless_rejects_self_redelegation() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let rate = add_validator(&mut state, true, validator::State::Active, 1_0000_0000);
+
+        let redelegate = rate.build_redelegate(&rate, 1000u64.into());
+        assert!(redelegate.check_stateless(()).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_stateful_accepts_built_redelegation() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let from = add_validator(&mut state, true, validator::State::Active, 2_0000_0000);
+        let to = add_validator(&mut state, true, validator::State::Inactive, 3_0000_0000);
+
+        let redelegate = from.build_redelegate(&to, 1000u64.into());
+        assert_eq!(redelegate.unbonded_amount, Amount::from(2000u64));
+        assert_eq!(redelegate.to_delegation_amount, Amount::from(666u64));
+
+        redelegate.check_stateless(()).await?;
+        redelegate.check_stateful(Arc::new(state)).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_stateful_rejects_incorrect_amounts() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let from = add_validator(&mut state, true, validator::State::Active, 2_0000_0000);
+        let to = add_validator(&mut state, true, validator::State::Active, 3_0000_0000);
+        let state = Arc::new(state);
+
+        let redelegate = from.build_redelegate(&to, 1000u64.into());
+
+        let mut inflated_unbonded = redelegate.clone();
+        inflated_unbonded.unbonded_amount = 2001u64.into();
+        assert!(inflated_unbonded
+            .check_stateful(state.clone())
+            .await
+            .is_err());
+
+        let mut inflated_delegation = redelegate.clone();
+        inflated_delegation.to_delegation_amount = 667u64.into();
+        assert!(inflated_delegation
+            .check_stateful(state.clone())
+            .await
+            .is_err());
+
+        let mut stale = redelegate;
+        stale.epoch_index = 2;
+        assert!(stale.check_stateful(state).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_stateful_rejects_unavailable_destination() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let from = add_validator(&mut state, true, validator::State::Active, 1_0000_0000);
+        let disabled = add_validator(&mut state, false, validator::State::Active, 1_0000_0000);
+        let jailed = add_validator(&mut state, true, validator::State::Jailed, 1_0000_0000);
+        let state = Arc::new(state);
+
+        let to_disabled = from.build_redelegate(&disabled, 1000u64.into());
+        assert!(to_disabled.check_stateful(state.clone()).await.is_err());
+
+        let to_jailed = from.build_redelegate(&jailed, 1000u64.into());
+        assert!(to_jailed.check_stateful(state).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn redelegation_escrows_destination_delegation_tokens() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let from = add_validator(&mut state, true, validator::State::Active, 1_0000_0000);
+        let to = add_validator(&mut state, true, validator::State::Active, 1_0000_0000);
+
+        let redelegate = from.build_redelegate(&to, 1000u64.into());
+        let balance = redelegate.balance();
+
+        // The redelegator receives redelegation tokens, which can only be
+        // converted to the destination's delegation tokens by a claim.
+        let escrowed = Value {
+            amount: 1000u64.into(),
+            asset_id: redelegate.redelegation_token().id(),
+        };
+        let to_delegation_id = DelegationToken::new(to.identity_key.clone()).id();
+        assert!(balance.provided().any(|v| v == escrowed));
+        assert!(!balance.provided().any(|v| v.asset_id == to_delegation_id));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use penumbra_chain::component::StateReadExt;
+use penumbra_shielded_pool::component::SupplyWrite;
+
+use crate::{action_handler::ActionHandler, event, RedelegateClaim, StateReadExt as _};
+
+#[async_trait]
+impl ActionHandler for RedelegateClaim {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        let expected_delegation_amount = self.penalty.apply_to_amount(self.escrowed_amount);
+        ensure!(
+            self.delegation_amount == expected_delegation_amount,
+            "given a penalty of {:?}, expected {} delegation tokens for {} redelegation tokens but description produces {}",
+            self.penalty,
+            expected_delegation_amount,
+            self.escrowed_amount,
+            self.delegation_amount,
+        );
+
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        // We need to check two things, exactly as for an undelegation claim:
+
+        // 1. That we're past the end of the unbonding period that began when
+        // the stake was redelegated.
+
+        let current_epoch = state.epoch().await?;
+        let end_epoch_index = state
+            .unbonding_end_epoch_for(&self.from_validator_identity, self.start_epoch_index)
+            .await?;
+        ensure!(
+            current_epoch.index >= end_epoch_index,
+            "cannot claim redelegation tokens before the end epoch"
+        );
+
+        // 2. That the penalty is the one the source validator received over
+        // that unbonding period.
+        let expected_penalty = state
+            .compounded_penalty_over_range(
+                &self.from_validator_identity,
+                self.start_epoch_index,
+                end_epoch_index,
+            )
+            .await?;
+        ensure!(
+            self.penalty == expected_penalty,
+            "penalty does not match expected penalty"
+        );
+
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // The escrowed delegation tokens were counted in the destination
+        // validator's delegation token supply when the redelegation took
+        // effect.  Those lost to the penalty are burned here, so that only the
+        // redelegated stake bears the penalty, not the rest of the pool.
+        let slashed_amount = self.slashed_amount();
+        if slashed_amount.value() != 0 {
+            tracing::debug!(?self, ?slashed_amount, "burning slashed redelegated stake");
+            state
+                .update_token_supply(
+                    &self.to_delegation_token().id(),
+                    -(slashed_amount.value() as i128),
+                )
+                .await?;
+        }
+
+        state.record(event::redelegate_claim(self));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{Snapshot, StateDelta, TempStorage};
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use penumbra_chain::{component::StateWriteExt as _, Epoch};
+    use penumbra_num::Amount;
+    use penumbra_shielded_pool::component::SupplyRead;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{component::StateWriteExt as _, params::StakeParameters, state_key, Penalty};
+
+    fn identity_key() -> crate::IdentityKey {
+        let sk = SigningKey::<SpendAuth>::new(OsRng);
+        crate::IdentityKey((&sk).into())
+    }
+
+    /// Returns a state in which the current epoch is `epoch_index`, with the
+    /// default unbonding period of two epochs.
+    async fn state_in_epoch(storage: &TempStorage, epoch_index: u64) -> StateDelta<Snapshot> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(100);
+        state.put_epoch_by_height(
+            100,
+            Epoch {
+                index: epoch_index,
+                start_height: 100,
+            },
+        );
+        state.put_stake_params(StakeParameters {
+            unbonding_epochs: 2,
+            ..Default::default()
+        });
+        state
+    }
+
+    #[test]
+    fn claim_rounds_delegation_amount_down() {
+        let claim = RedelegateClaim::new(
+            identity_key(),
+            identity_key(),
+            5,
+            Penalty::from_percent(1),
+            150u64.into(),
+        );
+
+        // 99% of 150 is 148.5, which is rounded down, so the redelegator bears
+        // the rounding rather than the destination pool.
+        assert_eq!(claim.delegation_amount, Amount::from(148u64));
+        assert_eq!(claim.slashed_amount(), Amount::from(2u64));
+    }
+
+    #[tokio::test]
+    async fn check_stateless_rejects_inconsistent_delegation_amount() {
+        let mut claim = RedelegateClaim::new(
+            identity_key(),
+            identity_key(),
+            5,
+            Penalty::from_percent(10),
+            1000u64.into(),
+        );
+        assert!(claim.check_stateless(()).await.is_ok());
+
+        claim.delegation_amount = 901u64.into();
+        assert!(claim.check_stateless(()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn check_stateful_waits_for_unbonding_period() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?;
+        let claim = RedelegateClaim::new(
+            identity_key(),
+            identity_key(),
+            5,
+            Penalty::from_percent(0),
+            1000u64.into(),
+        );
+
+        let early = state_in_epoch(&storage, 6).await;
+        assert!(claim.check_stateful(Arc::new(early)).await.is_err());
+
+        let done = state_in_epoch(&storage, 7).await;
+        assert!(claim.check_stateful(Arc::new(done)).await.is_ok());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_stateful_requires_source_penalty() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?;
+        let from = identity_key();
+        let to = identity_key();
+
+        let mut state = state_in_epoch(&storage, 8).await;
+        // Only the penalty within the unbonding period [5, 7) applies.
+        state.put(
+            state_key::penalty_in_epoch(&from, 6),
+            Penalty::from_percent(10),
+        );
+        state.put(
+            state_key::penalty_in_epoch(&from, 7),
+            Penalty::from_percent(50),
+        );
+        let state = Arc::new(state);
+
+        let unpenalized = RedelegateClaim::new(
+            from.clone(),
+            to.clone(),
+            5,
+            Penalty::from_percent(0),
+            1000u64.into(),
+        );
+        assert!(unpenalized.check_stateful(state.clone()).await.is_err());
+
+        let overpenalized = RedelegateClaim::new(
+            from.clone(),
+            to.clone(),
+            5,
+            Penalty::from_percent(10).compound(Penalty::from_percent(50)),
+            1000u64.into(),
+        );
+        assert!(overpenalized.check_stateful(state.clone()).await.is_err());
+
+        let penalized =
+            RedelegateClaim::new(from, to, 5, Penalty::from_percent(10), 1000u64.into());
+        assert!(penalized.check_stateful(state).await.is_ok());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_burns_only_slashed_redelegated_stake() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?;
+        let claim = RedelegateClaim::new(
+            identity_key(),
+            identity_key(),
+            5,
+            Penalty::from_percent(10),
+            500u64.into(),
+        );
+        let to_token = claim.to_delegation_token().id();
+
+        let mut state = state_in_epoch(&storage, 7).await;
+        state.update_token_supply(&to_token, 1000).await?;
+        claim.execute(&mut state).await?;
+
+        // 10% of the 500 escrowed delegation tokens are burned; the other
+        // 500 delegation tokens in the pool are untouched.
+        assert_eq!(
+            state.token_supply(&to_token).await?,
+            Some(Amount::from(950u64))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_without_penalty_leaves_supply_unchanged() -> anyhow::Result<()> {
+        let storage = TempStorage::new().await?;
+        let claim = RedelegateClaim::new(
+            identity_key(),
+            identity_key(),
+            5,
+            Penalty::from_percent(0),
+            500u64.into(),
+        );
+        let to_token = claim.to_delegation_token().id();
+
+        let mut state = state_in_epoch(&storage, 7).await;
+        state.update_token_supply(&to_token, 1000).await?;
+        claim.execute(&mut state).await?;
+
+        assert_eq!(
+            state.token_supply(&to_token).await?,
+            Some(Amount::from(1000u64))
+        );
+
+        Ok(())
+    }
+}
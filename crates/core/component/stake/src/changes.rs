@@ -1,4 +1,4 @@
-use crate::{Delegate, Undelegate};
+use crate::{Delegate, Redelegate, Undelegate};
 use anyhow::Result;
 use penumbra_proto::{penumbra::core::component::stake::v1alpha1 as pb, DomainType};
 use serde::{Deserialize, Serialize};
@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 pub struct DelegationChanges {
     pub delegations: Vec<Delegate>,
     pub undelegations: Vec<Undelegate>,
+    pub redelegations: Vec<Redelegate>,
 }
 
 impl DomainType for DelegationChanges {
@@ -21,6 +22,7 @@ impl From<DelegationChanges> for pb::DelegationChanges {
         pb::DelegationChanges {
             delegations: changes.delegations.into_iter().map(Into::into).collect(),
             undelegations: changes.undelegations.into_iter().map(Into::into).collect(),
+            redelegations: changes.redelegations.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -39,6 +41,11 @@ impl TryFrom<pb::DelegationChanges> for DelegationChanges {
                 .into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<_>>()?,
+            redelegations: changes
+                .redelegations
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
         })
     }
 }
@@ -28,7 +28,7 @@ use penumbra_distributions::component::StateReadExt as _;
 use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proto::{
     state::future::{DomainFuture, ProtoFuture},
-    StateReadProto, StateWriteProto,
+    DomainType, StateReadProto, StateWriteProto,
 };
 use penumbra_sct::CommitmentSource;
 use penumbra_shielded_pool::{
@@ -56,7 +56,7 @@ use crate::{
     validator::{self, Validator},
     CurrentConsensusKeys, DelegationChanges, Penalty, Uptime, {DelegationToken, IdentityKey},
 };
-use crate::{Delegate, Undelegate};
+use crate::{Delegate, Redelegate, Undelegate};
 
 // Max validator power is 1152921504606846975 (i64::MAX / 8)
 // https://github.com/tendermint/tendermint/blob/master/types/validator_set.go#L25
@@ -306,6 +306,8 @@ pub(crate) trait StakingImpl: StateWriteExt {
         // Collect all the delegation changes that occurred in the epoch we are ending.
         let mut delegations_by_validator = BTreeMap::<IdentityKey, Vec<Delegate>>::new();
         let mut undelegations_by_validator = BTreeMap::<IdentityKey, Vec<Undelegate>>::new();
+        let mut redelegations_from_validator = BTreeMap::<IdentityKey, Vec<Redelegate>>::new();
+        let mut redelegations_to_validator = BTreeMap::<IdentityKey, Vec<Redelegate>>::new();
 
         let end_height = self.get_block_height().await?;
 
@@ -329,6 +331,16 @@ pub(crate) trait StakingImpl: StateWriteExt {
                     .or_default()
                     .push(u);
             }
+            for r in changes.redelegations {
+                redelegations_from_validator
+                    .entry(r.from_validator_identity.clone())
+                    .or_default()
+                    .push(r.clone());
+                redelegations_to_validator
+                    .entry(r.to_validator_identity.clone())
+                    .or_default()
+                    .push(r);
+            }
         }
 
         tracing::debug!(
@@ -336,6 +348,8 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 .sum::<usize>(),
             total_undelegations = ?undelegations_by_validator.values().map(|v| v.len())
                 .sum::<usize>(),
+            total_redelegations = ?redelegations_from_validator.values().map(|v| v.len())
+                .sum::<usize>(),
                 epoch_start_height = epoch_to_end.start_height,
                 epoch_end_height = end_height,
                 "calculated delegation changes for epoch"
@@ -422,15 +436,30 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 &validator_state,
             );
 
+            // Redelegations count as an undelegation from the source validator
+            // and a delegation to the destination validator, whose delegation
+            // tokens are held as redelegation tokens until they are claimed.
             let total_delegations = delegations_by_validator
                 .get(&validator.identity_key)
                 .into_iter()
                 .flat_map(|ds| ds.iter().map(|d| d.delegation_amount.value()))
+                .chain(
+                    redelegations_to_validator
+                        .get(&validator.identity_key)
+                        .into_iter()
+                        .flat_map(|rs| rs.iter().map(|r| r.to_delegation_amount.value())),
+                )
                 .sum::<u128>();
             let total_undelegations = undelegations_by_validator
                 .get(&validator.identity_key)
                 .into_iter()
                 .flat_map(|us| us.iter().map(|u| u.delegation_amount.value()))
+                .chain(
+                    redelegations_from_validator
+                        .get(&validator.identity_key)
+                        .into_iter()
+                        .flat_map(|rs| rs.iter().map(|r| r.from_delegation_amount.value())),
+                )
                 .sum::<u128>();
             let delegation_delta = (total_delegations as i128) - (total_undelegations as i128);
 
@@ -1123,6 +1152,43 @@ pub trait StateReadExt: StateRead {
             .boxed()
     }
 
+    /// Returns the rate data recorded for the given validator in each epoch
+    /// of the inclusive range `start_epoch_index..=end_epoch_index`.
+    ///
+    /// Epochs for which no rate data was recorded, e.g. because they precede
+    /// the validator's definition, are skipped.
+    async fn validator_rate_history(
+        &self,
+        identity_key: &IdentityKey,
+        start_epoch_index: u64,
+        end_epoch_index: u64,
+    ) -> Result<Vec<RateData>> {
+        // Range queries under a prefix are bounded by the rest of the key, and
+        // can't include their end.
+        let prefix = state_key::rate_history::by_validator_prefix(identity_key);
+        let range = state_key::rate_history::epoch_suffix(start_epoch_index).into_bytes()
+            ..state_key::rate_history::epoch_suffix(end_epoch_index.saturating_add(1)).into_bytes();
+
+        self.nonverifiable_range_raw(Some(prefix.as_bytes()), range)?
+            .map(|entry| -> Result<RateData> {
+                let (_, bytes) = entry?;
+                RateData::decode(bytes.as_slice()).context("failed to decode historical rate data")
+            })
+            .try_collect()
+            .await
+    }
+
+    /// Returns the base rate data recorded for the given epoch, if any.
+    async fn base_rate_in_epoch(&self, epoch_index: u64) -> Result<Option<BaseRateData>> {
+        self.nonverifiable_get_raw(state_key::rate_history::base(epoch_index).as_bytes())
+            .await?
+            .map(|bytes| {
+                BaseRateData::decode(bytes.as_slice())
+                    .context("failed to decode historical base rate data")
+            })
+            .transpose()
+    }
+
     fn validator_power(&self, identity_key: &IdentityKey) -> ProtoFuture<u64, Self::GetRawFut> {
         self.get_proto(&state_key::power_by_validator(identity_key))
     }
@@ -1356,9 +1422,21 @@ pub trait StateWriteExt: StateWrite {
         self.put_stub_delegation_changes(changes);
     }
 
+    fn stub_push_redelegation(&mut self, redelegation: Redelegate) {
+        let mut changes = self.stub_delegation_changes();
+        changes.redelegations.push(redelegation);
+        self.put_stub_delegation_changes(changes);
+    }
+
     #[instrument(skip(self))]
     fn set_base_rate(&mut self, current: BaseRateData) {
         tracing::debug!("setting base rate");
+        // Keep a copy of each epoch's base rate, so that realized yields can be
+        // computed after the fact.
+        self.nonverifiable_put_raw(
+            state_key::rate_history::base(current.epoch_index).into_bytes(),
+            current.encode_to_vec(),
+        );
         self.put(state_key::current_base_rate().to_owned(), current);
     }
 
@@ -1381,6 +1459,13 @@ pub trait StateWriteExt: StateWrite {
     #[instrument(skip(self))]
     fn set_validator_rates(&mut self, identity_key: &IdentityKey, current_rates: RateData) {
         tracing::debug!("setting validator rates");
+        // Keep a copy of each epoch's rates, so that realized yields can be
+        // computed after the fact.
+        self.nonverifiable_put_raw(
+            state_key::rate_history::by_validator(identity_key, current_rates.epoch_index)
+                .into_bytes(),
+            current_rates.encode_to_vec(),
+        );
         self.put(
             state_key::current_rate_by_validator(identity_key),
             current_rates,
@@ -1539,3 +1624,64 @@ pub trait StateWriteExt: StateWrite {
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{StateDelta, TempStorage};
+    use decaf377_rdsa as rdsa;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn validator_rate_history_is_inclusive() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        let identity_key = IdentityKey((&rdsa::SigningKey::new(OsRng)).into());
+        let other_key = IdentityKey((&rdsa::SigningKey::new(OsRng)).into());
+        for epoch_index in [1, 2, 3, 5, 8] {
+            for identity_key in [&identity_key, &other_key] {
+                state.set_validator_rates(
+                    identity_key,
+                    RateData {
+                        identity_key: identity_key.clone(),
+                        epoch_index,
+                        validator_reward_rate: 0,
+                        validator_exchange_rate: 1_0000_0000,
+                    },
+                );
+            }
+        }
+        storage.commit(state).await?;
+
+        let snapshot = storage.latest_snapshot();
+        let epochs = |rates: Vec<RateData>| {
+            rates
+                .into_iter()
+                .map(|rate| {
+                    assert_eq!(rate.identity_key, identity_key);
+                    rate.epoch_index
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            epochs(snapshot.validator_rate_history(&identity_key, 2, 5).await?),
+            vec![2, 3, 5]
+        );
+        assert_eq!(
+            epochs(snapshot.validator_rate_history(&identity_key, 4, 4).await?),
+            Vec::<u64>::new()
+        );
+        assert_eq!(
+            epochs(
+                snapshot
+                    .validator_rate_history(&identity_key, 0, 100)
+                    .await?
+            ),
+            vec![1, 2, 3, 5, 8]
+        );
+
+        Ok(())
+    }
+}
@@ -8,8 +8,8 @@ use penumbra_proto::{
     core::component::stake::v1alpha1::{
         query_service_server::QueryService, CurrentValidatorRateRequest,
         CurrentValidatorRateResponse, ValidatorInfoRequest, ValidatorInfoResponse,
-        ValidatorPenaltyRequest, ValidatorPenaltyResponse, ValidatorStatusRequest,
-        ValidatorStatusResponse,
+        ValidatorPenaltyRequest, ValidatorPenaltyResponse, ValidatorRateHistoryRequest,
+        ValidatorRateHistoryResponse, ValidatorStatusRequest, ValidatorStatusResponse,
     },
     DomainType,
 };
@@ -34,6 +34,9 @@ impl Server {
 impl QueryService for Server {
     type ValidatorInfoStream =
         Pin<Box<dyn futures::Stream<Item = Result<ValidatorInfoResponse, tonic::Status>> + Send>>;
+    type ValidatorRateHistoryStream = Pin<
+        Box<dyn futures::Stream<Item = Result<ValidatorRateHistoryResponse, tonic::Status>> + Send>,
+    >;
 
     #[instrument(skip(self, request), fields(show_inactive = request.get_ref().show_inactive))]
     async fn validator_info(
@@ -168,4 +171,61 @@ impl QueryService for Server {
             None => Err(Status::not_found("current validator rate not found")),
         }
     }
+
+    #[instrument(skip(self, request))]
+    async fn validator_rate_history(
+        &self,
+        request: tonic::Request<ValidatorRateHistoryRequest>,
+    ) -> Result<tonic::Response<Self::ValidatorRateHistoryStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let request = request.into_inner();
+        let identity_key = request
+            .identity_key
+            .ok_or_else(|| Status::invalid_argument("missing identity key"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid identity key"))?;
+
+        if request.start_epoch_index > request.end_epoch_index {
+            return Err(Status::invalid_argument(format!(
+                "start epoch {} is after end epoch {}",
+                request.start_epoch_index, request.end_epoch_index
+            )));
+        }
+
+        let rates = state
+            .validator_rate_history(
+                &identity_key,
+                request.start_epoch_index,
+                request.end_epoch_index,
+            )
+            .await
+            .map_err(|e| Status::unavailable(format!("error getting rate history: {e}")))?;
+
+        let s = try_stream! {
+            for rate_data in rates {
+                let base_rate_data = state
+                    .base_rate_in_epoch(rate_data.epoch_index)
+                    .await?
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("missing base rate for epoch {}", rate_data.epoch_index)
+                    })?;
+                yield ValidatorRateHistoryResponse {
+                    rate_data: Some(rate_data.into()),
+                    base_rate_data: Some(base_rate_data.into()),
+                };
+            }
+        };
+
+        Ok(tonic::Response::new(
+            s.map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!("error getting rate history: {e}"))
+            })
+            .boxed(),
+        ))
+    }
 }
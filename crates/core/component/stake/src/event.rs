@@ -1,4 +1,4 @@
-use crate::{Delegate, Undelegate};
+use crate::{Delegate, Redelegate, RedelegateClaim, Undelegate};
 use tendermint::abci::{Event, EventAttributeIndexExt};
 
 pub fn delegate(delegate: &Delegate) -> Event {
@@ -20,3 +20,29 @@ pub fn undelegate(undelegate: &Undelegate) -> Event {
         ],
     )
 }
+
+pub fn redelegate(redelegate: &Redelegate) -> Event {
+    Event::new(
+        "action_redelegate",
+        [
+            (
+                "from_validator",
+                redelegate.from_validator_identity.to_string(),
+            )
+                .index(),
+            ("to_validator", redelegate.to_validator_identity.to_string()).index(),
+            ("amount", redelegate.unbonded_amount.to_string()).no_index(),
+        ],
+    )
+}
+
+pub fn redelegate_claim(claim: &RedelegateClaim) -> Event {
+    Event::new(
+        "action_redelegate_claim",
+        [
+            ("from_validator", claim.from_validator_identity.to_string()).index(),
+            ("to_validator", claim.to_validator_identity.to_string()).index(),
+            ("amount", claim.delegation_amount.to_string()).no_index(),
+        ],
+    )
+}
@@ -20,12 +20,16 @@ pub use component::StateReadExt;
 
 pub mod delegate;
 pub mod rate;
+pub mod redelegate;
+pub mod redelegate_claim;
 pub mod state_key;
 pub mod undelegate;
 pub mod undelegate_claim;
 pub mod validator;
 
 pub use delegate::Delegate;
+pub use redelegate::Redelegate;
+pub use redelegate_claim::RedelegateClaim;
 pub use undelegate::Undelegate;
 pub use undelegate_claim::{
     UndelegateClaim, UndelegateClaimBody, UndelegateClaimPlan, UndelegateClaimProof,
@@ -35,12 +39,14 @@ mod delegation_token;
 mod governance_key;
 mod identity_key;
 mod penalty;
+mod redelegation_token;
 mod unbonding_token;
 
 pub use delegation_token::DelegationToken;
 pub use governance_key::GovernanceKey;
 pub use identity_key::IdentityKey;
 pub use penalty::Penalty;
+pub use redelegation_token::RedelegationToken;
 pub use unbonding_token::UnbondingToken;
 
 pub use changes::DelegationChanges;
@@ -0,0 +1,132 @@
+use penumbra_asset::{Balance, Value};
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::stake::v1alpha1 as pb, DomainType};
+use penumbra_txhash::{EffectHash, EffectingData};
+use serde::{Deserialize, Serialize};
+
+use crate::{DelegationToken, IdentityKey, RedelegationToken};
+
+/// A transaction action moving stake from one validator's delegation pool to
+/// another's, without waiting for the unbonding period.
+///
+/// The redelegated stake remains subject to slashing of the source validator
+/// until the end of the unbonding period that an undelegation performed in the
+/// same epoch would have had.  Until then, the destination validator's
+/// delegation tokens are held as [`RedelegationToken`]s, so that any penalty
+/// is charged to the redelegated stake alone, when they are converted into
+/// delegation tokens by a [`RedelegateClaim`](crate::RedelegateClaim).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::Redelegate", into = "pb::Redelegate")]
+pub struct Redelegate {
+    /// The identity key of the validator to redelegate from.
+    pub from_validator_identity: IdentityKey,
+    /// The identity key of the validator to redelegate to.
+    pub to_validator_identity: IdentityKey,
+    /// The index of the epoch in which this redelegation was performed.
+    /// The redelegation takes effect in the next epoch.
+    pub epoch_index: u64,
+    /// The amount of the source validator's delegation tokens consumed by this action.
+    pub from_delegation_amount: Amount,
+    /// The amount of stake moved between the delegation pools, in units of unbonded stake.
+    ///
+    /// This is implied by the source validator's exchange rate in the specified epoch
+    /// (and should be checked in transaction validation!), but including it allows
+    /// stateless verification that the transaction is internally consistent.
+    pub unbonded_amount: Amount,
+    /// The amount of the destination validator's delegation tokens redelegated to, produced
+    /// by this action as redelegation tokens.
+    ///
+    /// This is implied by the destination validator's exchange rate in the specified epoch
+    /// (and should be checked in transaction validation!), but including it allows
+    /// stateless verification that the transaction is internally consistent.
+    pub to_delegation_amount: Amount,
+}
+
+impl EffectingData for Redelegate {
+    fn effect_hash(&self) -> EffectHash {
+        // For redelegations, the entire action is considered effecting data.
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl Redelegate {
+    /// Return the balance after consuming the source validator's delegation
+    /// tokens, and producing redelegation tokens for the destination validator.
+    pub fn balance(&self) -> Balance {
+        let from_delegation = Balance::from(Value {
+            amount: self.from_delegation_amount,
+            asset_id: self.from_delegation_token().id(),
+        });
+
+        let redelegation = Balance::from(Value {
+            amount: self.to_delegation_amount,
+            asset_id: self.redelegation_token().id(),
+        });
+
+        // We consume the source delegation tokens and produce redelegation tokens, which are
+        // claimed for the destination delegation tokens once the redelegation has unbonded.
+        redelegation - from_delegation
+    }
+
+    pub fn from_delegation_token(&self) -> DelegationToken {
+        DelegationToken::new(self.from_validator_identity.clone())
+    }
+
+    pub fn to_delegation_token(&self) -> DelegationToken {
+        DelegationToken::new(self.to_validator_identity.clone())
+    }
+
+    pub fn redelegation_token(&self) -> RedelegationToken {
+        RedelegationToken::new(
+            self.from_validator_identity.clone(),
+            self.to_validator_identity.clone(),
+            self.epoch_index,
+        )
+    }
+}
+
+impl DomainType for Redelegate {
+    type Proto = pb::Redelegate;
+}
+
+impl From<Redelegate> for pb::Redelegate {
+    fn from(d: Redelegate) -> Self {
+        pb::Redelegate {
+            from_validator_identity: Some(d.from_validator_identity.into()),
+            to_validator_identity: Some(d.to_validator_identity.into()),
+            epoch_index: d.epoch_index,
+            from_delegation_amount: Some(d.from_delegation_amount.into()),
+            unbonded_amount: Some(d.unbonded_amount.into()),
+            to_delegation_amount: Some(d.to_delegation_amount.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::Redelegate> for Redelegate {
+    type Error = anyhow::Error;
+    fn try_from(d: pb::Redelegate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from_validator_identity: d
+                .from_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing source validator identity"))?
+                .try_into()?,
+            to_validator_identity: d
+                .to_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing destination validator identity"))?
+                .try_into()?,
+            epoch_index: d.epoch_index,
+            from_delegation_amount: d
+                .from_delegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing source delegation amount"))?
+                .try_into()?,
+            unbonded_amount: d
+                .unbonded_amount
+                .ok_or_else(|| anyhow::anyhow!("missing unbonded amount"))?
+                .try_into()?,
+            to_delegation_amount: d
+                .to_delegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing destination delegation amount"))?
+                .try_into()?,
+        })
+    }
+}
@@ -0,0 +1,143 @@
+use penumbra_asset::{Balance, Value};
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::stake::v1alpha1 as pb, DomainType};
+use penumbra_txhash::{EffectHash, EffectingData};
+use serde::{Deserialize, Serialize};
+
+use crate::{DelegationToken, IdentityKey, Penalty, RedelegationToken};
+
+/// A transaction action finishing a [`Redelegate`](crate::Redelegate),
+/// converting the [`RedelegationToken`]s it produced into the destination
+/// validator's delegation tokens, less any penalty applied to the source
+/// validator while the redelegated stake was still subject to its slashing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::RedelegateClaim", into = "pb::RedelegateClaim")]
+pub struct RedelegateClaim {
+    /// The identity key of the validator redelegated from.
+    pub from_validator_identity: IdentityKey,
+    /// The identity key of the validator redelegated to.
+    pub to_validator_identity: IdentityKey,
+    /// The index of the epoch in which the redelegation was performed.
+    pub start_epoch_index: u64,
+    /// The penalty applied to the source validator over the redelegation's unbonding period.
+    pub penalty: Penalty,
+    /// The amount of redelegation tokens consumed by this action.
+    pub escrowed_amount: Amount,
+    /// The amount of the destination validator's delegation tokens produced by this action.
+    ///
+    /// This is implied by the penalty and the escrowed amount (and should be checked in
+    /// transaction validation!), but including it allows stateless verification that the
+    /// transaction is internally consistent.
+    pub delegation_amount: Amount,
+}
+
+impl EffectingData for RedelegateClaim {
+    fn effect_hash(&self) -> EffectHash {
+        // For redelegation claims, the entire action is considered effecting data.
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl RedelegateClaim {
+    /// Build a claim for `escrowed_amount` of the redelegation tokens produced
+    /// by a redelegation from `from_validator_identity` to
+    /// `to_validator_identity` in the epoch with index `start_epoch_index`,
+    /// given the `penalty` applied to the source validator since then.
+    pub fn new(
+        from_validator_identity: IdentityKey,
+        to_validator_identity: IdentityKey,
+        start_epoch_index: u64,
+        penalty: Penalty,
+        escrowed_amount: Amount,
+    ) -> Self {
+        Self {
+            from_validator_identity,
+            to_validator_identity,
+            start_epoch_index,
+            penalty,
+            escrowed_amount,
+            delegation_amount: penalty.apply_to_amount(escrowed_amount),
+        }
+    }
+
+    /// Return the balance after consuming the redelegation tokens, and
+    /// producing the destination validator's delegation tokens.
+    pub fn balance(&self) -> Balance {
+        let redelegation = Balance::from(Value {
+            amount: self.escrowed_amount,
+            asset_id: self.redelegation_token().id(),
+        });
+
+        let to_delegation = Balance::from(Value {
+            amount: self.delegation_amount,
+            asset_id: self.to_delegation_token().id(),
+        });
+
+        // We consume the redelegation tokens and produce the destination delegation tokens.
+        to_delegation - redelegation
+    }
+
+    /// The amount of the destination validator's delegation tokens that are
+    /// burned, rather than claimed, because of the penalty.
+    pub fn slashed_amount(&self) -> Amount {
+        self.escrowed_amount - self.delegation_amount
+    }
+
+    pub fn redelegation_token(&self) -> RedelegationToken {
+        RedelegationToken::new(
+            self.from_validator_identity.clone(),
+            self.to_validator_identity.clone(),
+            self.start_epoch_index,
+        )
+    }
+
+    pub fn to_delegation_token(&self) -> DelegationToken {
+        DelegationToken::new(self.to_validator_identity.clone())
+    }
+}
+
+impl DomainType for RedelegateClaim {
+    type Proto = pb::RedelegateClaim;
+}
+
+impl From<RedelegateClaim> for pb::RedelegateClaim {
+    fn from(c: RedelegateClaim) -> Self {
+        pb::RedelegateClaim {
+            from_validator_identity: Some(c.from_validator_identity.into()),
+            to_validator_identity: Some(c.to_validator_identity.into()),
+            start_epoch_index: c.start_epoch_index,
+            penalty: Some(c.penalty.into()),
+            escrowed_amount: Some(c.escrowed_amount.into()),
+            delegation_amount: Some(c.delegation_amount.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::RedelegateClaim> for RedelegateClaim {
+    type Error = anyhow::Error;
+    fn try_from(c: pb::RedelegateClaim) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from_validator_identity: c
+                .from_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing source validator identity"))?
+                .try_into()?,
+            to_validator_identity: c
+                .to_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing destination validator identity"))?
+                .try_into()?,
+            start_epoch_index: c.start_epoch_index,
+            penalty: c
+                .penalty
+                .ok_or_else(|| anyhow::anyhow!("missing penalty"))?
+                .try_into()?,
+            escrowed_amount: c
+                .escrowed_amount
+                .ok_or_else(|| anyhow::anyhow!("missing escrowed amount"))?
+                .try_into()?,
+            delegation_amount: c
+                .delegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing delegation amount"))?
+                .try_into()?,
+        })
+    }
+}
@@ -0,0 +1,187 @@
+use std::str::FromStr;
+
+use regex::Regex;
+
+use penumbra_asset::asset;
+
+use crate::IdentityKey;
+
+/// Redelegation tokens represent delegation tokens of the destination
+/// validator of a redelegation, while the redelegated stake is still subject
+/// to slashing of the source validator.
+///
+/// Redelegation tokens are parameterized by the source and destination
+/// validator identities, and the epoch in which the redelegation was
+/// performed.  Once the unbonding period that began in that epoch is over,
+/// they can be claimed for the destination validator's delegation tokens,
+/// less any penalty applied to the source validator in the meantime.
+pub struct RedelegationToken {
+    from_validator_identity: IdentityKey,
+    to_validator_identity: IdentityKey,
+    start_epoch_index: u64,
+    base_denom: asset::DenomMetadata,
+}
+
+impl RedelegationToken {
+    pub fn new(
+        from_validator_identity: IdentityKey,
+        to_validator_identity: IdentityKey,
+        start_epoch_index: u64,
+    ) -> Self {
+        // This format string needs to be in sync with the asset registry
+        let base_denom = asset::REGISTRY
+            .parse_denom(&format!(
+                // "ur" is not a typo, these are micro-redelegation tokens
+                "uredelegation_epoch_{start_epoch_index}_{from_validator_identity}_to_{to_validator_identity}"
+            ))
+            .expect("base denom format is valid");
+        RedelegationToken {
+            from_validator_identity,
+            to_validator_identity,
+            start_epoch_index,
+            base_denom,
+        }
+    }
+
+    /// Get the base denomination for this redelegation token.
+    pub fn denom(&self) -> asset::DenomMetadata {
+        self.base_denom.clone()
+    }
+
+    /// Get the default display denomination for this redelegation token.
+    pub fn default_unit(&self) -> asset::Unit {
+        self.base_denom.default_unit()
+    }
+
+    /// Get the asset ID for this redelegation token.
+    pub fn id(&self) -> asset::Id {
+        self.base_denom.id()
+    }
+
+    /// Get the identity key of the validator the stake was redelegated from.
+    pub fn from_validator(&self) -> IdentityKey {
+        self.from_validator_identity.clone()
+    }
+
+    /// Get the identity key of the validator the stake was redelegated to.
+    pub fn to_validator(&self) -> IdentityKey {
+        self.to_validator_identity.clone()
+    }
+
+    pub fn start_epoch_index(&self) -> u64 {
+        self.start_epoch_index
+    }
+}
+
+impl TryFrom<asset::DenomMetadata> for RedelegationToken {
+    type Error = anyhow::Error;
+
+    fn try_from(base_denom: asset::DenomMetadata) -> Result<Self, Self::Error> {
+        let base_string = base_denom.to_string();
+
+        // Note: this regex must be in sync with both asset::REGISTRY
+        // and VALIDATOR_IDENTITY_BECH32_PREFIX
+        // The data capture group is used by asset::REGISTRY
+        let captures = Regex::new(
+            "^uredelegation_(?P<data>epoch_(?P<start>[0-9]+)_(?P<from>penumbravalid1[a-zA-HJ-NP-Z0-9]+)_to_(?P<to>penumbravalid1[a-zA-HJ-NP-Z0-9]+))$",
+        )
+        .expect("regex is valid")
+        .captures(base_string.as_ref())
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "base denom {} is not a redelegation token",
+                base_denom.to_string()
+            )
+        })?;
+
+        let from_validator_identity = captures
+            .name("from")
+            .expect("from is a named capture")
+            .as_str()
+            .parse()?;
+
+        let to_validator_identity = captures
+            .name("to")
+            .expect("to is a named capture")
+            .as_str()
+            .parse()?;
+
+        let start_epoch_index = captures
+            .name("start")
+            .expect("start is a named capture")
+            .as_str()
+            .parse()?;
+
+        Ok(Self {
+            from_validator_identity,
+            to_validator_identity,
+            start_epoch_index,
+            base_denom,
+        })
+    }
+}
+
+impl FromStr for RedelegationToken {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        asset::REGISTRY
+            .parse_denom(s)
+            .ok_or_else(|| anyhow::anyhow!("could not parse {} as base denomination", s))?
+            .try_into()
+    }
+}
+
+impl std::fmt::Display for RedelegationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.base_denom.fmt(f)
+    }
+}
+
+impl std::fmt::Debug for RedelegationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.base_denom.fmt(f)
+    }
+}
+
+impl PartialEq for RedelegationToken {
+    fn eq(&self, other: &Self) -> bool {
+        self.base_denom.eq(&other.base_denom)
+    }
+}
+
+impl Eq for RedelegationToken {}
+
+impl std::hash::Hash for RedelegationToken {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.base_denom.hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+
+    use super::*;
+
+    #[test]
+    fn redelegation_token_denomination_round_trip() {
+        use rand_core::OsRng;
+
+        let from = IdentityKey(SigningKey::<SpendAuth>::new(OsRng).into());
+        let to = IdentityKey(SigningKey::<SpendAuth>::new(OsRng).into());
+        let start = 782;
+
+        let token = RedelegationToken::new(from.clone(), to.clone(), start);
+
+        let denom = token.to_string();
+        println!("denom: {denom}");
+        let token2 = RedelegationToken::from_str(&denom).unwrap();
+        let denom2 = token2.to_string();
+
+        assert_eq!(denom, denom2);
+        assert_eq!(token, token2);
+        assert_eq!(token2.from_validator(), from);
+        assert_eq!(token2.to_validator(), to);
+        assert_eq!(token2.start_epoch_index(), start);
+    }
+}
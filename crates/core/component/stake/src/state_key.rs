@@ -54,6 +54,32 @@ pub fn next_rate_by_validator(id: &IdentityKey) -> String {
     format!("staking/validator_rate/next/{id}")
 }
 
+pub mod rate_history {
+    use super::*;
+
+    // Load-bearing format strings: we need to pad with 0s to ensure that
+    // the lex order agrees with the numeric order on epochs.
+    // 10 decimal digits covers 2^32 epochs.
+
+    pub fn by_validator_prefix(id: &IdentityKey) -> String {
+        format!("staking/rate_history/validator/{id}/")
+    }
+
+    pub fn by_validator(id: &IdentityKey, epoch: u64) -> String {
+        format!("{}{}", by_validator_prefix(id), epoch_suffix(epoch))
+    }
+
+    // The part of a key after its prefix, which is what range queries under a
+    // prefix are bounded by.
+    pub fn epoch_suffix(epoch: u64) -> String {
+        format!("{epoch:010}")
+    }
+
+    pub fn base(epoch: u64) -> String {
+        format!("staking/rate_history/base/{epoch:010}")
+    }
+}
+
 pub fn power_by_validator(id: &IdentityKey) -> String {
     format!("staking/validator_power/{id}")
 }
@@ -144,4 +170,32 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(range, vec![k791, k792, k793,]);
     }
+
+    #[test]
+    fn rate_history_range() {
+        let sk = rdsa::SigningKey::new(OsRng);
+        let ik = IdentityKey((&sk).into());
+
+        let keys = [7, 79, 791, 792, 793]
+            .into_iter()
+            .map(|epoch| rate_history::by_validator(&ik, epoch))
+            .collect::<BTreeSet<String>>();
+
+        // Check that lex order agrees with numeric order
+        let range = keys
+            .range(rate_history::by_validator(&ik, 79)..=rate_history::by_validator(&ik, 792))
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            range,
+            vec![
+                rate_history::by_validator(&ik, 79),
+                rate_history::by_validator(&ik, 791),
+                rate_history::by_validator(&ik, 792),
+            ]
+        );
+        assert!(range
+            .iter()
+            .all(|k| k.starts_with(&rate_history::by_validator_prefix(&ik))));
+    }
 }
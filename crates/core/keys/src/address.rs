@@ -95,6 +95,16 @@ impl Address {
         &self.ck_d
     }
 
+    /// Derive the detection key for this address from the root detection key of the wallet it
+    /// belongs to, or return `None` if its clue key isn't derived from that root.
+    ///
+    /// Only addresses derived with [`DetectionKeyDerivation::Root`](crate::keys::DetectionKeyDerivation::Root)
+    /// have clue keys derived from the root.
+    pub fn detection_key(&self, root: &fmd::DetectionKey) -> Option<fmd::DetectionKey> {
+        let dtk_d = root.derive_child(self.d.as_ref());
+        (dtk_d.clue_key() == self.ck_d).then_some(dtk_d)
+    }
+
     pub fn transmission_key_s(&self) -> &Fq {
         &self.transmission_key_s
     }
@@ -271,7 +281,7 @@ mod tests {
     use rand_core::OsRng;
 
     use super::*;
-    use crate::keys::{Bip44Path, SeedPhrase, SpendKey};
+    use crate::keys::{Bip44Path, DetectionKeyDerivation, SeedPhrase, SpendKey};
 
     #[test]
     fn test_address_encoding() {
@@ -337,4 +347,57 @@ mod tests {
         assert!(dest1.clue_key() != dest2.clue_key());
         assert!(dtk_d1.to_bytes() != dtk_d2.to_bytes());
     }
+
+    #[test]
+    fn test_address_detection_keys_derive_from_root() {
+        let rng = OsRng;
+        let sk = SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(rng), &Bip44Path::new(0));
+        let fvk = sk.full_viewing_key();
+        let root = fvk.root_detection_key();
+
+        let (dest, dtk_d) = fvk.payment_address_with(1u32.into(), DetectionKeyDerivation::Root);
+        let (ephemeral, ephemeral_dtk_d) =
+            fvk.incoming()
+                .ephemeral_address_with(rng, 0u32.into(), DetectionKeyDerivation::Root);
+        for (address, dtk_d) in [(dest, dtk_d), (ephemeral, ephemeral_dtk_d)] {
+            let derived = address
+                .detection_key(&root)
+                .expect("address derives from the root");
+            assert_eq!(derived.to_bytes(), dtk_d.to_bytes());
+            assert_eq!(
+                root.clue_key()
+                    .derive_child(address.diversifier().as_ref())
+                    .expect("root clue key is valid"),
+                *address.clue_key()
+            );
+        }
+
+        let other_sk =
+            SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(rng), &Bip44Path::new(0));
+        let other_root = other_sk.full_viewing_key().root_detection_key();
+        assert!(dest.detection_key(&other_root).is_none());
+    }
+
+    #[test]
+    fn test_legacy_derivation_is_default_and_not_derived_from_root() {
+        let sk = SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(OsRng), &Bip44Path::new(0));
+        let fvk = sk.full_viewing_key();
+        let index = 1u32.into();
+
+        let (default, default_dtk_d) = fvk.payment_address(index);
+        let (legacy, legacy_dtk_d) =
+            fvk.payment_address_with(index, DetectionKeyDerivation::Legacy);
+        let (root, _) = fvk.payment_address_with(index, DetectionKeyDerivation::Root);
+        assert_eq!(default, legacy);
+        assert_eq!(default_dtk_d.to_bytes(), legacy_dtk_d.to_bytes());
+
+        // The two derivations differ only in the clue key, and both are viewed by the wallet.
+        assert_eq!(legacy.diversifier(), root.diversifier());
+        assert_eq!(legacy.transmission_key(), root.transmission_key());
+        assert_ne!(legacy.clue_key(), root.clue_key());
+        assert_eq!(fvk.address_index(&legacy), Some(index));
+        assert_eq!(fvk.address_index(&root), Some(index));
+
+        assert!(legacy.detection_key(&fvk.root_detection_key()).is_none());
+    }
 }
@@ -17,7 +17,10 @@ use crate::{
     Address, AddressView,
 };
 
-use super::{AddressIndex, DiversifierKey, IncomingViewingKey, NullifierKey, OutgoingViewingKey};
+use super::{
+    AddressIndex, DetectionKeyDerivation, DiversifierKey, IncomingViewingKey, NullifierKey,
+    OutgoingViewingKey,
+};
 
 pub mod r1cs;
 
@@ -43,6 +46,16 @@ impl FullViewingKey {
         self.incoming().payment_address(index)
     }
 
+    /// Derive a shielded payment address with the given [`AddressIndex`], deriving its detection
+    /// key as specified.
+    pub fn payment_address_with(
+        &self,
+        index: AddressIndex,
+        derivation: DetectionKeyDerivation,
+    ) -> (Address, fmd::DetectionKey) {
+        self.incoming().payment_address_with(index, derivation)
+    }
+
     /// Derive a random ephemeral address.
     pub fn ephemeral_address<R: RngCore + CryptoRng>(
         &self,
@@ -52,6 +65,12 @@ impl FullViewingKey {
         self.incoming().ephemeral_address(rng, address_index)
     }
 
+    /// Derive the root detection key, from which the detection key of every address derived with
+    /// [`DetectionKeyDerivation::Root`] is derived.
+    pub fn root_detection_key(&self) -> fmd::DetectionKey {
+        self.incoming().root_detection_key()
+    }
+
     /// Views the structure of the supplied address with this viewing key.
     pub fn view_address(&self, address: Address) -> AddressView {
         // WART: this can't cleanly forward to a method on the IVK,
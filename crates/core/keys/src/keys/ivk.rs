@@ -18,6 +18,29 @@ use crate::{
 pub const IVK_LEN_BYTES: usize = 64;
 const MOD_R_QUOTIENT: usize = 4;
 
+/// How the detection key, and so the clue key, of an address is derived.
+///
+/// Every address of a wallet is viewed by it regardless of how its detection
+/// key was derived, but only addresses derived from the root detection key can
+/// be detected by a detection server holding that key alone.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DetectionKeyDerivation {
+    /// Derive the detection key of each address independently from the
+    /// incoming viewing key and the diversifier.
+    ///
+    /// This is the original derivation, used by default so that the addresses
+    /// a wallet derives don't change.
+    #[default]
+    Legacy,
+    /// Derive the detection key of each address as the child of the
+    /// [root detection key](IncomingViewingKey::root_detection_key) labeled by
+    /// the diversifier.
+    ///
+    /// Addresses derived this way have different clue keys, and so different
+    /// encodings, than legacy addresses with the same index.
+    Root,
+}
+
 /// Allows viewing incoming notes, i.e., notes sent to the spending key this
 /// key is derived from.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -29,13 +52,28 @@ pub struct IncomingViewingKey {
 impl IncomingViewingKey {
     /// Derive a shielded payment address with the given [`AddressIndex`].
     pub fn payment_address(&self, index: AddressIndex) -> (Address, fmd::DetectionKey) {
+        self.payment_address_with(index, DetectionKeyDerivation::Legacy)
+    }
+
+    /// Derive a shielded payment address with the given [`AddressIndex`],
+    /// deriving its detection key as specified.
+    pub fn payment_address_with(
+        &self,
+        index: AddressIndex,
+        derivation: DetectionKeyDerivation,
+    ) -> (Address, fmd::DetectionKey) {
         let d = self.dk.diversifier_for_index(&index);
         let g_d = d.diversified_generator();
         let pk_d = self.ivk.diversified_public(&g_d);
 
-        let dtk_d = fmd::DetectionKey::from_field(Fr::from_le_bytes_mod_order(
-            prf::expand(b"PenumbraExpndFMD", &self.ivk.to_bytes(), d.as_ref()).as_bytes(),
-        ));
+        let dtk_d = match derivation {
+            DetectionKeyDerivation::Legacy => {
+                fmd::DetectionKey::from_field(Fr::from_le_bytes_mod_order(
+                    prf::expand(b"PenumbraExpndFMD", &self.ivk.to_bytes(), d.as_ref()).as_bytes(),
+                ))
+            }
+            DetectionKeyDerivation::Root => self.root_detection_key().derive_child(d.as_ref()),
+        };
         let ck_d = dtk_d.clue_key();
 
         (
@@ -44,11 +82,34 @@ impl IncomingViewingKey {
         )
     }
 
+    /// Derive the root detection key for this viewing key.
+    ///
+    /// The detection key of every address derived with
+    /// [`DetectionKeyDerivation::Root`] is the child of the root labeled by the
+    /// address's diversifier, so the root detection key can be handed to a
+    /// detection server in place of the detection keys of those addresses.
+    pub fn root_detection_key(&self) -> fmd::DetectionKey {
+        fmd::DetectionKey::from_field(Fr::from_le_bytes_mod_order(
+            prf::expand(b"Penumbra_FMDRoot", &self.ivk.to_bytes(), &[]).as_bytes(),
+        ))
+    }
+
     /// Derive an ephemeral address for the provided account.
     pub fn ephemeral_address<R: RngCore + CryptoRng>(
+        &self,
+        rng: R,
+        address_index: AddressIndex,
+    ) -> (Address, fmd::DetectionKey) {
+        self.ephemeral_address_with(rng, address_index, DetectionKeyDerivation::Legacy)
+    }
+
+    /// Derive an ephemeral address for the provided account, deriving its
+    /// detection key as specified.
+    pub fn ephemeral_address_with<R: RngCore + CryptoRng>(
         &self,
         mut rng: R,
         mut address_index: AddressIndex,
+        derivation: DetectionKeyDerivation,
     ) -> (Address, fmd::DetectionKey) {
         let mut random_index = [0u8; 12];
 
@@ -56,7 +117,7 @@ impl IncomingViewingKey {
 
         address_index.randomizer = random_index;
 
-        self.payment_address(address_index)
+        self.payment_address_with(address_index, derivation)
     }
 
     /// Perform key agreement with a given public key.
@@ -42,3 +42,16 @@ pub static FULL_VIEWING_KEY: Lazy<FullViewingKey> =
     Lazy::new(|| SPEND_KEY.full_viewing_key().clone());
 
 pub static WALLET_ID: Lazy<WalletId> = Lazy::new(|| FULL_VIEWING_KEY.wallet_id());
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_addresses_match_derivation() {
+        // Changing how addresses are derived by default breaks every address already handed out,
+        // so these vectors must only change along with a versioned derivation.
+        assert_eq!(FULL_VIEWING_KEY.payment_address(0u32.into()).0, *ADDRESS_0);
+        assert_eq!(FULL_VIEWING_KEY.payment_address(1u32.into()).0, *ADDRESS_1);
+    }
+}
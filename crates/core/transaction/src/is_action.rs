@@ -16,7 +16,7 @@ use penumbra_governance::{
 };
 use penumbra_ibc::IbcRelay;
 use penumbra_shielded_pool::{Ics20Withdrawal, Note, Output, OutputView, Spend, SpendView};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaim};
+use penumbra_stake::{Delegate, Redelegate, RedelegateClaim, Undelegate, UndelegateClaim};
 
 use crate::{Action, ActionView, TransactionPerspective};
 
@@ -185,6 +185,26 @@ impl IsAction for Undelegate {
     }
 }
 
+impl IsAction for Redelegate {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::Redelegate(self.to_owned())
+    }
+}
+
+impl IsAction for RedelegateClaim {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::RedelegateClaim(self.to_owned())
+    }
+}
+
 impl IsAction for UndelegateClaim {
     fn balance_commitment(&self) -> balance::Commitment {
         self.body.balance_commitment
@@ -26,6 +26,7 @@ mod witness_data;
 pub mod action;
 pub mod gas;
 pub mod memo;
+pub mod payment_request;
 pub mod plan;
 pub mod view;
 
@@ -35,6 +36,7 @@ pub use detection_data::DetectionData;
 pub use error::Error;
 pub use is_action::IsAction;
 pub use parameters::TransactionParameters;
+pub use payment_request::PaymentRequest;
 pub use plan::ActionPlan;
 pub use transaction::{Transaction, TransactionBody};
 pub use view::{ActionView, MemoPlaintextView, MemoView, TransactionPerspective, TransactionView};
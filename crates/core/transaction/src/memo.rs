@@ -23,7 +23,7 @@ pub const MEMO_CIPHERTEXT_LEN_BYTES: usize = 528;
 pub const MEMO_LEN_BYTES: usize = 512;
 
 // This is the largest text length we can support
-const MAX_TEXT_LEN: usize = MEMO_LEN_BYTES - ADDRESS_LEN_BYTES;
+pub(crate) const MAX_TEXT_LEN: usize = MEMO_LEN_BYTES - ADDRESS_LEN_BYTES;
 
 /// A method which reads out bytes in a lossy way, and trims out null bytes
 fn raw_bytes_to_text(data: &[u8]) -> String {
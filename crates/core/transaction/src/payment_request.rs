@@ -0,0 +1,197 @@
+//! Payment request URIs, which let a recipient ask for a payment in a single string.
+//!
+//! A payment request URI has the form
+//!
+//! ```text
+//! penumbra:<address>?amount=<value>&memo=<text>&expiry_height=<height>
+//! ```
+//!
+//! where every parameter is optional, the value is written in display units as understood by
+//! [`Value`]'s `FromStr` implementation (e.g. `1.5penumbra`), and the parameters are
+//! `application/x-www-form-urlencoded`.
+
+use std::{fmt, str::FromStr};
+
+use anyhow::Context;
+use penumbra_asset::{asset, Value};
+use penumbra_keys::Address;
+use url::Url;
+
+use crate::memo::MAX_TEXT_LEN;
+
+/// The URI scheme of payment requests.
+pub const PAYMENT_REQUEST_SCHEME: &str = "penumbra";
+
+/// A request for a payment to an address, encoded as a URI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentRequest {
+    /// The address to pay.
+    pub address: Address,
+    /// The value requested, if any.
+    pub value: Option<Value>,
+    /// The text to put in the payment's memo, if any.
+    pub memo: Option<String>,
+    /// The height after which the payment should no longer be made, if any.
+    pub expiry_height: Option<u64>,
+}
+
+impl PaymentRequest {
+    /// Creates a request for any payment to `address`.
+    pub fn new(address: Address) -> Self {
+        Self {
+            address,
+            value: None,
+            memo: None,
+            expiry_height: None,
+        }
+    }
+
+    /// Whether the request has expired once the chain has reached `height`.
+    pub fn is_expired(&self, height: u64) -> bool {
+        self.expiry_height
+            .map_or(false, |expiry_height| height > expiry_height)
+    }
+
+    /// Encodes the request as a URI, writing its value in the display units of `cache`.
+    pub fn to_uri(&self, cache: &asset::Cache) -> String {
+        let mut uri = Url::parse(&format!("{PAYMENT_REQUEST_SCHEME}:{}", self.address))
+            .expect("address is a valid URI path");
+        {
+            let mut query = uri.query_pairs_mut();
+            if let Some(value) = &self.value {
+                query.append_pair("amount", &value.format(cache));
+            }
+            if let Some(memo) = &self.memo {
+                query.append_pair("memo", memo);
+            }
+            if let Some(expiry_height) = self.expiry_height {
+                query.append_pair("expiry_height", &expiry_height.to_string());
+            }
+        }
+        // Don't leave a trailing `?` on a request with no parameters.
+        if uri.query() == Some("") {
+            uri.set_query(None);
+        }
+        uri.to_string()
+    }
+}
+
+impl fmt::Display for PaymentRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_uri(&asset::Cache::with_known_assets()))
+    }
+}
+
+impl FromStr for PaymentRequest {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let uri = Url::parse(s).context("payment request is not a valid URI")?;
+        if uri.scheme() != PAYMENT_REQUEST_SCHEME {
+            anyhow::bail!(
+                "payment request must use the `{PAYMENT_REQUEST_SCHEME}:` scheme, found `{}:`",
+                uri.scheme()
+            );
+        }
+
+        let address = uri
+            .path()
+            .parse()
+            .map_err(|_| anyhow::anyhow!("payment request address is invalid"))?;
+        let mut request = PaymentRequest::new(address);
+
+        for (key, value) in uri.query_pairs() {
+            let duplicate = match key.as_ref() {
+                "amount" => request
+                    .value
+                    .replace(value.parse().context("payment request amount is invalid")?)
+                    .is_some(),
+                "memo" => {
+                    if value.len() > MAX_TEXT_LEN {
+                        anyhow::bail!(
+                            "payment request memo length must be <= {MAX_TEXT_LEN}, found {}",
+                            value.len()
+                        );
+                    }
+                    request.memo.replace(value.into_owned()).is_some()
+                }
+                "expiry_height" => request
+                    .expiry_height
+                    .replace(
+                        value
+                            .parse()
+                            .context("payment request expiry height is invalid")?,
+                    )
+                    .is_some(),
+                // Refuse requests we can't fully honor, rather than silently ignoring part of them.
+                other => anyhow::bail!("unknown payment request parameter `{other}`"),
+            };
+            if duplicate {
+                anyhow::bail!("duplicate payment request parameter `{key}`");
+            }
+        }
+
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn address() -> Address {
+        let sk = SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(OsRng), &Bip44Path::new(0));
+        sk.full_viewing_key().payment_address(0u32.into()).0
+    }
+
+    #[test]
+    fn payment_request_round_trip() {
+        let request = PaymentRequest {
+            address: address(),
+            value: Some(Value {
+                amount: 1_500_000u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            }),
+            memo: Some("invoice #12 & thanks!".to_string()),
+            expiry_height: Some(12345),
+        };
+
+        let uri = request.to_string();
+        assert!(uri.starts_with("penumbra:penumbra"));
+        assert!(uri.contains("amount=1.5penumbra"));
+        assert_eq!(uri.parse::<PaymentRequest>().unwrap(), request);
+
+        let bare = PaymentRequest::new(address());
+        assert_eq!(bare.to_string(), format!("penumbra:{}", bare.address));
+        assert_eq!(bare.to_string().parse::<PaymentRequest>().unwrap(), bare);
+    }
+
+    #[test]
+    fn payment_request_expires_after_its_expiry_height() {
+        let mut request = PaymentRequest::new(address());
+        assert!(!request.is_expired(u64::MAX));
+
+        request.expiry_height = Some(100);
+        assert!(!request.is_expired(99));
+        assert!(!request.is_expired(100));
+        assert!(request.is_expired(101));
+    }
+
+    #[test]
+    fn payment_request_rejects_invalid() {
+        let address = address();
+        for uri in [
+            format!("bitcoin:{address}"),
+            format!("penumbra:{address}?amount=1penumbra&amount=2penumbra"),
+            format!("penumbra:{address}?req-fee=1"),
+            format!("penumbra:{address}?expiry_height=soon"),
+            "penumbra:notanaddress".to_string(),
+        ] {
+            assert!(uri.parse::<PaymentRequest>().is_err(), "{uri}");
+        }
+    }
+}
@@ -24,7 +24,7 @@ use penumbra_ibc::IbcRelay;
 use penumbra_keys::{symmetric::PayloadKey, FullViewingKey};
 use penumbra_proto::{core::transaction::v1alpha1 as pb_t, DomainType};
 use penumbra_shielded_pool::{Ics20Withdrawal, OutputPlan, SpendPlan};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaimPlan};
+use penumbra_stake::{Delegate, Redelegate, RedelegateClaim, Undelegate, UndelegateClaimPlan};
 use serde::{Deserialize, Serialize};
 
 /// A declaration of a planned [`Action`], for use in transaction creation.
@@ -47,6 +47,12 @@ pub enum ActionPlan {
     /// because we don't yet use flow encryption.
     Undelegate(Undelegate),
     UndelegateClaim(UndelegateClaimPlan),
+    /// We don't need any extra information (yet) to understand redelegations,
+    /// because we don't yet use flow encryption.
+    Redelegate(Redelegate),
+    /// We don't need any extra information (yet) to understand redelegation
+    /// claims, because they are transparent.
+    RedelegateClaim(RedelegateClaim),
     ValidatorDefinition(penumbra_stake::validator::Definition),
     /// Describes a proposed swap.
     Swap(SwapPlan),
@@ -134,6 +140,8 @@ impl ActionPlan {
             Delegate(plan) => Action::Delegate(plan.clone()),
             Undelegate(plan) => Action::Undelegate(plan.clone()),
             UndelegateClaim(plan) => Action::UndelegateClaim(plan.undelegate_claim()),
+            Redelegate(plan) => Action::Redelegate(plan.clone()),
+            RedelegateClaim(plan) => Action::RedelegateClaim(plan.clone()),
             ValidatorDefinition(plan) => Action::ValidatorDefinition(plan.clone()),
             // Fixme: action name
             IbcAction(plan) => Action::IbcRelay(plan.clone()),
@@ -172,6 +180,8 @@ impl ActionPlan {
             Delegate(delegate) => delegate.balance(),
             Undelegate(undelegate) => undelegate.balance(),
             UndelegateClaim(undelegate_claim) => undelegate_claim.balance(),
+            Redelegate(redelegate) => redelegate.balance(),
+            RedelegateClaim(claim) => claim.balance(),
             Swap(swap) => swap.balance(),
             SwapClaim(swap_claim) => swap_claim.balance(),
             ProposalSubmit(proposal_submit) => proposal_submit.balance(),
@@ -200,6 +210,8 @@ impl ActionPlan {
             Delegate(_) => Fr::zero(),
             Undelegate(_) => Fr::zero(),
             UndelegateClaim(undelegate_claim) => undelegate_claim.balance_blinding,
+            Redelegate(_) => Fr::zero(),
+            RedelegateClaim(_) => Fr::zero(),
             ValidatorDefinition(_) => Fr::zero(),
             Swap(swap) => swap.fee_blinding,
             SwapClaim(_) => Fr::zero(),
@@ -230,6 +242,8 @@ impl ActionPlan {
             Delegate(plan) => plan.effect_hash(),
             Undelegate(plan) => plan.effect_hash(),
             UndelegateClaim(plan) => plan.undelegate_claim_body().effect_hash(),
+            Redelegate(plan) => plan.effect_hash(),
+            RedelegateClaim(plan) => plan.effect_hash(),
             ValidatorDefinition(plan) => plan.effect_hash(),
             Swap(plan) => plan.swap_body(fvk).effect_hash(),
             SwapClaim(plan) => plan.swap_claim_body(fvk).effect_hash(),
@@ -289,6 +303,18 @@ impl From<Undelegate> for ActionPlan {
     }
 }
 
+impl From<Redelegate> for ActionPlan {
+    fn from(inner: Redelegate) -> ActionPlan {
+        ActionPlan::Redelegate(inner)
+    }
+}
+
+impl From<RedelegateClaim> for ActionPlan {
+    fn from(inner: RedelegateClaim) -> ActionPlan {
+        ActionPlan::RedelegateClaim(inner)
+    }
+}
+
 impl From<penumbra_stake::validator::Definition> for ActionPlan {
     fn from(inner: penumbra_stake::validator::Definition) -> ActionPlan {
         ActionPlan::ValidatorDefinition(inner)
@@ -371,6 +397,12 @@ impl From<ActionPlan> for pb_t::ActionPlan {
             ActionPlan::UndelegateClaim(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::UndelegateClaim(inner.into())),
             },
+            ActionPlan::Redelegate(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::Redelegate(inner.into())),
+            },
+            ActionPlan::RedelegateClaim(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::RedelegateClaim(inner.into())),
+            },
             ActionPlan::ValidatorDefinition(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::ValidatorDefinition(inner.into())),
             },
@@ -457,6 +489,12 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::UndelegateClaim(inner) => {
                 Ok(ActionPlan::UndelegateClaim(inner.try_into()?))
             }
+            pb_t::action_plan::Action::Redelegate(inner) => {
+                Ok(ActionPlan::Redelegate(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::RedelegateClaim(inner) => {
+                Ok(ActionPlan::RedelegateClaim(inner.try_into()?))
+            }
             pb_t::action_plan::Action::ValidatorDefinition(inner) => {
                 Ok(ActionPlan::ValidatorDefinition(inner.try_into()?))
             }
@@ -21,7 +21,7 @@ use penumbra_proto::{
 };
 use penumbra_sct::Nullifier;
 use penumbra_shielded_pool::{Note, Output, Spend};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaim};
+use penumbra_stake::{Delegate, Redelegate, RedelegateClaim, Undelegate, UndelegateClaim};
 use penumbra_tct as tct;
 use penumbra_tct::StateCommitment;
 use penumbra_txhash::{
@@ -244,6 +244,8 @@ impl Transaction {
                 | Action::Delegate(_)
                 | Action::Undelegate(_)
                 | Action::UndelegateClaim(_)
+                | Action::Redelegate(_)
+                | Action::RedelegateClaim(_)
                 | Action::ValidatorDefinition(_)
                 | Action::IbcRelay(_)
                 | Action::ProposalSubmit(_)
@@ -358,6 +360,26 @@ impl Transaction {
         })
     }
 
+    pub fn redelegations(&self) -> impl Iterator<Item = &Redelegate> {
+        self.actions().filter_map(|action| {
+            if let Action::Redelegate(r) = action {
+                Some(r)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn redelegate_claims(&self) -> impl Iterator<Item = &RedelegateClaim> {
+        self.actions().filter_map(|action| {
+            if let Action::RedelegateClaim(c) = action {
+                Some(c)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn undelegate_claims(&self) -> impl Iterator<Item = &UndelegateClaim> {
         self.actions().filter_map(|action| {
             if let Action::UndelegateClaim(d) = action {
@@ -8,7 +8,7 @@ use penumbra_governance::{ProposalDepositClaim, ProposalSubmit, ProposalWithdraw
 use penumbra_ibc::IbcRelay;
 use penumbra_proto::{core::transaction::v1alpha1 as pbt, DomainType};
 use penumbra_shielded_pool::Ics20Withdrawal;
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaim};
+use penumbra_stake::{Delegate, Redelegate, RedelegateClaim, Undelegate, UndelegateClaim};
 use serde::{Deserialize, Serialize};
 
 pub use penumbra_governance::DelegatorVoteView;
@@ -41,6 +41,8 @@ pub enum ActionView {
     Delegate(Delegate),
     Undelegate(Undelegate),
     UndelegateClaim(UndelegateClaim),
+    Redelegate(Redelegate),
+    RedelegateClaim(RedelegateClaim),
     Ics20Withdrawal(Ics20Withdrawal),
     CommunityPoolDeposit(CommunityPoolDeposit),
     CommunityPoolSpend(CommunityPoolSpend),
@@ -66,6 +68,8 @@ impl TryFrom<pbt::ActionView> for ActionView {
                 AV::Output(x) => ActionView::Output(x.try_into()?),
                 AV::Undelegate(x) => ActionView::Undelegate(x.try_into()?),
                 AV::UndelegateClaim(x) => ActionView::UndelegateClaim(x.try_into()?),
+                AV::Redelegate(x) => ActionView::Redelegate(x.try_into()?),
+                AV::RedelegateClaim(x) => ActionView::RedelegateClaim(x.try_into()?),
                 AV::Swap(x) => ActionView::Swap(x.try_into()?),
                 AV::SwapClaim(x) => ActionView::SwapClaim(x.try_into()?),
                 AV::ValidatorDefinition(x) => ActionView::ValidatorDefinition(x.try_into()?),
@@ -100,6 +104,8 @@ impl From<ActionView> for pbt::ActionView {
                 ActionView::Delegate(x) => AV::Delegate(x.into()),
                 ActionView::Undelegate(x) => AV::Undelegate(x.into()),
                 ActionView::UndelegateClaim(x) => AV::UndelegateClaim(x.into()),
+                ActionView::Redelegate(x) => AV::Redelegate(x.into()),
+                ActionView::RedelegateClaim(x) => AV::RedelegateClaim(x.into()),
                 ActionView::ValidatorDefinition(x) => AV::ValidatorDefinition(x.into()),
                 ActionView::IbcRelay(x) => AV::IbcRelayAction(x.into()),
                 ActionView::ProposalSubmit(x) => AV::ProposalSubmit(x.into()),
@@ -130,6 +136,8 @@ impl From<ActionView> for Action {
             ActionView::Delegate(x) => Action::Delegate(x),
             ActionView::Undelegate(x) => Action::Undelegate(x),
             ActionView::UndelegateClaim(x) => Action::UndelegateClaim(x),
+            ActionView::Redelegate(x) => Action::Redelegate(x),
+            ActionView::RedelegateClaim(x) => Action::RedelegateClaim(x),
             ActionView::ValidatorDefinition(x) => Action::ValidatorDefinition(x),
             ActionView::IbcRelay(x) => Action::IbcRelay(x),
             ActionView::ProposalSubmit(x) => Action::ProposalSubmit(x),
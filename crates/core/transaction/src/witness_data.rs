@@ -19,6 +19,44 @@ impl WitnessData {
     }
 }
 
+impl WitnessData {
+    /// Encode the witness data with the proofs relative to the anchor batched together, so that
+    /// the parts of their auth paths they have in common are only sent once.
+    ///
+    /// Any other proofs, like those for dummy spends, are still sent individually.  Only clients
+    /// which asked for batched proofs understand this encoding, so the default conversion to
+    /// [`pb::WitnessData`] sends every proof individually.
+    pub fn into_batched_proto(self) -> pb::WitnessData {
+        let (batched, individual): (Vec<_>, Vec<_>) = self
+            .state_commitment_proofs
+            .into_values()
+            .partition(|proof| proof.verify(self.anchor).is_ok());
+        let batch = (!batched.is_empty()).then(|| {
+            tct::BatchProof::new(self.anchor, batched)
+                .expect("batched proofs verify against the anchor")
+                .into()
+        });
+
+        pb::WitnessData {
+            anchor: Some(self.anchor.into()),
+            state_commitment_proofs: individual.into_iter().map(|v| v.into()).collect(),
+            batch_state_commitment_proof: batch,
+        }
+    }
+}
+
+impl From<tct::BatchProof> for WitnessData {
+    fn from(batch: tct::BatchProof) -> Self {
+        Self {
+            anchor: batch.root(),
+            state_commitment_proofs: batch
+                .proofs()
+                .map(|proof| (proof.commitment(), proof))
+                .collect(),
+        }
+    }
+}
+
 impl DomainType for WitnessData {
     type Proto = pb::WitnessData;
 }
@@ -32,6 +70,7 @@ impl From<WitnessData> for pb::WitnessData {
                 .into_values()
                 .map(|v| v.into())
                 .collect(),
+            batch_state_commitment_proof: None,
         }
     }
 }
@@ -45,12 +84,71 @@ impl TryFrom<pb::WitnessData> for WitnessData {
             let tct_proof: tct::Proof = proof.try_into()?;
             state_commitment_proofs.insert(tct_proof.commitment(), tct_proof);
         }
+        let anchor: tct::Root = msg
+            .anchor
+            .ok_or_else(|| anyhow::anyhow!("missing anchor"))?
+            .try_into()?;
+        if let Some(batch) = msg.batch_state_commitment_proof {
+            let batch: tct::BatchProof = batch.try_into()?;
+            batch.verify(anchor)?;
+            state_commitment_proofs.extend(batch.proofs().map(|proof| (proof.commitment(), proof)));
+        }
         Ok(Self {
-            anchor: msg
-                .anchor
-                .ok_or_else(|| anyhow::anyhow!("missing anchor"))?
-                .try_into()?,
+            anchor,
             state_commitment_proofs,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use decaf377::Fq;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn witness_data() -> WitnessData {
+        let mut sct = tct::Tree::new();
+        let commitments = (0..5u64)
+            .map(|i| tct::StateCommitment(Fq::from(i)))
+            .collect::<Vec<_>>();
+        for commitment in &commitments {
+            sct.insert(tct::Witness::Keep, *commitment).unwrap();
+        }
+        let mut witness_data = WitnessData::from(sct.witness_batch(commitments).unwrap());
+        let dummy = tct::StateCommitment(Fq::from(100u64));
+        witness_data.add_proof(dummy, tct::Proof::dummy(&mut OsRng, dummy));
+        witness_data
+    }
+
+    #[test]
+    fn proofs_are_sent_individually_by_default() {
+        let witness_data = witness_data();
+        let proto = pb::WitnessData::from(witness_data.clone());
+        assert_eq!(proto.state_commitment_proofs.len(), 6);
+        assert!(proto.batch_state_commitment_proof.is_none());
+
+        let decoded = WitnessData::try_from(proto).unwrap();
+        assert_eq!(decoded.anchor, witness_data.anchor);
+        assert_eq!(
+            decoded.state_commitment_proofs,
+            witness_data.state_commitment_proofs
+        );
+    }
+
+    #[test]
+    fn batched_proofs_round_trip() {
+        let witness_data = witness_data();
+        let proto = witness_data.clone().into_batched_proto();
+        // Only the dummy proof is sent individually.
+        assert_eq!(proto.state_commitment_proofs.len(), 1);
+        assert!(proto.batch_state_commitment_proof.is_some());
+
+        let decoded = WitnessData::try_from(proto).unwrap();
+        assert_eq!(decoded.anchor, witness_data.anchor);
+        assert_eq!(
+            decoded.state_commitment_proofs,
+            witness_data.state_commitment_proofs
+        );
+    }
+}
@@ -35,6 +35,24 @@ impl ClueKey {
         ExpandedClueKey::new(self)
     }
 
+    /// Derive the clue key of the child detection key labeled by `input`.
+    ///
+    /// This is the clue key of
+    /// [`DetectionKey::derive_child`](crate::DetectionKey::derive_child) on
+    /// the detection key for this clue key.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the bytes don't encode a valid clue key.
+    pub fn derive_child(&self, input: &[u8]) -> Result<ClueKey, Error> {
+        let root_pub_enc = decaf377::Encoding(self.0);
+        let root_pub = root_pub_enc
+            .vartime_decompress()
+            .map_err(|_| Error::InvalidClueKey)?;
+        let child_pub = hkd::derive_child_public(&root_pub, &root_pub_enc, input);
+        Ok(ClueKey(child_pub.vartime_compress().0))
+    }
+
     /// Expand this clue key encoding.
     ///
     /// This method always results in a valid clue key, though the clue key may not have
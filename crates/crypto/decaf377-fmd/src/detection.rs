@@ -69,6 +69,18 @@ impl DetectionKey {
         ClueKey((self.dtk * decaf377::basepoint()).vartime_compress().0)
     }
 
+    /// Derive the child detection key labeled by `input`.
+    ///
+    /// Child keys let a single root detection key stand in for many: the
+    /// clue key of each child can be derived from the root clue key alone,
+    /// using [`ClueKey::derive_child`], and anyone holding the root
+    /// detection key can derive the detection key of any child whose label
+    /// they know.
+    pub fn derive_child(&self, input: &[u8]) -> Self {
+        let root_pub_enc = decaf377::Encoding(self.clue_key().0);
+        Self::from_field(hkd::derive_child_private(&self.dtk, &root_pub_enc, input))
+    }
+
     /// Use this detection key to examine the given `clue`, returning `true` if the
     /// clue was possibly sent to this detection key's clue key.
     ///
@@ -31,6 +31,32 @@ pub fn derive_private(root_priv: &Fr, root_pub_enc: &decaf377::Encoding, index:
     *root_priv + x
 }
 
+/// Hash `input` to the tweak relating a root key to its child key labeled by `input`.
+///
+/// This uses a different personalization than the per-bit derivation above, so that child keys
+/// are independent of the subkeys used for detection.
+fn child_tweak(root_pub_enc: &decaf377::Encoding, input: &[u8]) -> Fr {
+    let hash = blake2b_simd::Params::default()
+        .personal(b"decaf377-fmd.chd")
+        .to_state()
+        .update(&root_pub_enc.0)
+        .update(input)
+        .finalize();
+    Fr::from_le_bytes_mod_order(hash.as_bytes())
+}
+
+pub fn derive_child_public(
+    root_pub: &decaf377::Element,
+    root_pub_enc: &decaf377::Encoding,
+    input: &[u8],
+) -> decaf377::Element {
+    root_pub + child_tweak(root_pub_enc, input) * decaf377::basepoint()
+}
+
+pub fn derive_child_private(root_priv: &Fr, root_pub_enc: &decaf377::Encoding, input: &[u8]) -> Fr {
+    *root_priv + child_tweak(root_pub_enc, input)
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::prelude::*;
@@ -55,5 +81,14 @@ mod tests {
                 assert_eq!(child_pub, child_pub_from_priv);
             }
         }
+
+        #[test]
+        fn public_private_child_derivation_match(root_priv in fr_strategy(), input: [u8; 16]) {
+            let root_pub = root_priv * decaf377::basepoint();
+            let root_pub_enc = root_pub.vartime_compress();
+            let child_pub = derive_child_public(&root_pub, &root_pub_enc, &input);
+            let child_priv = derive_child_private(&root_priv, &root_pub_enc, &input);
+            assert_eq!(child_pub, child_priv * decaf377::basepoint());
+        }
     }
 }
@@ -0,0 +1,58 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::Rng;
+
+use penumbra_eddy::{BabyStepGiantStep, DecryptionTable, FileDecryptionTable};
+
+/// The encodings of the four limbs of the aggregate of `flows` 64-bit values,
+/// as they are looked up when decrypting the aggregate ciphertext.
+fn aggregate_limbs(flows: u32) -> Vec<[u8; 32]> {
+    let mut rng = rand::thread_rng();
+    (0..4)
+        .map(|_| {
+            let limb: u32 = (0..flows).map(|_| rng.gen::<u16>() as u32).sum();
+            (decaf377::Fr::from(limb) * decaf377::basepoint())
+                .vartime_compress()
+                .0
+        })
+        .collect()
+}
+
+fn decrypt_limbs(table: &dyn DecryptionTable, limbs: &[[u8; 32]]) {
+    for limb in limbs {
+        futures::executor::block_on(table.lookup(*limb))
+            .expect("lookup succeeds")
+            .expect("limb is in range of the table");
+    }
+}
+
+fn bench(c: &mut Criterion) {
+    let dir = tempfile::tempdir().expect("can create temp dir");
+
+    let mut group = c.benchmark_group("aggregate-decryption-lookups");
+    group.sample_size(10);
+    // Aggregating 2^n flows of 16-bit limbs needs discrete logarithms up to 2^{16 + n}, so 256
+    // flows need baby steps and giant steps covering 24 bits.
+    for baby_bits in [16, 18, 20] {
+        let path = dir.path().join(format!("table_{baby_bits}.bin"));
+        FileDecryptionTable::generate(&path, baby_bits).expect("can generate table");
+        let table = BabyStepGiantStep::new(
+            FileDecryptionTable::open(&path).expect("can open table"),
+            baby_bits,
+            24 - baby_bits,
+        );
+
+        for flows in [1, 16, 64, 256] {
+            let limbs = aggregate_limbs(flows);
+            group.throughput(Throughput::Elements(limbs.len() as u64));
+            group.bench_with_input(
+                BenchmarkId::new(format!("baby_bits_{baby_bits}"), flows),
+                &limbs,
+                |b, limbs| b.iter(|| decrypt_limbs(&table, limbs)),
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,22 @@
+//! Generate a [`FileDecryptionTable`] once, to be memory-mapped by decryptors.
+//!
+//! Usage: `eddy-table <PATH> <BITSIZE>`
+
+use anyhow::Context;
+use penumbra_eddy::FileDecryptionTable;
+
+fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let (Some(path), Some(bitsize), None) = (args.next(), args.next(), args.next()) else {
+        anyhow::bail!("usage: eddy-table <PATH> <BITSIZE>");
+    };
+    let bitsize: usize = bitsize
+        .parse()
+        .with_context(|| format!("invalid bitsize {bitsize}"))?;
+
+    println!("Generating a decryption table of bitsize {bitsize} at {path}...");
+    FileDecryptionTable::generate(&path, bitsize)?;
+    println!("Done.");
+
+    Ok(())
+}
@@ -1,14 +1,23 @@
 //! Lookup tables for decryption.
 //!
-//! Currently, this module just has a naive, in-memory implementation.  In the
-//! future, it should have a trait that allows users to plug their own storage
-//! system to back the lookup table.
+//! Tables implement the [`DecryptionTable`] trait, so that users can plug in
+//! their own storage.  This module provides a naive, in-memory
+//! [`MockDecryptionTable`] for testing, and a [`FileDecryptionTable`] that is
+//! generated once and then memory-mapped from disk.  Either can be wrapped in a
+//! [`BabyStepGiantStep`] table, which trades lookup time for a much smaller
+//! table.
 
 use std::{collections::BTreeMap, future::Future, pin::Pin, sync::Arc};
 
 use futures::FutureExt;
 use parking_lot::Mutex;
 
+mod bsgs;
+mod file;
+
+pub use bsgs::BabyStepGiantStep;
+pub use file::FileDecryptionTable;
+
 /// An error indicating that the [`DecryptionTable`] did not contain a requested
 /// discrete logarithm.
 ///
@@ -40,11 +49,6 @@ pub trait DecryptionTable: Send + Sync {
         key: [u8; 32],
     ) -> Pin<Box<dyn Future<Output = anyhow::Result<Option<u32>>> + Send + 'static>>;
     /// Store a 32-bit discrete logarithm, indexed by the byte-encoded group element.
-    // idea: a rocksdb backing store could store the data in the form
-    // (nbits || key, value)
-    // where nbits is the bitsize of value
-    // then do a linear scan through bitsizes to cut the working set size
-    // might not be better than naive caching?
     fn store(
         &self,
         key: [u8; 32],
@@ -111,6 +115,7 @@ impl DecryptionTable for MockDecryptionTable {
 }
 
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
     use super::*;
 
@@ -144,4 +149,42 @@ mod tests {
     async fn build_21() {
         build_bitsize(21).await;
     }
+
+    #[tokio::test]
+    async fn file_table_matches_mock_table() {
+        let dir = tempfile::tempdir().expect("can create temp dir");
+        let path = dir.path().join("table.bin");
+        FileDecryptionTable::generate(&path, 10).expect("can generate table");
+        let file_table = FileDecryptionTable::open(&path).expect("can open table");
+        assert_eq!(file_table.bitsize(), 10);
+
+        let mock_table = MockDecryptionTable::default();
+        mock_table
+            .initialize(10)
+            .await
+            .expect("can initialize table");
+
+        let B = decaf377::basepoint();
+        for x in [0u32, 1, 2, 511, 1023, 1024, 5000] {
+            let key = (decaf377::Fr::from(x) * B).vartime_compress().0;
+            assert_eq!(
+                file_table.lookup(key).await.expect("lookup succeeds"),
+                mock_table.lookup(key).await.expect("lookup succeeds"),
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn baby_step_giant_step_lookup() {
+        let table = BabyStepGiantStep::new(MockDecryptionTable::default(), 8, 4);
+        table.initialize(12).await.expect("can initialize table");
+
+        let B = decaf377::basepoint();
+        for x in [0u32, 1, 255, 256, 257, 1000, 4095] {
+            let key = (decaf377::Fr::from(x) * B).vartime_compress().0;
+            assert_eq!(table.lookup(key).await.expect("lookup succeeds"), Some(x));
+        }
+        let key = (decaf377::Fr::from(4096u32) * B).vartime_compress().0;
+        assert_eq!(table.lookup(key).await.expect("lookup succeeds"), None);
+    }
 }
@@ -0,0 +1,108 @@
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use futures::FutureExt;
+
+use super::DecryptionTable;
+
+/// A decryption table that finds discrete logarithms using baby-step
+/// giant-step search over a smaller table.
+///
+/// The underlying table holds only the "baby steps", the discrete logarithms up
+/// to `2^baby_bits`.  A lookup of `P` then searches for `x` in the underlying
+/// table at `P - j * 2^baby_bits * B`, for each "giant step" `j` up to
+/// `2^giant_bits`, finding discrete logarithms up to `2^{baby_bits +
+/// giant_bits}`.
+///
+/// This shrinks the table by a factor of `2^giant_bits`, at the cost of up to
+/// `2^giant_bits` lookups into it.  For instance, decrypting the aggregate of
+/// 64 ciphertexts needs discrete logarithms up to `2^22`, which can be found in
+/// a table of size `2^16` with at most 64 lookups per limb.
+pub struct BabyStepGiantStep<T> {
+    baby_steps: Arc<T>,
+    baby_bits: usize,
+    giant_bits: usize,
+}
+
+impl<T: DecryptionTable + 'static> BabyStepGiantStep<T> {
+    /// Search `baby_steps`, which should hold the discrete logarithms up to
+    /// `2^baby_bits`, for discrete logarithms up to `2^{baby_bits +
+    /// giant_bits}`.
+    ///
+    /// # Panics
+    ///
+    /// If `baby_bits + giant_bits` exceeds 32, since the discrete logarithms
+    /// would not fit in a `u32`.
+    pub fn new(baby_steps: T, baby_bits: usize, giant_bits: usize) -> Self {
+        assert!(
+            baby_bits + giant_bits <= 32,
+            "discrete logarithms must fit in 32 bits"
+        );
+        Self {
+            baby_steps: Arc::new(baby_steps),
+            baby_bits,
+            giant_bits,
+        }
+    }
+
+    /// The bitsize of the largest discrete logarithm this table can find.
+    pub fn bitsize(&self) -> usize {
+        self.baby_bits + self.giant_bits
+    }
+}
+
+impl<T: DecryptionTable + 'static> DecryptionTable for BabyStepGiantStep<T> {
+    fn lookup(
+        &self,
+        key: [u8; 32],
+    ) -> Pin<Box<dyn Future<Output = anyhow::Result<Option<u32>>> + Send + 'static>> {
+        let baby_steps = self.baby_steps.clone();
+        let baby_bits = self.baby_bits;
+        let giant_steps = 1u64 << self.giant_bits;
+        let giant_step = decaf377::Fr::from(1u64 << baby_bits) * decaf377::basepoint();
+
+        async move {
+            // A key that isn't a valid encoding can't be the encoding of a multiple of the
+            // basepoint either.
+            let Ok(mut point) = decaf377::Encoding(key).vartime_decompress() else {
+                return Ok(None);
+            };
+
+            for j in 0..giant_steps {
+                if let Some(x) = baby_steps.lookup(point.vartime_compress().0).await? {
+                    // This fits, since baby_bits + giant_bits <= 32 and x < 2^baby_bits.
+                    return Ok(Some(((j << baby_bits) as u32) + x));
+                }
+                point -= giant_step;
+            }
+
+            Ok(None)
+        }
+        .boxed()
+    }
+
+    /// Store a baby step in the underlying table.
+    fn store(
+        &self,
+        key: [u8; 32],
+        value: u32,
+    ) -> Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + 'static>> {
+        self.baby_steps.store(key, value)
+    }
+
+    /// Initialize the underlying table with the baby steps.
+    ///
+    /// This fails if `k` exceeds [`BabyStepGiantStep::bitsize`].
+    fn initialize(
+        &self,
+        k: usize,
+    ) -> Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + '_>> {
+        if k > self.bitsize() {
+            let bitsize = self.bitsize();
+            return futures::future::ready(Err(anyhow::anyhow!(
+                "baby-step giant-step table can only find discrete logarithms up to 2^{bitsize}, not 2^{k}"
+            )))
+            .boxed();
+        }
+        self.baby_steps.initialize(self.baby_bits)
+    }
+}
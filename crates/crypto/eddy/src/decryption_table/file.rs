@@ -0,0 +1,198 @@
+use std::{
+    fs::{self, File},
+    future::Future,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::Arc,
+};
+
+use anyhow::Context;
+use futures::FutureExt;
+use memmap2::Mmap;
+
+use super::DecryptionTable;
+
+/// Identifies the file format, and its version.
+const MAGIC: &[u8; 8] = b"eddydlt1";
+/// The magic, the bitsize as a little-endian `u32`, and four reserved bytes.
+const HEADER_LEN: usize = 16;
+/// A fingerprint as a little-endian `u64`, then a value as a little-endian `u32`.
+const RECORD_LEN: usize = 12;
+
+/// A decryption table stored in a file, which is generated once with
+/// [`FileDecryptionTable::generate`] and then memory-mapped.
+///
+/// Opening the table is cheap, and only the pages of the table that lookups
+/// touch are read into memory, so that a large table can be shared by
+/// processes, and survive restarts, without being regenerated.
+///
+/// The file holds one record per discrete logarithm, sorted by a 64-bit
+/// fingerprint of the group element: 12 bytes per entry, or 48 MiB for a table
+/// of size `2^22`.  Since fingerprints can collide, every match is checked
+/// against the full group element before it is returned.
+///
+/// The table is read-only: [`DecryptionTable::store`] fails, and
+/// [`DecryptionTable::initialize`] only checks that the table is large enough.
+#[derive(Clone)]
+pub struct FileDecryptionTable {
+    mmap: Arc<Mmap>,
+    bitsize: usize,
+}
+
+fn fingerprint(key: &[u8; 32]) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&key[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+impl FileDecryptionTable {
+    /// Generate a table holding all discrete logarithms up to `2^k`, and write
+    /// it to `path`.
+    ///
+    /// The table is written to a temporary file next to `path` first, so that
+    /// an interrupted generation never leaves a truncated table behind.
+    #[allow(non_snake_case)]
+    pub fn generate(path: impl AsRef<Path>, k: usize) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        if k > 32 {
+            anyhow::bail!("discrete logarithms must fit in 32 bits, so k must be at most 32");
+        }
+
+        let bound = 1u64 << k;
+        let mut records = Vec::with_capacity(bound as usize);
+        let (mut x, mut xB) = (0u64, decaf377::Element::default());
+        let B = decaf377::basepoint();
+        while x < bound {
+            records.push((fingerprint(&xB.vartime_compress().0), x as u32));
+            x += 1;
+            xB += B;
+        }
+        records.sort_unstable();
+
+        let tmp_path = PathBuf::from(format!("{}.tmp", path.display()));
+        let file = File::create(&tmp_path)
+            .with_context(|| format!("could not create {}", tmp_path.display()))?;
+        let mut writer = BufWriter::new(file);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&(k as u32).to_le_bytes())?;
+        writer.write_all(&[0u8; 4])?;
+        for (fingerprint, value) in records {
+            writer.write_all(&fingerprint.to_le_bytes())?;
+            writer.write_all(&value.to_le_bytes())?;
+        }
+        writer
+            .into_inner()
+            .map_err(|e| e.into_error())?
+            .sync_all()?;
+        fs::rename(&tmp_path, path)?;
+
+        Ok(())
+    }
+
+    /// Open a table previously written by [`FileDecryptionTable::generate`].
+    pub fn open(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let file =
+            File::open(path).with_context(|| format!("could not open {}", path.display()))?;
+        // SAFETY: the table is never written to once generated, and is replaced
+        // rather than modified in place when regenerated, so the mapped memory
+        // does not change under us.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        if mmap.len() < HEADER_LEN || &mmap[..8] != MAGIC {
+            anyhow::bail!("{} is not a decryption table", path.display());
+        }
+        let mut bitsize = [0u8; 4];
+        bitsize.copy_from_slice(&mmap[8..12]);
+        let bitsize = u32::from_le_bytes(bitsize) as usize;
+        if bitsize > 32 || mmap.len() != HEADER_LEN + (RECORD_LEN << bitsize) {
+            anyhow::bail!(
+                "decryption table {} is truncated or corrupt",
+                path.display()
+            );
+        }
+
+        Ok(Self {
+            mmap: Arc::new(mmap),
+            bitsize,
+        })
+    }
+
+    /// The bitsize of the table, which holds all discrete logarithms up to
+    /// `2^bitsize`.
+    pub fn bitsize(&self) -> usize {
+        self.bitsize
+    }
+
+    fn record(&self, index: usize) -> (u64, u32) {
+        let start = HEADER_LEN + index * RECORD_LEN;
+        let record = &self.mmap[start..start + RECORD_LEN];
+        let mut fingerprint = [0u8; 8];
+        fingerprint.copy_from_slice(&record[..8]);
+        let mut value = [0u8; 4];
+        value.copy_from_slice(&record[8..]);
+        (u64::from_le_bytes(fingerprint), u32::from_le_bytes(value))
+    }
+
+    fn get(&self, key: &[u8; 32]) -> Option<u32> {
+        let target = fingerprint(key);
+
+        // Find the first record with the target fingerprint, if any.
+        let (mut lo, mut hi) = (0usize, 1usize << self.bitsize);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.record(mid).0 < target {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+
+        (lo..1usize << self.bitsize)
+            .map(|index| self.record(index))
+            .take_while(|(fingerprint, _)| *fingerprint == target)
+            .map(|(_, value)| value)
+            .find(|value| {
+                (decaf377::Fr::from(*value) * decaf377::basepoint())
+                    .vartime_compress()
+                    .0
+                    == *key
+            })
+    }
+}
+
+impl DecryptionTable for FileDecryptionTable {
+    fn lookup(
+        &self,
+        key: [u8; 32],
+    ) -> Pin<Box<dyn Future<Output = anyhow::Result<Option<u32>>> + Send + 'static>> {
+        futures::future::ready(Ok(self.get(&key))).boxed()
+    }
+
+    fn store(
+        &self,
+        _key: [u8; 32],
+        _value: u32,
+    ) -> Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + 'static>> {
+        futures::future::ready(Err(anyhow::anyhow!(
+            "file decryption tables are read-only; use FileDecryptionTable::generate"
+        )))
+        .boxed()
+    }
+
+    fn initialize(
+        &self,
+        k: usize,
+    ) -> Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + '_>> {
+        let result = if k > self.bitsize {
+            Err(anyhow::anyhow!(
+                "decryption table only holds discrete logarithms up to 2^{}, not 2^{k}; regenerate it",
+                self.bitsize
+            ))
+        } else {
+            Ok(())
+        };
+        futures::future::ready(result).boxed()
+    }
+}
@@ -1,8 +1,818 @@
-//! Types used to perform distributed key generation (WIP).
+//! Distributed key generation for a committee of decryptors, using Feldman
+//! verifiable secret sharing.
+//!
+//! Each of the `n` participants, indexed from `1` to `n`, deals a random
+//! secret to the others by sharing it with a random polynomial of degree
+//! `t - 1`.  The shared decryption key is the sum of the secrets of the
+//! qualified dealers, so that no participant ever learns it, and any `t`
+//! participants can decrypt together.
+//!
+//! The protocol proceeds in rounds, with every message in a round either
+//! broadcast to all participants, or sent privately to one:
+//!
+//! 1. Every participant calls [`Participant::deal`], broadcasting the
+//!    [`Dealing`], which commits to its polynomial and proves knowledge of its
+//!    secret, and privately sending each other participant its
+//!    [`DealtShare`].
+//! 2. Every participant calls [`Participant::receive`] with all dealings and
+//!    the shares dealt to it, and broadcasts a [`Complaint`] about every
+//!    dealer whose share didn't match its dealing.
+//! 3. Every participant calls [`Participant::respond`] with all complaints,
+//!    and broadcasts a [`ComplaintResponse`] revealing the share of each
+//!    participant that complained about it.
+//! 4. Every participant calls [`Participant::finalize`] with all complaints
+//!    and responses, producing its [`PrivateKeyShare`], along with the
+//!    [`EncryptionKey`] and the [`Committee`].
+//!
+//! Dealers whose dealing is invalid, or who fail to answer a complaint with a
+//! valid share, are disqualified.  The broadcast messages make up the
+//! [`PublicTranscript`], from which anyone can compute the qualified dealers,
+//! the encryption key, and the public key shares of the committee.
+//!
+//! The protocol assumes a broadcast channel, and authenticated, encrypted
+//! channels for the private shares; providing these is up to the user.
 
-use crate::PublicKeyShare;
+use std::collections::{BTreeMap, BTreeSet};
 
+use ark_ff::Zero;
+use ark_std::UniformRand;
+use decaf377::Fr;
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use crate::{EncryptionKey, PrivateKeyShare, PublicKeyShare, TranscriptProtocol};
+
+mod serialize;
+
+/// The public key shares of a committee of decryptors, any `threshold` of
+/// which can decrypt together.
+#[derive(Debug, Clone)]
 pub struct Committee {
     pub shares: Vec<PublicKeyShare>,
     pub threshold: u32,
 }
+
+impl Committee {
+    /// The public key share of the participant with the given index, if any.
+    pub fn share(&self, participant_index: u32) -> Option<&PublicKeyShare> {
+        self.shares
+            .iter()
+            .find(|share| share.participant_index == participant_index)
+    }
+}
+
+/// The size and threshold of a committee being generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parameters {
+    participants: u32,
+    threshold: u32,
+}
+
+impl Parameters {
+    /// Parameters for `participants` participants, any `threshold` of which
+    /// can decrypt together.
+    pub fn new(participants: u32, threshold: u32) -> anyhow::Result<Self> {
+        if threshold == 0 || threshold > participants {
+            anyhow::bail!(
+                "threshold {threshold} must be between 1 and the number of participants {participants}"
+            );
+        }
+        Ok(Self {
+            participants,
+            threshold,
+        })
+    }
+
+    pub fn participants(&self) -> u32 {
+        self.participants
+    }
+
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    fn indices(&self) -> impl Iterator<Item = u32> {
+        1..=self.participants
+    }
+
+    fn contains(&self, index: u32) -> bool {
+        (1..=self.participants).contains(&index)
+    }
+}
+
+/// A dealer's broadcast commitment to its sharing polynomial, with a proof of
+/// knowledge of the secret it deals.
+///
+/// Deserializing a dealing only checks that its encoding is valid; check the
+/// dealing itself with [`Dealing::verify`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dealing {
+    dealer: u32,
+    /// Commitments to the coefficients of the polynomial, from the constant
+    /// term up.
+    #[serde(with = "serialize::elements")]
+    commitments: Vec<decaf377::Element>,
+    proof: DealingProof,
+}
+
+/// A Schnorr proof of knowledge of the discrete logarithm of the first
+/// commitment of a [`Dealing`], bound to all of its commitments.
+///
+/// This prevents a dealer from choosing its secret as a function of the
+/// secrets of others, e.g. to cancel them out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DealingProof {
+    /// The challenge scalar
+    #[serde(with = "serialize::fr")]
+    c: Fr,
+    /// The response to the challenge
+    #[serde(with = "serialize::fr")]
+    r: Fr,
+}
+
+/// A share of a dealer's secret, sent privately to one participant.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DealtShare {
+    dealer: u32,
+    recipient: u32,
+    #[serde(with = "serialize::fr")]
+    share: Fr,
+}
+
+/// A broadcast accusation that a dealer didn't send a participant a share
+/// matching its dealing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Complaint {
+    accuser: u32,
+    dealer: u32,
+}
+
+/// A dealer's broadcast answer to a [`Complaint`], revealing the share of the
+/// participant who complained.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplaintResponse {
+    dealer: u32,
+    accuser: u32,
+    #[serde(with = "serialize::fr")]
+    share: Fr,
+}
+
+/// The messages broadcast during key generation, which determine its public
+/// outcome.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PublicTranscript {
+    pub dealings: Vec<Dealing>,
+    pub complaints: Vec<Complaint>,
+    pub responses: Vec<ComplaintResponse>,
+}
+
+/// The outcome of key generation for one participant.
+pub struct DkgOutput {
+    pub private_key_share: PrivateKeyShare,
+    pub encryption_key: EncryptionKey,
+    pub committee: Committee,
+}
+
+/// One participant's state during key generation.
+pub struct Participant {
+    params: Parameters,
+    index: u32,
+    /// The coefficients of this participant's sharing polynomial, from the
+    /// constant term up.
+    coefficients: Vec<Fr>,
+    dealings: Vec<Dealing>,
+    /// The valid shares dealt to this participant, by dealer.
+    shares: BTreeMap<u32, Fr>,
+}
+
+/// Evaluate the polynomial with the given coefficients at `x`.
+fn evaluate(coefficients: &[Fr], x: u32) -> Fr {
+    let x = Fr::from(x);
+    coefficients
+        .iter()
+        .rev()
+        .fold(Fr::zero(), |acc, coefficient| acc * x + *coefficient)
+}
+
+fn dealing_transcript(
+    params: &Parameters,
+    dealer: u32,
+    commitments: &[decaf377::Element],
+) -> merlin::Transcript {
+    let mut transcript = merlin::Transcript::new(b"eddy-dkg");
+    transcript.begin_dealing(params.participants, params.threshold, dealer);
+    for commitment in commitments {
+        transcript.append_polynomial_commitment(commitment);
+    }
+    transcript
+}
+
+impl Dealing {
+    pub fn dealer(&self) -> u32 {
+        self.dealer
+    }
+
+    /// Check that this dealing is well-formed for `params`, and that its
+    /// dealer knows the secret it deals.
+    #[allow(non_snake_case)]
+    pub fn verify(&self, params: &Parameters) -> anyhow::Result<()> {
+        if !params.contains(self.dealer) {
+            anyhow::bail!("dealer {} is not a participant", self.dealer);
+        }
+        if self.commitments.len() != params.threshold as usize {
+            anyhow::bail!(
+                "dealing from {} commits to {} coefficients, but the threshold is {}",
+                self.dealer,
+                self.commitments.len(),
+                params.threshold
+            );
+        }
+
+        let kB = decaf377::basepoint() * self.proof.r + self.commitments[0] * self.proof.c;
+        let mut transcript = dealing_transcript(params, self.dealer, &self.commitments);
+        transcript.append_blinding_commitment(b"kB", &kB);
+        if transcript.challenge_scalar(b"c") != self.proof.c {
+            anyhow::bail!("invalid proof of knowledge in dealing from {}", self.dealer);
+        }
+
+        Ok(())
+    }
+
+    /// The commitment to the share of the participant with the given index.
+    fn share_commitment(&self, index: u32) -> decaf377::Element {
+        let x = Fr::from(index);
+        self.commitments
+            .iter()
+            .rev()
+            .fold(decaf377::Element::default(), |acc, commitment| {
+                acc * x + *commitment
+            })
+    }
+
+    /// Check a share dealt to the participant with the given index against
+    /// this dealing.
+    fn verify_share(&self, index: u32, share: &Fr) -> bool {
+        *share * decaf377::basepoint() == self.share_commitment(index)
+    }
+}
+
+impl DealtShare {
+    pub fn dealer(&self) -> u32 {
+        self.dealer
+    }
+
+    pub fn recipient(&self) -> u32 {
+        self.recipient
+    }
+}
+
+impl Complaint {
+    pub fn accuser(&self) -> u32 {
+        self.accuser
+    }
+
+    pub fn dealer(&self) -> u32 {
+        self.dealer
+    }
+}
+
+impl ComplaintResponse {
+    pub fn dealer(&self) -> u32 {
+        self.dealer
+    }
+
+    pub fn accuser(&self) -> u32 {
+        self.accuser
+    }
+}
+
+impl PublicTranscript {
+    /// The well-formed dealings, by dealer.
+    ///
+    /// A dealer that broadcast more than one dealing has equivocated, and none
+    /// of its dealings count.
+    fn valid_dealings(&self, params: &Parameters) -> BTreeMap<u32, &Dealing> {
+        let mut counts = BTreeMap::<u32, usize>::new();
+        for dealing in &self.dealings {
+            *counts.entry(dealing.dealer).or_default() += 1;
+        }
+        self.dealings
+            .iter()
+            .filter(|dealing| counts[&dealing.dealer] == 1 && dealing.verify(params).is_ok())
+            .map(|dealing| (dealing.dealer, dealing))
+            .collect()
+    }
+
+    /// The dealers whose secrets make up the shared key: those whose dealings
+    /// are valid, and who answered every complaint about them with a valid
+    /// share.
+    pub fn qualified(&self, params: &Parameters) -> BTreeSet<u32> {
+        let dealings = self.valid_dealings(params);
+        let mut qualified: BTreeSet<u32> = dealings.keys().copied().collect();
+
+        for complaint in &self.complaints {
+            let Some(dealing) = dealings.get(&complaint.dealer) else {
+                continue;
+            };
+            if !params.contains(complaint.accuser) {
+                continue;
+            }
+            let answered = self.responses.iter().any(|response| {
+                response.dealer == complaint.dealer
+                    && response.accuser == complaint.accuser
+                    && dealing.verify_share(complaint.accuser, &response.share)
+            });
+            if !answered {
+                qualified.remove(&complaint.dealer);
+            }
+        }
+
+        qualified
+    }
+
+    /// Compute the public outcome of key generation: the encryption key, and
+    /// the public key shares of the committee.
+    pub fn finalize(&self, params: &Parameters) -> anyhow::Result<(EncryptionKey, Committee)> {
+        let dealings = self.valid_dealings(params);
+        let qualified = self.qualified(params);
+        if qualified.is_empty() {
+            anyhow::bail!("no dealers qualified, so no key was generated");
+        }
+        let qualified_dealings = || qualified.iter().map(|dealer| dealings[dealer]);
+
+        let encryption_key = qualified_dealings()
+            .fold(decaf377::Element::default(), |acc, dealing| {
+                acc + dealing.commitments[0]
+            });
+        let shares = params
+            .indices()
+            .map(|index| PublicKeyShare {
+                participant_index: index,
+                pub_key_share: qualified_dealings()
+                    .fold(decaf377::Element::default(), |acc, dealing| {
+                        acc + dealing.share_commitment(index)
+                    }),
+            })
+            .collect();
+
+        Ok((
+            EncryptionKey(encryption_key),
+            Committee {
+                shares,
+                threshold: params.threshold,
+            },
+        ))
+    }
+}
+
+impl Participant {
+    /// Start key generation as the participant with the given index, choosing
+    /// a random secret to deal.
+    pub fn new<R: RngCore + CryptoRng>(
+        params: Parameters,
+        index: u32,
+        mut rng: R,
+    ) -> anyhow::Result<Self> {
+        if !params.contains(index) {
+            anyhow::bail!(
+                "participant index {index} must be between 1 and {}",
+                params.participants
+            );
+        }
+        Ok(Self {
+            params,
+            index,
+            coefficients: (0..params.threshold).map(|_| Fr::rand(&mut rng)).collect(),
+            dealings: Vec::new(),
+            shares: BTreeMap::new(),
+        })
+    }
+
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Deal this participant's secret, producing the [`Dealing`] to broadcast
+    /// and the [`DealtShare`] to send to each participant, including this one.
+    #[allow(non_snake_case)]
+    pub fn deal<R: RngCore + CryptoRng>(&self, mut rng: R) -> (Dealing, Vec<DealtShare>) {
+        let commitments: Vec<_> = self
+            .coefficients
+            .iter()
+            .map(|coefficient| *coefficient * decaf377::basepoint())
+            .collect();
+
+        let mut transcript = dealing_transcript(&self.params, self.index, &commitments);
+        let k = Fr::rand(&mut rng);
+        let kB = k * decaf377::basepoint();
+        transcript.append_blinding_commitment(b"kB", &kB);
+        let c = transcript.challenge_scalar(b"c");
+        let r = k - self.coefficients[0] * c;
+
+        let shares = self
+            .params
+            .indices()
+            .map(|recipient| DealtShare {
+                dealer: self.index,
+                recipient,
+                share: evaluate(&self.coefficients, recipient),
+            })
+            .collect();
+
+        (
+            Dealing {
+                dealer: self.index,
+                commitments,
+                proof: DealingProof { c, r },
+            },
+            shares,
+        )
+    }
+
+    /// Receive the broadcast dealings, and the shares dealt to this
+    /// participant, returning the complaints to broadcast about the dealers
+    /// whose share was missing or didn't match their dealing.
+    pub fn receive(&mut self, dealings: &[Dealing], shares: &[DealtShare]) -> Vec<Complaint> {
+        self.dealings = dealings.to_vec();
+        let transcript = PublicTranscript {
+            dealings: self.dealings.clone(),
+            ..Default::default()
+        };
+        let valid_dealings = transcript.valid_dealings(&self.params);
+
+        for share in shares {
+            if share.recipient != self.index {
+                continue;
+            }
+            if let Some(dealing) = valid_dealings.get(&share.dealer) {
+                if dealing.verify_share(self.index, &share.share) {
+                    self.shares.insert(share.dealer, share.share);
+                }
+            }
+        }
+
+        // Invalid dealings need no complaint, since everyone can see they're invalid.
+        valid_dealings
+            .keys()
+            .filter(|dealer| !self.shares.contains_key(dealer))
+            .map(|&dealer| Complaint {
+                accuser: self.index,
+                dealer,
+            })
+            .collect()
+    }
+
+    /// Answer the complaints about this participant's dealing, returning the
+    /// responses to broadcast.
+    pub fn respond(&self, complaints: &[Complaint]) -> Vec<ComplaintResponse> {
+        let accusers: BTreeSet<u32> = complaints
+            .iter()
+            .filter(|complaint| {
+                complaint.dealer == self.index && self.params.contains(complaint.accuser)
+            })
+            .map(|complaint| complaint.accuser)
+            .collect();
+        accusers
+            .into_iter()
+            .map(|accuser| ComplaintResponse {
+                dealer: self.index,
+                accuser,
+                share: evaluate(&self.coefficients, accuser),
+            })
+            .collect()
+    }
+
+    /// Finish key generation, given all broadcast complaints and responses.
+    pub fn finalize(
+        self,
+        complaints: &[Complaint],
+        responses: &[ComplaintResponse],
+    ) -> anyhow::Result<DkgOutput> {
+        let transcript = PublicTranscript {
+            dealings: self.dealings,
+            complaints: complaints.to_vec(),
+            responses: responses.to_vec(),
+        };
+        let (encryption_key, committee) = transcript.finalize(&self.params)?;
+
+        let mut key_share = Fr::zero();
+        for dealer in transcript.qualified(&self.params) {
+            // A share revealed in response to our complaint was checked when
+            // qualifying the dealer.
+            let share = self.shares.get(&dealer).copied().or_else(|| {
+                transcript
+                    .responses
+                    .iter()
+                    .find(|response| response.dealer == dealer && response.accuser == self.index)
+                    .map(|response| response.share)
+            });
+            match share {
+                Some(share) => key_share += share,
+                None => anyhow::bail!(
+                    "missing share from qualified dealer {dealer}; was our complaint broadcast?"
+                ),
+            }
+        }
+
+        let cached_pub = committee
+            .share(self.index)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("missing public key share for {}", self.index))?;
+        if key_share * decaf377::basepoint() != cached_pub.pub_key_share {
+            anyhow::bail!("private key share does not match the committee's public key share");
+        }
+
+        Ok(DkgOutput {
+            private_key_share: PrivateKeyShare {
+                participant_index: self.index,
+                key_share,
+                cached_pub,
+            },
+            encryption_key,
+            committee,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{DecryptionTable, MockDecryptionTable, Value};
+
+    /// How a simulated participant behaves when dealing.
+    #[derive(Clone, Copy)]
+    enum Behavior {
+        Honest,
+        /// Deal a bad share to one participant, and answer their complaint
+        /// only if `responds`.
+        BadShare {
+            recipient: u32,
+            responds: bool,
+        },
+        /// Broadcast a dealing with an invalid proof of knowledge.
+        BadProof,
+    }
+
+    /// Run key generation locally among participants with the given
+    /// behaviors, routing every message as the network would.
+    fn run_dkg(params: Parameters, behaviors: &[Behavior]) -> (PublicTranscript, Vec<DkgOutput>) {
+        let mut rng = rand::thread_rng();
+        let mut participants: Vec<_> = params
+            .indices()
+            .map(|index| Participant::new(params, index, &mut rng).expect("valid index"))
+            .collect();
+
+        let mut dealings = Vec::new();
+        let mut shares = Vec::new();
+        for (participant, behavior) in participants.iter().zip(behaviors) {
+            let (mut dealing, mut dealt) = participant.deal(&mut rng);
+            match behavior {
+                Behavior::Honest => {}
+                Behavior::BadShare { recipient, .. } => {
+                    for share in dealt.iter_mut().filter(|s| s.recipient == *recipient) {
+                        share.share += Fr::from(1u32);
+                    }
+                }
+                Behavior::BadProof => dealing.proof.r += Fr::from(1u32),
+            }
+            dealings.push(dealing);
+            shares.extend(dealt);
+        }
+
+        let complaints: Vec<_> = participants
+            .iter_mut()
+            .flat_map(|participant| participant.receive(&dealings, &shares))
+            .collect();
+
+        let responses: Vec<_> = participants
+            .iter()
+            .zip(behaviors)
+            .filter(|(_, behavior)| {
+                !matches!(
+                    behavior,
+                    Behavior::BadShare {
+                        responds: false,
+                        ..
+                    }
+                )
+            })
+            .flat_map(|(participant, _)| participant.respond(&complaints))
+            .collect();
+
+        let outputs = participants
+            .into_iter()
+            .map(|participant| {
+                participant
+                    .finalize(&complaints, &responses)
+                    .expect("key generation succeeds")
+            })
+            .collect();
+
+        (
+            PublicTranscript {
+                dealings,
+                complaints,
+                responses,
+            },
+            outputs,
+        )
+    }
+
+    /// Check that all participants agree on the outcome, and that any
+    /// threshold of them can decrypt, while fewer cannot.
+    async fn check_outputs(
+        params: Parameters,
+        transcript: &PublicTranscript,
+        outputs: &[DkgOutput],
+    ) {
+        let mut rng = rand::thread_rng();
+        let (encryption_key, committee) = transcript.finalize(&params).expect("outcome is public");
+        for output in outputs {
+            assert_eq!(output.encryption_key, encryption_key);
+            for (a, b) in output.committee.shares.iter().zip(&committee.shares) {
+                assert_eq!(a.pub_key_share, b.pub_key_share);
+            }
+        }
+
+        let table = MockDecryptionTable::default();
+        table.initialize(16).await.expect("can initialize table");
+
+        let value = Value::from(0x0123_4567_89ab_cdefu64);
+        let (ciphertext, _) = value
+            .transparent_encrypt(&encryption_key, &mut rng)
+            .expect("can encrypt");
+        let shares: Vec<_> = outputs
+            .iter()
+            .rev()
+            .map(|output| {
+                let share = output.private_key_share.decryption_share(
+                    &ciphertext,
+                    &mut merlin::Transcript::new(b"test"),
+                    &mut rng,
+                );
+                let pub_key_share = committee
+                    .share(output.private_key_share.participant_index)
+                    .expect("committee has share");
+                share
+                    .verify(
+                        &ciphertext,
+                        pub_key_share,
+                        &mut merlin::Transcript::new(b"test"),
+                    )
+                    .expect("decryption share is valid")
+            })
+            .collect();
+
+        let threshold = params.threshold() as usize;
+        let decrypted = ciphertext
+            .decrypt(shares[..threshold].to_vec(), &table)
+            .await
+            .expect("threshold shares decrypt");
+        assert_eq!(decrypted.0, value.0);
+        assert!(ciphertext
+            .decrypt(shares[..threshold - 1].to_vec(), &table)
+            .await
+            .is_err());
+    }
+
+    /// Round-trip a message through its JSON encoding.
+    fn round_trip<T: Serialize + serde::de::DeserializeOwned>(message: &T) -> T {
+        let json = serde_json::to_string(message).expect("can serialize");
+        serde_json::from_str(&json).expect("can deserialize")
+    }
+
+    #[test]
+    fn messages_round_trip() {
+        let mut rng = rand::thread_rng();
+        let params = Parameters::new(3, 2).expect("valid parameters");
+        let participant = Participant::new(params, 1, &mut rng).expect("valid index");
+        let (dealing, shares) = participant.deal(&mut rng);
+
+        let decoded = round_trip(&dealing);
+        assert_eq!(decoded.dealer, dealing.dealer);
+        assert_eq!(decoded.commitments, dealing.commitments);
+        assert_eq!(decoded.proof.c, dealing.proof.c);
+        assert_eq!(decoded.proof.r, dealing.proof.r);
+        decoded.verify(&params).expect("decoded dealing is valid");
+
+        for share in &shares {
+            let decoded = round_trip(share);
+            assert_eq!(decoded.dealer, share.dealer);
+            assert_eq!(decoded.recipient, share.recipient);
+            assert_eq!(decoded.share, share.share);
+        }
+
+        // Complain about our own dealing so there's a response to encode.
+        let complaint = Complaint {
+            accuser: 2,
+            dealer: 1,
+        };
+        assert_eq!(round_trip(&complaint), complaint);
+        let responses = participant.respond(&[complaint]);
+        assert_eq!(responses.len(), 1);
+        let decoded = round_trip(&responses[0]);
+        assert_eq!(decoded.dealer, 1);
+        assert_eq!(decoded.accuser, 2);
+        assert_eq!(decoded.share, responses[0].share);
+
+        let transcript = PublicTranscript {
+            dealings: vec![dealing],
+            complaints: vec![complaint],
+            responses,
+        };
+        let decoded = round_trip(&transcript);
+        assert_eq!(decoded.qualified(&params), BTreeSet::from([1]));
+        let (key, _) = transcript.finalize(&params).expect("dealer qualifies");
+        let (decoded_key, _) = decoded.finalize(&params).expect("dealer qualifies");
+        assert_eq!(decoded_key, key);
+    }
+
+    #[test]
+    fn invalid_encodings_are_rejected() {
+        let mut rng = rand::thread_rng();
+        let params = Parameters::new(2, 1).expect("valid parameters");
+        let participant = Participant::new(params, 1, &mut rng).expect("valid index");
+        let (dealing, _) = participant.deal(&mut rng);
+
+        // No valid group element encodes to all ones.
+        let mut json = serde_json::to_value(&dealing).expect("can serialize");
+        json["commitments"][0] = serde_json::json!([0xffu8; 32]);
+        assert!(serde_json::from_value::<Dealing>(json).is_err());
+
+        // Nor is all ones a canonical scalar.
+        let mut json = serde_json::to_value(&dealing).expect("can serialize");
+        json["proof"]["r"] = serde_json::json!([0xffu8; 32]);
+        assert!(serde_json::from_value::<Dealing>(json).is_err());
+
+        // Commitments must be exactly 32 bytes.
+        let mut json = serde_json::to_value(&dealing).expect("can serialize");
+        json["commitments"][0] = serde_json::json!([0u8; 31]);
+        assert!(serde_json::from_value::<Dealing>(json).is_err());
+    }
+
+    #[tokio::test]
+    async fn honest_dkg_decrypts() {
+        let params = Parameters::new(4, 3).expect("valid parameters");
+        let (transcript, outputs) = run_dkg(params, &[Behavior::Honest; 4]);
+        assert!(transcript.complaints.is_empty());
+        assert_eq!(transcript.qualified(&params).len(), 4);
+        check_outputs(params, &transcript, &outputs).await;
+    }
+
+    #[tokio::test]
+    async fn answered_complaint_keeps_dealer() {
+        let params = Parameters::new(4, 3).expect("valid parameters");
+        let behaviors = [
+            Behavior::Honest,
+            Behavior::BadShare {
+                recipient: 3,
+                responds: true,
+            },
+            Behavior::Honest,
+            Behavior::Honest,
+        ];
+        let (transcript, outputs) = run_dkg(params, &behaviors);
+        assert_eq!(
+            transcript.complaints,
+            vec![Complaint {
+                accuser: 3,
+                dealer: 2
+            }]
+        );
+        assert_eq!(transcript.qualified(&params).len(), 4);
+        check_outputs(params, &transcript, &outputs).await;
+    }
+
+    #[tokio::test]
+    async fn unanswered_complaint_disqualifies_dealer() {
+        let params = Parameters::new(4, 3).expect("valid parameters");
+        let behaviors = [
+            Behavior::Honest,
+            Behavior::BadShare {
+                recipient: 3,
+                responds: false,
+            },
+            Behavior::Honest,
+            Behavior::Honest,
+        ];
+        let (transcript, outputs) = run_dkg(params, &behaviors);
+        assert_eq!(transcript.qualified(&params), BTreeSet::from([1, 3, 4]));
+        check_outputs(params, &transcript, &outputs).await;
+    }
+
+    #[tokio::test]
+    async fn invalid_dealing_disqualifies_dealer() {
+        let params = Parameters::new(4, 2).expect("valid parameters");
+        let behaviors = [
+            Behavior::Honest,
+            Behavior::Honest,
+            Behavior::Honest,
+            Behavior::BadProof,
+        ];
+        let (transcript, outputs) = run_dkg(params, &behaviors);
+        assert!(transcript.complaints.is_empty());
+        assert_eq!(transcript.qualified(&params), BTreeSet::from([1, 2, 3]));
+        check_outputs(params, &transcript, &outputs).await;
+    }
+}
@@ -0,0 +1,42 @@
+//! Serde helpers for the scalars and group elements in key generation
+//! messages, which are encoded as 32-byte arrays.
+
+use decaf377::{Element, Encoding, Fr};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub mod fr {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(fr: &Fr, serializer: S) -> Result<S::Ok, S::Error> {
+        fr.to_bytes().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Fr, D::Error> {
+        let bytes = <[u8; 32]>::deserialize(deserializer)?;
+        Fr::from_bytes(bytes).map_err(|_| serde::de::Error::custom("invalid scalar encoding"))
+    }
+}
+
+pub mod elements {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        elements: &[Element],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(elements.iter().map(|element| element.vartime_compress().0))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Element>, D::Error> {
+        Vec::<[u8; 32]>::deserialize(deserializer)?
+            .into_iter()
+            .map(|bytes| {
+                Encoding(bytes)
+                    .vartime_decompress()
+                    .map_err(|_| serde::de::Error::custom("invalid group element encoding"))
+            })
+            .collect()
+    }
+}
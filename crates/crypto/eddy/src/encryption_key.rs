@@ -1,3 +1,4 @@
 /// The key used to encrypt ciphertexts (the public key of the encryption
 /// scheme).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct EncryptionKey(pub(crate) decaf377::Element);
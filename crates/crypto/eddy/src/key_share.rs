@@ -6,6 +6,7 @@ pub struct PrivateKeyShare {
 }
 
 /// A decryptor's public key share.
+#[derive(Debug, Clone)]
 pub struct PublicKeyShare {
     pub(crate) participant_index: u32,
     pub(crate) pub_key_share: decaf377::Element,
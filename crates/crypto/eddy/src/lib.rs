@@ -5,9 +5,10 @@
 //! - [x] Decryption
 //! - [x] Decryption Proofs
 //! - [x] Lookup table interface
+//! - [x] Persistent lookup tables
 //! - [ ] Error on insufficient shares
-//! - [ ] Distributed key generation
-//! - [ ] Serialization
+//! - [x] Distributed key generation
+//! - [ ] Serialization (key generation messages only)
 //! - [ ] Encryption Proofs
 //!
 //! [protocol-batching]: https://protocol.penumbra.zone/main/concepts/batching_flows.html
@@ -29,7 +30,9 @@ pub mod proofs;
 
 pub use ciphertext::{Ciphertext, InsufficientSharesError};
 pub use decryption_share::{DecryptionShare, Unverified, VerificationStatus, Verified};
-pub use decryption_table::{DecryptionTable, MockDecryptionTable, TableLookupError};
+pub use decryption_table::{
+    BabyStepGiantStep, DecryptionTable, FileDecryptionTable, MockDecryptionTable, TableLookupError,
+};
 pub use encryption_key::EncryptionKey;
 pub use key_share::{PrivateKeyShare, PublicKeyShare};
 pub use value::Value;
@@ -7,6 +7,8 @@ pub trait TranscriptProtocol {
     fn append_limb_ciphertext(&mut self, ciphertext: &limb::Ciphertext);
     fn append_decryption_share_point(&mut self, point: &decaf377::Element);
     fn append_blinding_commitment(&mut self, label: &'static [u8], point: &decaf377::Element);
+    fn begin_dealing(&mut self, participants: u32, threshold: u32, dealer: u32);
+    fn append_polynomial_commitment(&mut self, point: &decaf377::Element);
 
     fn challenge_scalar(&mut self, label: &'static [u8]) -> decaf377::Fr;
 }
@@ -38,6 +40,15 @@ impl TranscriptProtocol for merlin::Transcript {
         self.append_message(b"dom-sep", label);
         self.append_message(b"blinding-commitment", &point.vartime_compress().0);
     }
+    fn begin_dealing(&mut self, participants: u32, threshold: u32, dealer: u32) {
+        self.append_message(b"dom-sep", b"eddy-decaf377-dkg-dealing");
+        self.append_message(b"participants", &participants.to_le_bytes());
+        self.append_message(b"threshold", &threshold.to_le_bytes());
+        self.append_message(b"dealer", &dealer.to_le_bytes());
+    }
+    fn append_polynomial_commitment(&mut self, point: &decaf377::Element) {
+        self.append_message(b"polynomial-commitment", &point.vartime_compress().0);
+    }
 
     fn challenge_scalar(&mut self, label: &'static [u8]) -> decaf377::Fr {
         use ark_ff::fields::PrimeField;
@@ -15,6 +15,8 @@ pub mod proof {
         path::PathDecodeError,
         proof::{ProofDecodeError as DecodeError, VerifyError},
     };
+    #[doc(inline)]
+    pub use crate::proof::BatchProofDecodeError as BatchDecodeError;
 }
 
 pub mod block {
@@ -54,7 +54,7 @@ impl<Tree: Height> Proof<Tree> {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
 #[error("invalid inclusion proof for root hash {root:?}")]
 pub struct VerifyError {
-    root: Hash,
+    pub(crate) root: Hash,
 }
 
 impl VerifyError {
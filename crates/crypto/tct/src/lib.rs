@@ -68,7 +68,7 @@ pub use {
     commitment::StateCommitment,
     internal::hash::Forgotten,
     internal::hash::DOMAIN_SEPARATOR,
-    proof::Proof,
+    proof::{BatchProof, Proof},
     tree::{Position, Root, Tree},
     witness::Witness,
 };
@@ -120,7 +120,7 @@ mod prelude {
             StoredPosition, Update, Write,
         },
         structure::{self, HashOrNode, HashedNode, Kind, Node, Place},
-        Position, Proof, Root, StateCommitment, Tree,
+        BatchProof, Position, Proof, Root, StateCommitment, Tree,
     };
 
     // We use the hash map from `im`, but with the fast "hash prehashed data" hasher from `hash_hasher`
@@ -3,6 +3,9 @@ use poseidon377::Fq;
 
 use crate::prelude::*;
 
+mod batch;
+pub use batch::{BatchProof, BatchProofDecodeError};
+
 /// A proof of the inclusion of some [`Commitment`] in a [`Tree`] with a particular [`Root`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Proof(
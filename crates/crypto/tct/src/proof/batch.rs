@@ -0,0 +1,329 @@
+use std::collections::BTreeMap;
+
+use decaf377::{FieldExt, Fq};
+use penumbra_proto::penumbra::crypto::tct::v1alpha1 as pb;
+
+use crate::prelude::*;
+
+/// The height of a [`Tree`], from its root down to its commitments.
+const HEIGHT: u8 = 24;
+
+/// A proof of the inclusion of many [`Commitment`]s in a [`Tree`] with a particular [`Root`].
+///
+/// Each individual [`Proof`] carries a full authentication path of 24 × 3 sibling hashes, but
+/// commitments near each other in the tree share most of their paths, and the path of one
+/// commitment often passes through the siblings of another.  A [`BatchProof`] records each hash
+/// needed to compute the root only once, and omits those which can be computed from the witnessed
+/// commitments themselves.  It can be verified as a whole, or split back into individual
+/// [`Proof`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchProof {
+    /// The root of the tree in which the commitments are included.
+    root: Hash,
+    /// The witnessed commitments, by position.
+    commitments: BTreeMap<Position, StateCommitment>,
+    /// The hashes of the nodes needed to compute the root, by height and index at that height,
+    /// except for those on the path from the root to a witnessed commitment.
+    nodes: BTreeMap<(u8, u64), Hash>,
+}
+
+impl BatchProof {
+    /// Combine [`Proof`]s of inclusion in a [`Tree`] with the given [`Root`] into a
+    /// [`BatchProof`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VerifyError`] if any of the proofs is invalid for that [`Root`].
+    pub fn new(root: Root, proofs: impl IntoIterator<Item = Proof>) -> Result<Self, VerifyError> {
+        let mut commitments = BTreeMap::new();
+        let mut nodes = BTreeMap::new();
+
+        for proof in proofs {
+            proof.verify(root)?;
+            let position = u64::from(proof.position());
+            // The authentication path is ordered from the root to the leaf, so the first set of
+            // siblings is just below the root.
+            for (siblings, height) in proof.auth_path().into_iter().zip((0..HEIGHT).rev()) {
+                let index = index_at(height, position);
+                let sibling_indices = sibling_group(index).filter(|&sibling| sibling != index);
+                for (sibling, sibling_index) in siblings.iter().zip(sibling_indices) {
+                    nodes.insert((height, sibling_index), *sibling);
+                }
+            }
+            commitments.insert(proof.position(), proof.commitment());
+        }
+
+        // The nodes on the path to each witnessed commitment are computed, rather than stored:
+        for &position in commitments.keys() {
+            for height in 0..HEIGHT {
+                nodes.remove(&(height, index_at(height, position.into())));
+            }
+        }
+
+        Ok(Self {
+            root: root.0,
+            commitments,
+            nodes,
+        })
+    }
+
+    /// Verify a [`BatchProof`] of inclusion against the [`Root`] of a [`Tree`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VerifyError`] if the proof is invalid for that [`Root`].
+    pub fn verify(&self, root: Root) -> Result<(), VerifyError> {
+        if self.root == root.0 {
+            Ok(())
+        } else {
+            Err(VerifyError { root: root.0 })
+        }
+    }
+
+    /// Get the root of the tree from which the proof was generated.
+    pub fn root(&self) -> Root {
+        Root(self.root)
+    }
+
+    /// Get the number of commitments whose inclusion is witnessed by the proof.
+    pub fn len(&self) -> usize {
+        self.commitments.len()
+    }
+
+    /// Check whether the proof witnesses no commitments at all.
+    pub fn is_empty(&self) -> bool {
+        self.commitments.is_empty()
+    }
+
+    /// Get an iterator over the witnessed commitments, **ordered by position**.
+    pub fn commitments(&self) -> impl Iterator<Item = (Position, StateCommitment)> + '_ {
+        self.commitments
+            .iter()
+            .map(|(&position, &commitment)| (position, commitment))
+    }
+
+    /// Split the proof into an individual [`Proof`] for each witnessed commitment, **ordered by
+    /// position**.
+    pub fn proofs(&self) -> impl Iterator<Item = Proof> + '_ {
+        let nodes = compute_nodes(&self.commitments, self.nodes.clone())
+            .expect("batch proof has every node needed to compute its root");
+
+        self.commitments
+            .iter()
+            .map(move |(&position, &commitment)| {
+                let index = u64::from(position);
+                let mut auth_path = [[Hash::zero(); 3]; HEIGHT as usize];
+                for (siblings, height) in auth_path.iter_mut().zip((0..HEIGHT).rev()) {
+                    let own_index = index_at(height, index);
+                    let sibling_indices = sibling_group(own_index).filter(|&i| i != own_index);
+                    for (sibling, sibling_index) in siblings.iter_mut().zip(sibling_indices) {
+                        *sibling = nodes[&(height, sibling_index)];
+                    }
+                }
+                Proof::new(commitment, position, auth_path)
+            })
+    }
+}
+
+/// The index of the node at `height` on the path to the commitment at `position`.
+fn index_at(height: u8, position: u64) -> u64 {
+    position >> (2 * height)
+}
+
+/// The indices of the node at `index` and its siblings, in order.
+fn sibling_group(index: u64) -> impl Iterator<Item = u64> {
+    let first = index & !0b11;
+    first..first + 4
+}
+
+/// Compute the hashes of the nodes on the paths to the given commitments, adding them to the
+/// given hashes of all the other nodes needed to compute them.
+///
+/// Returns `None` if a needed node is missing.
+fn compute_nodes(
+    commitments: &BTreeMap<Position, StateCommitment>,
+    mut nodes: BTreeMap<(u8, u64), Hash>,
+) -> Option<BTreeMap<(u8, u64), Hash>> {
+    for (&position, &commitment) in commitments {
+        nodes.insert((0, position.into()), Hash::of(commitment));
+    }
+
+    // Working from the leaves up, each node on a path can be computed from its children, since
+    // those are either on a path themselves, and so computed already, or stored.
+    for height in 1..=HEIGHT {
+        for &position in commitments.keys() {
+            let index = index_at(height, position.into());
+            if nodes.contains_key(&(height, index)) {
+                continue;
+            }
+            let child = |i| nodes.get(&(height - 1, (index << 2) + i)).copied();
+            let hash = Hash::node(height, child(0)?, child(1)?, child(2)?, child(3)?);
+            nodes.insert((height, index), hash);
+        }
+    }
+
+    Some(nodes)
+}
+
+/// When deserializing a batch proof, it was malformed or inconsistent with its root.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Error)]
+#[error("could not decode batch proof")]
+pub struct BatchProofDecodeError;
+
+impl From<BatchProof> for pb::BatchStateCommitmentProof {
+    fn from(proof: BatchProof) -> Self {
+        use pb::batch_state_commitment_proof::{Node, Witnessed};
+
+        Self {
+            root: Some(Root(proof.root).into()),
+            witnessed: proof
+                .commitments
+                .into_iter()
+                .map(|(position, commitment)| Witnessed {
+                    note_commitment: Some(commitment.into()),
+                    position: position.into(),
+                })
+                .collect(),
+            nodes: proof
+                .nodes
+                .into_iter()
+                .map(|((height, index), hash)| Node {
+                    height: height.into(),
+                    index,
+                    hash: hash.to_bytes().to_vec(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::BatchStateCommitmentProof> for BatchProof {
+    type Error = BatchProofDecodeError;
+
+    fn try_from(proof: pb::BatchStateCommitmentProof) -> Result<Self, Self::Error> {
+        let root = Root::try_from(proof.root.ok_or(BatchProofDecodeError)?)
+            .map_err(|_| BatchProofDecodeError)?
+            .0;
+
+        let mut commitments = BTreeMap::new();
+        for witnessed in proof.witnessed {
+            let position = Position::from(witnessed.position);
+            if u64::from(position) != witnessed.position {
+                return Err(BatchProofDecodeError);
+            }
+            let commitment = witnessed
+                .note_commitment
+                .ok_or(BatchProofDecodeError)?
+                .try_into()
+                .map_err(|_| BatchProofDecodeError)?;
+            commitments.insert(position, commitment);
+        }
+
+        let mut nodes = BTreeMap::new();
+        for node in proof.nodes {
+            let height = u8::try_from(node.height).map_err(|_| BatchProofDecodeError)?;
+            if height >= HEIGHT {
+                return Err(BatchProofDecodeError);
+            }
+            let hash = Fq::from_bytes(node.hash.try_into().map_err(|_| BatchProofDecodeError)?)
+                .map_err(|_| BatchProofDecodeError)?;
+            nodes.insert((height, node.index), Hash::new(hash));
+        }
+
+        // Nodes on the path to a witnessed commitment must be computed, never taken on trust:
+        if commitments.keys().any(|&position| {
+            (0..HEIGHT)
+                .any(|height| nodes.contains_key(&(height, index_at(height, position.into()))))
+        }) {
+            return Err(BatchProofDecodeError);
+        }
+
+        // If any commitments are witnessed, the root they and the nodes compute must be the root
+        // the proof claims.
+        if !commitments.is_empty() {
+            let computed =
+                compute_nodes(&commitments, nodes.clone()).ok_or(BatchProofDecodeError)?;
+            if computed.get(&(HEIGHT, 0)) != Some(&root) {
+                return Err(BatchProofDecodeError);
+            }
+        }
+
+        Ok(Self {
+            root,
+            commitments,
+            nodes,
+        })
+    }
+}
+
+impl penumbra_proto::DomainType for BatchProof {
+    type Proto = pb::BatchStateCommitmentProof;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tree_with_commitments(count: u64) -> (Tree, Vec<StateCommitment>) {
+        let mut tree = Tree::new();
+        let mut commitments = Vec::new();
+        for i in 0..count {
+            let commitment = StateCommitment(Fq::from(i));
+            tree.insert(crate::Witness::Keep, commitment).unwrap();
+            commitments.push(commitment);
+            if i % 5 == 4 {
+                tree.end_block().unwrap();
+            }
+            if i % 17 == 16 {
+                tree.end_epoch().unwrap();
+            }
+        }
+        (tree, commitments)
+    }
+
+    #[test]
+    fn batch_proof_splits_into_individual_proofs() {
+        let (tree, commitments) = tree_with_commitments(50);
+        let witnessed = [3, 4, 9, 20, 21, 49].map(|i| commitments[i]);
+        let batch = tree.witness_batch(witnessed).unwrap();
+
+        batch.verify(tree.root()).unwrap();
+        assert_eq!(batch.len(), witnessed.len());
+        let proofs = batch.proofs().collect::<Vec<_>>();
+        for (proof, commitment) in proofs.iter().zip(witnessed) {
+            assert_eq!(Some(proof), tree.witness(commitment).as_ref());
+        }
+    }
+
+    #[test]
+    fn batch_proof_shares_nodes() {
+        let (tree, commitments) = tree_with_commitments(50);
+        let batch = tree
+            .witness_batch(commitments[10..20].iter().copied())
+            .unwrap();
+        // Ten individual proofs would have 720 sibling hashes between them.
+        assert!(batch.nodes.len() * 3 < 720);
+    }
+
+    #[test]
+    fn batch_proof_round_trips() {
+        let (tree, commitments) = tree_with_commitments(50);
+        let batch = tree
+            .witness_batch([commitments[0], commitments[33]])
+            .unwrap();
+        let decoded =
+            BatchProof::try_from(pb::BatchStateCommitmentProof::from(batch.clone())).unwrap();
+        assert_eq!(batch, decoded);
+    }
+
+    #[test]
+    fn batch_proof_rejects_wrong_root() {
+        let (tree, commitments) = tree_with_commitments(50);
+        let batch = tree
+            .witness_batch([commitments[0], commitments[33]])
+            .unwrap();
+        let mut proto = pb::BatchStateCommitmentProof::from(batch);
+        proto.nodes[0].hash = Hash::new(Fq::from(12345u64)).to_bytes().to_vec();
+        assert!(BatchProof::try_from(proto).is_err());
+    }
+}
@@ -316,30 +316,7 @@ pub fn to_writer<W: Write>(writer: &mut W, tree: &crate::Tree) -> Result<(), W::
     let last_forgotten = writer.forgotten()?;
 
     for update in updates(last_position, last_forgotten, tree) {
-        match update {
-            Update::SetPosition(position) => writer.set_position(position)?,
-            Update::SetForgotten(forgotten) => writer.set_forgotten(forgotten)?,
-            Update::StoreHash(StoreHash {
-                position,
-                height,
-                hash,
-                essential,
-            }) => {
-                writer.add_hash(position, height, hash, essential)?;
-            }
-            Update::StoreCommitment(StoreCommitment {
-                position,
-                commitment,
-            }) => {
-                writer.add_commitment(position, commitment)?;
-            }
-            Update::DeleteRange(DeleteRange {
-                below_height,
-                positions,
-            }) => {
-                writer.delete_range(below_height, positions)?;
-            }
-        }
+        update.apply(writer)?;
     }
 
     Ok(())
@@ -292,6 +292,29 @@ impl Tree {
         Some(proof)
     }
 
+    /// Get a [`BatchProof`] of inclusion for many commitments in the tree at once.
+    ///
+    /// This is more compact than getting a [`Proof`] for each commitment individually, since the
+    /// parts of the authentication paths they share are only included once.
+    ///
+    /// If any of the commitments is not witnessed in this tree, return `None`.
+    #[instrument(level = "trace", skip(self, commitments))]
+    pub fn witness_batch(
+        &self,
+        commitments: impl IntoIterator<Item = StateCommitment>,
+    ) -> Option<BatchProof> {
+        let proofs = commitments
+            .into_iter()
+            .map(|commitment| self.witness(commitment))
+            .collect::<Option<Vec<_>>>()?;
+
+        let batch = BatchProof::new(self.root(), proofs)
+            .expect("proofs from the tree must verify against its root");
+
+        trace!(witnessed = batch.len());
+        Some(batch)
+    }
+
     /// Forget about the witness for the given [`Commitment`].
     ///
     /// Returns `true` if the commitment was previously witnessed (and now is forgotten), and `false` if
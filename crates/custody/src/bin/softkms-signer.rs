@@ -0,0 +1,34 @@
+//! A reference external signer, which serves a [`SoftKms`] over the external
+//! signer protocol, for testing external custody integrations.
+//!
+//! Usage: `softkms-signer <CONFIG> (--socket <PATH> | --stdio)`
+//!
+//! where `<CONFIG>` is a TOML file holding a soft KMS
+//! [`Config`](penumbra_custody::soft_kms::Config).
+
+use anyhow::Context;
+use penumbra_custody::{external, soft_kms::SoftKms};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let usage = "usage: softkms-signer <CONFIG> (--socket <PATH> | --stdio)";
+    let (config_path, listen) = match args.as_slice() {
+        [config, listen @ ..] => (config, listen),
+        _ => anyhow::bail!(usage),
+    };
+
+    let config = std::fs::read_to_string(config_path)
+        .with_context(|| format!("could not read soft KMS config {config_path}"))?;
+    let soft_kms = SoftKms::new(toml::from_str(&config)?);
+
+    match listen {
+        #[cfg(unix)]
+        [flag, path] if flag == "--socket" => {
+            eprintln!("softkms-signer listening on {path}");
+            external::serve_socket(soft_kms, path).await
+        }
+        [flag] if flag == "--stdio" => external::serve_stdio(soft_kms).await,
+        _ => anyhow::bail!(usage),
+    }
+}
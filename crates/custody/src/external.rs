@@ -0,0 +1,223 @@
+//! A custody backend that delegates to an external signer, such as a hardware
+//! wallet or an HSM integration, running out of process.
+//!
+//! The signer speaks the ordinary `CustodyProtocolService` (`authorize`,
+//! `export_full_viewing_key`, and `confirm_address`) over gRPC, either on a Unix
+//! socket or over the stdin and stdout of a process spawned for the purpose, so
+//! that any signer implementing that service can be plugged in without changes
+//! to the client.  [`serve_socket`] and [`serve_stdio`] expose a custody service
+//! in either way when the `server` feature is enabled, and the `softkms-signer`
+//! binary uses them to provide a reference signer built on the
+//! [`SoftKms`](crate::soft_kms::SoftKms).
+//!
+//! Unix sockets are only supported on Unix platforms; elsewhere, only
+//! [`Signer::Command`] signers can be used.
+
+use std::{
+    io,
+    pin::Pin,
+    process::Stdio,
+    task::{Context, Poll},
+};
+
+use tokio::{
+    io::{AsyncRead, AsyncWrite, ReadBuf},
+    process::{ChildStdin, ChildStdout, Command},
+};
+use tonic::transport::{Channel, Endpoint, Uri};
+
+mod config;
+
+pub use config::{Config, Signer};
+
+/// The URI used for connections to the signer, which is ignored, since the
+/// connection is made over a socket or a pipe rather than over TCP.
+const SIGNER_URI: &str = "http://external-signer";
+
+/// Connect to the external signer described by `config`.
+///
+/// The returned [`Channel`] can be used to build a
+/// `CustodyProtocolServiceClient` that forwards every request to the signer.
+/// The connection is made lazily, on the first request, so that the signer
+/// need not be available (or, for [`Signer::Command`], spawned) unless it is
+/// actually used, and is remade if it is lost.
+pub fn connect(config: &Config) -> Channel {
+    let endpoint = Endpoint::from_static(SIGNER_URI);
+    match config.signer.clone() {
+        #[cfg(unix)]
+        Signer::Socket(path) => {
+            endpoint.connect_with_connector_lazy(tower::service_fn(move |_: Uri| {
+                tokio::net::UnixStream::connect(path.clone())
+            }))
+        }
+        #[cfg(not(unix))]
+        Signer::Socket(_) => endpoint.connect_with_connector_lazy(tower::service_fn(|_: Uri| {
+            futures::future::ready(Err::<Duplex<ChildStdout, ChildStdin>, _>(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Unix socket signers are only supported on Unix platforms",
+            )))
+        })),
+        Signer::Command { program, args } => {
+            endpoint.connect_with_connector_lazy(tower::service_fn(move |_: Uri| {
+                let (program, args) = (program.clone(), args.clone());
+                async move { spawn(&program, &args) }
+            }))
+        }
+    }
+}
+
+/// Spawn a signer process, returning a connection over its stdio.
+///
+/// The signer's stderr is inherited, so that it can log or prompt the user.
+fn spawn(program: &str, args: &[String]) -> io::Result<Duplex<ChildStdout, ChildStdin>> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()?;
+    let stdout = child.stdout.take().expect("stdout is piped");
+    let stdin = child.stdin.take().expect("stdin is piped");
+    // The signer exits once we close its stdin, and the runtime reaps it then.
+    Ok(Duplex {
+        read: stdout,
+        write: stdin,
+    })
+}
+
+#[cfg(feature = "server")]
+pub use server::*;
+
+#[cfg(feature = "server")]
+mod server {
+    #[cfg(unix)]
+    use std::path::Path;
+
+    #[cfg(unix)]
+    use anyhow::Context as _;
+    use penumbra_proto::custody::v1alpha1::custody_protocol_service_server::{
+        CustodyProtocolService, CustodyProtocolServiceServer,
+    };
+
+    use super::Duplex;
+
+    /// Serve `service` to external signer clients on a Unix socket at `path`.
+    ///
+    /// Any stale socket at `path` is removed first, but anything else already
+    /// at `path` is left alone and an error returned. The new socket is only
+    /// accessible by the current user, since anyone who can connect to it can
+    /// request signatures.
+    #[cfg(unix)]
+    pub async fn serve_socket<S: CustodyProtocolService>(
+        service: S,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<()> {
+        use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+
+        let path = path.as_ref();
+        let parent = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        match std::fs::symlink_metadata(path) {
+            Ok(metadata) if metadata.file_type().is_socket() => {
+                std::fs::remove_file(path)
+                    .with_context(|| format!("could not remove stale socket {}", path.display()))?;
+            }
+            Ok(_) => anyhow::bail!("{} already exists and is not a socket", path.display()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                return Err(e).with_context(|| format!("could not inspect {}", path.display()))
+            }
+        }
+
+        // Bind the socket in a directory only we can enter, and only move it into place once
+        // its permissions are restricted, so that nobody can connect to it in between.
+        let staging = parent.join(format!(".signer-{}.tmp", std::process::id()));
+        std::fs::DirBuilder::new()
+            .mode(0o700)
+            .create(&staging)
+            .with_context(|| format!("could not create directory {}", staging.display()))?;
+        let staged = staging.join("signer.sock");
+        let listener = (|| {
+            let listener = tokio::net::UnixListener::bind(&staged)
+                .with_context(|| format!("could not bind socket {}", path.display()))?;
+            std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+            std::fs::rename(&staged, path)
+                .with_context(|| format!("could not move socket to {}", path.display()))?;
+            anyhow::Ok(listener)
+        })();
+        let _ = std::fs::remove_file(&staged);
+        std::fs::remove_dir(&staging)
+            .with_context(|| format!("could not remove directory {}", staging.display()))?;
+        let listener = listener?;
+
+        let incoming = futures::stream::unfold(listener, |listener| async move {
+            let stream = listener.accept().await.map(|(stream, _)| stream);
+            Some((stream, listener))
+        });
+
+        tonic::transport::Server::builder()
+            .add_service(CustodyProtocolServiceServer::new(service))
+            .serve_with_incoming(incoming)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Serve `service` to a single external signer client over stdin and stdout,
+    /// returning once the client closes the connection.
+    ///
+    /// Nothing else may be written to stdout while serving, so signers should log to
+    /// stderr instead.
+    pub async fn serve_stdio<S: CustodyProtocolService>(service: S) -> anyhow::Result<()> {
+        let stdio = Duplex {
+            read: tokio::io::stdin(),
+            write: tokio::io::stdout(),
+        };
+
+        // A tonic `Server` would return as soon as its incoming stream of connections ended, rather
+        // than when the only connection closes, so serve the connection directly.
+        hyper::server::conn::Http::new()
+            .http2_only(true)
+            .serve_connection(stdio, CustodyProtocolServiceServer::new(service))
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// A bidirectional stream made of a pair of unidirectional ones, such as the
+/// stdout and stdin of a process.
+struct Duplex<R, W> {
+    read: R,
+    write: W,
+}
+
+impl<R: AsyncRead + Unpin, W: Unpin> AsyncRead for Duplex<R, W> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.read).poll_read(cx, buf)
+    }
+}
+
+impl<R: Unpin, W: AsyncWrite + Unpin> AsyncWrite for Duplex<R, W> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.write).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.write).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.write).poll_shutdown(cx)
+    }
+}
@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration data for an external signer.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    /// How to reach the signer.
+    pub signer: Signer,
+}
+
+/// How to reach an external signer.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Signer {
+    /// A signer daemon listening on a Unix socket at this path.
+    Socket(PathBuf),
+    /// A signer process to spawn, which speaks the custody protocol over its
+    /// stdin and stdout.
+    ///
+    /// A new process is spawned for each connection.
+    Command {
+        program: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        args: Vec<String>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toml_config_round_trip() {
+        for signer in [
+            Signer::Socket("/run/penumbra/signer.sock".into()),
+            Signer::Command {
+                program: "hw-signer".to_string(),
+                args: vec!["--device".to_string(), "0".to_string()],
+            },
+        ] {
+            let config = Config { signer };
+            let toml_config = toml::to_string_pretty(&config).unwrap();
+            println!("{}", toml_config);
+            let config2: Config = toml::from_str(&toml_config).unwrap();
+            assert_eq!(config, config2);
+        }
+    }
+}
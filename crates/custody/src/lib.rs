@@ -2,7 +2,8 @@
 //!
 //! This crate currently focuses on the [`soft_kms`] implementation, a basic
 //! software key management system that can perform basic policy-based
-//! authorization or blind signing.
+//! authorization or blind signing.  The [`external`] backend delegates to
+//! out-of-process signers, such as hardware wallets, instead.
 
 #![deny(clippy::unwrap_used)]
 #[macro_use]
@@ -12,6 +13,7 @@ mod client;
 mod pre_auth;
 mod request;
 
+pub mod external;
 pub mod null_kms;
 pub mod policy;
 pub mod soft_kms;
@@ -3,11 +3,17 @@
 use std::collections::HashSet;
 
 use penumbra_keys::Address;
+use penumbra_stake::IdentityKey;
 use penumbra_transaction::plan::ActionPlan;
 use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
 
 use crate::{AuthorizeRequest, PreAuthorization};
 
+pub mod spend_limit;
+
+pub use spend_limit::{SpendLedger, SpendLimit};
+
 /// A trait for checking whether a transaction plan is allowed by a policy.
 pub trait Policy {
     /// Checks whether the proposed transaction plan is allowed by this policy.
@@ -19,6 +25,10 @@ pub trait Policy {
 /// These policies are intended to be simple enough that they can be written by
 /// hand in a config file.  More complex policy logic than than should be
 /// implemented by a custom implementation of the [`Policy`] trait.
+///
+/// These policies only look at the request being authorized; limits on the
+/// amounts spent over time are configured separately, as [`SpendLimit`]s.
+#[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(tag = "type")]
 pub enum AuthPolicy {
@@ -36,6 +46,77 @@ pub enum AuthPolicy {
     OnlyIbcRelay,
     /// Require specific pre-authorizations for submitted [`TransactionPlan`](penumbra_transaction::plan::TransactionPlan)s.
     PreAuthorization(PreAuthorizationPolicy),
+    /// Forbid transactions containing any of the denied kinds of action.
+    ActionDenyList { denied_actions: Vec<ActionKind> },
+    /// Only allow ICS-20 withdrawals over one of the allowed IBC channels,
+    /// such as `channel-0`.
+    IbcChannelAllowList { allowed_channels: Vec<String> },
+    /// Only allow delegations, and redelegations, to one of the allowed
+    /// validators.
+    ValidatorAllowList {
+        #[serde_as(as = "Vec<DisplayFromStr>")]
+        allowed_validators: Vec<IdentityKey>,
+    },
+}
+
+/// The kind of an [`ActionPlan`], used to name actions in policies.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ActionKind {
+    Spend,
+    Output,
+    Delegate,
+    Undelegate,
+    UndelegateClaim,
+    Redelegate,
+    RedelegateClaim,
+    ValidatorDefinition,
+    Swap,
+    SwapClaim,
+    IbcAction,
+    ProposalSubmit,
+    ProposalWithdraw,
+    DelegatorVote,
+    ValidatorVote,
+    ProposalDepositClaim,
+    PositionOpen,
+    PositionClose,
+    PositionWithdraw,
+    PositionRewardClaim,
+    CommunityPoolSpend,
+    CommunityPoolOutput,
+    CommunityPoolDeposit,
+    Withdrawal,
+}
+
+impl From<&ActionPlan> for ActionKind {
+    fn from(action: &ActionPlan) -> Self {
+        match action {
+            ActionPlan::Spend(_) => ActionKind::Spend,
+            ActionPlan::Output(_) => ActionKind::Output,
+            ActionPlan::Delegate(_) => ActionKind::Delegate,
+            ActionPlan::Undelegate(_) => ActionKind::Undelegate,
+            ActionPlan::UndelegateClaim(_) => ActionKind::UndelegateClaim,
+            ActionPlan::Redelegate(_) => ActionKind::Redelegate,
+            ActionPlan::RedelegateClaim(_) => ActionKind::RedelegateClaim,
+            ActionPlan::ValidatorDefinition(_) => ActionKind::ValidatorDefinition,
+            ActionPlan::Swap(_) => ActionKind::Swap,
+            ActionPlan::SwapClaim(_) => ActionKind::SwapClaim,
+            ActionPlan::IbcAction(_) => ActionKind::IbcAction,
+            ActionPlan::ProposalSubmit(_) => ActionKind::ProposalSubmit,
+            ActionPlan::ProposalWithdraw(_) => ActionKind::ProposalWithdraw,
+            ActionPlan::DelegatorVote(_) => ActionKind::DelegatorVote,
+            ActionPlan::ValidatorVote(_) => ActionKind::ValidatorVote,
+            ActionPlan::ProposalDepositClaim(_) => ActionKind::ProposalDepositClaim,
+            ActionPlan::PositionOpen(_) => ActionKind::PositionOpen,
+            ActionPlan::PositionClose(_) => ActionKind::PositionClose,
+            ActionPlan::PositionWithdraw(_) => ActionKind::PositionWithdraw,
+            ActionPlan::PositionRewardClaim(_) => ActionKind::PositionRewardClaim,
+            ActionPlan::CommunityPoolSpend(_) => ActionKind::CommunityPoolSpend,
+            ActionPlan::CommunityPoolOutput(_) => ActionKind::CommunityPoolOutput,
+            ActionPlan::CommunityPoolDeposit(_) => ActionKind::CommunityPoolDeposit,
+            ActionPlan::Withdrawal(_) => ActionKind::Withdrawal,
+        }
+    }
 }
 
 /// A set of pre-authorization policies.
@@ -158,6 +239,46 @@ impl Policy for AuthPolicy {
                 Ok(())
             }
             AuthPolicy::PreAuthorization(policy) => policy.check(request),
+            AuthPolicy::ActionDenyList { denied_actions } => {
+                for action in &plan.actions {
+                    if denied_actions.contains(&ActionKind::from(action)) {
+                        anyhow::bail!("action {:?} denied by ActionDenyList policy", action);
+                    }
+                }
+                Ok(())
+            }
+            AuthPolicy::IbcChannelAllowList { allowed_channels } => {
+                for withdrawal in plan.ics20_withdrawals() {
+                    let channel = withdrawal.source_channel.to_string();
+                    if !allowed_channels.contains(&channel) {
+                        anyhow::bail!(
+                            "withdrawal {:?} uses channel {} not in allow list",
+                            withdrawal,
+                            channel
+                        );
+                    }
+                }
+                Ok(())
+            }
+            AuthPolicy::ValidatorAllowList { allowed_validators } => {
+                for delegation in plan.delegations() {
+                    if !allowed_validators.contains(&delegation.validator_identity) {
+                        anyhow::bail!(
+                            "delegation {:?} is to a validator not in allow list",
+                            delegation
+                        );
+                    }
+                }
+                for redelegation in plan.redelegations() {
+                    if !allowed_validators.contains(&redelegation.to_validator_identity) {
+                        anyhow::bail!(
+                            "redelegation {:?} is to a validator not in allow list",
+                            redelegation
+                        );
+                    }
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -203,3 +324,158 @@ impl Policy for PreAuthorizationPolicy {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_types::core::{channel::ChannelId, client::Height};
+    use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_keys::{
+        keys::{Bip44Path, SeedPhrase, SpendKey},
+        test_keys,
+    };
+    use penumbra_shielded_pool::{Ics20Withdrawal, Note, OutputPlan, SpendPlan};
+    use penumbra_stake::{Delegate, Redelegate};
+    use penumbra_transaction::plan::TransactionPlan;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn check(policy: &AuthPolicy, actions: Vec<ActionPlan>) -> anyhow::Result<()> {
+        policy.check(&AuthorizeRequest {
+            plan: TransactionPlan {
+                actions,
+                ..Default::default()
+            },
+            pre_authorizations: Vec::new(),
+        })
+    }
+
+    fn value(amount: u64) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    fn spend() -> ActionPlan {
+        let (address, _) = test_keys::FULL_VIEWING_KEY.payment_address(Default::default());
+        let note = Note::generate(&mut OsRng, &address, value(10));
+        SpendPlan::new(&mut OsRng, note, 0u64.into()).into()
+    }
+
+    fn validator() -> IdentityKey {
+        let sk = SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(OsRng), &Bip44Path::new(0));
+        IdentityKey(*sk.full_viewing_key().spend_verification_key())
+    }
+
+    fn delegate(validator_identity: IdentityKey) -> ActionPlan {
+        ActionPlan::Delegate(Delegate {
+            validator_identity,
+            epoch_index: 1,
+            unbonded_amount: 10u64.into(),
+            delegation_amount: 10u64.into(),
+        })
+    }
+
+    fn redelegate(from: IdentityKey, to: IdentityKey) -> ActionPlan {
+        ActionPlan::Redelegate(Redelegate {
+            from_validator_identity: from,
+            to_validator_identity: to,
+            epoch_index: 1,
+            from_delegation_amount: 10u64.into(),
+            unbonded_amount: 10u64.into(),
+            to_delegation_amount: 10u64.into(),
+        })
+    }
+
+    fn withdrawal(channel: u64) -> ActionPlan {
+        ActionPlan::Withdrawal(Ics20Withdrawal {
+            amount: 10u64.into(),
+            denom: asset::Cache::with_known_assets()
+                .get_unit("upenumbra")
+                .unwrap()
+                .base(),
+            destination_chain_address: "cosmos1destination".to_string(),
+            return_address: test_keys::FULL_VIEWING_KEY
+                .ephemeral_address(OsRng, Default::default())
+                .0,
+            timeout_height: Height::new(0, 1000).unwrap(),
+            timeout_time: 0,
+            source_channel: ChannelId::new(channel),
+        })
+    }
+
+    #[test]
+    fn action_deny_list() {
+        let policy = AuthPolicy::ActionDenyList {
+            denied_actions: vec![ActionKind::Delegate, ActionKind::Withdrawal],
+        };
+        let (address, _) = test_keys::FULL_VIEWING_KEY.payment_address(Default::default());
+
+        check(&policy, vec![]).unwrap();
+        check(
+            &policy,
+            vec![
+                spend(),
+                OutputPlan::new(&mut OsRng, value(10), address).into(),
+            ],
+        )
+        .unwrap();
+        assert!(check(&policy, vec![spend(), delegate(validator())]).is_err());
+        assert!(check(&policy, vec![spend(), withdrawal(0)]).is_err());
+    }
+
+    #[test]
+    fn ibc_channel_allow_list() {
+        let policy = AuthPolicy::IbcChannelAllowList {
+            allowed_channels: vec!["channel-0".to_string(), "channel-3".to_string()],
+        };
+
+        check(&policy, vec![spend()]).unwrap();
+        check(&policy, vec![spend(), withdrawal(0), withdrawal(3)]).unwrap();
+        assert!(check(&policy, vec![spend(), withdrawal(1)]).is_err());
+        // A single withdrawal over another channel is enough to deny the transaction.
+        assert!(check(&policy, vec![spend(), withdrawal(0), withdrawal(2)]).is_err());
+    }
+
+    #[test]
+    fn validator_allow_list() {
+        let (allowed, other) = (validator(), validator());
+        let policy = AuthPolicy::ValidatorAllowList {
+            allowed_validators: vec![allowed],
+        };
+
+        check(&policy, vec![spend(), delegate(allowed)]).unwrap();
+        assert!(check(&policy, vec![spend(), delegate(other)]).is_err());
+
+        // Only the destination of a redelegation must be allowed.
+        check(&policy, vec![redelegate(other, allowed)]).unwrap();
+        assert!(check(&policy, vec![redelegate(allowed, other)]).is_err());
+    }
+
+    #[test]
+    fn toml_policies_round_trip() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Policies {
+            auth_policy: Vec<AuthPolicy>,
+        }
+
+        for policy in [
+            AuthPolicy::ActionDenyList {
+                denied_actions: vec![ActionKind::Swap, ActionKind::CommunityPoolDeposit],
+            },
+            AuthPolicy::IbcChannelAllowList {
+                allowed_channels: vec!["channel-0".to_string()],
+            },
+            AuthPolicy::ValidatorAllowList {
+                allowed_validators: vec![validator()],
+            },
+        ] {
+            let policies = Policies {
+                auth_policy: vec![policy],
+            };
+            let encoded = toml::to_string_pretty(&policies).unwrap();
+            assert_eq!(policies, toml::from_str(&encoded).unwrap());
+        }
+    }
+}
@@ -0,0 +1,388 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use penumbra_asset::{asset, Value};
+use penumbra_keys::FullViewingKey;
+use penumbra_num::Amount;
+use penumbra_transaction::plan::TransactionPlan;
+use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
+
+/// A cap on the amount of an asset that can leave the wallet's control over a
+/// rolling period.
+///
+/// Unlike an [`AuthPolicy`](super::AuthPolicy), a spend limit depends on what
+/// was authorized before, so it is checked against a [`SpendLedger`] that
+/// records past spending.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SpendLimit {
+    /// The maximum value that can be spent in any period, such as
+    /// `"1000penumbra"`.
+    #[serde(with = "value_as_string")]
+    pub limit: Value,
+    /// The length of the rolling period, in seconds.
+    pub period_seconds: u64,
+}
+
+impl SpendLimit {
+    /// Checks whether spending `outflows` at time `now` keeps the spending
+    /// recorded in `ledger` within this limit.
+    pub fn check(
+        &self,
+        ledger: &SpendLedger,
+        outflows: &BTreeMap<asset::Id, Amount>,
+        now: u64,
+    ) -> anyhow::Result<()> {
+        let asset_id = self.limit.asset_id;
+        let Some(outflow) = outflows.get(&asset_id) else {
+            return Ok(());
+        };
+        let spent = ledger.spent(asset_id, now.saturating_sub(self.period_seconds));
+        let total = spent.value().saturating_add(outflow.value());
+        if total > self.limit.amount.value() {
+            anyhow::bail!(
+                "spending {} would exceed the limit of {} per {} seconds, of which {} was already spent",
+                display(*outflow, asset_id),
+                display(self.limit.amount, asset_id),
+                self.period_seconds,
+                display(spent, asset_id),
+            );
+        }
+        Ok(())
+    }
+}
+
+fn display(amount: Amount, asset_id: asset::Id) -> String {
+    Value { amount, asset_id }.format(&asset::Cache::with_known_assets())
+}
+
+/// The value of each asset that leaves the wallet's control if `plan` is
+/// authorized.
+///
+/// This counts outputs, and swaps whose outputs are claimed by, addresses that
+/// `fvk` does not control, ICS-20 withdrawals, deposits into the community
+/// pool, and the transaction fee.  Value that stays under the wallet's control,
+/// such as change, delegations, and liquidity positions, does not count.
+pub fn outflows(plan: &TransactionPlan, fvk: &FullViewingKey) -> BTreeMap<asset::Id, Amount> {
+    let mut outflows = BTreeMap::<asset::Id, Amount>::new();
+    let mut add = |value: Value| {
+        *outflows.entry(value.asset_id).or_default() += value.amount;
+    };
+
+    for output in plan.output_plans() {
+        if fvk.address_index(&output.dest_address).is_none() {
+            add(output.value);
+        }
+    }
+    for swap in plan.swap_plans() {
+        let plaintext = &swap.swap_plaintext;
+        if fvk.address_index(&plaintext.claim_address).is_none() {
+            add(Value {
+                amount: plaintext.delta_1_i,
+                asset_id: plaintext.trading_pair.asset_1(),
+            });
+            add(Value {
+                amount: plaintext.delta_2_i,
+                asset_id: plaintext.trading_pair.asset_2(),
+            });
+        }
+    }
+    for withdrawal in plan.ics20_withdrawals() {
+        add(Value {
+            amount: withdrawal.amount,
+            asset_id: withdrawal.denom.id(),
+        });
+    }
+    for deposit in plan.community_pool_deposits() {
+        add(deposit.value);
+    }
+    add(plan.transaction_parameters.fee.0);
+
+    outflows.retain(|_, amount| *amount != Amount::zero());
+    outflows
+}
+
+/// A persistent record of the spending authorized under [`SpendLimit`]s, so
+/// that restarting the custody service does not reset the limits.
+///
+/// The ledger is stored as a JSON file, which is rewritten atomically whenever
+/// spending is recorded.
+#[derive(Debug)]
+pub struct SpendLedger {
+    path: PathBuf,
+    entries: Vec<LedgerEntry>,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct LedgerEntry {
+    /// When the spending was authorized, in seconds since the Unix epoch.
+    time: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    asset_id: asset::Id,
+    #[serde_as(as = "DisplayFromStr")]
+    amount: u128,
+}
+
+impl SpendLedger {
+    /// Load the ledger stored at `path`, or start an empty one if there is no
+    /// file there yet.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref().to_owned();
+        let entries = if path.exists() {
+            let contents = fs::read_to_string(&path)
+                .with_context(|| format!("could not read spend ledger {}", path.display()))?;
+            serde_json::from_str(&contents)
+                .with_context(|| format!("could not parse spend ledger {}", path.display()))?
+        } else {
+            Vec::new()
+        };
+        Ok(Self { path, entries })
+    }
+
+    /// The amount of `asset_id` spent after time `since`.
+    pub fn spent(&self, asset_id: asset::Id, since: u64) -> Amount {
+        self.entries
+            .iter()
+            .filter(|entry| entry.asset_id == asset_id && entry.time > since)
+            .map(|entry| Amount::from(entry.amount))
+            .sum()
+    }
+
+    /// Record spending `outflows` at time `now`, forgetting spending older than
+    /// `retain_seconds`, and save the ledger.
+    pub fn record(
+        &mut self,
+        outflows: &BTreeMap<asset::Id, Amount>,
+        now: u64,
+        retain_seconds: u64,
+    ) -> anyhow::Result<()> {
+        let since = now.saturating_sub(retain_seconds);
+        self.entries.retain(|entry| entry.time > since);
+        self.entries
+            .extend(outflows.iter().map(|(asset_id, amount)| LedgerEntry {
+                time: now,
+                asset_id: *asset_id,
+                amount: amount.value(),
+            }));
+
+        let tmp_path = PathBuf::from(format!("{}.tmp", self.path.display()));
+        fs::write(&tmp_path, serde_json::to_string_pretty(&self.entries)?)
+            .with_context(|| format!("could not write spend ledger {}", tmp_path.display()))?;
+        fs::rename(&tmp_path, &self.path)
+            .with_context(|| format!("could not write spend ledger {}", self.path.display()))?;
+        Ok(())
+    }
+}
+
+/// A serde helper to write values as human-readable strings, like
+/// `"1000penumbra"`, in config files.
+mod value_as_string {
+    use penumbra_asset::{asset, Value};
+
+    pub fn serialize<S: serde::Serializer>(
+        value: &Value,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.format(&asset::Cache::with_known_assets()))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_community_pool::CommunityPoolDeposit;
+    use penumbra_dex::{
+        swap::{SwapPlaintext, SwapPlan},
+        TradingPair,
+    };
+    use penumbra_fee::Fee;
+    use penumbra_keys::{
+        keys::{Bip44Path, SeedPhrase, SpendKey},
+        test_keys, Address,
+    };
+    use penumbra_shielded_pool::OutputPlan;
+    use penumbra_stake::{Delegate, IdentityKey};
+    use penumbra_transaction::{plan::ActionPlan, TransactionParameters};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn gm() -> asset::Id {
+        asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .unwrap()
+            .id()
+    }
+
+    fn value(amount: u64, asset_id: asset::Id) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id,
+        }
+    }
+
+    fn plan(actions: Vec<ActionPlan>, fee: u64) -> TransactionPlan {
+        TransactionPlan {
+            actions,
+            transaction_parameters: TransactionParameters {
+                fee: Fee::from_staking_token_amount(fee.into()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn own_address() -> Address {
+        test_keys::FULL_VIEWING_KEY.payment_address(1u32.into()).0
+    }
+
+    fn other_address() -> Address {
+        SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(OsRng), &Bip44Path::new(0))
+            .full_viewing_key()
+            .payment_address(Default::default())
+            .0
+    }
+
+    fn output(value: Value, address: Address) -> ActionPlan {
+        OutputPlan::new(&mut OsRng, value, address).into()
+    }
+
+    fn swap(address: Address) -> ActionPlan {
+        let plaintext = SwapPlaintext::new(
+            &mut OsRng,
+            TradingPair::new(*STAKING_TOKEN_ASSET_ID, gm()),
+            5u64.into(),
+            7u64.into(),
+            Fee::default(),
+            address,
+        );
+        ActionPlan::Swap(SwapPlan::new(&mut OsRng, plaintext))
+    }
+
+    #[test]
+    fn outflows_count_value_leaving_the_wallet() {
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+        let staking = *STAKING_TOKEN_ASSET_ID;
+
+        let outflows = super::outflows(
+            &plan(
+                vec![
+                    output(value(100, staking), other_address()),
+                    output(value(3, gm()), other_address()),
+                    ActionPlan::CommunityPoolDeposit(CommunityPoolDeposit {
+                        value: value(20, staking),
+                    }),
+                    swap(other_address()),
+                ],
+                2,
+            ),
+            fvk,
+        );
+        // The trading pair orders its assets, so the swap's input amounts may be swapped too.
+        let (swap_staking, swap_gm) = if TradingPair::new(staking, gm()).asset_1() == staking {
+            (5u64, 7u64)
+        } else {
+            (7, 5)
+        };
+        assert_eq!(
+            outflows,
+            BTreeMap::from([
+                (staking, Amount::from(100 + 20 + swap_staking + 2)),
+                (gm(), Amount::from(3 + swap_gm)),
+            ])
+        );
+    }
+
+    #[test]
+    fn outflows_ignore_value_staying_in_the_wallet() {
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+        let staking = *STAKING_TOKEN_ASSET_ID;
+        let validator = IdentityKey(
+            *SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(OsRng), &Bip44Path::new(0))
+                .full_viewing_key()
+                .spend_verification_key(),
+        );
+
+        // Change to any of the wallet's addresses, swaps claimed by it and delegations don't
+        // leave its control, and with no fee, nothing is left at all.
+        let outflows = super::outflows(
+            &plan(
+                vec![
+                    output(value(100, staking), own_address()),
+                    output(
+                        value(100, staking),
+                        fvk.ephemeral_address(OsRng, 2u32.into()).0,
+                    ),
+                    swap(own_address()),
+                    ActionPlan::Delegate(Delegate {
+                        validator_identity: validator,
+                        epoch_index: 1,
+                        unbonded_amount: 10u64.into(),
+                        delegation_amount: 10u64.into(),
+                    }),
+                ],
+                0,
+            ),
+            fvk,
+        );
+        assert!(outflows.is_empty());
+
+        // The fee always leaves.
+        let outflows = super::outflows(&plan(vec![], 5), fvk);
+        assert_eq!(outflows, BTreeMap::from([(staking, Amount::from(5u64))]));
+    }
+
+    #[test]
+    fn spend_limit_rolls_over() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ledger.json");
+        let limit = SpendLimit {
+            limit: Value {
+                amount: 100u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+            period_seconds: 60,
+        };
+        let spend = |amount: u64| BTreeMap::from([(*STAKING_TOKEN_ASSET_ID, Amount::from(amount))]);
+
+        let mut ledger = SpendLedger::load(&path).unwrap();
+        limit.check(&ledger, &spend(70), 1000).unwrap();
+        ledger.record(&spend(70), 1000, 60).unwrap();
+
+        // The ledger persists, so that restarting doesn't reset the limit.
+        let mut ledger = SpendLedger::load(&path).unwrap();
+        assert!(limit.check(&ledger, &spend(40), 1030).is_err());
+        limit.check(&ledger, &spend(30), 1030).unwrap();
+        ledger.record(&spend(30), 1030, 60).unwrap();
+
+        // Once the first spend is out of the period, there is room again.
+        assert!(limit.check(&ledger, &spend(70), 1059).is_err());
+        limit.check(&ledger, &spend(70), 1060).unwrap();
+    }
+
+    #[test]
+    fn toml_spend_limit_round_trip() {
+        let limit = SpendLimit {
+            limit: "1000penumbra".parse().unwrap(),
+            period_seconds: 86400,
+        };
+        let encoded = toml::to_string_pretty(&limit).unwrap();
+        println!("{encoded}");
+        assert_eq!(limit, toml::from_str(&encoded).unwrap());
+    }
+}
@@ -1,12 +1,21 @@
 //! A basic software key management system that stores keys in memory but
 //! presents as an asynchronous signer.
 
+use std::{
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context;
 use penumbra_proto::custody::v1alpha1::{self as pb, AuthorizeResponse};
 use penumbra_transaction::AuthorizationData;
 use rand_core::OsRng;
 use tonic::{async_trait, Request, Response, Status};
 
-use crate::{policy::Policy, AuthorizeRequest};
+use crate::{
+    policy::{spend_limit, Policy, SpendLedger},
+    AuthorizeRequest,
+};
 
 mod config;
 
@@ -14,14 +23,22 @@ pub use config::Config;
 
 /// A basic software key management system that stores keys in memory but
 /// presents as an asynchronous signer.
+///
+/// Clones share the record of spending under the configured spend limits.
+#[derive(Clone)]
 pub struct SoftKms {
     config: Config,
+    /// The spend ledger, loaded on first use.
+    ledger: Arc<Mutex<Option<SpendLedger>>>,
 }
 
 impl SoftKms {
     /// Initialize with the given [`Config`].
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            ledger: Default::default(),
+        }
     }
 
     /// Attempt to authorize the requested [`TransactionPlan`](penumbra_transaction::plan::TransactionPlan).
@@ -33,7 +50,45 @@ impl SoftKms {
             policy.check(request)?;
         }
 
-        Ok(request.plan.authorize(OsRng, &self.config.spend_key)?)
+        if self.config.spend_limits.is_empty() {
+            return Ok(request.plan.authorize(OsRng, &self.config.spend_key)?);
+        }
+
+        // Hold the ledger for the whole authorization, so that concurrent requests can't both
+        // fit under a limit that they exceed together.
+        let mut ledger = self
+            .ledger
+            .lock()
+            .map_err(|_| anyhow::anyhow!("spend ledger lock poisoned"))?;
+        if ledger.is_none() {
+            let path = self
+                .config
+                .spend_ledger
+                .as_ref()
+                .context("spend limits require a spend_ledger file to record spending in")?;
+            *ledger = Some(SpendLedger::load(path)?);
+        }
+        let ledger = ledger.as_mut().expect("spend ledger was just loaded");
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let outflows =
+            spend_limit::outflows(&request.plan, self.config.spend_key.full_viewing_key());
+        for limit in &self.config.spend_limits {
+            limit.check(ledger, &outflows, now)?;
+        }
+
+        let authorization_data = request.plan.authorize(OsRng, &self.config.spend_key)?;
+
+        let retain_seconds = self
+            .config
+            .spend_limits
+            .iter()
+            .map(|limit| limit.period_seconds)
+            .max()
+            .unwrap_or_default();
+        ledger.record(&outflows, now, retain_seconds)?;
+
+        Ok(authorization_data)
     }
 }
 
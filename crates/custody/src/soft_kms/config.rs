@@ -1,4 +1,6 @@
-use crate::policy::AuthPolicy;
+use std::path::PathBuf;
+
+use crate::policy::{AuthPolicy, SpendLimit};
 use penumbra_keys::keys::SpendKey;
 use serde::{Deserialize, Serialize};
 use serde_with::DisplayFromStr;
@@ -14,6 +16,15 @@ pub struct Config {
     pub spend_key: SpendKey,
     #[serde(default, skip_serializing_if = "is_default")]
     pub auth_policy: Vec<AuthPolicy>,
+    /// Caps on the amounts that can be spent over rolling periods.
+    ///
+    /// Setting any requires a `spend_ledger`.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub spend_limits: Vec<SpendLimit>,
+    /// The file in which spending under the `spend_limits` is recorded, so
+    /// that restarts don't reset the limits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spend_ledger: Option<PathBuf>,
 }
 
 impl From<SpendKey> for Config {
@@ -21,6 +32,8 @@ impl From<SpendKey> for Config {
         Self {
             spend_key,
             auth_policy: Default::default(),
+            spend_limits: Default::default(),
+            spend_ledger: None,
         }
     }
 }
@@ -39,8 +52,9 @@ fn is_default<T: Default + Eq>(value: &T) -> bool {
 #[cfg(test)]
 mod tests {
     use penumbra_keys::keys::{Bip44Path, SeedPhrase};
+    use penumbra_stake::IdentityKey;
 
-    use crate::policy::PreAuthorizationPolicy;
+    use crate::policy::{ActionKind, PreAuthorizationPolicy};
 
     use super::*;
 
@@ -66,11 +80,30 @@ mod tests {
                 required_signatures: 1,
                 allowed_signers: vec![pvk],
             }),
+            AuthPolicy::ActionDenyList {
+                denied_actions: vec![ActionKind::PositionOpen, ActionKind::ProposalSubmit],
+            },
+            AuthPolicy::IbcChannelAllowList {
+                allowed_channels: vec!["channel-0".to_string()],
+            },
+            AuthPolicy::ValidatorAllowList {
+                allowed_validators: vec![IdentityKey(
+                    spend_key
+                        .full_viewing_key()
+                        .spend_verification_key()
+                        .clone(),
+                )],
+            },
         ];
 
         let example = Config {
             spend_key: spend_key.clone(),
             auth_policy,
+            spend_limits: vec![SpendLimit {
+                limit: "1000penumbra".parse().unwrap(),
+                period_seconds: 86400,
+            }],
+            spend_ledger: Some("/var/lib/pclientd/spend-ledger.json".into()),
         };
 
         let encoded = toml::to_string_pretty(&example).unwrap();
@@ -15,6 +15,7 @@ pub use self::config::Config;
 
 mod config;
 mod dkg;
+mod reshare;
 mod sign;
 
 fn to_json<T>(data: &T) -> Result<String>
@@ -168,6 +169,120 @@ pub async fn dkg(t: u16, n: u16, terminal: &impl Terminal) -> Result<Config> {
     dkg::round3(&mut OsRng, state, round2_replies)
 }
 
+/// Parameters for resharing a threshold key with [`reshare`].
+#[derive(Clone, Debug)]
+pub struct ReshareParameters {
+    /// The number of participants in the old set who deal new shares, which
+    /// must be at least the old threshold.
+    pub dealers: u16,
+    /// The number of participants in the new set.
+    pub participants: u16,
+    /// The minimum number of participants in the new set required to sign.
+    pub threshold: u16,
+}
+
+/// A protocol for resharing a threshold key to a new set of participants, with a
+/// new threshold, producing a config for the same full viewing key.
+///
+/// Participants of the old set deal new shares if `config` is set, and
+/// everyone who sets `join` gets a share in the new set.  This returns the
+/// new config if `join` is set, and only once every participant in the new set
+/// has confirmed their share, so dealers can safely destroy their old shares.
+///
+/// This takes in a terminal, because it requires interacting with the other participants.
+pub async fn reshare(
+    config: Option<Config>,
+    join: bool,
+    parameters: &ReshareParameters,
+    terminal: &impl Terminal,
+) -> Result<Option<Config>> {
+    let dealing = config.is_some();
+    // Round 1 top
+    let (round1_message, state) = reshare::round1(&mut OsRng, config, join)?;
+    terminal
+        .explain("Round 1/3: Send this message to all other participants:")
+        .await?;
+    terminal.broadcast(&to_json(&round1_message)?).await?;
+    // Round 1 bottom
+    terminal
+        .explain(&format!(
+            "Round 1/3: Gather messages from the other participants, until you have heard from all {} dealers and {} new participants:",
+            parameters.dealers, parameters.participants
+        ))
+        .await?;
+    let round1_replies = {
+        let (mut dealers, mut participants) = (u16::from(dealing), u16::from(join));
+        let mut acc: Vec<reshare::Round1> = Vec::new();
+        while dealers < parameters.dealers || participants < parameters.participants {
+            let string = terminal
+                .next_response()
+                .await?
+                .ok_or(anyhow!("expected message from another participant"))?;
+            let message: reshare::Round1 = from_json(&string)?;
+            dealers += u16::from(message.is_dealer());
+            participants += u16::from(message.is_participant());
+            acc.push(message);
+        }
+        acc
+    };
+
+    // Round 2 top
+    let (round2_message, state) =
+        reshare::round2(&mut OsRng, state, round1_replies, parameters.threshold)?;
+    if let Some(round2_message) = round2_message {
+        terminal
+            .explain("Round 2/3: Send this message to all other participants:")
+            .await?;
+        terminal.broadcast(&to_json(&round2_message)?).await?;
+    }
+    // Round 2 bottom
+    let expected_responses = parameters.dealers.saturating_sub(u16::from(dealing)) as usize;
+    terminal
+        .explain(&format!(
+            "Round 2/3: Gather {expected_responses} messages from the dealers:"
+        ))
+        .await?;
+    let round2_replies = {
+        let mut acc: Vec<reshare::Round2> = Vec::new();
+        while acc.len() < expected_responses {
+            let string = terminal
+                .next_response()
+                .await?
+                .ok_or(anyhow!("expected message from a dealer"))?;
+            acc.push(from_json(&string)?);
+        }
+        acc
+    };
+
+    // Round 3 top
+    let (round3_message, state) = reshare::round3(&mut OsRng, state, round2_replies)?;
+    if let Some(round3_message) = round3_message {
+        terminal
+            .explain("Round 3/3: Send this message to all other participants:")
+            .await?;
+        terminal.broadcast(&to_json(&round3_message)?).await?;
+    }
+    // Round 3 bottom
+    let expected_responses = parameters.participants.saturating_sub(u16::from(join)) as usize;
+    terminal
+        .explain(&format!(
+            "Round 3/3: Gather {expected_responses} messages from the new participants:"
+        ))
+        .await?;
+    let round3_replies = {
+        let mut acc: Vec<reshare::Round3> = Vec::new();
+        while acc.len() < expected_responses {
+            let string = terminal
+                .next_response()
+                .await?
+                .ok_or(anyhow!("expected message from a new participant"))?;
+            acc.push(from_json(&string)?);
+        }
+        acc
+    };
+    reshare::finish(state, round3_replies)
+}
+
 /// A custody backend using threshold signing.  
 ///
 /// This backend is initialized with a full viewing key, but only a share
@@ -0,0 +1,943 @@
+//! A protocol for resharing a threshold key to a new set of participants, with a
+//! new threshold, without changing the key.
+//!
+//! A quorum of at least `t` participants of the old set act as dealers.  Each
+//! dealer scales their share by its Lagrange coefficient for the quorum, so that
+//! the scaled shares sum to the spend key, and shares the result with a fresh
+//! polynomial of degree `t' - 1` among the new participants, publishing Feldman
+//! commitments to its coefficients.  Each new participant sums the shares they
+//! receive to get a share of the same spend key under the new threshold `t'`.
+//!
+//! The new participants check each dealing against the dealer's verifying share
+//! from the old set, and check that the dealings sum to the spend verification
+//! key, so a dealer cannot change the key.  The nullifier key is carried over,
+//! so the full viewing key, and so the wallet, stays the same.
+//!
+//! Finally, each new participant confirms that their new share checks out, or
+//! complains that it doesn't, over a hash of the dealings they received.  Old
+//! shares should only be deleted once every new participant has confirmed the
+//! same dealings, since until then the old set may be the only one able to sign.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use ark_ff::{Field as _, UniformRand};
+use decaf377::{Element, FieldExt, Fr};
+use decaf377_frost as frost;
+use ed25519_consensus::{Signature, SigningKey, VerificationKey};
+use penumbra_keys::FullViewingKey;
+use penumbra_proto::{custody::threshold::v1alpha1 as pb, DomainType, Message};
+use rand_core::CryptoRngCore;
+
+use super::{
+    dkg::encryption::{DecryptionKey, EncryptionKey},
+    Config,
+};
+
+/// The FROST identifier of a participant, as a scalar.
+fn identifier_scalar(vk: &VerificationKey) -> Result<Fr> {
+    let id = frost::Identifier::derive(vk.as_bytes())?;
+    decode_scalar(&id.serialize())
+}
+
+fn decode_scalar(bytes: &[u8]) -> Result<Fr> {
+    Fr::from_bytes(bytes.try_into()?).map_err(|_| anyhow!("invalid scalar encoding"))
+}
+
+fn decode_element(bytes: &[u8]) -> Result<Element> {
+    decaf377::Encoding(bytes.try_into()?)
+        .vartime_decompress()
+        .map_err(|_| anyhow!("invalid group element encoding"))
+}
+
+/// The Lagrange coefficient of `x` for interpolating the polynomial through the
+/// points at `xs` at zero.
+fn lagrange_coefficient(x: Fr, xs: &[Fr]) -> Result<Fr> {
+    let mut numerator = Fr::from(1u64);
+    let mut denominator = Fr::from(1u64);
+    for x_j in xs.iter().filter(|x_j| **x_j != x) {
+        numerator *= x_j;
+        denominator *= *x_j - x;
+    }
+    Ok(numerator
+        * denominator
+            .inverse()
+            .ok_or(anyhow!("duplicate identifiers in quorum"))?)
+}
+
+/// Evaluate the polynomial with the given coefficients at `x`.
+fn evaluate(coefficients: &[Fr], x: Fr) -> Fr {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Fr::from(0u64), |acc, coefficient| acc * x + *coefficient)
+}
+
+/// Evaluate the polynomial whose coefficients are committed to by `commitments`
+/// at `x`, in the exponent.
+fn evaluate_commitments(commitments: &[Element], x: Fr) -> Element {
+    commitments
+        .iter()
+        .rev()
+        .fold(Element::default(), |acc, commitment| x * acc + *commitment)
+}
+
+fn sorted(mut vks: Vec<VerificationKey>) -> Vec<VerificationKey> {
+    vks.sort_by_key(|vk| *vk.as_bytes());
+    vks
+}
+
+/// The message we send in round 1 of the resharing protocol.
+#[derive(Clone)]
+pub struct Round1 {
+    /// Our identity, and an encryption key to receive our shares, if we're
+    /// joining the new set.
+    participant: Option<(VerificationKey, EncryptionKey)>,
+    /// Our identity in the old set, if we're dealing.
+    dealer_vk: Option<VerificationKey>,
+}
+
+impl Round1 {
+    /// Whether the sender is dealing new shares.
+    pub fn is_dealer(&self) -> bool {
+        self.dealer_vk.is_some()
+    }
+
+    /// Whether the sender is joining the new set.
+    pub fn is_participant(&self) -> bool {
+        self.participant.is_some()
+    }
+}
+
+impl From<Round1> for pb::ReshareRound1 {
+    fn from(value: Round1) -> Self {
+        Self {
+            participant: value
+                .participant
+                .map(|(vk, epk)| pb::reshare_round1::Participant {
+                    epk: epk.as_bytes().to_vec(),
+                    vk: vk.as_bytes().to_vec(),
+                }),
+            dealer_vk: value
+                .dealer_vk
+                .map(|vk| vk.as_bytes().to_vec())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<pb::ReshareRound1> for Round1 {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ReshareRound1) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            participant: value
+                .participant
+                .map(|p| -> Result<_> {
+                    Ok((p.vk.as_slice().try_into()?, p.epk.as_slice().try_into()?))
+                })
+                .transpose()?,
+            dealer_vk: if value.dealer_vk.is_empty() {
+                None
+            } else {
+                Some(value.dealer_vk.as_slice().try_into()?)
+            },
+        })
+    }
+}
+
+impl DomainType for Round1 {
+    type Proto = pb::ReshareRound1;
+}
+
+/// The message each dealer sends in round 2 of the resharing protocol.
+#[derive(Clone, Debug)]
+pub struct Round2 {
+    /// The dealing, which we keep in its proto form, since that's what's signed.
+    inner: pb::reshare_round2::Inner,
+    /// The dealer's identity in the old set.
+    vk: VerificationKey,
+    /// A signature over the inner message.
+    sig: Signature,
+}
+
+impl From<Round2> for pb::ReshareRound2 {
+    fn from(value: Round2) -> Self {
+        Self {
+            inner: Some(value.inner),
+            vk: value.vk.as_bytes().to_vec(),
+            sig: value.sig.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::ReshareRound2> for Round2 {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ReshareRound2) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            inner: value.inner.ok_or(anyhow!("ReshareRound2 missing inner"))?,
+            vk: value.vk.as_slice().try_into()?,
+            sig: value.sig.as_slice().try_into()?,
+        })
+    }
+}
+
+impl DomainType for Round2 {
+    type Proto = pb::ReshareRound2;
+}
+
+/// The message each new participant sends in round 3 of the resharing protocol.
+#[derive(Clone, Debug)]
+pub struct Round3 {
+    /// The confirmation or complaint, which we keep in its proto form, since that's what's
+    /// signed.
+    inner: pb::reshare_round3::Inner,
+    /// The participant's identity in the new set.
+    vk: VerificationKey,
+    /// A signature over the inner message.
+    sig: Signature,
+}
+
+impl Round3 {
+    fn make(sk: &SigningKey, inner: pb::reshare_round3::Inner) -> Self {
+        let sig = sk.sign(&inner.encode_to_vec());
+        Self {
+            inner,
+            vk: sk.verification_key(),
+            sig,
+        }
+    }
+}
+
+impl From<Round3> for pb::ReshareRound3 {
+    fn from(value: Round3) -> Self {
+        Self {
+            inner: Some(value.inner),
+            vk: value.vk.as_bytes().to_vec(),
+            sig: value.sig.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::ReshareRound3> for Round3 {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ReshareRound3) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            inner: value.inner.ok_or(anyhow!("ReshareRound3 missing inner"))?,
+            vk: value.vk.as_slice().try_into()?,
+            sig: value.sig.as_slice().try_into()?,
+        })
+    }
+}
+
+impl DomainType for Round3 {
+    type Proto = pb::ReshareRound3;
+}
+
+/// A dealing, after checking its signature and decoding it.
+struct Dealing {
+    fvk: FullViewingKey,
+    old_threshold: u16,
+    old_shares: HashMap<VerificationKey, Element>,
+    dealers: Vec<VerificationKey>,
+    new_threshold: u16,
+    commitments: Vec<Element>,
+    encrypted_shares: HashMap<VerificationKey, Vec<u8>>,
+}
+
+impl Round2 {
+    fn make(sk: &SigningKey, inner: pb::reshare_round2::Inner) -> Self {
+        let sig = sk.sign(&inner.encode_to_vec());
+        Self {
+            inner,
+            vk: sk.verification_key(),
+            sig,
+        }
+    }
+
+    /// The parts of the dealing that all the dealers must agree on.
+    fn context(&self) -> pb::reshare_round2::Inner {
+        let mut context = self.inner.clone();
+        context.commitments.clear();
+        for share in &mut context.encrypted_shares {
+            share.encrypted_share.clear();
+        }
+        context
+    }
+
+    fn dealing(self) -> Result<(VerificationKey, Dealing)> {
+        self.vk.verify(&self.sig, &self.inner.encode_to_vec())?;
+        let inner = self.inner;
+        let dealing = Dealing {
+            fvk: inner
+                .fvk
+                .ok_or(anyhow!("ReshareRound2 missing fvk"))?
+                .try_into()?,
+            old_threshold: inner.old_threshold.try_into()?,
+            old_shares: inner
+                .old_shares
+                .into_iter()
+                .map(|x| {
+                    Ok((
+                        x.vk.as_slice().try_into()?,
+                        decode_element(&x.verifying_share)?,
+                    ))
+                })
+                .collect::<Result<_>>()?,
+            dealers: inner
+                .dealers
+                .iter()
+                .map(|x| Ok(x.as_slice().try_into()?))
+                .collect::<Result<_>>()?,
+            new_threshold: inner.new_threshold.try_into()?,
+            commitments: inner
+                .commitments
+                .iter()
+                .map(|x| decode_element(x))
+                .collect::<Result<_>>()?,
+            encrypted_shares: inner
+                .encrypted_shares
+                .into_iter()
+                .map(|x| Ok((x.vk.as_slice().try_into()?, x.encrypted_share)))
+                .collect::<Result<_>>()?,
+        };
+        Ok((self.vk, dealing))
+    }
+}
+
+/// The state we need to remember after round 1.
+pub struct Round1State {
+    /// Our config in the old set, if we're dealing.
+    dealer: Option<Config>,
+    /// Our new signing key, and the decryption key for our shares, if we're
+    /// joining the new set.
+    participant: Option<(SigningKey, DecryptionKey)>,
+}
+
+/// The state we need to remember after round 2.
+pub struct Round2State {
+    /// Our new signing key, and the decryption key for our shares, if we're
+    /// joining the new set.
+    participant: Option<(SigningKey, DecryptionKey)>,
+    /// The identities of the new set, sorted.
+    participants: Vec<VerificationKey>,
+    /// The identities of the dealers in the old set, sorted.
+    dealers: Vec<VerificationKey>,
+    new_threshold: u16,
+    /// Our own dealing, which we won't receive from the others.
+    own_dealing: Option<Round2>,
+}
+
+/// The state we need to remember after round 3.
+pub struct Round3State {
+    /// Our identity in the new set, and our new config, or why we couldn't produce one, if
+    /// we're joining the new set.
+    participant: Option<(VerificationKey, std::result::Result<Config, String>)>,
+    /// The identities of the new set, sorted.
+    participants: Vec<VerificationKey>,
+    /// The hash of the dealings we received.
+    dealings_hash: Vec<u8>,
+}
+
+/// A hash of a set of dealings, which the new participants confirm they agree on.
+fn dealings_hash(messages: &[Round2]) -> Vec<u8> {
+    let mut encoded = messages
+        .iter()
+        .map(|message| pb::ReshareRound2::from(message.clone()).encode_to_vec())
+        .collect::<Vec<_>>();
+    encoded.sort();
+    let mut state = blake2b_simd::Params::new()
+        .personal(b"reshare-dealings")
+        .to_state();
+    for message in &encoded {
+        state.update(&(message.len() as u64).to_le_bytes());
+        state.update(message);
+    }
+    state.finalize().as_bytes().to_vec()
+}
+
+/// Start resharing, as a dealer if `dealer` holds our config in the old set, and
+/// as a participant in the new set if `join` is set.
+pub fn round1(
+    mut rng: impl CryptoRngCore,
+    dealer: Option<Config>,
+    join: bool,
+) -> Result<(Round1, Round1State)> {
+    if dealer.is_none() && !join {
+        anyhow::bail!("must either deal or join the new set to take part in resharing");
+    }
+    let participant = join.then(|| (SigningKey::new(&mut rng), DecryptionKey::new(&mut rng)));
+    let round1 = Round1 {
+        participant: participant
+            .as_ref()
+            .map(|(sk, edk)| (sk.verification_key(), edk.public())),
+        dealer_vk: dealer
+            .as_ref()
+            .map(|config| config.signing_key().verification_key()),
+    };
+    Ok((
+        round1,
+        Round1State {
+            dealer,
+            participant,
+        },
+    ))
+}
+
+/// Deal our share to the new set, if we're a dealer, given the round 1 messages
+/// of everyone else.
+pub fn round2(
+    mut rng: impl CryptoRngCore,
+    state: Round1State,
+    messages: Vec<Round1>,
+    new_threshold: u16,
+) -> Result<(Option<Round2>, Round2State)> {
+    let mut participants = Vec::new();
+    let mut dealers = Vec::new();
+    if let Some((sk, edk)) = &state.participant {
+        participants.push((sk.verification_key(), edk.public()));
+    }
+    if let Some(config) = &state.dealer {
+        dealers.push(config.signing_key().verification_key());
+    }
+    for message in messages {
+        participants.extend(message.participant);
+        dealers.extend(message.dealer_vk);
+    }
+
+    // Check that all verification keys are unique.
+    let participant_vks = sorted(participants.iter().map(|(vk, _)| *vk).collect());
+    let dealers = sorted(dealers);
+    if participant_vks.iter().collect::<HashSet<_>>().len() != participant_vks.len()
+        || dealers.iter().collect::<HashSet<_>>().len() != dealers.len()
+    {
+        anyhow::bail!("duplicate verification key in messages");
+    }
+    if new_threshold < 2 || usize::from(new_threshold) > participant_vks.len() {
+        anyhow::bail!(
+            "new threshold must be at least 2, and at most the {} new participants",
+            participant_vks.len()
+        );
+    }
+
+    let own_dealing = state
+        .dealer
+        .map(|config| deal(&mut rng, &config, &dealers, &participants, new_threshold))
+        .transpose()?;
+
+    let state = Round2State {
+        participant: state.participant,
+        participants: participant_vks,
+        dealers,
+        new_threshold,
+        own_dealing: own_dealing.clone(),
+    };
+    Ok((own_dealing, state))
+}
+
+fn deal(
+    mut rng: impl CryptoRngCore,
+    config: &Config,
+    dealers: &[VerificationKey],
+    participants: &[(VerificationKey, EncryptionKey)],
+    new_threshold: u16,
+) -> Result<Round2> {
+    let old_vks = config.verification_keys();
+    if let Some(vk) = dealers.iter().find(|vk| !old_vks.contains(vk)) {
+        anyhow::bail!(
+            "dealer {} is not in the old set",
+            hex::encode(vk.as_bytes())
+        );
+    }
+    if dealers.len() < usize::from(config.threshold()) {
+        anyhow::bail!(
+            "need at least {} dealers from the old set, but only have {}",
+            config.threshold(),
+            dealers.len()
+        );
+    }
+
+    // Scale our share so that the dealers' scaled shares sum to the spend key.
+    let xs = dealers
+        .iter()
+        .map(identifier_scalar)
+        .collect::<Result<Vec<_>>>()?;
+    let lambda = lagrange_coefficient(
+        identifier_scalar(&config.signing_key().verification_key())?,
+        &xs,
+    )?;
+    let share = decode_scalar(&config.key_package().secret_share().serialize())?;
+
+    let coefficients = std::iter::once(lambda * share)
+        .chain((1..new_threshold).map(|_| Fr::rand(&mut rng)))
+        .collect::<Vec<_>>();
+    let commitments = coefficients
+        .iter()
+        .map(|coefficient| {
+            (*coefficient * decaf377::basepoint())
+                .vartime_compress()
+                .0
+                .to_vec()
+        })
+        .collect();
+
+    let mut encrypted_shares = participants
+        .iter()
+        .map(|(vk, epk)| {
+            let share = evaluate(&coefficients, identifier_scalar(vk)?);
+            Ok(pb::reshare_round2::TargetedShare {
+                vk: vk.as_bytes().to_vec(),
+                encrypted_share: epk.encrypt(&mut rng, &share.to_bytes()),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    encrypted_shares.sort_by(|a, b| a.vk.cmp(&b.vk));
+
+    let signer_pubkeys = config.public_key_package().signer_pubkeys().clone();
+    let old_shares = sorted(old_vks.into_iter().collect())
+        .into_iter()
+        .map(|vk| {
+            let id = frost::Identifier::derive(vk.as_bytes())?;
+            let verifying_share = signer_pubkeys
+                .get(&id)
+                .ok_or(anyhow!("missing verifying share in config"))?;
+            Ok(pb::reshare_round2::OldShare {
+                vk: vk.as_bytes().to_vec(),
+                verifying_share: verifying_share.serialize(),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    let inner = pb::reshare_round2::Inner {
+        fvk: Some(config.fvk().clone().into()),
+        old_threshold: config.threshold().into(),
+        old_shares,
+        dealers: dealers.iter().map(|vk| vk.as_bytes().to_vec()).collect(),
+        new_threshold: new_threshold.into(),
+        commitments,
+        encrypted_shares,
+    };
+    Ok(Round2::make(config.signing_key(), inner))
+}
+
+/// Check the dealings from all of the other dealers, given the state after round 2.
+///
+/// If we're joining the new set, this produces the [`Round3`] message to send to
+/// everyone else, confirming that our new share checks out, or complaining that
+/// it doesn't.  Dealers leaving the set still need the dealings, to check that
+/// the new participants confirm the same ones.
+pub fn round3(
+    mut rng: impl CryptoRngCore,
+    state: Round2State,
+    messages: Vec<Round2>,
+) -> Result<(Option<Round3>, Round3State)> {
+    let messages = messages
+        .into_iter()
+        .chain(state.own_dealing.clone())
+        .collect::<Vec<_>>();
+    let dealings_hash = dealings_hash(&messages);
+    let participants = state.participants.clone();
+
+    let Some((sk, _)) = &state.participant else {
+        return Ok((
+            None,
+            Round3State {
+                participant: None,
+                participants,
+                dealings_hash,
+            },
+        ));
+    };
+    let sk = sk.clone();
+    let outcome = new_config(&mut rng, state, messages).map_err(|e| e.to_string());
+    let round3 = Round3::make(
+        &sk,
+        pb::reshare_round3::Inner {
+            dealings_hash: dealings_hash.clone(),
+            complaint: outcome.as_ref().err().cloned().unwrap_or_default(),
+        },
+    );
+    Ok((
+        Some(round3),
+        Round3State {
+            participant: Some((sk.verification_key(), outcome)),
+            participants,
+            dealings_hash,
+        },
+    ))
+}
+
+/// Finish resharing, given the round 3 messages of every other new participant,
+/// producing our new config if we're joining the new set.
+///
+/// This fails unless every new participant confirmed the same dealings, in which
+/// case dealers must keep their old shares.
+pub fn finish(state: Round3State, messages: Vec<Round3>) -> Result<Option<Config>> {
+    if let Some((_, Err(complaint))) = &state.participant {
+        anyhow::bail!("could not finish resharing: {complaint}");
+    }
+    let mut senders = Vec::new();
+    for message in messages {
+        message
+            .vk
+            .verify(&message.sig, &message.inner.encode_to_vec())?;
+        let sender = hex::encode(message.vk.as_bytes());
+        if !message.inner.complaint.is_empty() {
+            anyhow::bail!(
+                "participant {sender} could not finish resharing: {}",
+                message.inner.complaint
+            );
+        }
+        if message.inner.dealings_hash != state.dealings_hash {
+            anyhow::bail!("participant {sender} received different dealings");
+        }
+        senders.push(message.vk);
+    }
+    senders.extend(state.participant.as_ref().map(|(vk, _)| *vk));
+    if sorted(senders) != state.participants {
+        anyhow::bail!("expected one confirmation from each new participant, and no others");
+    }
+    match state.participant {
+        Some((_, outcome)) => Ok(Some(outcome.map_err(|e| anyhow!(e))?)),
+        None => Ok(None),
+    }
+}
+
+/// Check the dealings, including our own, as a participant in the new set,
+/// producing our new config.
+fn new_config(
+    mut rng: impl CryptoRngCore,
+    state: Round2State,
+    messages: Vec<Round2>,
+) -> Result<Config> {
+    let (sk, edk) = state
+        .participant
+        .ok_or(anyhow!("only participants in the new set finish resharing"))?;
+
+    let context = messages
+        .first()
+        .ok_or(anyhow!("no dealings to reshare from"))?
+        .context();
+    if messages.iter().any(|message| message.context() != context) {
+        anyhow::bail!("dealers disagree on the parameters of the resharing");
+    }
+    let dealings = messages
+        .into_iter()
+        .map(Round2::dealing)
+        .collect::<Result<HashMap<_, _>>>()?;
+    if sorted(dealings.keys().cloned().collect()) != state.dealers {
+        anyhow::bail!("expected one dealing from each dealer, and no others");
+    }
+
+    // Since the dealers agree on the context, we can check it once.
+    let Some(first) = dealings.values().next() else {
+        anyhow::bail!("no dealings to reshare from");
+    };
+    let fvk = first.fvk.clone();
+    if first.dealers != state.dealers
+        || sorted(first.encrypted_shares.keys().cloned().collect()) != state.participants
+        || first.new_threshold != state.new_threshold
+    {
+        anyhow::bail!("dealings do not match the announced dealers and participants");
+    }
+    if state.dealers.len() < usize::from(first.old_threshold) {
+        anyhow::bail!(
+            "need at least {} dealers from the old set, but only have {}",
+            first.old_threshold,
+            state.dealers.len()
+        );
+    }
+
+    // Check that each dealer shared their scaled share, and that the scaled shares sum to the
+    // spend key.
+    let xs = state
+        .dealers
+        .iter()
+        .map(identifier_scalar)
+        .collect::<Result<Vec<_>>>()?;
+    let mut group_public = Element::default();
+    for (vk, dealing) in &dealings {
+        let old_share = first
+            .old_shares
+            .get(vk)
+            .ok_or(anyhow!("dealer is not in the old set"))?;
+        if dealing.commitments.len() != usize::from(state.new_threshold) {
+            anyhow::bail!("dealing has the wrong number of commitments");
+        }
+        let lambda = lagrange_coefficient(identifier_scalar(vk)?, &xs)?;
+        if dealing.commitments[0] != lambda * *old_share {
+            anyhow::bail!("dealing does not share the dealer's share of the spend key");
+        }
+        group_public += dealing.commitments[0];
+    }
+    if group_public.vartime_compress().0 != fvk.spend_verification_key().to_bytes() {
+        anyhow::bail!("dealings do not share the spend key of the full viewing key");
+    }
+
+    // Decrypt and check our parts of each dealing, summing them to get our new share.
+    let my_vk = sk.verification_key();
+    let my_x = identifier_scalar(&my_vk)?;
+    let mut share = Fr::from(0u64);
+    for dealing in dealings.values() {
+        let ciphertext = dealing
+            .encrypted_shares
+            .get(&my_vk)
+            .ok_or(anyhow!("no encrypted share for this recipient"))?;
+        let part = decode_scalar(&edk.decrypt(&mut rng, ciphertext)?)?;
+        if part * decaf377::basepoint() != evaluate_commitments(&dealing.commitments, my_x) {
+            anyhow::bail!("share does not match the dealer's commitments");
+        }
+        share += part;
+    }
+
+    // Everyone's new verifying shares follow from the commitments.
+    let mut signer_pubkeys = HashMap::new();
+    for vk in &state.participants {
+        let x = identifier_scalar(vk)?;
+        let verifying_share = dealings
+            .values()
+            .map(|dealing| evaluate_commitments(&dealing.commitments, x))
+            .fold(Element::default(), |acc, x| acc + x);
+        signer_pubkeys.insert(
+            frost::Identifier::derive(vk.as_bytes())?,
+            frost::keys::VerifyingShare::deserialize(
+                verifying_share.vartime_compress().0.to_vec(),
+            )?,
+        );
+    }
+
+    let group_public =
+        frost::keys::VerifyingKey::deserialize(fvk.spend_verification_key().to_bytes().to_vec())?;
+    let signing_share = frost::keys::SigningShare::deserialize(share.to_bytes().to_vec())?;
+    let key_package = frost::keys::KeyPackage::new(
+        frost::Identifier::derive(my_vk.as_bytes())?,
+        signing_share,
+        signing_share.into(),
+        group_public,
+        state.new_threshold,
+    );
+    let public_key_package = frost::keys::PublicKeyPackage::new(signer_pubkeys, group_public);
+    let config = Config::from_parts(
+        key_package,
+        public_key_package,
+        sk,
+        state.participants,
+        fvk.nullifier_key().0,
+    );
+    if config.fvk() != &fvk {
+        anyhow::bail!("resharing changed the full viewing key");
+    }
+    Ok(config)
+}
+
+#[cfg(test)]
+mod test {
+    use rand_core::OsRng;
+
+    use super::*;
+
+    /// Run the resharing protocol, with each old config dealing, and joining the new set if
+    /// its entry in `joins` is set, along with `new_members` newcomers, returning how it
+    /// finishes for each of them, in that order.
+    ///
+    /// If `malicious` is set, that old config's dealing commits to the wrong share.
+    fn run(
+        old: Vec<Config>,
+        joins: &[bool],
+        new_members: usize,
+        new_threshold: u16,
+        malicious: Option<usize>,
+    ) -> Result<Vec<Result<Option<Config>>>> {
+        let signing_keys = old
+            .iter()
+            .map(|config| config.signing_key().clone())
+            .collect::<Vec<_>>();
+        let mut round1s = Vec::new();
+        for (config, join) in old.into_iter().zip(joins) {
+            round1s.push(round1(OsRng, Some(config), *join)?);
+        }
+        for _ in 0..new_members {
+            round1s.push(round1(OsRng, None, true)?);
+        }
+
+        // Everyone gets everyone else's round 1 message.
+        let messages = round1s
+            .iter()
+            .map(|(message, _)| message.clone())
+            .collect::<Vec<_>>();
+        let mut dealt = Vec::new();
+        let mut states = Vec::new();
+        for (i, (_, state)) in round1s.into_iter().enumerate() {
+            let others = messages
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, message)| message.clone())
+                .collect();
+            let (mut dealing, state) = round2(OsRng, state, others, new_threshold)?;
+            if malicious == Some(i) {
+                // Commit to a different share, and sign the result, so that only the
+                // commitment gives it away.
+                let mut inner = dealing.expect("old configs deal").inner;
+                let wrong = decode_element(&inner.commitments[0])? + decaf377::basepoint();
+                inner.commitments[0] = wrong.vartime_compress().0.to_vec();
+                dealing = Some(Round2::make(&signing_keys[i], inner));
+            }
+            dealt.push(dealing);
+            states.push(state);
+        }
+
+        let mut confirmed = Vec::new();
+        let mut states3 = Vec::new();
+        for (i, state) in states.into_iter().enumerate() {
+            let others = dealt
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .filter_map(|(_, dealing)| dealing.clone())
+                .collect();
+            let (confirmation, state) = round3(OsRng, state, others)?;
+            confirmed.push(confirmation);
+            states3.push(state);
+        }
+
+        Ok(states3
+            .into_iter()
+            .enumerate()
+            .map(|(i, state)| {
+                let others = confirmed
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .filter_map(|(_, confirmation)| confirmation.clone())
+                    .collect();
+                finish(state, others)
+            })
+            .collect())
+    }
+
+    /// Run the resharing protocol honestly, returning the configs of the new set.
+    fn reshare(
+        old: Vec<Config>,
+        joins: &[bool],
+        new_members: usize,
+        new_threshold: u16,
+    ) -> Result<Vec<Config>> {
+        run(old, joins, new_members, new_threshold, None)?
+            .into_iter()
+            .filter_map(|outcome| outcome.transpose())
+            .collect()
+    }
+
+    #[test]
+    fn test_reshare_keeps_key() -> Result<()> {
+        let old = Config::deal(&mut OsRng, 2, 3)?;
+        let fvk = old[0].fvk().clone();
+
+        // Two of the three old participants deal; one stays, one leaves, and three join.
+        let new = reshare(old[..2].to_vec(), &[true, false], 3, 3)?;
+        assert_eq!(new.len(), 4);
+        for config in &new {
+            assert_eq!(config.fvk(), &fvk);
+            assert_eq!(config.threshold(), 3);
+            assert_eq!(config.verification_keys().len(), 4);
+        }
+
+        // The new shares reconstruct the same spend key, with the new threshold.
+        let key_packages = new[..3]
+            .iter()
+            .map(|config| config.key_package())
+            .collect::<Vec<_>>();
+        let spend_key = frost::keys::reconstruct(&key_packages)?;
+        assert_eq!(
+            decaf377_rdsa::VerificationKey::from(&spend_key).to_bytes(),
+            fvk.spend_verification_key().to_bytes()
+        );
+        // Each new verifying share matches the new signing share.
+        for config in &new {
+            let key_package = config.key_package();
+            assert_eq!(
+                config.public_key_package().signer_pubkeys()[key_package.identifier()],
+                *key_package.public()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_reshare_needs_quorum() -> Result<()> {
+        let old = Config::deal(&mut OsRng, 3, 3)?;
+        assert!(reshare(old[..2].to_vec(), &[true, true], 1, 2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_leaving_dealer_finishes_once_confirmed() -> Result<()> {
+        let old = Config::deal(&mut OsRng, 2, 2)?;
+        let outcomes = run(old, &[false, true], 1, 2, None)?;
+        assert_eq!(outcomes.len(), 3);
+        let mut outcomes = outcomes.into_iter();
+        assert!(outcomes.next().expect("leaving dealer")?.is_none());
+        for outcome in outcomes {
+            assert!(outcome?.is_some());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_reshare_rejects_dealing_of_wrong_share() -> Result<()> {
+        let old = Config::deal(&mut OsRng, 2, 3)?;
+        // The first dealer leaves, committing to the wrong share on its way out.
+        let outcomes = run(old[..2].to_vec(), &[false, true], 2, 2, Some(0))?;
+        assert_eq!(outcomes.len(), 4);
+        // Every new participant complains, so nobody finishes, and in particular the dealers
+        // know to keep their old shares.
+        for outcome in outcomes {
+            let error = outcome.expect_err("resharing fails").to_string();
+            assert!(
+                error.contains("does not share the dealer's share of the spend key"),
+                "{error}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_leaving_dealer_needs_every_confirmation() -> Result<()> {
+        let old = Config::deal(&mut OsRng, 2, 2)?;
+        let (r1_leaving, s1_leaving) = round1(OsRng, Some(old[0].clone()), false)?;
+        let (r1_staying, s1_staying) = round1(OsRng, Some(old[1].clone()), true)?;
+        let (r1_new, _) = round1(OsRng, None, true)?;
+
+        let (dealing, s2_leaving) = round2(
+            OsRng,
+            s1_leaving,
+            vec![r1_staying.clone(), r1_new.clone()],
+            2,
+        )?;
+        let (other_dealing, s2_staying) = round2(OsRng, s1_staying, vec![r1_leaving, r1_new], 2)?;
+        let (_, s3_leaving) = round3(
+            OsRng,
+            s2_leaving,
+            vec![other_dealing.expect("staying dealer deals")],
+        )?;
+        let (confirmation, _) = round3(
+            OsRng,
+            s2_staying,
+            vec![dealing.expect("leaving dealer deals")],
+        )?;
+
+        // The newcomer never confirmed, so the leaving dealer must keep its share.
+        let error = finish(
+            s3_leaving,
+            vec![confirmation.expect("staying dealer joins")],
+        )
+        .expect_err("resharing is unconfirmed");
+        assert!(error
+            .to_string()
+            .contains("confirmation from each new participant"));
+        Ok(())
+    }
+}
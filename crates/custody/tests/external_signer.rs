@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+use penumbra_custody::{
+    external::{self, Config, Signer},
+    soft_kms::{self, SoftKms},
+};
+use penumbra_keys::{keys::AddressIndex, test_keys, Address, FullViewingKey};
+use penumbra_proto::custody::v1alpha1::{
+    self as pb, custody_protocol_service_client::CustodyProtocolServiceClient,
+};
+
+/// Check that the signer at the other end of `config` holds the test keys.
+async fn check_signer(config: &Config) -> anyhow::Result<()> {
+    let mut client = CustodyProtocolServiceClient::new(external::connect(config));
+
+    let fvk: FullViewingKey = client
+        .export_full_viewing_key(pb::ExportFullViewingKeyRequest {})
+        .await?
+        .into_inner()
+        .full_viewing_key
+        .expect("signer returns a full viewing key")
+        .try_into()?;
+    assert_eq!(fvk, *test_keys::FULL_VIEWING_KEY);
+
+    let address: Address = client
+        .confirm_address(pb::ConfirmAddressRequest {
+            address_index: Some(AddressIndex::new(1).into()),
+        })
+        .await?
+        .into_inner()
+        .address
+        .expect("signer returns an address")
+        .try_into()?;
+    assert_eq!(address, fvk.payment_address(AddressIndex::new(1)).0);
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn socket_signer() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let socket = dir.path().join("signer.sock");
+
+    let soft_kms = SoftKms::new(soft_kms::Config::from(test_keys::SPEND_KEY.clone()));
+    tokio::spawn(external::serve_socket(soft_kms, socket.clone()));
+    while !socket.exists() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    use std::os::unix::fs::PermissionsExt;
+    let mode = std::fs::metadata(&socket)?.permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    // The staging directory the socket was bound in is gone.
+    assert_eq!(std::fs::read_dir(dir.path())?.count(), 1);
+
+    check_signer(&Config {
+        signer: Signer::Socket(socket),
+    })
+    .await
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn socket_signer_does_not_replace_other_files() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("signer.sock");
+    std::fs::write(&path, "not a socket")?;
+
+    let soft_kms = SoftKms::new(soft_kms::Config::from(test_keys::SPEND_KEY.clone()));
+    assert!(external::serve_socket(soft_kms, path.clone())
+        .await
+        .is_err());
+    assert_eq!(std::fs::read_to_string(&path)?, "not a socket");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stdio_signer() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let config_path = dir.path().join("signer.toml");
+    std::fs::write(
+        &config_path,
+        toml::to_string(&soft_kms::Config::from(test_keys::SPEND_KEY.clone()))?,
+    )?;
+
+    check_signer(&Config {
+        signer: Signer::Command {
+            program: env!("CARGO_BIN_EXE_softkms-signer").to_string(),
+            args: vec![config_path.display().to_string(), "--stdio".to_string()],
+        },
+    })
+    .await
+}
@@ -251,7 +251,7 @@ fn write_to_file(tree: &Tree, args: &Args) -> Result<()> {
         print!("Writing {} ... ", svg_path.display());
         let start = Instant::now();
         let mut svg_file = File::create(svg_path)?;
-        write_svg_direct(tree, &mut svg_file)?;
+        render::svg(tree, &mut svg_file)?;
         println!("({:?})", start.elapsed());
     } else {
         // Allocate an intermediate dot file in memory
@@ -298,21 +298,6 @@ fn write_svg<W: Write>(dot: &[u8], writer: &mut W) -> Result<()> {
     Ok(())
 }
 
-fn write_svg_direct<W: Write>(tree: &Tree, writer: &mut W) -> Result<()> {
-    let (mut stdin, mut stdout) = dot_command()?;
-    thread::scope(|scope| {
-        let render_thread = scope.spawn(move || {
-            render::dot(tree, &mut stdin)?;
-            stdin.flush()?;
-            Ok::<_, io::Error>(())
-        });
-        std::io::copy(&mut stdout, writer)?;
-        render_thread.join().unwrap()?;
-        anyhow::Ok(())
-    })?;
-    Ok(())
-}
-
 fn dot_command() -> io::Result<(impl Write, impl Read)> {
     let mut child = Command::new("dot")
         .args(["-Tsvg"])
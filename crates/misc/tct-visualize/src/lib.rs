@@ -1,5 +1,7 @@
+#[cfg(feature = "live")]
 #[macro_use]
 extern crate serde;
 
+#[cfg(feature = "live")]
 pub mod live;
 pub mod render;
@@ -1,4 +1,9 @@
-use std::io::{self, Write};
+use std::{
+    collections::BTreeSet,
+    io::{self, Write},
+    process::{ChildStdin, Command, Stdio},
+    thread,
+};
 
 use decaf377::FieldExt;
 
@@ -11,11 +16,108 @@ mod dot;
 
 /// Renders the tree as a DOT format graph, for visualization of its structure.
 pub fn dot<W: Write>(tree: &Tree, writer: &mut W) -> io::Result<()> {
-    dot::render(tree, false, writer)
+    dot::render(tree, false, false, writer)
 }
 
 /// Renders the tree as a DOT format graph, like [`Tree::render_dot`], but with the formatting
 /// of the DOT file more human-readable and well-indented.
 pub fn dot_pretty<W: Write>(tree: &Tree, writer: &mut W) -> io::Result<()> {
-    dot::render(tree, true, writer)
+    dot::render(tree, true, false, writer)
+}
+
+/// Renders only the witnessed portion of the tree as a DOT format graph: the paths from its
+/// witnessed commitments to the root, along with the siblings whose hashes make up their auth
+/// paths, leaving every other subtree collapsed.
+///
+/// If `pretty` is set, the DOT file is formatted like [`dot_pretty`].
+pub fn witnessed_dot<W: Write>(tree: &Tree, pretty: bool, writer: &mut W) -> io::Result<()> {
+    dot::render(tree, pretty, true, writer)
+}
+
+/// Renders the tree as an SVG image, by piping its DOT format graph through the `dot` command from
+/// Graphviz, which must be installed.
+pub fn svg<W: Write>(tree: &Tree, writer: &mut W) -> io::Result<()> {
+    pipe_svg(writer, |stdin| dot(tree, stdin))
+}
+
+/// Renders only the witnessed portion of the tree as an SVG image, like [`witnessed_dot`] piped
+/// through the `dot` command from Graphviz, which must be installed.
+pub fn witnessed_svg<W: Write>(tree: &Tree, writer: &mut W) -> io::Result<()> {
+    pipe_svg(writer, |stdin| witnessed_dot(tree, false, stdin))
+}
+
+fn pipe_svg<W: Write>(
+    writer: &mut W,
+    render: impl FnOnce(&mut ChildStdin) -> io::Result<()> + Send,
+) -> io::Result<()> {
+    let mut child = Command::new("dot")
+        .args(["-Tsvg"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    let mut stdin = child.stdin.take().expect("stdin of dot is piped");
+    let mut stdout = child.stdout.take().expect("stdout of dot is piped");
+
+    thread::scope(|scope| {
+        // Dropping stdin when rendering finishes closes it, so that dot can finish too
+        let render_thread = scope.spawn(move || {
+            render(&mut stdin)?;
+            stdin.flush()
+        });
+        io::copy(&mut stdout, writer)?;
+        render_thread
+            .join()
+            .expect("rendering to dot does not panic")
+    })?;
+
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!("dot exited unsuccessfully: {status}"),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_tct::{StateCommitment, Witness};
+
+    use super::*;
+
+    fn render(tree: &Tree, witnessed_only: bool) -> String {
+        let mut bytes = Vec::new();
+        if witnessed_only {
+            witnessed_dot(tree, true, &mut bytes).unwrap();
+        } else {
+            dot_pretty(tree, &mut bytes).unwrap();
+        }
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn witnessed_dot_collapses_unwitnessed_subtrees() {
+        let mut tree = Tree::new();
+        tree.insert(Witness::Keep, StateCommitment::try_from([0; 32]).unwrap())
+            .unwrap();
+        tree.end_block().unwrap();
+        for i in 1..4 {
+            tree.insert(Witness::Forget, StateCommitment::try_from([i; 32]).unwrap())
+                .unwrap();
+        }
+
+        let full = render(&tree, false);
+        let witnessed = render(&tree, true);
+
+        // Both include the witnessed commitment:
+        let commitment = "COMMITMENT_epoch_0_block_0_commitment_0";
+        assert!(full.contains(commitment));
+        assert!(witnessed.contains(commitment));
+
+        // Only the full rendering expands the block which has nothing witnessed in it:
+        let forgotten = "NODE_height_0_epoch_0_block_1_commitment_2";
+        assert!(full.contains(forgotten));
+        assert!(!witnessed.contains(forgotten));
+    }
 }
@@ -50,13 +50,22 @@ pub struct Writer<W: Write> {
     // Output properties
     pretty: bool,
     invisible_ordering_edges: bool,
+    // If set, only the subtrees containing these positions are expanded
+    witnessed: Option<BTreeSet<Position>>,
     // Inner mutable state
     indent: usize,
     writer: W,
 }
 
-pub fn render<W: Write>(tree: &Tree, pretty: bool, writer: &mut W) -> io::Result<()> {
-    dot::Writer::digraph(pretty, writer, |w| {
+pub fn render<W: Write>(
+    tree: &Tree,
+    pretty: bool,
+    witnessed_only: bool,
+    writer: &mut W,
+) -> io::Result<()> {
+    let witnessed =
+        witnessed_only.then(|| tree.commitments().map(|(position, _)| position).collect());
+    dot::Writer::digraph(pretty, witnessed, writer, |w| {
         let root = tree.structure();
         w.nodes_and_edges(root)?;
         w.connect_commitments(tree)?;
@@ -67,6 +76,7 @@ pub fn render<W: Write>(tree: &Tree, pretty: bool, writer: &mut W) -> io::Result
 impl<W: Write> Writer<W> {
     fn digraph(
         pretty: bool,
+        witnessed: Option<BTreeSet<Position>>,
         mut writer: W,
         graph: impl FnOnce(&mut Self) -> io::Result<()>,
     ) -> io::Result<()> {
@@ -77,6 +87,7 @@ impl<W: Write> Writer<W> {
             pretty,
             // Enable this if ordering=out override isn't sufficient to correctly order tree
             invisible_ordering_edges: false,
+            witnessed,
         };
         if dot_writer.pretty {
             writeln!(dot_writer.writer)?;
@@ -94,11 +105,20 @@ impl<W: Write> Writer<W> {
         Ok(())
     }
 
+    /// The children of the node to render, which are none if the node is collapsed, because
+    /// nothing witnessed is beneath it.
+    fn children<'tree>(&self, node: Node<'tree>) -> Vec<Node<'tree>> {
+        match &self.witnessed {
+            Some(witnessed) if witnessed.range(node.range()).next().is_none() => Vec::new(),
+            _ => node.children(),
+        }
+    }
+
     fn nodes_and_edges(&mut self, node: Node) -> io::Result<()> {
         let global_position = node.global_position();
         self.node(node)?; // The node itself
         self.node_commitment(node)?; // Its commitment below, if any
-        let children = node.children();
+        let children = self.children(node);
         for &child in children.iter() {
             // All its children, as subgraphs
             self.subtree(
@@ -106,7 +126,7 @@ impl<W: Write> Writer<W> {
                 child.position(),
                 global_position,
                 Some(child.place()),
-                child.children().is_empty(),
+                self.children(child).is_empty(),
                 matches!(
                     child.kind(),
                     Kind::Leaf {
@@ -486,7 +506,7 @@ impl<W: Write> Writer<W> {
 
     fn outgoing_edges(&mut self, node: Node) -> io::Result<()> {
         self.node_commitment_edge(node)?;
-        let children = node.children();
+        let children = self.children(node);
         let mut left: Option<Node> = None;
         for &child in children.iter() {
             if self.invisible_ordering_edges {
@@ -538,6 +558,7 @@ impl<W: Write> Writer<W> {
         let parent_id = self.node_name(parent.height(), parent.position(), Some(parent.place()));
         let child_id = self.node_name(child.height(), child.position(), Some(child.place()));
         let edge_id = self.edge_name(parent_id, child_id);
+        let expanded = !self.children(child).is_empty();
 
         self.line(|w| {
             // Edge specification
@@ -562,7 +583,7 @@ impl<W: Write> Writer<W> {
             write!(w, "[penwidth={PEN_WIDTH}]")?;
             let color = match (child.place(), child.height()) {
                 (Place::Frontier, 0 | 8 | 16) => FRONTIER_EDGE_COLOR,
-                (Place::Frontier, _) if !child.children().is_empty() => FRONTIER_EDGE_COLOR,
+                (Place::Frontier, _) if expanded => FRONTIER_EDGE_COLOR,
                 _ => "black",
             };
             write!(w, "[color=\"{color}\"]")
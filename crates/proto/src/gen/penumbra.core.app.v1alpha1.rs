@@ -36,6 +36,92 @@ impl ::prost::Name for TransactionsByHeightResponse {
         ::prost::alloc::format!("penumbra.core.app.v1alpha1.{}", Self::NAME)
     }
 }
+/// Requests a dry run of the enactment of a governance proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateProposalRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The proposal to simulate. Its ID is ignored, and replaced with the next proposal ID.
+    #[prost(message, optional, tag = "2")]
+    pub proposal: ::core::option::Option<
+        super::super::component::governance::v1alpha1::Proposal,
+    >,
+}
+impl ::prost::Name for SimulateProposalRequest {
+    const NAME: &'static str = "SimulateProposalRequest";
+    const PACKAGE: &'static str = "penumbra.core.app.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1alpha1.{}", Self::NAME)
+    }
+}
+/// The outcome of a dry run of the enactment of a governance proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateProposalResponse {
+    /// If non-empty, the proposal would fail to be submitted or enacted, for this reason.
+    #[prost(string, tag = "1")]
+    pub error: ::prost::alloc::string::String,
+    /// The app parameters before the proposal is enacted.
+    #[prost(message, optional, tag = "2")]
+    pub old_app_parameters: ::core::option::Option<AppParameters>,
+    /// The app parameters after the proposal is enacted, if it changes them.
+    #[prost(message, optional, tag = "3")]
+    pub new_app_parameters: ::core::option::Option<AppParameters>,
+    /// The events emitted while enacting the proposal.
+    #[prost(message, repeated, tag = "4")]
+    pub events: ::prost::alloc::vec::Vec<simulate_proposal_response::Event>,
+}
+/// Nested message and enum types in `SimulateProposalResponse`.
+pub mod simulate_proposal_response {
+    /// An ABCI event emitted while enacting the proposal.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Event {
+        #[prost(string, tag = "1")]
+        pub kind: ::prost::alloc::string::String,
+        #[prost(message, repeated, tag = "2")]
+        pub attributes: ::prost::alloc::vec::Vec<event::Attribute>,
+    }
+    /// Nested message and enum types in `Event`.
+    pub mod event {
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Attribute {
+            #[prost(string, tag = "1")]
+            pub key: ::prost::alloc::string::String,
+            #[prost(string, tag = "2")]
+            pub value: ::prost::alloc::string::String,
+        }
+        impl ::prost::Name for Attribute {
+            const NAME: &'static str = "Attribute";
+            const PACKAGE: &'static str = "penumbra.core.app.v1alpha1";
+            fn full_name() -> ::prost::alloc::string::String {
+                ::prost::alloc::format!(
+                    "penumbra.core.app.v1alpha1.SimulateProposalResponse.Event.{}",
+                    Self::NAME
+                )
+            }
+        }
+    }
+    impl ::prost::Name for Event {
+        const NAME: &'static str = "Event";
+        const PACKAGE: &'static str = "penumbra.core.app.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.app.v1alpha1.SimulateProposalResponse.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for SimulateProposalResponse {
+    const NAME: &'static str = "SimulateProposalResponse";
+    const PACKAGE: &'static str = "penumbra.core.app.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1alpha1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AppParameters {
@@ -334,6 +420,38 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Simulates the enactment of a governance proposal against the latest state,
+        /// without committing anything.
+        pub async fn simulate_proposal(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SimulateProposalRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateProposalResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.app.v1alpha1.QueryService/SimulateProposal",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.app.v1alpha1.QueryService",
+                        "SimulateProposal",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -360,6 +478,15 @@ pub mod query_service_server {
             tonic::Response<super::TransactionsByHeightResponse>,
             tonic::Status,
         >;
+        /// Simulates the enactment of a governance proposal against the latest state,
+        /// without committing anything.
+        async fn simulate_proposal(
+            &self,
+            request: tonic::Request<super::SimulateProposalRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateProposalResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the overall Penumbra application.
     #[derive(Debug)]
@@ -534,6 +661,53 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.app.v1alpha1.QueryService/SimulateProposal" => {
+                    #[allow(non_camel_case_types)]
+                    struct SimulateProposalSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::SimulateProposalRequest>
+                    for SimulateProposalSvc<T> {
+                        type Response = super::SimulateProposalResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SimulateProposalRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::simulate_proposal(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SimulateProposalSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
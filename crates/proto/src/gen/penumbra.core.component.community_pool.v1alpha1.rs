@@ -32,6 +32,37 @@ impl ::prost::Name for GenesisContent {
         )
     }
 }
+/// A schedule of payouts from the Community Pool to a single recipient, paid
+/// out at the end of each epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VestingSchedule {
+    /// The address receiving the payouts.
+    #[prost(message, optional, tag = "1")]
+    pub recipient: ::core::option::Option<super::super::super::keys::v1alpha1::Address>,
+    /// The total value to be paid out over the life of the schedule.
+    #[prost(message, optional, tag = "2")]
+    pub total: ::core::option::Option<super::super::super::asset::v1alpha1::Value>,
+    /// The first epoch in which funds vest.
+    #[prost(uint64, tag = "3")]
+    pub start_epoch: u64,
+    /// No payouts are made before this epoch. At the end of the cliff epoch, everything
+    /// vested since the start epoch is paid out at once.
+    #[prost(uint64, tag = "4")]
+    pub cliff_epoch: u64,
+    /// The amount vesting in each epoch, until the total has been paid out.
+    #[prost(message, optional, tag = "5")]
+    pub per_epoch: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
+}
+impl ::prost::Name for VestingSchedule {
+    const NAME: &'static str = "VestingSchedule";
+    const PACKAGE: &'static str = "penumbra.core.component.community_pool.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.community_pool.v1alpha1.{}", Self::NAME
+        )
+    }
+}
 /// Requests the list of all asset balances associated with the Community Pool.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -71,6 +102,46 @@ impl ::prost::Name for CommunityPoolAssetBalancesResponse {
         )
     }
 }
+/// Requests the list of all active vesting schedules.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VestingSchedulesRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+impl ::prost::Name for VestingSchedulesRequest {
+    const NAME: &'static str = "VestingSchedulesRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.community_pool.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.community_pool.v1alpha1.{}", Self::NAME
+        )
+    }
+}
+/// A single active vesting schedule.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VestingSchedulesResponse {
+    /// The identifier of the schedule, which is the ID of the proposal that registered it.
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    /// The terms of the schedule.
+    #[prost(message, optional, tag = "2")]
+    pub schedule: ::core::option::Option<VestingSchedule>,
+    /// The amount paid out under the schedule so far.
+    #[prost(message, optional, tag = "3")]
+    pub paid: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
+}
+impl ::prost::Name for VestingSchedulesResponse {
+    const NAME: &'static str = "VestingSchedulesResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.community_pool.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.community_pool.v1alpha1.{}", Self::NAME
+        )
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -190,6 +261,36 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        pub async fn vesting_schedules(
+            &mut self,
+            request: impl tonic::IntoRequest<super::VestingSchedulesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::VestingSchedulesResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.community_pool.v1alpha1.QueryService/VestingSchedules",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.community_pool.v1alpha1.QueryService",
+                        "VestingSchedules",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -216,6 +317,22 @@ pub mod query_service_server {
             tonic::Response<Self::CommunityPoolAssetBalancesStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the VestingSchedules method.
+        type VestingSchedulesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::VestingSchedulesResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        async fn vesting_schedules(
+            &self,
+            request: tonic::Request<super::VestingSchedulesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::VestingSchedulesStream>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the community_pool component.
     #[derive(Debug)]
@@ -351,6 +468,55 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.community_pool.v1alpha1.QueryService/VestingSchedules" => {
+                    #[allow(non_camel_case_types)]
+                    struct VestingSchedulesSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::VestingSchedulesRequest,
+                    > for VestingSchedulesSvc<T> {
+                        type Response = super::VestingSchedulesResponse;
+                        type ResponseStream = T::VestingSchedulesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::VestingSchedulesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::vesting_schedules(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = VestingSchedulesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
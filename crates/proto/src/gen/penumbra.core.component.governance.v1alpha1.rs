@@ -120,7 +120,9 @@ pub struct ValidatorVoteBody {
     /// The proposal being voted on.
     #[prost(uint64, tag = "1")]
     pub proposal: u64,
-    /// The vote.
+    /// The vote, if cast entirely for a single option.
+    ///
+    /// Exactly one of `vote` and `weighted_vote` is set.
     #[prost(message, optional, tag = "2")]
     pub vote: ::core::option::Option<Vote>,
     /// The validator identity.
@@ -136,6 +138,11 @@ pub struct ValidatorVoteBody {
     /// A justification of the vote.
     #[prost(message, optional, tag = "5")]
     pub reason: ::core::option::Option<ValidatorVoteReason>,
+    /// The vote, if split between options.
+    ///
+    /// The validator's voting power is divided between the options in proportion to the weights.
+    #[prost(message, optional, tag = "6")]
+    pub weighted_vote: ::core::option::Option<WeightedVote>,
 }
 impl ::prost::Name for ValidatorVoteBody {
     const NAME: &'static str = "ValidatorVoteBody";
@@ -179,7 +186,9 @@ pub struct DelegatorVoteBody {
     /// The start position of the proposal in the TCT.
     #[prost(uint64, tag = "2")]
     pub start_position: u64,
-    /// The vote.
+    /// The vote, if cast entirely for a single option.
+    ///
+    /// Exactly one of `vote` and `weighted_vote` is set.
     #[prost(message, optional, tag = "3")]
     pub vote: ::core::option::Option<Vote>,
     /// The value of the delegation note.
@@ -198,6 +207,11 @@ pub struct DelegatorVoteBody {
     pub rk: ::core::option::Option<
         super::super::super::super::crypto::decaf377_rdsa::v1alpha1::SpendVerificationKey,
     >,
+    /// The vote, if split between options.
+    ///
+    /// The weights must sum to `unbonded_amount`.
+    #[prost(message, optional, tag = "8")]
+    pub weighted_vote: ::core::option::Option<WeightedVote>,
 }
 impl ::prost::Name for DelegatorVoteBody {
     const NAME: &'static str = "DelegatorVoteBody";
@@ -279,7 +293,9 @@ pub struct DelegatorVotePlan {
     /// The start position of the proposal in the TCT.
     #[prost(uint64, tag = "2")]
     pub start_position: u64,
-    /// The vote to cast.
+    /// The vote to cast, if cast entirely for a single option.
+    ///
+    /// Exactly one of `vote` and `weighted_vote` is set.
     #[prost(message, optional, tag = "3")]
     pub vote: ::core::option::Option<Vote>,
     /// The delegation note to prove that we can vote.
@@ -302,6 +318,11 @@ pub struct DelegatorVotePlan {
     /// The second blinding factor to use for the ZK delegator vote proof.
     #[prost(bytes = "vec", tag = "9")]
     pub proof_blinding_s: ::prost::alloc::vec::Vec<u8>,
+    /// The vote to cast, if split between options.
+    ///
+    /// The weights must sum to `unbonded_amount`.
+    #[prost(message, optional, tag = "10")]
+    pub weighted_vote: ::core::option::Option<WeightedVote>,
 }
 impl ::prost::Name for DelegatorVotePlan {
     const NAME: &'static str = "DelegatorVotePlan";
@@ -426,6 +447,29 @@ impl ::prost::Name for Vote {
         )
     }
 }
+/// A vote whose voting power is split between the options, according to the given weights.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WeightedVote {
+    /// The weight given to voting yes.
+    #[prost(message, optional, tag = "1")]
+    pub yes: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
+    /// The weight given to voting no.
+    #[prost(message, optional, tag = "2")]
+    pub no: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
+    /// The weight given to abstaining.
+    #[prost(message, optional, tag = "3")]
+    pub abstain: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
+}
+impl ::prost::Name for WeightedVote {
+    const NAME: &'static str = "WeightedVote";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.governance.v1alpha1.{}", Self::NAME
+        )
+    }
+}
 /// The current state of a proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -673,6 +717,14 @@ pub struct Proposal {
     pub freeze_ibc_client: ::core::option::Option<proposal::FreezeIbcClient>,
     #[prost(message, optional, tag = "11")]
     pub unfreeze_ibc_client: ::core::option::Option<proposal::UnfreezeIbcClient>,
+    #[prost(message, optional, tag = "12")]
+    pub community_pool_vesting_grant: ::core::option::Option<
+        proposal::CommunityPoolVestingGrant,
+    >,
+    #[prost(message, optional, tag = "13")]
+    pub community_pool_vesting_cancel: ::core::option::Option<
+        proposal::CommunityPoolVestingCancel,
+    >,
 }
 /// Nested message and enum types in `Proposal`.
 pub mod proposal {
@@ -812,6 +864,45 @@ pub mod proposal {
             )
         }
     }
+    /// A Community Pool vesting grant registers a schedule of payouts from the Community Pool, which
+    /// are made at the end of each epoch until the schedule completes or is cancelled. The schedule
+    /// is identified by the ID of the proposal that registered it.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CommunityPoolVestingGrant {
+        #[prost(message, optional, tag = "1")]
+        pub schedule: ::core::option::Option<
+            super::super::super::community_pool::v1alpha1::VestingSchedule,
+        >,
+    }
+    impl ::prost::Name for CommunityPoolVestingGrant {
+        const NAME: &'static str = "CommunityPoolVestingGrant";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1alpha1.Proposal.{}", Self::NAME
+            )
+        }
+    }
+    /// A Community Pool vesting cancellation stops any further payouts from a vesting schedule
+    /// registered by a previous proposal. Funds that have not yet been paid out stay in the
+    /// Community Pool.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CommunityPoolVestingCancel {
+        /// The ID of the proposal that registered the vesting schedule.
+        #[prost(uint64, tag = "1")]
+        pub schedule_id: u64,
+    }
+    impl ::prost::Name for CommunityPoolVestingCancel {
+        const NAME: &'static str = "CommunityPoolVestingCancel";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1alpha1.Proposal.{}", Self::NAME
+            )
+        }
+    }
 }
 impl ::prost::Name for Proposal {
     const NAME: &'static str = "Proposal";
@@ -1023,7 +1114,7 @@ impl ::prost::Name for ValidatorVotesRequest {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorVotesResponse {
-    /// The vote.
+    /// The vote, if cast entirely for a single option.
     #[prost(message, optional, tag = "1")]
     pub vote: ::core::option::Option<Vote>,
     /// The validator identity.
@@ -1031,6 +1122,9 @@ pub struct ValidatorVotesResponse {
     pub identity_key: ::core::option::Option<
         super::super::super::keys::v1alpha1::IdentityKey,
     >,
+    /// The vote, if split between options.
+    #[prost(message, optional, tag = "3")]
+    pub weighted_vote: ::core::option::Option<WeightedVote>,
 }
 impl ::prost::Name for ValidatorVotesResponse {
     const NAME: &'static str = "ValidatorVotesResponse";
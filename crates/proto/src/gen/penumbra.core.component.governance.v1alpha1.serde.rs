@@ -973,6 +973,9 @@ impl serde::Serialize for DelegatorVoteBody {
         if self.rk.is_some() {
             len += 1;
         }
+        if self.weighted_vote.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.DelegatorVoteBody", len)?;
         if self.proposal != 0 {
             #[allow(clippy::needless_borrow)]
@@ -997,6 +1000,9 @@ impl serde::Serialize for DelegatorVoteBody {
         if let Some(v) = self.rk.as_ref() {
             struct_ser.serialize_field("rk", v)?;
         }
+        if let Some(v) = self.weighted_vote.as_ref() {
+            struct_ser.serialize_field("weightedVote", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1016,6 +1022,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
             "unbondedAmount",
             "nullifier",
             "rk",
+            "weighted_vote",
+            "weightedVote",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1027,6 +1035,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
             UnbondedAmount,
             Nullifier,
             Rk,
+            WeightedVote,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1055,6 +1064,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                             "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
                             "nullifier" => Ok(GeneratedField::Nullifier),
                             "rk" => Ok(GeneratedField::Rk),
+                            "weightedVote" | "weighted_vote" => Ok(GeneratedField::WeightedVote),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1081,6 +1091,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                 let mut unbonded_amount__ = None;
                 let mut nullifier__ = None;
                 let mut rk__ = None;
+                let mut weighted_vote__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -1129,6 +1140,12 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                             }
                             rk__ = map_.next_value()?;
                         }
+                        GeneratedField::WeightedVote => {
+                            if weighted_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("weightedVote"));
+                            }
+                            weighted_vote__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(DelegatorVoteBody {
@@ -1139,6 +1156,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                     unbonded_amount: unbonded_amount__,
                     nullifier: nullifier__,
                     rk: rk__,
+                    weighted_vote: weighted_vote__,
                 })
             }
         }
@@ -1180,6 +1198,9 @@ impl serde::Serialize for DelegatorVotePlan {
         if !self.proof_blinding_s.is_empty() {
             len += 1;
         }
+        if self.weighted_vote.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.DelegatorVotePlan", len)?;
         if self.proposal != 0 {
             #[allow(clippy::needless_borrow)]
@@ -1214,6 +1235,9 @@ impl serde::Serialize for DelegatorVotePlan {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
         }
+        if let Some(v) = self.weighted_vote.as_ref() {
+            struct_ser.serialize_field("weightedVote", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1239,6 +1263,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
             "proofBlindingR",
             "proof_blinding_s",
             "proofBlindingS",
+            "weighted_vote",
+            "weightedVote",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1252,6 +1278,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
             Randomizer,
             ProofBlindingR,
             ProofBlindingS,
+            WeightedVote,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1282,6 +1309,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                             "randomizer" => Ok(GeneratedField::Randomizer),
                             "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
                             "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            "weightedVote" | "weighted_vote" => Ok(GeneratedField::WeightedVote),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1310,6 +1338,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                 let mut randomizer__ = None;
                 let mut proof_blinding_r__ = None;
                 let mut proof_blinding_s__ = None;
+                let mut weighted_vote__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -1378,6 +1407,12 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::WeightedVote => {
+                            if weighted_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("weightedVote"));
+                            }
+                            weighted_vote__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(DelegatorVotePlan {
@@ -1390,6 +1425,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                     randomizer: randomizer__.unwrap_or_default(),
                     proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
                     proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                    weighted_vote: weighted_vote__,
                 })
             }
         }
@@ -2190,6 +2226,12 @@ impl serde::Serialize for Proposal {
         if self.unfreeze_ibc_client.is_some() {
             len += 1;
         }
+        if self.community_pool_vesting_grant.is_some() {
+            len += 1;
+        }
+        if self.community_pool_vesting_cancel.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal", len)?;
         if self.id != 0 {
             #[allow(clippy::needless_borrow)]
@@ -2222,6 +2264,12 @@ impl serde::Serialize for Proposal {
         if let Some(v) = self.unfreeze_ibc_client.as_ref() {
             struct_ser.serialize_field("unfreezeIbcClient", v)?;
         }
+        if let Some(v) = self.community_pool_vesting_grant.as_ref() {
+            struct_ser.serialize_field("communityPoolVestingGrant", v)?;
+        }
+        if let Some(v) = self.community_pool_vesting_cancel.as_ref() {
+            struct_ser.serialize_field("communityPoolVestingCancel", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2247,6 +2295,10 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             "freezeIbcClient",
             "unfreeze_ibc_client",
             "unfreezeIbcClient",
+            "community_pool_vesting_grant",
+            "communityPoolVestingGrant",
+            "community_pool_vesting_cancel",
+            "communityPoolVestingCancel",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2261,6 +2313,8 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             UpgradePlan,
             FreezeIbcClient,
             UnfreezeIbcClient,
+            CommunityPoolVestingGrant,
+            CommunityPoolVestingCancel,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2292,6 +2346,8 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
                             "freezeIbcClient" | "freeze_ibc_client" => Ok(GeneratedField::FreezeIbcClient),
                             "unfreezeIbcClient" | "unfreeze_ibc_client" => Ok(GeneratedField::UnfreezeIbcClient),
+                            "communityPoolVestingGrant" | "community_pool_vesting_grant" => Ok(GeneratedField::CommunityPoolVestingGrant),
+                            "communityPoolVestingCancel" | "community_pool_vesting_cancel" => Ok(GeneratedField::CommunityPoolVestingCancel),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2321,6 +2377,8 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                 let mut upgrade_plan__ = None;
                 let mut freeze_ibc_client__ = None;
                 let mut unfreeze_ibc_client__ = None;
+                let mut community_pool_vesting_grant__ = None;
+                let mut community_pool_vesting_cancel__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -2385,6 +2443,18 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             }
                             unfreeze_ibc_client__ = map_.next_value()?;
                         }
+                        GeneratedField::CommunityPoolVestingGrant => {
+                            if community_pool_vesting_grant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolVestingGrant"));
+                            }
+                            community_pool_vesting_grant__ = map_.next_value()?;
+                        }
+                        GeneratedField::CommunityPoolVestingCancel => {
+                            if community_pool_vesting_cancel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolVestingCancel"));
+                            }
+                            community_pool_vesting_cancel__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(Proposal {
@@ -2398,6 +2468,8 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                     upgrade_plan: upgrade_plan__,
                     freeze_ibc_client: freeze_ibc_client__,
                     unfreeze_ibc_client: unfreeze_ibc_client__,
+                    community_pool_vesting_grant: community_pool_vesting_grant__,
+                    community_pool_vesting_cancel: community_pool_vesting_cancel__,
                 })
             }
         }
@@ -2496,6 +2568,192 @@ impl<'de> serde::Deserialize<'de> for proposal::CommunityPoolSpend {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.CommunityPoolSpend", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for proposal::CommunityPoolVestingCancel {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.schedule_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.CommunityPoolVestingCancel", len)?;
+        if self.schedule_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("scheduleId", ToString::to_string(&self.schedule_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::CommunityPoolVestingCancel {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "schedule_id",
+            "scheduleId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduleId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduleId" | "schedule_id" => Ok(GeneratedField::ScheduleId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::CommunityPoolVestingCancel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.CommunityPoolVestingCancel")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::CommunityPoolVestingCancel, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut schedule_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduleId => {
+                            if schedule_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleId"));
+                            }
+                            schedule_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(proposal::CommunityPoolVestingCancel {
+                    schedule_id: schedule_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.CommunityPoolVestingCancel", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::CommunityPoolVestingGrant {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.schedule.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.CommunityPoolVestingGrant", len)?;
+        if let Some(v) = self.schedule.as_ref() {
+            struct_ser.serialize_field("schedule", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::CommunityPoolVestingGrant {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "schedule",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Schedule,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "schedule" => Ok(GeneratedField::Schedule),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::CommunityPoolVestingGrant;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.CommunityPoolVestingGrant")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::CommunityPoolVestingGrant, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut schedule__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Schedule => {
+                            if schedule__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("schedule"));
+                            }
+                            schedule__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(proposal::CommunityPoolVestingGrant {
+                    schedule: schedule__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.CommunityPoolVestingGrant", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for proposal::Emergency {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -5643,6 +5901,9 @@ impl serde::Serialize for ValidatorVoteBody {
         if self.reason.is_some() {
             len += 1;
         }
+        if self.weighted_vote.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.ValidatorVoteBody", len)?;
         if self.proposal != 0 {
             #[allow(clippy::needless_borrow)]
@@ -5660,6 +5921,9 @@ impl serde::Serialize for ValidatorVoteBody {
         if let Some(v) = self.reason.as_ref() {
             struct_ser.serialize_field("reason", v)?;
         }
+        if let Some(v) = self.weighted_vote.as_ref() {
+            struct_ser.serialize_field("weightedVote", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -5677,6 +5941,8 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
             "governance_key",
             "governanceKey",
             "reason",
+            "weighted_vote",
+            "weightedVote",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -5686,6 +5952,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
             IdentityKey,
             GovernanceKey,
             Reason,
+            WeightedVote,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5712,6 +5979,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
                             "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
                             "governanceKey" | "governance_key" => Ok(GeneratedField::GovernanceKey),
                             "reason" => Ok(GeneratedField::Reason),
+                            "weightedVote" | "weighted_vote" => Ok(GeneratedField::WeightedVote),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5736,6 +6004,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
                 let mut identity_key__ = None;
                 let mut governance_key__ = None;
                 let mut reason__ = None;
+                let mut weighted_vote__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -5770,6 +6039,12 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
                             }
                             reason__ = map_.next_value()?;
                         }
+                        GeneratedField::WeightedVote => {
+                            if weighted_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("weightedVote"));
+                            }
+                            weighted_vote__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(ValidatorVoteBody {
@@ -5778,6 +6053,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
                     identity_key: identity_key__,
                     governance_key: governance_key__,
                     reason: reason__,
+                    weighted_vote: weighted_vote__,
                 })
             }
         }
@@ -6002,6 +6278,9 @@ impl serde::Serialize for ValidatorVotesResponse {
         if self.identity_key.is_some() {
             len += 1;
         }
+        if self.weighted_vote.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.ValidatorVotesResponse", len)?;
         if let Some(v) = self.vote.as_ref() {
             struct_ser.serialize_field("vote", v)?;
@@ -6009,6 +6288,9 @@ impl serde::Serialize for ValidatorVotesResponse {
         if let Some(v) = self.identity_key.as_ref() {
             struct_ser.serialize_field("identityKey", v)?;
         }
+        if let Some(v) = self.weighted_vote.as_ref() {
+            struct_ser.serialize_field("weightedVote", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -6022,12 +6304,15 @@ impl<'de> serde::Deserialize<'de> for ValidatorVotesResponse {
             "vote",
             "identity_key",
             "identityKey",
+            "weighted_vote",
+            "weightedVote",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Vote,
             IdentityKey,
+            WeightedVote,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -6051,6 +6336,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVotesResponse {
                         match value {
                             "vote" => Ok(GeneratedField::Vote),
                             "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "weightedVote" | "weighted_vote" => Ok(GeneratedField::WeightedVote),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -6072,6 +6358,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorVotesResponse {
             {
                 let mut vote__ = None;
                 let mut identity_key__ = None;
+                let mut weighted_vote__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Vote => {
@@ -6086,11 +6373,18 @@ impl<'de> serde::Deserialize<'de> for ValidatorVotesResponse {
                             }
                             identity_key__ = map_.next_value()?;
                         }
+                        GeneratedField::WeightedVote => {
+                            if weighted_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("weightedVote"));
+                            }
+                            weighted_vote__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(ValidatorVotesResponse {
                     vote: vote__,
                     identity_key: identity_key__,
+                    weighted_vote: weighted_vote__,
                 })
             }
         }
@@ -6493,6 +6787,131 @@ impl<'de> serde::Deserialize<'de> for VotingPowerAtProposalStartResponse {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.VotingPowerAtProposalStartResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for WeightedVote {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.yes.is_some() {
+            len += 1;
+        }
+        if self.no.is_some() {
+            len += 1;
+        }
+        if self.abstain.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.WeightedVote", len)?;
+        if let Some(v) = self.yes.as_ref() {
+            struct_ser.serialize_field("yes", v)?;
+        }
+        if let Some(v) = self.no.as_ref() {
+            struct_ser.serialize_field("no", v)?;
+        }
+        if let Some(v) = self.abstain.as_ref() {
+            struct_ser.serialize_field("abstain", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WeightedVote {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "yes",
+            "no",
+            "abstain",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Yes,
+            No,
+            Abstain,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "yes" => Ok(GeneratedField::Yes),
+                            "no" => Ok(GeneratedField::No),
+                            "abstain" => Ok(GeneratedField::Abstain),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WeightedVote;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.WeightedVote")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WeightedVote, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut yes__ = None;
+                let mut no__ = None;
+                let mut abstain__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Yes => {
+                            if yes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("yes"));
+                            }
+                            yes__ = map_.next_value()?;
+                        }
+                        GeneratedField::No => {
+                            if no__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("no"));
+                            }
+                            no__ = map_.next_value()?;
+                        }
+                        GeneratedField::Abstain => {
+                            if abstain__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("abstain"));
+                            }
+                            abstain__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WeightedVote {
+                    yes: yes__,
+                    no: no__,
+                    abstain: abstain__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.WeightedVote", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ZkDelegatorVoteProof {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -225,6 +225,49 @@ impl ::prost::Name for EventBlockRoot {
         ::prost::alloc::format!("penumbra.core.component.sct.v1alpha1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SctFrontierRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The height from which the client needs to scan the chain.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+}
+impl ::prost::Name for SctFrontierRequest {
+    const NAME: &'static str = "SctFrontierRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.sct.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.sct.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SctFrontierResponse {
+    /// The height of the block at the end of which the frontier was taken.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The bincode-serialized `penumbra_tct::storage::Updates` which load the
+    /// frontier into empty storage, with every commitment forgotten.
+    #[prost(bytes = "vec", tag = "2")]
+    pub frontier: ::prost::alloc::vec::Vec<u8>,
+    /// The gas prices in effect at the end of the block at `height`.
+    #[prost(message, optional, tag = "3")]
+    pub gas_prices: ::core::option::Option<super::super::fee::v1alpha1::GasPrices>,
+    /// The FMD parameters in effect at the end of the block at `height`.
+    #[prost(message, optional, tag = "4")]
+    pub fmd_parameters: ::core::option::Option<
+        super::super::chain::v1alpha1::FmdParameters,
+    >,
+}
+impl ::prost::Name for SctFrontierResponse {
+    const NAME: &'static str = "SctFrontierResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.sct.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.sct.v1alpha1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -312,6 +355,39 @@ pub mod query_service_client {
             self.inner = self.inner.max_encoding_message_size(limit);
             self
         }
+        /// Get the frontier of the state commitment tree as of the end of the last
+        /// epoch which ended before the given height, so that clients which can't own
+        /// any earlier state commitments can start synchronizing from there.
+        pub async fn sct_frontier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SctFrontierRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SctFrontierResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.sct.v1alpha1.QueryService/SctFrontier",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.sct.v1alpha1.QueryService",
+                        "SctFrontier",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -321,7 +397,18 @@ pub mod query_service_server {
     use tonic::codegen::*;
     /// Generated trait containing gRPC methods that should be implemented for use with QueryServiceServer.
     #[async_trait]
-    pub trait QueryService: Send + Sync + 'static {}
+    pub trait QueryService: Send + Sync + 'static {
+        /// Get the frontier of the state commitment tree as of the end of the last
+        /// epoch which ended before the given height, so that clients which can't own
+        /// any earlier state commitments can start synchronizing from there.
+        async fn sct_frontier(
+            &self,
+            request: tonic::Request<super::SctFrontierRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SctFrontierResponse>,
+            tonic::Status,
+        >;
+    }
     /// Query operations for the SCT component.
     #[derive(Debug)]
     pub struct QueryServiceServer<T: QueryService> {
@@ -402,6 +489,52 @@ pub mod query_service_server {
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
+                "/penumbra.core.component.sct.v1alpha1.QueryService/SctFrontier" => {
+                    #[allow(non_camel_case_types)]
+                    struct SctFrontierSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::SctFrontierRequest>
+                    for SctFrontierSvc<T> {
+                        type Response = super::SctFrontierResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SctFrontierRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::sct_frontier(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SctFrontierSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
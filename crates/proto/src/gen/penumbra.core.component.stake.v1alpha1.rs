@@ -433,6 +433,108 @@ impl ::prost::Name for Undelegate {
         ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
     }
 }
+/// A transaction action moving stake from one validator's delegation pool to
+/// another's, without waiting for the unbonding period.
+///
+/// The redelegated stake remains subject to slashing of the source validator
+/// until the end of the unbonding period that an undelegation started in the
+/// same epoch would have had.  Until then, the destination validator's
+/// delegation tokens are held as "redelegation tokens", which are converted
+/// into delegation tokens, less any penalty, by a `RedelegateClaim`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Redelegate {
+    /// The identity key of the validator to redelegate from.
+    #[prost(message, optional, tag = "1")]
+    pub from_validator_identity: ::core::option::Option<
+        super::super::super::keys::v1alpha1::IdentityKey,
+    >,
+    /// The identity key of the validator to redelegate to.
+    #[prost(message, optional, tag = "2")]
+    pub to_validator_identity: ::core::option::Option<
+        super::super::super::keys::v1alpha1::IdentityKey,
+    >,
+    /// The index of the epoch in which this redelegation was performed.
+    /// The redelegation takes effect in the next epoch.
+    #[prost(uint64, tag = "3")]
+    pub epoch_index: u64,
+    /// The amount of the source validator's delegation tokens consumed by this action.
+    #[prost(message, optional, tag = "4")]
+    pub from_delegation_amount: ::core::option::Option<
+        super::super::super::num::v1alpha1::Amount,
+    >,
+    /// The amount of stake moved between the delegation pools, in units of unbonded stake.
+    ///
+    /// This is implied by the source validator's exchange rate in the specified epoch
+    /// (and should be checked in transaction validation!), but including it allows
+    /// stateless verification that the transaction is internally consistent.
+    #[prost(message, optional, tag = "5")]
+    pub unbonded_amount: ::core::option::Option<
+        super::super::super::num::v1alpha1::Amount,
+    >,
+    /// The amount of the destination validator's delegation tokens redelegated to,
+    /// produced by this action as redelegation tokens.
+    ///
+    /// This is implied by the destination validator's exchange rate in the specified epoch
+    /// (and should be checked in transaction validation!), but including it allows
+    /// stateless verification that the transaction is internally consistent.
+    #[prost(message, optional, tag = "6")]
+    pub to_delegation_amount: ::core::option::Option<
+        super::super::super::num::v1alpha1::Amount,
+    >,
+}
+impl ::prost::Name for Redelegate {
+    const NAME: &'static str = "Redelegate";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
+    }
+}
+/// A transaction action finishing a redelegation, converting the redelegation
+/// tokens it produced into the destination validator's delegation tokens, less
+/// any penalty applied to the source validator while the redelegated stake was
+/// still subject to its slashing.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RedelegateClaim {
+    /// The identity key of the validator redelegated from.
+    #[prost(message, optional, tag = "1")]
+    pub from_validator_identity: ::core::option::Option<
+        super::super::super::keys::v1alpha1::IdentityKey,
+    >,
+    /// The identity key of the validator redelegated to.
+    #[prost(message, optional, tag = "2")]
+    pub to_validator_identity: ::core::option::Option<
+        super::super::super::keys::v1alpha1::IdentityKey,
+    >,
+    /// The index of the epoch in which the redelegation was performed.
+    #[prost(uint64, tag = "3")]
+    pub start_epoch_index: u64,
+    /// The penalty applied to the source validator over the redelegation's unbonding period.
+    #[prost(message, optional, tag = "4")]
+    pub penalty: ::core::option::Option<Penalty>,
+    /// The amount of redelegation tokens consumed by this action.
+    #[prost(message, optional, tag = "5")]
+    pub escrowed_amount: ::core::option::Option<
+        super::super::super::num::v1alpha1::Amount,
+    >,
+    /// The amount of the destination validator's delegation tokens produced by this action.
+    ///
+    /// This is implied by the penalty and the escrowed amount (and should be checked in
+    /// transaction validation!), but including it allows stateless verification that the
+    /// transaction is internally consistent.
+    #[prost(message, optional, tag = "6")]
+    pub delegation_amount: ::core::option::Option<
+        super::super::super::num::v1alpha1::Amount,
+    >,
+}
+impl ::prost::Name for RedelegateClaim {
+    const NAME: &'static str = "RedelegateClaim";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
+    }
+}
 /// A transaction action finishing an undelegation, converting (slashable)
 /// "unbonding tokens" to (unslashable) staking tokens.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -516,7 +618,7 @@ impl ::prost::Name for UndelegateClaimPlan {
         ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
     }
 }
-/// A list of pending delegations and undelegations.
+/// A list of pending delegations, undelegations and redelegations.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DelegationChanges {
@@ -524,6 +626,8 @@ pub struct DelegationChanges {
     pub delegations: ::prost::alloc::vec::Vec<Delegate>,
     #[prost(message, repeated, tag = "2")]
     pub undelegations: ::prost::alloc::vec::Vec<Undelegate>,
+    #[prost(message, repeated, tag = "3")]
+    pub redelegations: ::prost::alloc::vec::Vec<Redelegate>,
 }
 impl ::prost::Name for DelegationChanges {
     const NAME: &'static str = "DelegationChanges";
@@ -710,6 +814,49 @@ impl ::prost::Name for CurrentValidatorRateResponse {
         ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
     }
 }
+/// Requests a validator's historical rate data over a range of epochs.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorRateHistoryRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<
+        super::super::super::keys::v1alpha1::IdentityKey,
+    >,
+    /// The first epoch to return rate data for (inclusive).
+    #[prost(uint64, tag = "3")]
+    pub start_epoch_index: u64,
+    /// The last epoch to return rate data for (inclusive).
+    #[prost(uint64, tag = "4")]
+    pub end_epoch_index: u64,
+}
+impl ::prost::Name for ValidatorRateHistoryRequest {
+    const NAME: &'static str = "ValidatorRateHistoryRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
+    }
+}
+/// The rate data for a validator in a single epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorRateHistoryResponse {
+    /// The validator's rate data in the epoch.
+    #[prost(message, optional, tag = "1")]
+    pub rate_data: ::core::option::Option<RateData>,
+    /// The base rate data in the same epoch.
+    #[prost(message, optional, tag = "2")]
+    pub base_rate_data: ::core::option::Option<BaseRateData>,
+}
+impl ::prost::Name for ValidatorRateHistoryResponse {
+    const NAME: &'static str = "ValidatorRateHistoryResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
+    }
+}
 /// Staking configuration data.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -969,6 +1116,39 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Queries a validator's rate data, and the base rate data, over a range of epochs.
+        pub async fn validator_rate_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorRateHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::ValidatorRateHistoryResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1alpha1.QueryService/ValidatorRateHistory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1alpha1.QueryService",
+                        "ValidatorRateHistory",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -1014,6 +1194,23 @@ pub mod query_service_server {
             tonic::Response<super::CurrentValidatorRateResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the ValidatorRateHistory method.
+        type ValidatorRateHistoryStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ValidatorRateHistoryResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Queries a validator's rate data, and the base rate data, over a range of epochs.
+        async fn validator_rate_history(
+            &self,
+            request: tonic::Request<super::ValidatorRateHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ValidatorRateHistoryStream>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the staking component.
     #[derive(Debug)]
@@ -1282,6 +1479,55 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.stake.v1alpha1.QueryService/ValidatorRateHistory" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorRateHistorySvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::ValidatorRateHistoryRequest,
+                    > for ValidatorRateHistorySvc<T> {
+                        type Response = super::ValidatorRateHistoryResponse;
+                        type ResponseStream = T::ValidatorRateHistoryStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorRateHistoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_rate_history(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorRateHistorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -782,6 +782,9 @@ impl serde::Serialize for DelegationChanges {
         if !self.undelegations.is_empty() {
             len += 1;
         }
+        if !self.redelegations.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.DelegationChanges", len)?;
         if !self.delegations.is_empty() {
             struct_ser.serialize_field("delegations", &self.delegations)?;
@@ -789,6 +792,9 @@ impl serde::Serialize for DelegationChanges {
         if !self.undelegations.is_empty() {
             struct_ser.serialize_field("undelegations", &self.undelegations)?;
         }
+        if !self.redelegations.is_empty() {
+            struct_ser.serialize_field("redelegations", &self.redelegations)?;
+        }
         struct_ser.end()
     }
 }
@@ -801,12 +807,14 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
         const FIELDS: &[&str] = &[
             "delegations",
             "undelegations",
+            "redelegations",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Delegations,
             Undelegations,
+            Redelegations,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -830,6 +838,7 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
                         match value {
                             "delegations" => Ok(GeneratedField::Delegations),
                             "undelegations" => Ok(GeneratedField::Undelegations),
+                            "redelegations" => Ok(GeneratedField::Redelegations),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -851,6 +860,7 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
             {
                 let mut delegations__ = None;
                 let mut undelegations__ = None;
+                let mut redelegations__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Delegations => {
@@ -865,11 +875,18 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
                             }
                             undelegations__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Redelegations => {
+                            if redelegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegations"));
+                            }
+                            redelegations__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(DelegationChanges {
                     delegations: delegations__.unwrap_or_default(),
                     undelegations: undelegations__.unwrap_or_default(),
+                    redelegations: redelegations__.unwrap_or_default(),
                 })
             }
         }
@@ -1550,6 +1567,375 @@ impl<'de> serde::Deserialize<'de> for RateData {
         deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.RateData", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for Redelegate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.from_validator_identity.is_some() {
+            len += 1;
+        }
+        if self.to_validator_identity.is_some() {
+            len += 1;
+        }
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if self.from_delegation_amount.is_some() {
+            len += 1;
+        }
+        if self.unbonded_amount.is_some() {
+            len += 1;
+        }
+        if self.to_delegation_amount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.Redelegate", len)?;
+        if let Some(v) = self.from_validator_identity.as_ref() {
+            struct_ser.serialize_field("fromValidatorIdentity", v)?;
+        }
+        if let Some(v) = self.to_validator_identity.as_ref() {
+            struct_ser.serialize_field("toValidatorIdentity", v)?;
+        }
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if let Some(v) = self.from_delegation_amount.as_ref() {
+            struct_ser.serialize_field("fromDelegationAmount", v)?;
+        }
+        if let Some(v) = self.unbonded_amount.as_ref() {
+            struct_ser.serialize_field("unbondedAmount", v)?;
+        }
+        if let Some(v) = self.to_delegation_amount.as_ref() {
+            struct_ser.serialize_field("toDelegationAmount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Redelegate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "from_validator_identity",
+            "fromValidatorIdentity",
+            "to_validator_identity",
+            "toValidatorIdentity",
+            "epoch_index",
+            "epochIndex",
+            "from_delegation_amount",
+            "fromDelegationAmount",
+            "unbonded_amount",
+            "unbondedAmount",
+            "to_delegation_amount",
+            "toDelegationAmount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FromValidatorIdentity,
+            ToValidatorIdentity,
+            EpochIndex,
+            FromDelegationAmount,
+            UnbondedAmount,
+            ToDelegationAmount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fromValidatorIdentity" | "from_validator_identity" => Ok(GeneratedField::FromValidatorIdentity),
+                            "toValidatorIdentity" | "to_validator_identity" => Ok(GeneratedField::ToValidatorIdentity),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "fromDelegationAmount" | "from_delegation_amount" => Ok(GeneratedField::FromDelegationAmount),
+                            "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
+                            "toDelegationAmount" | "to_delegation_amount" => Ok(GeneratedField::ToDelegationAmount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Redelegate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1alpha1.Redelegate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Redelegate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut from_validator_identity__ = None;
+                let mut to_validator_identity__ = None;
+                let mut epoch_index__ = None;
+                let mut from_delegation_amount__ = None;
+                let mut unbonded_amount__ = None;
+                let mut to_delegation_amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FromValidatorIdentity => {
+                            if from_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromValidatorIdentity"));
+                            }
+                            from_validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::ToValidatorIdentity => {
+                            if to_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toValidatorIdentity"));
+                            }
+                            to_validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FromDelegationAmount => {
+                            if from_delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromDelegationAmount"));
+                            }
+                            from_delegation_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::UnbondedAmount => {
+                            if unbonded_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondedAmount"));
+                            }
+                            unbonded_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::ToDelegationAmount => {
+                            if to_delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toDelegationAmount"));
+                            }
+                            to_delegation_amount__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Redelegate {
+                    from_validator_identity: from_validator_identity__,
+                    to_validator_identity: to_validator_identity__,
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    from_delegation_amount: from_delegation_amount__,
+                    unbonded_amount: unbonded_amount__,
+                    to_delegation_amount: to_delegation_amount__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.Redelegate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RedelegateClaim {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.from_validator_identity.is_some() {
+            len += 1;
+        }
+        if self.to_validator_identity.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        if self.escrowed_amount.is_some() {
+            len += 1;
+        }
+        if self.delegation_amount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.RedelegateClaim", len)?;
+        if let Some(v) = self.from_validator_identity.as_ref() {
+            struct_ser.serialize_field("fromValidatorIdentity", v)?;
+        }
+        if let Some(v) = self.to_validator_identity.as_ref() {
+            struct_ser.serialize_field("toValidatorIdentity", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        if let Some(v) = self.escrowed_amount.as_ref() {
+            struct_ser.serialize_field("escrowedAmount", v)?;
+        }
+        if let Some(v) = self.delegation_amount.as_ref() {
+            struct_ser.serialize_field("delegationAmount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RedelegateClaim {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "from_validator_identity",
+            "fromValidatorIdentity",
+            "to_validator_identity",
+            "toValidatorIdentity",
+            "start_epoch_index",
+            "startEpochIndex",
+            "penalty",
+            "escrowed_amount",
+            "escrowedAmount",
+            "delegation_amount",
+            "delegationAmount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FromValidatorIdentity,
+            ToValidatorIdentity,
+            StartEpochIndex,
+            Penalty,
+            EscrowedAmount,
+            DelegationAmount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fromValidatorIdentity" | "from_validator_identity" => Ok(GeneratedField::FromValidatorIdentity),
+                            "toValidatorIdentity" | "to_validator_identity" => Ok(GeneratedField::ToValidatorIdentity),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            "escrowedAmount" | "escrowed_amount" => Ok(GeneratedField::EscrowedAmount),
+                            "delegationAmount" | "delegation_amount" => Ok(GeneratedField::DelegationAmount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RedelegateClaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1alpha1.RedelegateClaim")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RedelegateClaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut from_validator_identity__ = None;
+                let mut to_validator_identity__ = None;
+                let mut start_epoch_index__ = None;
+                let mut penalty__ = None;
+                let mut escrowed_amount__ = None;
+                let mut delegation_amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FromValidatorIdentity => {
+                            if from_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromValidatorIdentity"));
+                            }
+                            from_validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::ToValidatorIdentity => {
+                            if to_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toValidatorIdentity"));
+                            }
+                            to_validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
+                            }
+                            penalty__ = map_.next_value()?;
+                        }
+                        GeneratedField::EscrowedAmount => {
+                            if escrowed_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("escrowedAmount"));
+                            }
+                            escrowed_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::DelegationAmount => {
+                            if delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegationAmount"));
+                            }
+                            delegation_amount__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RedelegateClaim {
+                    from_validator_identity: from_validator_identity__,
+                    to_validator_identity: to_validator_identity__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    penalty: penalty__,
+                    escrowed_amount: escrowed_amount__,
+                    delegation_amount: delegation_amount__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.RedelegateClaim", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for StakeParameters {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -3537,6 +3923,268 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyResponse {
         deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.ValidatorPenaltyResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ValidatorRateHistoryRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.end_epoch_index != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.ValidatorRateHistoryRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if self.end_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endEpochIndex", ToString::to_string(&self.end_epoch_index).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorRateHistoryRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "identity_key",
+            "identityKey",
+            "start_epoch_index",
+            "startEpochIndex",
+            "end_epoch_index",
+            "endEpochIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            IdentityKey,
+            StartEpochIndex,
+            EndEpochIndex,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorRateHistoryRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1alpha1.ValidatorRateHistoryRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorRateHistoryRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut identity_key__ = None;
+                let mut start_epoch_index__ = None;
+                let mut end_epoch_index__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndEpochIndex => {
+                            if end_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endEpochIndex"));
+                            }
+                            end_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ValidatorRateHistoryRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    identity_key: identity_key__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    end_epoch_index: end_epoch_index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.ValidatorRateHistoryRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorRateHistoryResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rate_data.is_some() {
+            len += 1;
+        }
+        if self.base_rate_data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.ValidatorRateHistoryResponse", len)?;
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
+        }
+        if let Some(v) = self.base_rate_data.as_ref() {
+            struct_ser.serialize_field("baseRateData", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorRateHistoryResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rate_data",
+            "rateData",
+            "base_rate_data",
+            "baseRateData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RateData,
+            BaseRateData,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "baseRateData" | "base_rate_data" => Ok(GeneratedField::BaseRateData),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorRateHistoryResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1alpha1.ValidatorRateHistoryResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorRateHistoryResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rate_data__ = None;
+                let mut base_rate_data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
+                            }
+                            rate_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::BaseRateData => {
+                            if base_rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("baseRateData"));
+                            }
+                            base_rate_data__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ValidatorRateHistoryResponse {
+                    rate_data: rate_data__,
+                    base_rate_data: base_rate_data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.ValidatorRateHistoryResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ValidatorState {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -121,6 +121,21 @@ impl ::prost::Name for FullViewingKey {
         ::prost::alloc::format!("penumbra.core.keys.v1alpha1.{}", Self::NAME)
     }
 }
+/// A detection key for fuzzy message detection, which can be used to detect
+/// clues sent to the corresponding clue key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetectionKey {
+    #[prost(bytes = "vec", tag = "1")]
+    pub inner: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for DetectionKey {
+    const NAME: &'static str = "DetectionKey";
+    const PACKAGE: &'static str = "penumbra.core.keys.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.keys.v1alpha1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WalletId {
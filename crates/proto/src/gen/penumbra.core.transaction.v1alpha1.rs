@@ -96,7 +96,7 @@ impl ::prost::Name for DetectionData {
 pub struct Action {
     #[prost(
         oneof = "action::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52, 200"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 43, 44, 50, 51, 52, 200"
     )]
     pub action: ::core::option::Option<action::Action>,
 }
@@ -163,6 +163,12 @@ pub mod action {
         UndelegateClaim(
             super::super::super::component::stake::v1alpha1::UndelegateClaim,
         ),
+        #[prost(message, tag = "43")]
+        Redelegate(super::super::super::component::stake::v1alpha1::Redelegate),
+        #[prost(message, tag = "44")]
+        RedelegateClaim(
+            super::super::super::component::stake::v1alpha1::RedelegateClaim,
+        ),
         /// Community Pool
         #[prost(message, tag = "50")]
         CommunityPoolSpend(
@@ -316,7 +322,7 @@ impl ::prost::Name for TransactionBodyView {
 pub struct ActionView {
     #[prost(
         oneof = "action_view::ActionView",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 41, 42, 50, 51, 52, 43, 200"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 41, 42, 50, 51, 52, 43, 44, 45, 200"
     )]
     pub action_view: ::core::option::Option<action_view::ActionView>,
 }
@@ -398,6 +404,12 @@ pub mod action_view {
         UndelegateClaim(
             super::super::super::component::stake::v1alpha1::UndelegateClaim,
         ),
+        #[prost(message, tag = "44")]
+        Redelegate(super::super::super::component::stake::v1alpha1::Redelegate),
+        #[prost(message, tag = "45")]
+        RedelegateClaim(
+            super::super::super::component::stake::v1alpha1::RedelegateClaim,
+        ),
         #[prost(message, tag = "200")]
         Ics20Withdrawal(super::super::super::component::ibc::v1alpha1::Ics20Withdrawal),
     }
@@ -451,6 +463,15 @@ pub struct WitnessData {
     pub state_commitment_proofs: ::prost::alloc::vec::Vec<
         super::super::super::crypto::tct::v1alpha1::StateCommitmentProof,
     >,
+    /// Auth paths relative to the anchor, batched together so that the parts they have in
+    /// common are only sent once, in addition to those in `state_commitment_proofs`.
+    ///
+    /// This is only set when batched proofs were asked for, as with `batch_proofs` in the view
+    /// service's `WitnessRequest`; otherwise every auth path is in `state_commitment_proofs`.
+    #[prost(message, optional, tag = "3")]
+    pub batch_state_commitment_proof: ::core::option::Option<
+        super::super::super::crypto::tct::v1alpha1::BatchStateCommitmentProof,
+    >,
 }
 impl ::prost::Name for WitnessData {
     const NAME: &'static str = "WitnessData";
@@ -509,7 +530,7 @@ impl ::prost::Name for DetectionDataPlan {
 pub struct ActionPlan {
     #[prost(
         oneof = "action_plan::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 23, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 23, 30, 31, 32, 34, 40, 41, 42, 43, 44, 50, 51, 52"
     )]
     pub action: ::core::option::Option<action_plan::Action>,
 }
@@ -582,6 +603,16 @@ pub mod action_plan {
         UndelegateClaim(
             super::super::super::component::stake::v1alpha1::UndelegateClaimPlan,
         ),
+        /// We don't need any extra information (yet) to understand redelegations,
+        /// because we don't yet use flow encryption.
+        #[prost(message, tag = "43")]
+        Redelegate(super::super::super::component::stake::v1alpha1::Redelegate),
+        /// We don't need any extra information (yet) to understand redelegation claims,
+        /// because they are transparent.
+        #[prost(message, tag = "44")]
+        RedelegateClaim(
+            super::super::super::component::stake::v1alpha1::RedelegateClaim,
+        ),
         /// Community Pool
         #[prost(message, tag = "50")]
         CommunityPoolSpend(
@@ -66,6 +66,12 @@ impl serde::Serialize for Action {
                 action::Action::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action::Action::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
+                action::Action::RedelegateClaim(v) => {
+                    struct_ser.serialize_field("redelegateClaim", v)?;
+                }
                 action::Action::CommunityPoolSpend(v) => {
                     struct_ser.serialize_field("communityPoolSpend", v)?;
                 }
@@ -121,6 +127,9 @@ impl<'de> serde::Deserialize<'de> for Action {
             "undelegate",
             "undelegate_claim",
             "undelegateClaim",
+            "redelegate",
+            "redelegate_claim",
+            "redelegateClaim",
             "community_pool_spend",
             "communityPoolSpend",
             "community_pool_output",
@@ -151,6 +160,8 @@ impl<'de> serde::Deserialize<'de> for Action {
             Delegate,
             Undelegate,
             UndelegateClaim,
+            Redelegate,
+            RedelegateClaim,
             CommunityPoolSpend,
             CommunityPoolOutput,
             CommunityPoolDeposit,
@@ -194,6 +205,8 @@ impl<'de> serde::Deserialize<'de> for Action {
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
+                            "redelegateClaim" | "redelegate_claim" => Ok(GeneratedField::RedelegateClaim),
                             "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
                             "communityPoolOutput" | "community_pool_output" => Ok(GeneratedField::CommunityPoolOutput),
                             "communityPoolDeposit" | "community_pool_deposit" => Ok(GeneratedField::CommunityPoolDeposit),
@@ -344,6 +357,20 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::UndelegateClaim)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::Redelegate)
+;
+                        }
+                        GeneratedField::RedelegateClaim => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegateClaim"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::RedelegateClaim)
 ;
                         }
                         GeneratedField::CommunityPoolSpend => {
@@ -455,6 +482,12 @@ impl serde::Serialize for ActionPlan {
                 action_plan::Action::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action_plan::Action::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
+                action_plan::Action::RedelegateClaim(v) => {
+                    struct_ser.serialize_field("redelegateClaim", v)?;
+                }
                 action_plan::Action::CommunityPoolSpend(v) => {
                     struct_ser.serialize_field("communityPoolSpend", v)?;
                 }
@@ -508,6 +541,9 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             "undelegate",
             "undelegate_claim",
             "undelegateClaim",
+            "redelegate",
+            "redelegate_claim",
+            "redelegateClaim",
             "community_pool_spend",
             "communityPoolSpend",
             "community_pool_output",
@@ -537,6 +573,8 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             Delegate,
             Undelegate,
             UndelegateClaim,
+            Redelegate,
+            RedelegateClaim,
             CommunityPoolSpend,
             CommunityPoolOutput,
             CommunityPoolDeposit,
@@ -580,6 +618,8 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
+                            "redelegateClaim" | "redelegate_claim" => Ok(GeneratedField::RedelegateClaim),
                             "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
                             "communityPoolOutput" | "community_pool_output" => Ok(GeneratedField::CommunityPoolOutput),
                             "communityPoolDeposit" | "community_pool_deposit" => Ok(GeneratedField::CommunityPoolDeposit),
@@ -736,6 +776,20 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::UndelegateClaim)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::Redelegate)
+;
+                        }
+                        GeneratedField::RedelegateClaim => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegateClaim"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::RedelegateClaim)
 ;
                         }
                         GeneratedField::CommunityPoolSpend => {
@@ -846,6 +900,12 @@ impl serde::Serialize for ActionView {
                 action_view::ActionView::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action_view::ActionView::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
+                action_view::ActionView::RedelegateClaim(v) => {
+                    struct_ser.serialize_field("redelegateClaim", v)?;
+                }
                 action_view::ActionView::Ics20Withdrawal(v) => {
                     struct_ser.serialize_field("ics20Withdrawal", v)?;
                 }
@@ -898,6 +958,9 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             "communityPoolDeposit",
             "undelegate_claim",
             "undelegateClaim",
+            "redelegate",
+            "redelegate_claim",
+            "redelegateClaim",
             "ics20_withdrawal",
             "ics20Withdrawal",
         ];
@@ -925,6 +988,8 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             CommunityPoolOutput,
             CommunityPoolDeposit,
             UndelegateClaim,
+            Redelegate,
+            RedelegateClaim,
             Ics20Withdrawal,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -968,6 +1033,8 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                             "communityPoolOutput" | "community_pool_output" => Ok(GeneratedField::CommunityPoolOutput),
                             "communityPoolDeposit" | "community_pool_deposit" => Ok(GeneratedField::CommunityPoolDeposit),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
+                            "redelegateClaim" | "redelegate_claim" => Ok(GeneratedField::RedelegateClaim),
                             "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
@@ -1136,6 +1203,20 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::UndelegateClaim)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::Redelegate)
+;
+                        }
+                        GeneratedField::RedelegateClaim => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegateClaim"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::RedelegateClaim)
 ;
                         }
                         GeneratedField::Ics20Withdrawal => {
@@ -3559,6 +3640,9 @@ impl serde::Serialize for WitnessData {
         if !self.state_commitment_proofs.is_empty() {
             len += 1;
         }
+        if self.batch_state_commitment_proof.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.transaction.v1alpha1.WitnessData", len)?;
         if let Some(v) = self.anchor.as_ref() {
             struct_ser.serialize_field("anchor", v)?;
@@ -3566,6 +3650,9 @@ impl serde::Serialize for WitnessData {
         if !self.state_commitment_proofs.is_empty() {
             struct_ser.serialize_field("stateCommitmentProofs", &self.state_commitment_proofs)?;
         }
+        if let Some(v) = self.batch_state_commitment_proof.as_ref() {
+            struct_ser.serialize_field("batchStateCommitmentProof", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -3579,12 +3666,15 @@ impl<'de> serde::Deserialize<'de> for WitnessData {
             "anchor",
             "state_commitment_proofs",
             "stateCommitmentProofs",
+            "batch_state_commitment_proof",
+            "batchStateCommitmentProof",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Anchor,
             StateCommitmentProofs,
+            BatchStateCommitmentProof,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3608,6 +3698,7 @@ impl<'de> serde::Deserialize<'de> for WitnessData {
                         match value {
                             "anchor" => Ok(GeneratedField::Anchor),
                             "stateCommitmentProofs" | "state_commitment_proofs" => Ok(GeneratedField::StateCommitmentProofs),
+                            "batchStateCommitmentProof" | "batch_state_commitment_proof" => Ok(GeneratedField::BatchStateCommitmentProof),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3629,6 +3720,7 @@ impl<'de> serde::Deserialize<'de> for WitnessData {
             {
                 let mut anchor__ = None;
                 let mut state_commitment_proofs__ = None;
+                let mut batch_state_commitment_proof__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Anchor => {
@@ -3643,11 +3735,18 @@ impl<'de> serde::Deserialize<'de> for WitnessData {
                             }
                             state_commitment_proofs__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::BatchStateCommitmentProof => {
+                            if batch_state_commitment_proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchStateCommitmentProof"));
+                            }
+                            batch_state_commitment_proof__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(WitnessData {
                     anchor: anchor__,
                     state_commitment_proofs: state_commitment_proofs__.unwrap_or_default(),
+                    batch_state_commitment_proof: batch_state_commitment_proof__,
                 })
             }
         }
@@ -61,3 +61,67 @@ impl ::prost::Name for MerklePathChunk {
         ::prost::alloc::format!("penumbra.crypto.tct.v1alpha1.{}", Self::NAME)
     }
 }
+/// Authentication paths from many state commitments to the same root of the state commitment tree.
+///
+/// Rather than repeating the siblings along each path, this records each node hash needed to
+/// compute the root once, omitting those which can be computed from the witnessed commitments.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchStateCommitmentProof {
+    /// The root of the tree that the commitments are included in.
+    #[prost(message, optional, tag = "1")]
+    pub root: ::core::option::Option<MerkleRoot>,
+    #[prost(message, repeated, tag = "2")]
+    pub witnessed: ::prost::alloc::vec::Vec<batch_state_commitment_proof::Witnessed>,
+    #[prost(message, repeated, tag = "3")]
+    pub nodes: ::prost::alloc::vec::Vec<batch_state_commitment_proof::Node>,
+}
+/// Nested message and enum types in `BatchStateCommitmentProof`.
+pub mod batch_state_commitment_proof {
+    /// A witnessed state commitment and its position in the tree.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Witnessed {
+        #[prost(message, optional, tag = "1")]
+        pub note_commitment: ::core::option::Option<super::StateCommitment>,
+        #[prost(uint64, tag = "2")]
+        pub position: u64,
+    }
+    impl ::prost::Name for Witnessed {
+        const NAME: &'static str = "Witnessed";
+        const PACKAGE: &'static str = "penumbra.crypto.tct.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.crypto.tct.v1alpha1.BatchStateCommitmentProof.{}", Self::NAME
+            )
+        }
+    }
+    /// The hash of a node in the tree, identified by its height above the commitments and its index
+    /// among the nodes at that height.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Node {
+        #[prost(uint32, tag = "1")]
+        pub height: u32,
+        #[prost(uint64, tag = "2")]
+        pub index: u64,
+        #[prost(bytes = "vec", tag = "3")]
+        pub hash: ::prost::alloc::vec::Vec<u8>,
+    }
+    impl ::prost::Name for Node {
+        const NAME: &'static str = "Node";
+        const PACKAGE: &'static str = "penumbra.crypto.tct.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.crypto.tct.v1alpha1.BatchStateCommitmentProof.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for BatchStateCommitmentProof {
+    const NAME: &'static str = "BatchStateCommitmentProof";
+    const PACKAGE: &'static str = "penumbra.crypto.tct.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.crypto.tct.v1alpha1.{}", Self::NAME)
+    }
+}
@@ -280,3 +280,192 @@ impl ::prost::Name for DkgRound2 {
         ::prost::alloc::format!("penumbra.custody.threshold.v1alpha1.{}", Self::NAME)
     }
 }
+/// The first message we broadcast in the resharing protocol.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReshareRound1 {
+    /// The sender's identity as a new participant, if they are joining the new set.
+    #[prost(message, optional, tag = "1")]
+    pub participant: ::core::option::Option<reshare_round1::Participant>,
+    /// The sender's verification key in the old set, if they are dealing new shares.
+    #[prost(bytes = "vec", tag = "2")]
+    pub dealer_vk: ::prost::alloc::vec::Vec<u8>,
+}
+/// Nested message and enum types in `ReshareRound1`.
+pub mod reshare_round1 {
+    /// Set if the sender is joining the new set of participants.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Participant {
+        /// An encryption key for receiving a share in the second round.
+        #[prost(bytes = "vec", tag = "1")]
+        pub epk: ::prost::alloc::vec::Vec<u8>,
+        /// A verification key establishing an identity for the sender in the new set.
+        #[prost(bytes = "vec", tag = "2")]
+        pub vk: ::prost::alloc::vec::Vec<u8>,
+    }
+    impl ::prost::Name for Participant {
+        const NAME: &'static str = "Participant";
+        const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.threshold.v1alpha1.ReshareRound1.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for ReshareRound1 {
+    const NAME: &'static str = "ReshareRound1";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1alpha1.{}", Self::NAME)
+    }
+}
+/// The second message we broadcast in the resharing protocol, sent by each dealer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReshareRound2 {
+    #[prost(message, optional, tag = "1")]
+    pub inner: ::core::option::Option<reshare_round2::Inner>,
+    /// The verification key identifying the sender in the old set.
+    #[prost(bytes = "vec", tag = "2")]
+    pub vk: ::prost::alloc::vec::Vec<u8>,
+    /// A signature over the proto-encoded inner message.
+    #[prost(bytes = "vec", tag = "3")]
+    pub sig: ::prost::alloc::vec::Vec<u8>,
+}
+/// Nested message and enum types in `ReshareRound2`.
+pub mod reshare_round2 {
+    /// The verifying share of a participant in the old set.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct OldShare {
+        /// The verification key identifying the participant.
+        #[prost(bytes = "vec", tag = "1")]
+        pub vk: ::prost::alloc::vec::Vec<u8>,
+        /// The participant's verifying share.
+        #[prost(bytes = "vec", tag = "2")]
+        pub verifying_share: ::prost::alloc::vec::Vec<u8>,
+    }
+    impl ::prost::Name for OldShare {
+        const NAME: &'static str = "OldShare";
+        const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.threshold.v1alpha1.ReshareRound2.{}", Self::NAME
+            )
+        }
+    }
+    /// A new share, encrypted, along with an identifier for the recipient.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TargetedShare {
+        /// A verification key identifying the recipient.
+        #[prost(bytes = "vec", tag = "1")]
+        pub vk: ::prost::alloc::vec::Vec<u8>,
+        /// The ciphertext of the recipient's part of the dealer's share.
+        #[prost(bytes = "vec", tag = "2")]
+        pub encrypted_share: ::prost::alloc::vec::Vec<u8>,
+    }
+    impl ::prost::Name for TargetedShare {
+        const NAME: &'static str = "TargetedShare";
+        const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.threshold.v1alpha1.ReshareRound2.{}", Self::NAME
+            )
+        }
+    }
+    /// An inner message that will be signed.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Inner {
+        /// The full viewing key being reshared, which stays the same.
+        #[prost(message, optional, tag = "1")]
+        pub fvk: ::core::option::Option<
+            super::super::super::super::core::keys::v1alpha1::FullViewingKey,
+        >,
+        /// The threshold of the old set.
+        #[prost(uint32, tag = "2")]
+        pub old_threshold: u32,
+        /// The verifying shares of every participant in the old set.
+        #[prost(message, repeated, tag = "3")]
+        pub old_shares: ::prost::alloc::vec::Vec<OldShare>,
+        /// The verification keys of the dealers, from the old set.
+        #[prost(bytes = "vec", repeated, tag = "4")]
+        pub dealers: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+        /// The threshold of the new set.
+        #[prost(uint32, tag = "5")]
+        pub new_threshold: u32,
+        /// Commitments to the coefficients of the polynomial sharing the dealer's share.
+        #[prost(bytes = "vec", repeated, tag = "6")]
+        pub commitments: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+        /// Encrypted shares for each participant in the new set.
+        #[prost(message, repeated, tag = "7")]
+        pub encrypted_shares: ::prost::alloc::vec::Vec<TargetedShare>,
+    }
+    impl ::prost::Name for Inner {
+        const NAME: &'static str = "Inner";
+        const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.threshold.v1alpha1.ReshareRound2.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for ReshareRound2 {
+    const NAME: &'static str = "ReshareRound2";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1alpha1.{}", Self::NAME)
+    }
+}
+/// The third message we broadcast in the resharing protocol, sent by each new
+/// participant, confirming that their new share matches the dealings, or
+/// complaining that it doesn't.
+///
+/// Dealers only delete their old shares once every new participant has
+/// confirmed the same dealings.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReshareRound3 {
+    #[prost(message, optional, tag = "1")]
+    pub inner: ::core::option::Option<reshare_round3::Inner>,
+    /// The verification key identifying the sender in the new set.
+    #[prost(bytes = "vec", tag = "2")]
+    pub vk: ::prost::alloc::vec::Vec<u8>,
+    /// A signature over the proto-encoded inner message.
+    #[prost(bytes = "vec", tag = "3")]
+    pub sig: ::prost::alloc::vec::Vec<u8>,
+}
+/// Nested message and enum types in `ReshareRound3`.
+pub mod reshare_round3 {
+    /// An inner message that will be signed.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Inner {
+        /// A hash of the dealings the sender reshared from.
+        #[prost(bytes = "vec", tag = "1")]
+        pub dealings_hash: ::prost::alloc::vec::Vec<u8>,
+        /// Why the sender could not finish resharing, or empty if they could.
+        #[prost(string, tag = "2")]
+        pub complaint: ::prost::alloc::string::String,
+    }
+    impl ::prost::Name for Inner {
+        const NAME: &'static str = "Inner";
+        const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.threshold.v1alpha1.ReshareRound3.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for ReshareRound3 {
+    const NAME: &'static str = "ReshareRound3";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1alpha1.{}", Self::NAME)
+    }
+}
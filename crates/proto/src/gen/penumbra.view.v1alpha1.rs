@@ -11,6 +11,8 @@ pub struct AuthorizeAndBuildRequest {
     pub authorization_data: ::core::option::Option<
         super::super::core::transaction::v1alpha1::AuthorizationData,
     >,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for AuthorizeAndBuildRequest {
     const NAME: &'static str = "AuthorizeAndBuildRequest";
@@ -46,6 +48,8 @@ pub struct BroadcastTransactionRequest {
     /// If true, wait for the view service to detect the transaction during sync.
     #[prost(bool, tag = "2")]
     pub await_detection: bool,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for BroadcastTransactionRequest {
     const NAME: &'static str = "BroadcastTransactionRequest";
@@ -90,6 +94,12 @@ pub struct TransactionPlannerRequest {
     /// If present, only spends funds from the given account.
     #[prost(message, optional, tag = "4")]
     pub source: ::core::option::Option<super::super::core::keys::v1alpha1::AddressIndex>,
+    /// The strategy used to select the notes spent to fund the plan.
+    #[prost(enumeration = "transaction_planner_request::SelectionStrategy", tag = "5")]
+    pub selection_strategy: i32,
+    /// The wallet whose funds are used to fund the plan.
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
     /// Request contents
     #[prost(message, repeated, tag = "20")]
     pub outputs: ::prost::alloc::vec::Vec<transaction_planner_request::Output>,
@@ -101,6 +111,12 @@ pub struct TransactionPlannerRequest {
     pub delegations: ::prost::alloc::vec::Vec<transaction_planner_request::Delegate>,
     #[prost(message, repeated, tag = "50")]
     pub undelegations: ::prost::alloc::vec::Vec<transaction_planner_request::Undelegate>,
+    #[prost(message, repeated, tag = "51")]
+    pub redelegations: ::prost::alloc::vec::Vec<transaction_planner_request::Redelegate>,
+    #[prost(message, repeated, tag = "52")]
+    pub redelegate_claims: ::prost::alloc::vec::Vec<
+        transaction_planner_request::RedelegateClaim,
+    >,
     #[prost(message, repeated, tag = "60")]
     pub ibc_relay_actions: ::prost::alloc::vec::Vec<
         super::super::core::component::ibc::v1alpha1::IbcRelay,
@@ -245,6 +261,70 @@ pub mod transaction_planner_request {
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Redelegate {
+        /// The delegation tokens of the source validator to redelegate.
+        #[prost(message, optional, tag = "1")]
+        pub value: ::core::option::Option<
+            super::super::super::core::asset::v1alpha1::Value,
+        >,
+        /// The current rate data of the source validator.
+        #[prost(message, optional, tag = "2")]
+        pub from_rate_data: ::core::option::Option<
+            super::super::super::core::component::stake::v1alpha1::RateData,
+        >,
+        /// The current rate data of the destination validator.
+        #[prost(message, optional, tag = "3")]
+        pub to_rate_data: ::core::option::Option<
+            super::super::super::core::component::stake::v1alpha1::RateData,
+        >,
+    }
+    impl ::prost::Name for Redelegate {
+        const NAME: &'static str = "Redelegate";
+        const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.view.v1alpha1.TransactionPlannerRequest.{}", Self::NAME
+            )
+        }
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RedelegateClaim {
+        /// The identity key of the validator redelegated from.
+        #[prost(message, optional, tag = "1")]
+        pub from_validator_identity: ::core::option::Option<
+            super::super::super::core::keys::v1alpha1::IdentityKey,
+        >,
+        /// The identity key of the validator redelegated to.
+        #[prost(message, optional, tag = "2")]
+        pub to_validator_identity: ::core::option::Option<
+            super::super::super::core::keys::v1alpha1::IdentityKey,
+        >,
+        /// The index of the epoch in which the redelegation was performed.
+        #[prost(uint64, tag = "3")]
+        pub start_epoch_index: u64,
+        /// The penalty applied to the source validator over the redelegation's unbonding period.
+        #[prost(message, optional, tag = "4")]
+        pub penalty: ::core::option::Option<
+            super::super::super::core::component::stake::v1alpha1::Penalty,
+        >,
+        /// The amount of redelegation tokens to claim.
+        #[prost(message, optional, tag = "5")]
+        pub escrowed_amount: ::core::option::Option<
+            super::super::super::core::num::v1alpha1::Amount,
+        >,
+    }
+    impl ::prost::Name for RedelegateClaim {
+        const NAME: &'static str = "RedelegateClaim";
+        const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.view.v1alpha1.TransactionPlannerRequest.{}", Self::NAME
+            )
+        }
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct PositionOpen {
         /// Contains the data defining the position, sufficient to compute its `PositionId`.
         ///
@@ -310,6 +390,73 @@ pub mod transaction_planner_request {
             )
         }
     }
+    /// Strategies for selecting the notes to spend.
+    ///
+    /// Notes are only ever selected from the `source` account, whatever the strategy.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum SelectionStrategy {
+        /// Use the default strategy, `SELECTION_STRATEGY_GREEDY`.
+        Unspecified = 0,
+        /// Spend notes in the order the view service stores them, until the plan is funded.
+        Greedy = 1,
+        /// Spend the largest notes first, minimizing the number of spends.
+        MinimizeSpends = 2,
+        /// Prefer spending notes received at a single address, falling back to the whole account.
+        PreferSingleAddress = 3,
+        /// Only spend notes received at a single address, never linking different addresses.
+        SingleAddress = 4,
+        /// Spend the smallest notes first, consolidating dust into the change output.
+        ConsolidateDust = 5,
+        /// Spend notes in a random order.
+        Random = 6,
+    }
+    impl SelectionStrategy {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                SelectionStrategy::Unspecified => "SELECTION_STRATEGY_UNSPECIFIED",
+                SelectionStrategy::Greedy => "SELECTION_STRATEGY_GREEDY",
+                SelectionStrategy::MinimizeSpends => "SELECTION_STRATEGY_MINIMIZE_SPENDS",
+                SelectionStrategy::PreferSingleAddress => {
+                    "SELECTION_STRATEGY_PREFER_SINGLE_ADDRESS"
+                }
+                SelectionStrategy::SingleAddress => "SELECTION_STRATEGY_SINGLE_ADDRESS",
+                SelectionStrategy::ConsolidateDust => {
+                    "SELECTION_STRATEGY_CONSOLIDATE_DUST"
+                }
+                SelectionStrategy::Random => "SELECTION_STRATEGY_RANDOM",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "SELECTION_STRATEGY_UNSPECIFIED" => Some(Self::Unspecified),
+                "SELECTION_STRATEGY_GREEDY" => Some(Self::Greedy),
+                "SELECTION_STRATEGY_MINIMIZE_SPENDS" => Some(Self::MinimizeSpends),
+                "SELECTION_STRATEGY_PREFER_SINGLE_ADDRESS" => {
+                    Some(Self::PreferSingleAddress)
+                }
+                "SELECTION_STRATEGY_SINGLE_ADDRESS" => Some(Self::SingleAddress),
+                "SELECTION_STRATEGY_CONSOLIDATE_DUST" => Some(Self::ConsolidateDust),
+                "SELECTION_STRATEGY_RANDOM" => Some(Self::Random),
+                _ => None,
+            }
+        }
+    }
 }
 impl ::prost::Name for TransactionPlannerRequest {
     const NAME: &'static str = "TransactionPlannerRequest";
@@ -340,6 +487,8 @@ pub struct AddressByIndexRequest {
     pub address_index: ::core::option::Option<
         super::super::core::keys::v1alpha1::AddressIndex,
     >,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for AddressByIndexRequest {
     const NAME: &'static str = "AddressByIndexRequest";
@@ -363,6 +512,34 @@ impl ::prost::Name for AddressByIndexResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RootDetectionKeyRequest {
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
+}
+impl ::prost::Name for RootDetectionKeyRequest {
+    const NAME: &'static str = "RootDetectionKeyRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RootDetectionKeyResponse {
+    #[prost(message, optional, tag = "1")]
+    pub detection_key: ::core::option::Option<
+        super::super::core::keys::v1alpha1::DetectionKey,
+    >,
+}
+impl ::prost::Name for RootDetectionKeyResponse {
+    const NAME: &'static str = "RootDetectionKeyResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WalletIdRequest {}
 impl ::prost::Name for WalletIdRequest {
     const NAME: &'static str = "WalletIdRequest";
@@ -386,9 +563,34 @@ impl ::prost::Name for WalletIdResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalletsRequest {}
+impl ::prost::Name for WalletsRequest {
+    const NAME: &'static str = "WalletsRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalletsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
+}
+impl ::prost::Name for WalletsResponse {
+    const NAME: &'static str = "WalletsResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct IndexByAddressRequest {
     #[prost(message, optional, tag = "1")]
     pub address: ::core::option::Option<super::super::core::keys::v1alpha1::Address>,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for IndexByAddressRequest {
     const NAME: &'static str = "IndexByAddressRequest";
@@ -422,6 +624,8 @@ pub struct EphemeralAddressRequest {
     >,
     #[prost(bool, tag = "2")]
     pub display_confirm: bool,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for EphemeralAddressRequest {
     const NAME: &'static str = "EphemeralAddressRequest";
@@ -456,6 +660,8 @@ pub struct BalancesRequest {
     pub asset_id_filter: ::core::option::Option<
         super::super::core::asset::v1alpha1::AssetId,
     >,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for BalancesRequest {
     const NAME: &'static str = "BalancesRequest";
@@ -570,6 +776,8 @@ pub struct NotesRequest {
     pub amount_to_spend: ::core::option::Option<
         super::super::core::num::v1alpha1::Amount,
     >,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for NotesRequest {
     const NAME: &'static str = "NotesRequest";
@@ -590,6 +798,8 @@ pub struct NotesForVotingRequest {
     pub address_index: ::core::option::Option<
         super::super::core::keys::v1alpha1::AddressIndex,
     >,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for NotesForVotingRequest {
     const NAME: &'static str = "NotesForVotingRequest";
@@ -611,6 +821,11 @@ pub struct WitnessRequest {
     pub transaction_plan: ::core::option::Option<
         super::super::core::transaction::v1alpha1::TransactionPlan,
     >,
+    /// If set, the auth paths relative to the anchor are returned together in the
+    /// `batch_state_commitment_proof` of the witness data, rather than individually in its
+    /// `state_commitment_proofs`.  Only clients which understand the batched form should set this.
+    #[prost(bool, tag = "4")]
+    pub batch_proofs: bool,
 }
 impl ::prost::Name for WitnessRequest {
     const NAME: &'static str = "WitnessRequest";
@@ -645,6 +860,8 @@ pub struct WitnessAndBuildRequest {
     pub authorization_data: ::core::option::Option<
         super::super::core::transaction::v1alpha1::AuthorizationData,
     >,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for WitnessAndBuildRequest {
     const NAME: &'static str = "WitnessAndBuildRequest";
@@ -808,6 +1025,8 @@ pub struct NoteByCommitmentRequest {
     /// If set to true, waits to return until the requested note is detected.
     #[prost(bool, tag = "3")]
     pub await_detection: bool,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for NoteByCommitmentRequest {
     const NAME: &'static str = "NoteByCommitmentRequest";
@@ -839,6 +1058,8 @@ pub struct SwapByCommitmentRequest {
     /// If set to true, waits to return until the requested swap is detected.
     #[prost(bool, tag = "3")]
     pub await_detection: bool,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for SwapByCommitmentRequest {
     const NAME: &'static str = "SwapByCommitmentRequest";
@@ -862,7 +1083,10 @@ impl ::prost::Name for SwapByCommitmentResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UnclaimedSwapsRequest {}
+pub struct UnclaimedSwapsRequest {
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
+}
 impl ::prost::Name for UnclaimedSwapsRequest {
     const NAME: &'static str = "UnclaimedSwapsRequest";
     const PACKAGE: &'static str = "penumbra.view.v1alpha1";
@@ -892,6 +1116,8 @@ pub struct NullifierStatusRequest {
     >,
     #[prost(bool, tag = "3")]
     pub await_detection: bool,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for NullifierStatusRequest {
     const NAME: &'static str = "NullifierStatusRequest";
@@ -919,6 +1145,8 @@ pub struct TransactionInfoByHashRequest {
     /// The transaction hash to query for.
     #[prost(message, optional, tag = "2")]
     pub id: ::core::option::Option<super::super::core::txhash::v1alpha1::TransactionId>,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for TransactionInfoByHashRequest {
     const NAME: &'static str = "TransactionInfoByHashRequest";
@@ -936,6 +1164,8 @@ pub struct TransactionInfoRequest {
     /// If present, return only transactions before this height.
     #[prost(uint64, tag = "2")]
     pub end_height: u64,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for TransactionInfoRequest {
     const NAME: &'static str = "TransactionInfoRequest";
@@ -991,6 +1221,112 @@ impl ::prost::Name for TransactionInfoResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionHistoryRequest {
+    /// If present, return only transactions after this height.
+    #[prost(uint64, tag = "1")]
+    pub start_height: u64,
+    /// If present, return only transactions before this height.
+    #[prost(uint64, tag = "2")]
+    pub end_height: u64,
+    /// If present, return only transactions changing the balance of this account, and only the
+    /// change to its balance.
+    #[prost(message, optional, tag = "3")]
+    pub account_filter: ::core::option::Option<
+        super::super::core::keys::v1alpha1::AddressIndex,
+    >,
+    /// If present, return only transactions changing the balance of this asset, and only the change
+    /// to its balance.
+    #[prost(message, optional, tag = "4")]
+    pub asset_id_filter: ::core::option::Option<
+        super::super::core::asset::v1alpha1::AssetId,
+    >,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
+}
+impl ::prost::Name for TransactionHistoryRequest {
+    const NAME: &'static str = "TransactionHistoryRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionHistoryResponse {
+    #[prost(message, optional, tag = "1")]
+    pub entry: ::core::option::Option<TransactionHistoryEntry>,
+}
+impl ::prost::Name for TransactionHistoryResponse {
+    const NAME: &'static str = "TransactionHistoryResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+/// A summary of a transaction, as seen by this view server.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionHistoryEntry {
+    /// The height the transaction was included in a block, if known.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The hash of the transaction.
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<super::super::core::txhash::v1alpha1::TransactionId>,
+    /// The change the transaction made to the balance of each account.
+    #[prost(message, repeated, tag = "3")]
+    pub balance_deltas: ::prost::alloc::vec::Vec<AccountBalanceDelta>,
+    /// The fee paid by the transaction.
+    #[prost(message, optional, tag = "4")]
+    pub fee: ::core::option::Option<super::super::core::component::fee::v1alpha1::Fee>,
+    /// The return address from the memo, if visible.
+    #[prost(message, optional, tag = "5")]
+    pub return_address: ::core::option::Option<
+        super::super::core::keys::v1alpha1::Address,
+    >,
+    /// The text of the memo, if visible.
+    #[prost(string, tag = "6")]
+    pub memo_text: ::prost::alloc::string::String,
+    /// The addresses outside the wallet that the transaction sent outputs to.
+    #[prost(message, repeated, tag = "7")]
+    pub counterparties: ::prost::alloc::vec::Vec<
+        super::super::core::keys::v1alpha1::Address,
+    >,
+    /// The kinds of the transaction's actions, in order, e.g. `spend` or `output`.
+    #[prost(string, repeated, tag = "8")]
+    pub action_kinds: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+impl ::prost::Name for TransactionHistoryEntry {
+    const NAME: &'static str = "TransactionHistoryEntry";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+/// The change a transaction made to the balance of an account.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountBalanceDelta {
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<
+        super::super::core::keys::v1alpha1::AddressIndex,
+    >,
+    /// The values the account received.
+    #[prost(message, repeated, tag = "2")]
+    pub received: ::prost::alloc::vec::Vec<super::super::core::asset::v1alpha1::Value>,
+    /// The values the account spent.
+    #[prost(message, repeated, tag = "3")]
+    pub sent: ::prost::alloc::vec::Vec<super::super::core::asset::v1alpha1::Value>,
+}
+impl ::prost::Name for AccountBalanceDelta {
+    const NAME: &'static str = "AccountBalanceDelta";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransactionInfoByHashResponse {
     #[prost(message, optional, tag = "1")]
     pub tx_info: ::core::option::Option<TransactionInfo>,
@@ -1126,6 +1462,8 @@ pub struct OwnedPositionIdsRequest {
     pub trading_pair: ::core::option::Option<
         super::super::core::component::dex::v1alpha1::TradingPair,
     >,
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
 }
 impl ::prost::Name for OwnedPositionIdsRequest {
     const NAME: &'static str = "OwnedPositionIdsRequest";
@@ -1149,6 +1487,149 @@ impl ::prost::Name for OwnedPositionIdsResponse {
         ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NotificationsRequest {
+    /// If present, only stream events concerning this wallet; otherwise, stream
+    /// events concerning every tracked wallet.
+    #[prost(message, optional, tag = "1")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
+}
+impl ::prost::Name for NotificationsRequest {
+    const NAME: &'static str = "NotificationsRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NotificationsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub notification: ::core::option::Option<Notification>,
+}
+impl ::prost::Name for NotificationsResponse {
+    const NAME: &'static str = "NotificationsResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+/// An event concerning a tracked wallet, detected while scanning a block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Notification {
+    /// The wallet the event concerns.
+    #[prost(message, optional, tag = "1")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
+    /// The height of the block in which the event was detected.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    #[prost(oneof = "notification::Event", tags = "10, 11, 12, 13, 14")]
+    pub event: ::core::option::Option<notification::Event>,
+}
+/// Nested message and enum types in `Notification`.
+pub mod notification {
+    /// A new note was received.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct NoteReceived {
+        #[prost(message, optional, tag = "1")]
+        pub note_record: ::core::option::Option<super::SpendableNoteRecord>,
+    }
+    impl ::prost::Name for NoteReceived {
+        const NAME: &'static str = "NoteReceived";
+        const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.view.v1alpha1.Notification.{}", Self::NAME)
+        }
+    }
+    /// A note was spent.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct NoteSpent {
+        #[prost(message, optional, tag = "1")]
+        pub note_record: ::core::option::Option<super::SpendableNoteRecord>,
+    }
+    impl ::prost::Name for NoteSpent {
+        const NAME: &'static str = "NoteSpent";
+        const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.view.v1alpha1.Notification.{}", Self::NAME)
+        }
+    }
+    /// A swap was detected, and its outputs can now be claimed.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SwapClaimable {
+        #[prost(message, optional, tag = "1")]
+        pub swap_record: ::core::option::Option<super::SwapRecord>,
+    }
+    impl ::prost::Name for SwapClaimable {
+        const NAME: &'static str = "SwapClaimable";
+        const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.view.v1alpha1.Notification.{}", Self::NAME)
+        }
+    }
+    /// The unbonding period of held unbonding tokens has elapsed, so that they
+    /// can be claimed.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct UndelegationClaimable {
+        /// The note holding the unbonding tokens.
+        #[prost(message, optional, tag = "1")]
+        pub note_record: ::core::option::Option<super::SpendableNoteRecord>,
+    }
+    impl ::prost::Name for UndelegationClaimable {
+        const NAME: &'static str = "UndelegationClaimable";
+        const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.view.v1alpha1.Notification.{}", Self::NAME)
+        }
+    }
+    /// A position owned by the wallet changed state.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PositionStateChanged {
+        #[prost(message, optional, tag = "1")]
+        pub position_id: ::core::option::Option<
+            super::super::super::core::component::dex::v1alpha1::PositionId,
+        >,
+        #[prost(message, optional, tag = "2")]
+        pub state: ::core::option::Option<
+            super::super::super::core::component::dex::v1alpha1::PositionState,
+        >,
+    }
+    impl ::prost::Name for PositionStateChanged {
+        const NAME: &'static str = "PositionStateChanged";
+        const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.view.v1alpha1.Notification.{}", Self::NAME)
+        }
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag = "10")]
+        NoteReceived(NoteReceived),
+        #[prost(message, tag = "11")]
+        NoteSpent(NoteSpent),
+        #[prost(message, tag = "12")]
+        SwapClaimable(SwapClaimable),
+        #[prost(message, tag = "13")]
+        UndelegationClaimable(UndelegationClaimable),
+        #[prost(message, tag = "14")]
+        PositionStateChanged(PositionStateChanged),
+    }
+}
+impl ::prost::Name for Notification {
+    const NAME: &'static str = "Notification";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod view_protocol_service_client {
@@ -1159,6 +1640,9 @@ pub mod view_protocol_service_client {
     /// transaction-related actions, to request data from a view service, which is
     /// responsible for synchronizing and scanning the public chain state with one or
     /// more full viewing keys.
+    ///
+    /// Requests concerning a particular wallet carry a `wallet_id` identifying it.
+    /// The `wallet_id` may be omitted when the view service tracks a single wallet.
     #[derive(Debug, Clone)]
     pub struct ViewProtocolServiceClient<T> {
         inner: tonic::client::Grpc<T>,
@@ -1556,9 +2040,74 @@ pub mod view_protocol_service_client {
         /// Query for an address given an address index
         pub async fn address_by_index(
             &mut self,
-            request: impl tonic::IntoRequest<super::AddressByIndexRequest>,
+            request: impl tonic::IntoRequest<super::AddressByIndexRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddressByIndexResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/AddressByIndex",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "AddressByIndex",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Query for wallet id
+        ///
+        /// Fails if the view service tracks more than one wallet; use `Wallets` instead.
+        pub async fn wallet_id(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WalletIdRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::WalletIdResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/WalletId",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "WalletId",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Query for the ids of all wallets tracked by the view service.
+        /// Returns a stream of `WalletsResponse`s.
+        pub async fn wallets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WalletsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::AddressByIndexResponse>,
+            tonic::Response<tonic::codec::Streaming<super::WalletsResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -1572,24 +2121,30 @@ pub mod view_protocol_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1alpha1.ViewProtocolService/AddressByIndex",
+                "/penumbra.view.v1alpha1.ViewProtocolService/Wallets",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "penumbra.view.v1alpha1.ViewProtocolService",
-                        "AddressByIndex",
+                        "Wallets",
                     ),
                 );
-            self.inner.unary(req, path, codec).await
+            self.inner.server_streaming(req, path, codec).await
         }
-        /// Query for wallet id
-        pub async fn wallet_id(
+        /// Query for the root detection key of a wallet, from which the detection key
+        /// of each of its addresses derived from the root can be derived.
+        ///
+        /// The root detection key can be given to a detection server in place of the
+        /// detection keys of each such address.  Addresses are not derived from the
+        /// root by default, so this does not cover addresses returned by
+        /// `AddressByIndex` or `EphemeralAddress`.
+        pub async fn root_detection_key(
             &mut self,
-            request: impl tonic::IntoRequest<super::WalletIdRequest>,
+            request: impl tonic::IntoRequest<super::RootDetectionKeyRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::WalletIdResponse>,
+            tonic::Response<super::RootDetectionKeyResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1603,14 +2158,14 @@ pub mod view_protocol_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1alpha1.ViewProtocolService/WalletId",
+                "/penumbra.view.v1alpha1.ViewProtocolService/RootDetectionKey",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
                     GrpcMethod::new(
                         "penumbra.view.v1alpha1.ViewProtocolService",
-                        "WalletId",
+                        "RootDetectionKey",
                     ),
                 );
             self.inner.unary(req, path, codec).await
@@ -1896,6 +2451,39 @@ pub mod view_protocol_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Query for a summary of the transactions in the given range of blocks, with the change each
+        /// made to the balances of the wallet's accounts.
+        /// Returns a stream of `TransactionHistoryResponse`s.
+        pub async fn transaction_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::TransactionHistoryResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/TransactionHistory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "TransactionHistory",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         /// Query for a transaction plan
         pub async fn transaction_planner(
             &mut self,
@@ -2020,6 +2608,43 @@ pub mod view_protocol_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Stream events concerning the tracked wallets as they are detected during sync.
+        /// Returns a stream of `NotificationsResponse`s.
+        ///
+        /// Only events detected after the request is made are streamed. Delivery is
+        /// best-effort: a client that falls too far behind skips the events it missed,
+        /// and the stream carries on with later ones, so clients that need every event
+        /// should reconcile against the wallet's notes and swaps.
+        pub async fn notifications(
+            &mut self,
+            request: impl tonic::IntoRequest<super::NotificationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::NotificationsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/Notifications",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "Notifications",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -2138,6 +2763,8 @@ pub mod view_protocol_service_server {
             tonic::Status,
         >;
         /// Query for wallet id
+        ///
+        /// Fails if the view service tracks more than one wallet; use `Wallets` instead.
         async fn wallet_id(
             &self,
             request: tonic::Request<super::WalletIdRequest>,
@@ -2145,6 +2772,32 @@ pub mod view_protocol_service_server {
             tonic::Response<super::WalletIdResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the Wallets method.
+        type WalletsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::WalletsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Query for the ids of all wallets tracked by the view service.
+        /// Returns a stream of `WalletsResponse`s.
+        async fn wallets(
+            &self,
+            request: tonic::Request<super::WalletsRequest>,
+        ) -> std::result::Result<tonic::Response<Self::WalletsStream>, tonic::Status>;
+        /// Query for the root detection key of a wallet, from which the detection key
+        /// of each of its addresses derived from the root can be derived.
+        ///
+        /// The root detection key can be given to a detection server in place of the
+        /// detection keys of each such address.  Addresses are not derived from the
+        /// root by default, so this does not cover addresses returned by
+        /// `AddressByIndex` or `EphemeralAddress`.
+        async fn root_detection_key(
+            &self,
+            request: tonic::Request<super::RootDetectionKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RootDetectionKeyResponse>,
+            tonic::Status,
+        >;
         /// Query for an address given an address index
         async fn index_by_address(
             &self,
@@ -2234,6 +2887,25 @@ pub mod view_protocol_service_server {
             tonic::Response<Self::TransactionInfoStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the TransactionHistory method.
+        type TransactionHistoryStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::TransactionHistoryResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Query for a summary of the transactions in the given range of blocks, with the change each
+        /// made to the balances of the wallet's accounts.
+        /// Returns a stream of `TransactionHistoryResponse`s.
+        async fn transaction_history(
+            &self,
+            request: tonic::Request<super::TransactionHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::TransactionHistoryStream>,
+            tonic::Status,
+        >;
         /// Query for a transaction plan
         async fn transaction_planner(
             &self,
@@ -2275,11 +2947,34 @@ pub mod view_protocol_service_server {
             tonic::Response<super::AuthorizeAndBuildResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the Notifications method.
+        type NotificationsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::NotificationsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Stream events concerning the tracked wallets as they are detected during sync.
+        /// Returns a stream of `NotificationsResponse`s.
+        ///
+        /// Only events detected after the request is made are streamed. Delivery is
+        /// best-effort: a client that falls too far behind skips the events it missed,
+        /// and the stream carries on with later ones, so clients that need every event
+        /// should reconcile against the wallet's notes and swaps.
+        async fn notifications(
+            &self,
+            request: tonic::Request<super::NotificationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::NotificationsStream>,
+            tonic::Status,
+        >;
     }
     /// The view protocol is used by a view client, who wants to do some
     /// transaction-related actions, to request data from a view service, which is
     /// responsible for synchronizing and scanning the public chain state with one or
     /// more full viewing keys.
+    ///
+    /// Requests concerning a particular wallet carry a `wallet_id` identifying it.
+    /// The `wallet_id` may be omitted when the view service tracks a single wallet.
     #[derive(Debug)]
     pub struct ViewProtocolServiceServer<T: ViewProtocolService> {
         inner: _Inner<T>,
@@ -2931,6 +3626,103 @@ pub mod view_protocol_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1alpha1.ViewProtocolService/Wallets" => {
+                    #[allow(non_camel_case_types)]
+                    struct WalletsSvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::ServerStreamingService<super::WalletsRequest>
+                    for WalletsSvc<T> {
+                        type Response = super::WalletsResponse;
+                        type ResponseStream = T::WalletsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WalletsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewProtocolService>::wallets(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = WalletsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1alpha1.ViewProtocolService/RootDetectionKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct RootDetectionKeySvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::UnaryService<super::RootDetectionKeyRequest>
+                    for RootDetectionKeySvc<T> {
+                        type Response = super::RootDetectionKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RootDetectionKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewProtocolService>::root_detection_key(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RootDetectionKeySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.view.v1alpha1.ViewProtocolService/IndexByAddress" => {
                     #[allow(non_camel_case_types)]
                     struct IndexByAddressSvc<T: ViewProtocolService>(pub Arc<T>);
@@ -3378,6 +4170,58 @@ pub mod view_protocol_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1alpha1.ViewProtocolService/TransactionHistory" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionHistorySvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::ServerStreamingService<
+                        super::TransactionHistoryRequest,
+                    > for TransactionHistorySvc<T> {
+                        type Response = super::TransactionHistoryResponse;
+                        type ResponseStream = T::TransactionHistoryStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionHistoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewProtocolService>::transaction_history(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = TransactionHistorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.view.v1alpha1.ViewProtocolService/TransactionPlanner" => {
                     #[allow(non_camel_case_types)]
                     struct TransactionPlannerSvc<T: ViewProtocolService>(pub Arc<T>);
@@ -3580,6 +4424,54 @@ pub mod view_protocol_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1alpha1.ViewProtocolService/Notifications" => {
+                    #[allow(non_camel_case_types)]
+                    struct NotificationsSvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::ServerStreamingService<super::NotificationsRequest>
+                    for NotificationsSvc<T> {
+                        type Response = super::NotificationsResponse;
+                        type ResponseStream = T::NotificationsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NotificationsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewProtocolService>::notifications(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NotificationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -1,3 +1,128 @@
+impl serde::Serialize for AccountBalanceDelta {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.account.is_some() {
+            len += 1;
+        }
+        if !self.received.is_empty() {
+            len += 1;
+        }
+        if !self.sent.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.AccountBalanceDelta", len)?;
+        if let Some(v) = self.account.as_ref() {
+            struct_ser.serialize_field("account", v)?;
+        }
+        if !self.received.is_empty() {
+            struct_ser.serialize_field("received", &self.received)?;
+        }
+        if !self.sent.is_empty() {
+            struct_ser.serialize_field("sent", &self.sent)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AccountBalanceDelta {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "account",
+            "received",
+            "sent",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Account,
+            Received,
+            Sent,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "account" => Ok(GeneratedField::Account),
+                            "received" => Ok(GeneratedField::Received),
+                            "sent" => Ok(GeneratedField::Sent),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AccountBalanceDelta;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.AccountBalanceDelta")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AccountBalanceDelta, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut account__ = None;
+                let mut received__ = None;
+                let mut sent__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Account => {
+                            if account__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("account"));
+                            }
+                            account__ = map_.next_value()?;
+                        }
+                        GeneratedField::Received => {
+                            if received__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("received"));
+                            }
+                            received__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Sent => {
+                            if sent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sent"));
+                            }
+                            sent__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(AccountBalanceDelta {
+                    account: account__,
+                    received: received__.unwrap_or_default(),
+                    sent: sent__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.AccountBalanceDelta", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for AddressByIndexRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -9,10 +134,16 @@ impl serde::Serialize for AddressByIndexRequest {
         if self.address_index.is_some() {
             len += 1;
         }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.AddressByIndexRequest", len)?;
         if let Some(v) = self.address_index.as_ref() {
             struct_ser.serialize_field("addressIndex", v)?;
         }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -25,11 +156,14 @@ impl<'de> serde::Deserialize<'de> for AddressByIndexRequest {
         const FIELDS: &[&str] = &[
             "address_index",
             "addressIndex",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             AddressIndex,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -52,6 +186,7 @@ impl<'de> serde::Deserialize<'de> for AddressByIndexRequest {
                     {
                         match value {
                             "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -72,6 +207,7 @@ impl<'de> serde::Deserialize<'de> for AddressByIndexRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut address_index__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::AddressIndex => {
@@ -80,10 +216,17 @@ impl<'de> serde::Deserialize<'de> for AddressByIndexRequest {
                             }
                             address_index__ = map_.next_value()?;
                         }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(AddressByIndexRequest {
                     address_index: address_index__,
+                    wallet_id: wallet_id__,
                 })
             }
         }
@@ -648,6 +791,9 @@ impl serde::Serialize for AuthorizeAndBuildRequest {
         if self.authorization_data.is_some() {
             len += 1;
         }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.AuthorizeAndBuildRequest", len)?;
         if let Some(v) = self.transaction_plan.as_ref() {
             struct_ser.serialize_field("transactionPlan", v)?;
@@ -655,6 +801,9 @@ impl serde::Serialize for AuthorizeAndBuildRequest {
         if let Some(v) = self.authorization_data.as_ref() {
             struct_ser.serialize_field("authorizationData", v)?;
         }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -669,12 +818,15 @@ impl<'de> serde::Deserialize<'de> for AuthorizeAndBuildRequest {
             "transactionPlan",
             "authorization_data",
             "authorizationData",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             TransactionPlan,
             AuthorizationData,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -698,6 +850,7 @@ impl<'de> serde::Deserialize<'de> for AuthorizeAndBuildRequest {
                         match value {
                             "transactionPlan" | "transaction_plan" => Ok(GeneratedField::TransactionPlan),
                             "authorizationData" | "authorization_data" => Ok(GeneratedField::AuthorizationData),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -719,6 +872,7 @@ impl<'de> serde::Deserialize<'de> for AuthorizeAndBuildRequest {
             {
                 let mut transaction_plan__ = None;
                 let mut authorization_data__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::TransactionPlan => {
@@ -733,11 +887,18 @@ impl<'de> serde::Deserialize<'de> for AuthorizeAndBuildRequest {
                             }
                             authorization_data__ = map_.next_value()?;
                         }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(AuthorizeAndBuildRequest {
                     transaction_plan: transaction_plan__,
                     authorization_data: authorization_data__,
+                    wallet_id: wallet_id__,
                 })
             }
         }
@@ -849,6 +1010,9 @@ impl serde::Serialize for BalancesRequest {
         if self.asset_id_filter.is_some() {
             len += 1;
         }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.BalancesRequest", len)?;
         if let Some(v) = self.account_filter.as_ref() {
             struct_ser.serialize_field("accountFilter", v)?;
@@ -856,6 +1020,9 @@ impl serde::Serialize for BalancesRequest {
         if let Some(v) = self.asset_id_filter.as_ref() {
             struct_ser.serialize_field("assetIdFilter", v)?;
         }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -870,12 +1037,15 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
             "accountFilter",
             "asset_id_filter",
             "assetIdFilter",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             AccountFilter,
             AssetIdFilter,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -899,6 +1069,7 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
                         match value {
                             "accountFilter" | "account_filter" => Ok(GeneratedField::AccountFilter),
                             "assetIdFilter" | "asset_id_filter" => Ok(GeneratedField::AssetIdFilter),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -920,6 +1091,7 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
             {
                 let mut account_filter__ = None;
                 let mut asset_id_filter__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::AccountFilter => {
@@ -934,11 +1106,18 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
                             }
                             asset_id_filter__ = map_.next_value()?;
                         }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(BalancesRequest {
                     account_filter: account_filter__,
                     asset_id_filter: asset_id_filter__,
+                    wallet_id: wallet_id__,
                 })
             }
         }
@@ -1067,6 +1246,9 @@ impl serde::Serialize for BroadcastTransactionRequest {
         if self.await_detection {
             len += 1;
         }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.BroadcastTransactionRequest", len)?;
         if let Some(v) = self.transaction.as_ref() {
             struct_ser.serialize_field("transaction", v)?;
@@ -1074,6 +1256,9 @@ impl serde::Serialize for BroadcastTransactionRequest {
         if self.await_detection {
             struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
         }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1087,12 +1272,15 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
             "transaction",
             "await_detection",
             "awaitDetection",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Transaction,
             AwaitDetection,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1116,6 +1304,7 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
                         match value {
                             "transaction" => Ok(GeneratedField::Transaction),
                             "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1137,6 +1326,7 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
             {
                 let mut transaction__ = None;
                 let mut await_detection__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Transaction => {
@@ -1151,11 +1341,18 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
                             }
                             await_detection__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(BroadcastTransactionRequest {
                     transaction: transaction__,
                     await_detection: await_detection__.unwrap_or_default(),
+                    wallet_id: wallet_id__,
                 })
             }
         }
@@ -1288,6 +1485,9 @@ impl serde::Serialize for EphemeralAddressRequest {
         if self.display_confirm {
             len += 1;
         }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.EphemeralAddressRequest", len)?;
         if let Some(v) = self.address_index.as_ref() {
             struct_ser.serialize_field("addressIndex", v)?;
@@ -1295,6 +1495,9 @@ impl serde::Serialize for EphemeralAddressRequest {
         if self.display_confirm {
             struct_ser.serialize_field("displayConfirm", &self.display_confirm)?;
         }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1309,12 +1512,15 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
             "addressIndex",
             "display_confirm",
             "displayConfirm",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             AddressIndex,
             DisplayConfirm,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1338,6 +1544,7 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
                         match value {
                             "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
                             "displayConfirm" | "display_confirm" => Ok(GeneratedField::DisplayConfirm),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1359,6 +1566,7 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
             {
                 let mut address_index__ = None;
                 let mut display_confirm__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::AddressIndex => {
@@ -1373,11 +1581,18 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
                             }
                             display_confirm__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(EphemeralAddressRequest {
                     address_index: address_index__,
                     display_confirm: display_confirm__.unwrap_or_default(),
+                    wallet_id: wallet_id__,
                 })
             }
         }
@@ -1811,10 +2026,16 @@ impl serde::Serialize for IndexByAddressRequest {
         if self.address.is_some() {
             len += 1;
         }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.IndexByAddressRequest", len)?;
         if let Some(v) = self.address.as_ref() {
             struct_ser.serialize_field("address", v)?;
         }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1826,11 +2047,14 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
     {
         const FIELDS: &[&str] = &[
             "address",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Address,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1853,6 +2077,7 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
                     {
                         match value {
                             "address" => Ok(GeneratedField::Address),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1873,6 +2098,7 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut address__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Address => {
@@ -1881,10 +2107,17 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
                             }
                             address__ = map_.next_value()?;
                         }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(IndexByAddressRequest {
                     address: address__,
+                    wallet_id: wallet_id__,
                 })
             }
         }
@@ -1997,6 +2230,9 @@ impl serde::Serialize for NoteByCommitmentRequest {
         if self.await_detection {
             len += 1;
         }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NoteByCommitmentRequest", len)?;
         if let Some(v) = self.note_commitment.as_ref() {
             struct_ser.serialize_field("noteCommitment", v)?;
@@ -2004,6 +2240,9 @@ impl serde::Serialize for NoteByCommitmentRequest {
         if self.await_detection {
             struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
         }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2018,12 +2257,15 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
             "noteCommitment",
             "await_detection",
             "awaitDetection",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             NoteCommitment,
             AwaitDetection,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2047,6 +2289,7 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
                         match value {
                             "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
                             "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2068,6 +2311,7 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
             {
                 let mut note_commitment__ = None;
                 let mut await_detection__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::NoteCommitment => {
@@ -2082,11 +2326,18 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
                             }
                             await_detection__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(NoteByCommitmentRequest {
                     note_commitment: note_commitment__,
                     await_detection: await_detection__.unwrap_or_default(),
+                    wallet_id: wallet_id__,
                 })
             }
         }
@@ -2199,6 +2450,9 @@ impl serde::Serialize for NotesForVotingRequest {
         if self.address_index.is_some() {
             len += 1;
         }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotesForVotingRequest", len)?;
         if self.votable_at_height != 0 {
             #[allow(clippy::needless_borrow)]
@@ -2207,6 +2461,9 @@ impl serde::Serialize for NotesForVotingRequest {
         if let Some(v) = self.address_index.as_ref() {
             struct_ser.serialize_field("addressIndex", v)?;
         }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2221,12 +2478,15 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
             "votableAtHeight",
             "address_index",
             "addressIndex",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             VotableAtHeight,
             AddressIndex,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2250,6 +2510,7 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
                         match value {
                             "votableAtHeight" | "votable_at_height" => Ok(GeneratedField::VotableAtHeight),
                             "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2271,6 +2532,7 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
             {
                 let mut votable_at_height__ = None;
                 let mut address_index__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::VotableAtHeight => {
@@ -2287,11 +2549,18 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
                             }
                             address_index__ = map_.next_value()?;
                         }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(NotesForVotingRequest {
                     votable_at_height: votable_at_height__.unwrap_or_default(),
                     address_index: address_index__,
+                    wallet_id: wallet_id__,
                 })
             }
         }
@@ -2428,6 +2697,9 @@ impl serde::Serialize for NotesRequest {
         if self.amount_to_spend.is_some() {
             len += 1;
         }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotesRequest", len)?;
         if self.include_spent {
             struct_ser.serialize_field("includeSpent", &self.include_spent)?;
@@ -2441,6 +2713,9 @@ impl serde::Serialize for NotesRequest {
         if let Some(v) = self.amount_to_spend.as_ref() {
             struct_ser.serialize_field("amountToSpend", v)?;
         }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2459,6 +2734,8 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
             "addressIndex",
             "amount_to_spend",
             "amountToSpend",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2467,6 +2744,7 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
             AssetId,
             AddressIndex,
             AmountToSpend,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2492,6 +2770,7 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
                             "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
                             "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
                             "amountToSpend" | "amount_to_spend" => Ok(GeneratedField::AmountToSpend),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2515,6 +2794,7 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
                 let mut asset_id__ = None;
                 let mut address_index__ = None;
                 let mut amount_to_spend__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::IncludeSpent => {
@@ -2541,6 +2821,12 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
                             }
                             amount_to_spend__ = map_.next_value()?;
                         }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(NotesRequest {
@@ -2548,6 +2834,7 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
                     asset_id: asset_id__,
                     address_index: address_index__,
                     amount_to_spend: amount_to_spend__,
+                    wallet_id: wallet_id__,
                 })
             }
         }
@@ -2646,7 +2933,7 @@ impl<'de> serde::Deserialize<'de> for NotesResponse {
         deserializer.deserialize_struct("penumbra.view.v1alpha1.NotesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NullifierStatusRequest {
+impl serde::Serialize for Notification {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2654,38 +2941,76 @@ impl serde::Serialize for NullifierStatusRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.nullifier.is_some() {
+        if self.wallet_id.is_some() {
             len += 1;
         }
-        if self.await_detection {
+        if self.height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NullifierStatusRequest", len)?;
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
+        if self.event.is_some() {
+            len += 1;
         }
-        if self.await_detection {
-            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.Notification", len)?;
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.event.as_ref() {
+            match v {
+                notification::Event::NoteReceived(v) => {
+                    struct_ser.serialize_field("noteReceived", v)?;
+                }
+                notification::Event::NoteSpent(v) => {
+                    struct_ser.serialize_field("noteSpent", v)?;
+                }
+                notification::Event::SwapClaimable(v) => {
+                    struct_ser.serialize_field("swapClaimable", v)?;
+                }
+                notification::Event::UndelegationClaimable(v) => {
+                    struct_ser.serialize_field("undelegationClaimable", v)?;
+                }
+                notification::Event::PositionStateChanged(v) => {
+                    struct_ser.serialize_field("positionStateChanged", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
+impl<'de> serde::Deserialize<'de> for Notification {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "nullifier",
-            "await_detection",
-            "awaitDetection",
+            "wallet_id",
+            "walletId",
+            "height",
+            "note_received",
+            "noteReceived",
+            "note_spent",
+            "noteSpent",
+            "swap_claimable",
+            "swapClaimable",
+            "undelegation_claimable",
+            "undelegationClaimable",
+            "position_state_changed",
+            "positionStateChanged",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Nullifier,
-            AwaitDetection,
+            WalletId,
+            Height,
+            NoteReceived,
+            NoteSpent,
+            SwapClaimable,
+            UndelegationClaimable,
+            PositionStateChanged,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2707,8 +3032,13 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
+                            "height" => Ok(GeneratedField::Height),
+                            "noteReceived" | "note_received" => Ok(GeneratedField::NoteReceived),
+                            "noteSpent" | "note_spent" => Ok(GeneratedField::NoteSpent),
+                            "swapClaimable" | "swap_claimable" => Ok(GeneratedField::SwapClaimable),
+                            "undelegationClaimable" | "undelegation_claimable" => Ok(GeneratedField::UndelegationClaimable),
+                            "positionStateChanged" | "position_state_changed" => Ok(GeneratedField::PositionStateChanged),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2718,44 +3048,83 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NullifierStatusRequest;
+            type Value = Notification;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.NullifierStatusRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.Notification")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Notification, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut nullifier__ = None;
-                let mut await_detection__ = None;
+                let mut wallet_id__ = None;
+                let mut height__ = None;
+                let mut event__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
                             }
-                            nullifier__ = map_.next_value()?;
+                            wallet_id__ = map_.next_value()?;
                         }
-                        GeneratedField::AwaitDetection => {
-                            if await_detection__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            await_detection__ = Some(map_.next_value()?);
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NoteReceived => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteReceived"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(notification::Event::NoteReceived)
+;
+                        }
+                        GeneratedField::NoteSpent => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteSpent"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(notification::Event::NoteSpent)
+;
+                        }
+                        GeneratedField::SwapClaimable => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapClaimable"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(notification::Event::SwapClaimable)
+;
+                        }
+                        GeneratedField::UndelegationClaimable => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("undelegationClaimable"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(notification::Event::UndelegationClaimable)
+;
+                        }
+                        GeneratedField::PositionStateChanged => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionStateChanged"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(notification::Event::PositionStateChanged)
+;
                         }
                     }
                 }
-                Ok(NullifierStatusRequest {
-                    nullifier: nullifier__,
-                    await_detection: await_detection__.unwrap_or_default(),
+                Ok(Notification {
+                    wallet_id: wallet_id__,
+                    height: height__.unwrap_or_default(),
+                    event: event__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.NullifierStatusRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.Notification", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NullifierStatusResponse {
+impl serde::Serialize for notification::NoteReceived {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2763,29 +3132,30 @@ impl serde::Serialize for NullifierStatusResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.spent {
+        if self.note_record.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NullifierStatusResponse", len)?;
-        if self.spent {
-            struct_ser.serialize_field("spent", &self.spent)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.Notification.NoteReceived", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
+impl<'de> serde::Deserialize<'de> for notification::NoteReceived {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "spent",
+            "note_record",
+            "noteRecord",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Spent,
+            NoteRecord,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2807,7 +3177,7 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "spent" => Ok(GeneratedField::Spent),
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2817,36 +3187,36 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NullifierStatusResponse;
+            type Value = notification::NoteReceived;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.NullifierStatusResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.Notification.NoteReceived")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<notification::NoteReceived, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut spent__ = None;
+                let mut note_record__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Spent => {
-                            if spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("spent"));
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
                             }
-                            spent__ = Some(map_.next_value()?);
+                            note_record__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(NullifierStatusResponse {
-                    spent: spent__.unwrap_or_default(),
+                Ok(notification::NoteReceived {
+                    note_record: note_record__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.NullifierStatusResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.Notification.NoteReceived", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for OwnedPositionIdsRequest {
+impl serde::Serialize for notification::NoteSpent {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2854,39 +3224,30 @@ impl serde::Serialize for OwnedPositionIdsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_state.is_some() {
-            len += 1;
-        }
-        if self.trading_pair.is_some() {
+        if self.note_record.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", len)?;
-        if let Some(v) = self.position_state.as_ref() {
-            struct_ser.serialize_field("positionState", v)?;
-        }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.Notification.NoteSpent", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
+impl<'de> serde::Deserialize<'de> for notification::NoteSpent {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_state",
-            "positionState",
-            "trading_pair",
-            "tradingPair",
+            "note_record",
+            "noteRecord",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionState,
-            TradingPair,
+            NoteRecord,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2908,8 +3269,7 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2919,44 +3279,36 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsRequest;
+            type Value = notification::NoteSpent;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.Notification.NoteSpent")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<notification::NoteSpent, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_state__ = None;
-                let mut trading_pair__ = None;
+                let mut note_record__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionState => {
-                            if position_state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionState"));
-                            }
-                            position_state__ = map_.next_value()?;
-                        }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            note_record__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(OwnedPositionIdsRequest {
-                    position_state: position_state__,
-                    trading_pair: trading_pair__,
+                Ok(notification::NoteSpent {
+                    note_record: note_record__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.Notification.NoteSpent", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for OwnedPositionIdsResponse {
+impl serde::Serialize for notification::PositionStateChanged {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2967,14 +3319,20 @@ impl serde::Serialize for OwnedPositionIdsResponse {
         if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", len)?;
+        if self.state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.Notification.PositionStateChanged", len)?;
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
         }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
+impl<'de> serde::Deserialize<'de> for notification::PositionStateChanged {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2983,11 +3341,13 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
+            "state",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
+            State,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3010,6 +3370,7 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "state" => Ok(GeneratedField::State),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3019,17 +3380,18 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsResponse;
+            type Value = notification::PositionStateChanged;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.Notification.PositionStateChanged")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<notification::PositionStateChanged, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut position_id__ = None;
+                let mut state__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
@@ -3038,17 +3400,24 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
                             }
                             position_id__ = map_.next_value()?;
                         }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
                     }
                 }
-                Ok(OwnedPositionIdsResponse {
+                Ok(notification::PositionStateChanged {
                     position_id: position_id__,
+                    state: state__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.Notification.PositionStateChanged", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SpendableNoteRecord {
+impl serde::Serialize for notification::SwapClaimable {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3056,92 +3425,30 @@ impl serde::Serialize for SpendableNoteRecord {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.note_commitment.is_some() {
-            len += 1;
-        }
-        if self.note.is_some() {
-            len += 1;
-        }
-        if self.address_index.is_some() {
-            len += 1;
-        }
-        if self.nullifier.is_some() {
-            len += 1;
-        }
-        if self.height_created != 0 {
-            len += 1;
-        }
-        if self.height_spent != 0 {
-            len += 1;
-        }
-        if self.position != 0 {
-            len += 1;
-        }
-        if self.source.is_some() {
+        if self.swap_record.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.SpendableNoteRecord", len)?;
-        if let Some(v) = self.note_commitment.as_ref() {
-            struct_ser.serialize_field("noteCommitment", v)?;
-        }
-        if let Some(v) = self.note.as_ref() {
-            struct_ser.serialize_field("note", v)?;
-        }
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
-        }
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
-        }
-        if self.height_created != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("heightCreated", ToString::to_string(&self.height_created).as_str())?;
-        }
-        if self.height_spent != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("heightSpent", ToString::to_string(&self.height_spent).as_str())?;
-        }
-        if self.position != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
-        }
-        if let Some(v) = self.source.as_ref() {
-            struct_ser.serialize_field("source", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.Notification.SwapClaimable", len)?;
+        if let Some(v) = self.swap_record.as_ref() {
+            struct_ser.serialize_field("swapRecord", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
+impl<'de> serde::Deserialize<'de> for notification::SwapClaimable {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_commitment",
-            "noteCommitment",
-            "note",
-            "address_index",
-            "addressIndex",
-            "nullifier",
-            "height_created",
-            "heightCreated",
-            "height_spent",
-            "heightSpent",
-            "position",
-            "source",
+            "swap_record",
+            "swapRecord",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteCommitment,
-            Note,
-            AddressIndex,
-            Nullifier,
-            HeightCreated,
-            HeightSpent,
-            Position,
-            Source,
+            SwapRecord,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3163,14 +3470,7 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
-                            "note" => Ok(GeneratedField::Note),
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "heightCreated" | "height_created" => Ok(GeneratedField::HeightCreated),
-                            "heightSpent" | "height_spent" => Ok(GeneratedField::HeightSpent),
-                            "position" => Ok(GeneratedField::Position),
-                            "source" => Ok(GeneratedField::Source),
+                            "swapRecord" | "swap_record" => Ok(GeneratedField::SwapRecord),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3180,120 +3480,67 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SpendableNoteRecord;
+            type Value = notification::SwapClaimable;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.SpendableNoteRecord")
+                formatter.write_str("struct penumbra.view.v1alpha1.Notification.SwapClaimable")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpendableNoteRecord, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<notification::SwapClaimable, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_commitment__ = None;
-                let mut note__ = None;
-                let mut address_index__ = None;
-                let mut nullifier__ = None;
-                let mut height_created__ = None;
-                let mut height_spent__ = None;
-                let mut position__ = None;
-                let mut source__ = None;
+                let mut swap_record__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NoteCommitment => {
-                            if note_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
-                            }
-                            note_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Note => {
-                            if note__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("note"));
-                            }
-                            note__ = map_.next_value()?;
-                        }
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
-                            }
-                            address_index__ = map_.next_value()?;
-                        }
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                        GeneratedField::SwapRecord => {
+                            if swap_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapRecord"));
                             }
-                            nullifier__ = map_.next_value()?;
-                        }
-                        GeneratedField::HeightCreated => {
-                            if height_created__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("heightCreated"));
-                            }
-                            height_created__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::HeightSpent => {
-                            if height_spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("heightSpent"));
-                            }
-                            height_spent__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
-                            }
-                            position__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Source => {
-                            if source__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("source"));
-                            }
-                            source__ = map_.next_value()?;
+                            swap_record__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(SpendableNoteRecord {
-                    note_commitment: note_commitment__,
-                    note: note__,
-                    address_index: address_index__,
-                    nullifier: nullifier__,
-                    height_created: height_created__.unwrap_or_default(),
-                    height_spent: height_spent__.unwrap_or_default(),
-                    position: position__.unwrap_or_default(),
-                    source: source__,
+                Ok(notification::SwapClaimable {
+                    swap_record: swap_record__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.SpendableNoteRecord", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.Notification.SwapClaimable", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusRequest {
+impl serde::Serialize for notification::UndelegationClaimable {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.StatusRequest", len)?;
+        let mut len = 0;
+        if self.note_record.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.Notification.UndelegationClaimable", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusRequest {
+impl<'de> serde::Deserialize<'de> for notification::UndelegationClaimable {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "note_record",
+            "noteRecord",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            NoteRecord,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3314,7 +3561,10 @@ impl<'de> serde::Deserialize<'de> for StatusRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -3322,27 +3572,36 @@ impl<'de> serde::Deserialize<'de> for StatusRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusRequest;
+            type Value = notification::UndelegationClaimable;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.StatusRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.Notification.UndelegationClaimable")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<notification::UndelegationClaimable, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut note_record__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
+                            }
+                            note_record__ = map_.next_value()?;
+                        }
+                    }
                 }
-                Ok(StatusRequest {
+                Ok(notification::UndelegationClaimable {
+                    note_record: note_record__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.StatusRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.Notification.UndelegationClaimable", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusResponse {
+impl serde::Serialize for NotificationsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3350,50 +3609,30 @@ impl serde::Serialize for StatusResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.full_sync_height != 0 {
-            len += 1;
-        }
-        if self.partial_sync_height != 0 {
-            len += 1;
-        }
-        if self.catching_up {
+        if self.wallet_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.StatusResponse", len)?;
-        if self.full_sync_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("fullSyncHeight", ToString::to_string(&self.full_sync_height).as_str())?;
-        }
-        if self.partial_sync_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("partialSyncHeight", ToString::to_string(&self.partial_sync_height).as_str())?;
-        }
-        if self.catching_up {
-            struct_ser.serialize_field("catchingUp", &self.catching_up)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotificationsRequest", len)?;
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusResponse {
+impl<'de> serde::Deserialize<'de> for NotificationsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "full_sync_height",
-            "fullSyncHeight",
-            "partial_sync_height",
-            "partialSyncHeight",
-            "catching_up",
-            "catchingUp",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            FullSyncHeight,
-            PartialSyncHeight,
-            CatchingUp,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3415,9 +3654,7 @@ impl<'de> serde::Deserialize<'de> for StatusResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "fullSyncHeight" | "full_sync_height" => Ok(GeneratedField::FullSyncHeight),
-                            "partialSyncHeight" | "partial_sync_height" => Ok(GeneratedField::PartialSyncHeight),
-                            "catchingUp" | "catching_up" => Ok(GeneratedField::CatchingUp),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3427,78 +3664,66 @@ impl<'de> serde::Deserialize<'de> for StatusResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusResponse;
+            type Value = NotificationsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.StatusResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.NotificationsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotificationsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut full_sync_height__ = None;
-                let mut partial_sync_height__ = None;
-                let mut catching_up__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::FullSyncHeight => {
-                            if full_sync_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fullSyncHeight"));
-                            }
-                            full_sync_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::PartialSyncHeight => {
-                            if partial_sync_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("partialSyncHeight"));
-                            }
-                            partial_sync_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::CatchingUp => {
-                            if catching_up__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("catchingUp"));
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
                             }
-                            catching_up__ = Some(map_.next_value()?);
+                            wallet_id__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(StatusResponse {
-                    full_sync_height: full_sync_height__.unwrap_or_default(),
-                    partial_sync_height: partial_sync_height__.unwrap_or_default(),
-                    catching_up: catching_up__.unwrap_or_default(),
+                Ok(NotificationsRequest {
+                    wallet_id: wallet_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.StatusResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NotificationsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusStreamRequest {
+impl serde::Serialize for NotificationsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.StatusStreamRequest", len)?;
+        let mut len = 0;
+        if self.notification.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotificationsResponse", len)?;
+        if let Some(v) = self.notification.as_ref() {
+            struct_ser.serialize_field("notification", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusStreamRequest {
+impl<'de> serde::Deserialize<'de> for NotificationsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "notification",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Notification,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3519,7 +3744,10 @@ impl<'de> serde::Deserialize<'de> for StatusStreamRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "notification" => Ok(GeneratedField::Notification),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -3527,27 +3755,36 @@ impl<'de> serde::Deserialize<'de> for StatusStreamRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusStreamRequest;
+            type Value = NotificationsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.StatusStreamRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.NotificationsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusStreamRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotificationsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut notification__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Notification => {
+                            if notification__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("notification"));
+                            }
+                            notification__ = map_.next_value()?;
+                        }
+                    }
                 }
-                Ok(StatusStreamRequest {
+                Ok(NotificationsResponse {
+                    notification: notification__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.StatusStreamRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NotificationsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusStreamResponse {
+impl serde::Serialize for NullifierStatusRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3555,51 +3792,47 @@ impl serde::Serialize for StatusStreamResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.latest_known_block_height != 0 {
+        if self.nullifier.is_some() {
             len += 1;
         }
-        if self.full_sync_height != 0 {
+        if self.await_detection {
             len += 1;
         }
-        if self.partial_sync_height != 0 {
+        if self.wallet_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.StatusStreamResponse", len)?;
-        if self.latest_known_block_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("latestKnownBlockHeight", ToString::to_string(&self.latest_known_block_height).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NullifierStatusRequest", len)?;
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
         }
-        if self.full_sync_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("fullSyncHeight", ToString::to_string(&self.full_sync_height).as_str())?;
+        if self.await_detection {
+            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
         }
-        if self.partial_sync_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("partialSyncHeight", ToString::to_string(&self.partial_sync_height).as_str())?;
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusStreamResponse {
+impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "latest_known_block_height",
-            "latestKnownBlockHeight",
-            "full_sync_height",
-            "fullSyncHeight",
-            "partial_sync_height",
-            "partialSyncHeight",
+            "nullifier",
+            "await_detection",
+            "awaitDetection",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            LatestKnownBlockHeight,
-            FullSyncHeight,
-            PartialSyncHeight,
+            Nullifier,
+            AwaitDetection,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3621,8 +3854,1133 @@ impl<'de> serde::Deserialize<'de> for StatusStreamResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "latestKnownBlockHeight" | "latest_known_block_height" => Ok(GeneratedField::LatestKnownBlockHeight),
-                            "fullSyncHeight" | "full_sync_height" => Ok(GeneratedField::FullSyncHeight),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NullifierStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.NullifierStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut nullifier__ = None;
+                let mut await_detection__ = None;
+                let mut wallet_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::AwaitDetection => {
+                            if await_detection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                            }
+                            await_detection__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(NullifierStatusRequest {
+                    nullifier: nullifier__,
+                    await_detection: await_detection__.unwrap_or_default(),
+                    wallet_id: wallet_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NullifierStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NullifierStatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.spent {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NullifierStatusResponse", len)?;
+        if self.spent {
+            struct_ser.serialize_field("spent", &self.spent)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "spent",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Spent,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "spent" => Ok(GeneratedField::Spent),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NullifierStatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.NullifierStatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut spent__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Spent => {
+                            if spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spent"));
+                            }
+                            spent__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(NullifierStatusResponse {
+                    spent: spent__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NullifierStatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OwnedPositionIdsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_state.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", len)?;
+        if let Some(v) = self.position_state.as_ref() {
+            struct_ser.serialize_field("positionState", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_state",
+            "positionState",
+            "trading_pair",
+            "tradingPair",
+            "wallet_id",
+            "walletId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionState,
+            TradingPair,
+            WalletId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_state__ = None;
+                let mut trading_pair__ = None;
+                let mut wallet_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionState => {
+                            if position_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionState"));
+                            }
+                            position_state__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(OwnedPositionIdsRequest {
+                    position_state: position_state__,
+                    trading_pair: trading_pair__,
+                    wallet_id: wallet_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OwnedPositionIdsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(OwnedPositionIdsResponse {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RootDetectionKeyRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.RootDetectionKeyRequest", len)?;
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RootDetectionKeyRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "wallet_id",
+            "walletId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WalletId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RootDetectionKeyRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.RootDetectionKeyRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RootDetectionKeyRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut wallet_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RootDetectionKeyRequest {
+                    wallet_id: wallet_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.RootDetectionKeyRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RootDetectionKeyResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.detection_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.RootDetectionKeyResponse", len)?;
+        if let Some(v) = self.detection_key.as_ref() {
+            struct_ser.serialize_field("detectionKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RootDetectionKeyResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "detection_key",
+            "detectionKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DetectionKey,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "detectionKey" | "detection_key" => Ok(GeneratedField::DetectionKey),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RootDetectionKeyResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.RootDetectionKeyResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RootDetectionKeyResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut detection_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DetectionKey => {
+                            if detection_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("detectionKey"));
+                            }
+                            detection_key__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RootDetectionKeyResponse {
+                    detection_key: detection_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.RootDetectionKeyResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SpendableNoteRecord {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.note_commitment.is_some() {
+            len += 1;
+        }
+        if self.note.is_some() {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        if self.height_created != 0 {
+            len += 1;
+        }
+        if self.height_spent != 0 {
+            len += 1;
+        }
+        if self.position != 0 {
+            len += 1;
+        }
+        if self.source.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.SpendableNoteRecord", len)?;
+        if let Some(v) = self.note_commitment.as_ref() {
+            struct_ser.serialize_field("noteCommitment", v)?;
+        }
+        if let Some(v) = self.note.as_ref() {
+            struct_ser.serialize_field("note", v)?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if self.height_created != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightCreated", ToString::to_string(&self.height_created).as_str())?;
+        }
+        if self.height_spent != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightSpent", ToString::to_string(&self.height_spent).as_str())?;
+        }
+        if self.position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        }
+        if let Some(v) = self.source.as_ref() {
+            struct_ser.serialize_field("source", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "note_commitment",
+            "noteCommitment",
+            "note",
+            "address_index",
+            "addressIndex",
+            "nullifier",
+            "height_created",
+            "heightCreated",
+            "height_spent",
+            "heightSpent",
+            "position",
+            "source",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NoteCommitment,
+            Note,
+            AddressIndex,
+            Nullifier,
+            HeightCreated,
+            HeightSpent,
+            Position,
+            Source,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
+                            "note" => Ok(GeneratedField::Note),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "heightCreated" | "height_created" => Ok(GeneratedField::HeightCreated),
+                            "heightSpent" | "height_spent" => Ok(GeneratedField::HeightSpent),
+                            "position" => Ok(GeneratedField::Position),
+                            "source" => Ok(GeneratedField::Source),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SpendableNoteRecord;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.SpendableNoteRecord")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpendableNoteRecord, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut note_commitment__ = None;
+                let mut note__ = None;
+                let mut address_index__ = None;
+                let mut nullifier__ = None;
+                let mut height_created__ = None;
+                let mut height_spent__ = None;
+                let mut position__ = None;
+                let mut source__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NoteCommitment => {
+                            if note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
+                            }
+                            note_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Note => {
+                            if note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("note"));
+                            }
+                            note__ = map_.next_value()?;
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeightCreated => {
+                            if height_created__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightCreated"));
+                            }
+                            height_created__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::HeightSpent => {
+                            if height_spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightSpent"));
+                            }
+                            height_spent__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Source => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("source"));
+                            }
+                            source__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SpendableNoteRecord {
+                    note_commitment: note_commitment__,
+                    note: note__,
+                    address_index: address_index__,
+                    nullifier: nullifier__,
+                    height_created: height_created__.unwrap_or_default(),
+                    height_spent: height_spent__.unwrap_or_default(),
+                    position: position__.unwrap_or_default(),
+                    source: source__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.SpendableNoteRecord", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.StatusRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.StatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(StatusRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.StatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.full_sync_height != 0 {
+            len += 1;
+        }
+        if self.partial_sync_height != 0 {
+            len += 1;
+        }
+        if self.catching_up {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.StatusResponse", len)?;
+        if self.full_sync_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fullSyncHeight", ToString::to_string(&self.full_sync_height).as_str())?;
+        }
+        if self.partial_sync_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("partialSyncHeight", ToString::to_string(&self.partial_sync_height).as_str())?;
+        }
+        if self.catching_up {
+            struct_ser.serialize_field("catchingUp", &self.catching_up)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "full_sync_height",
+            "fullSyncHeight",
+            "partial_sync_height",
+            "partialSyncHeight",
+            "catching_up",
+            "catchingUp",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FullSyncHeight,
+            PartialSyncHeight,
+            CatchingUp,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fullSyncHeight" | "full_sync_height" => Ok(GeneratedField::FullSyncHeight),
+                            "partialSyncHeight" | "partial_sync_height" => Ok(GeneratedField::PartialSyncHeight),
+                            "catchingUp" | "catching_up" => Ok(GeneratedField::CatchingUp),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.StatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut full_sync_height__ = None;
+                let mut partial_sync_height__ = None;
+                let mut catching_up__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FullSyncHeight => {
+                            if full_sync_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullSyncHeight"));
+                            }
+                            full_sync_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PartialSyncHeight => {
+                            if partial_sync_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partialSyncHeight"));
+                            }
+                            partial_sync_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CatchingUp => {
+                            if catching_up__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("catchingUp"));
+                            }
+                            catching_up__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(StatusResponse {
+                    full_sync_height: full_sync_height__.unwrap_or_default(),
+                    partial_sync_height: partial_sync_height__.unwrap_or_default(),
+                    catching_up: catching_up__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.StatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusStreamRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.StatusStreamRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusStreamRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatusStreamRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.StatusStreamRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusStreamRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(StatusStreamRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.StatusStreamRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusStreamResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.latest_known_block_height != 0 {
+            len += 1;
+        }
+        if self.full_sync_height != 0 {
+            len += 1;
+        }
+        if self.partial_sync_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.StatusStreamResponse", len)?;
+        if self.latest_known_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("latestKnownBlockHeight", ToString::to_string(&self.latest_known_block_height).as_str())?;
+        }
+        if self.full_sync_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fullSyncHeight", ToString::to_string(&self.full_sync_height).as_str())?;
+        }
+        if self.partial_sync_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("partialSyncHeight", ToString::to_string(&self.partial_sync_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusStreamResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "latest_known_block_height",
+            "latestKnownBlockHeight",
+            "full_sync_height",
+            "fullSyncHeight",
+            "partial_sync_height",
+            "partialSyncHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            LatestKnownBlockHeight,
+            FullSyncHeight,
+            PartialSyncHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "latestKnownBlockHeight" | "latest_known_block_height" => Ok(GeneratedField::LatestKnownBlockHeight),
+                            "fullSyncHeight" | "full_sync_height" => Ok(GeneratedField::FullSyncHeight),
                             "partialSyncHeight" | "partial_sync_height" => Ok(GeneratedField::PartialSyncHeight),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
@@ -3633,58 +4991,479 @@ impl<'de> serde::Deserialize<'de> for StatusStreamResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusStreamResponse;
+            type Value = StatusStreamResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.StatusStreamResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusStreamResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut latest_known_block_height__ = None;
+                let mut full_sync_height__ = None;
+                let mut partial_sync_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::LatestKnownBlockHeight => {
+                            if latest_known_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("latestKnownBlockHeight"));
+                            }
+                            latest_known_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FullSyncHeight => {
+                            if full_sync_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullSyncHeight"));
+                            }
+                            full_sync_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PartialSyncHeight => {
+                            if partial_sync_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partialSyncHeight"));
+                            }
+                            partial_sync_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(StatusStreamResponse {
+                    latest_known_block_height: latest_known_block_height__.unwrap_or_default(),
+                    full_sync_height: full_sync_height__.unwrap_or_default(),
+                    partial_sync_height: partial_sync_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.StatusStreamResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SwapByCommitmentRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.swap_commitment.is_some() {
+            len += 1;
+        }
+        if self.await_detection {
+            len += 1;
+        }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.SwapByCommitmentRequest", len)?;
+        if let Some(v) = self.swap_commitment.as_ref() {
+            struct_ser.serialize_field("swapCommitment", v)?;
+        }
+        if self.await_detection {
+            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SwapByCommitmentRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "swap_commitment",
+            "swapCommitment",
+            "await_detection",
+            "awaitDetection",
+            "wallet_id",
+            "walletId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SwapCommitment,
+            AwaitDetection,
+            WalletId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SwapByCommitmentRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.SwapByCommitmentRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapByCommitmentRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut swap_commitment__ = None;
+                let mut await_detection__ = None;
+                let mut wallet_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SwapCommitment => {
+                            if swap_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
+                            }
+                            swap_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::AwaitDetection => {
+                            if await_detection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                            }
+                            await_detection__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SwapByCommitmentRequest {
+                    swap_commitment: swap_commitment__,
+                    await_detection: await_detection__.unwrap_or_default(),
+                    wallet_id: wallet_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.SwapByCommitmentRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SwapByCommitmentResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.swap.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.SwapByCommitmentResponse", len)?;
+        if let Some(v) = self.swap.as_ref() {
+            struct_ser.serialize_field("swap", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SwapByCommitmentResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "swap",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Swap,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "swap" => Ok(GeneratedField::Swap),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SwapByCommitmentResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.SwapByCommitmentResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapByCommitmentResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut swap__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Swap => {
+                            if swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swap"));
+                            }
+                            swap__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SwapByCommitmentResponse {
+                    swap: swap__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.SwapByCommitmentResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SwapRecord {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.swap_commitment.is_some() {
+            len += 1;
+        }
+        if self.swap.is_some() {
+            len += 1;
+        }
+        if self.position != 0 {
+            len += 1;
+        }
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        if self.output_data.is_some() {
+            len += 1;
+        }
+        if self.height_claimed != 0 {
+            len += 1;
+        }
+        if self.source.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.SwapRecord", len)?;
+        if let Some(v) = self.swap_commitment.as_ref() {
+            struct_ser.serialize_field("swapCommitment", v)?;
+        }
+        if let Some(v) = self.swap.as_ref() {
+            struct_ser.serialize_field("swap", v)?;
+        }
+        if self.position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        }
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if let Some(v) = self.output_data.as_ref() {
+            struct_ser.serialize_field("outputData", v)?;
+        }
+        if self.height_claimed != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightClaimed", ToString::to_string(&self.height_claimed).as_str())?;
+        }
+        if let Some(v) = self.source.as_ref() {
+            struct_ser.serialize_field("source", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SwapRecord {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "swap_commitment",
+            "swapCommitment",
+            "swap",
+            "position",
+            "nullifier",
+            "output_data",
+            "outputData",
+            "height_claimed",
+            "heightClaimed",
+            "source",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SwapCommitment,
+            Swap,
+            Position,
+            Nullifier,
+            OutputData,
+            HeightClaimed,
+            Source,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            "swap" => Ok(GeneratedField::Swap),
+                            "position" => Ok(GeneratedField::Position),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
+                            "heightClaimed" | "height_claimed" => Ok(GeneratedField::HeightClaimed),
+                            "source" => Ok(GeneratedField::Source),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SwapRecord;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.StatusStreamResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.SwapRecord")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusStreamResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapRecord, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut latest_known_block_height__ = None;
-                let mut full_sync_height__ = None;
-                let mut partial_sync_height__ = None;
+                let mut swap_commitment__ = None;
+                let mut swap__ = None;
+                let mut position__ = None;
+                let mut nullifier__ = None;
+                let mut output_data__ = None;
+                let mut height_claimed__ = None;
+                let mut source__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::LatestKnownBlockHeight => {
-                            if latest_known_block_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("latestKnownBlockHeight"));
+                        GeneratedField::SwapCommitment => {
+                            if swap_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
                             }
-                            latest_known_block_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            swap_commitment__ = map_.next_value()?;
                         }
-                        GeneratedField::FullSyncHeight => {
-                            if full_sync_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fullSyncHeight"));
+                        GeneratedField::Swap => {
+                            if swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swap"));
                             }
-                            full_sync_height__ = 
+                            swap__ = map_.next_value()?;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::PartialSyncHeight => {
-                            if partial_sync_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("partialSyncHeight"));
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
                             }
-                            partial_sync_height__ = 
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputData => {
+                            if output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputData"));
+                            }
+                            output_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeightClaimed => {
+                            if height_claimed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightClaimed"));
+                            }
+                            height_claimed__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Source => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("source"));
+                            }
+                            source__ = map_.next_value()?;
+                        }
                     }
                 }
-                Ok(StatusStreamResponse {
-                    latest_known_block_height: latest_known_block_height__.unwrap_or_default(),
-                    full_sync_height: full_sync_height__.unwrap_or_default(),
-                    partial_sync_height: partial_sync_height__.unwrap_or_default(),
+                Ok(SwapRecord {
+                    swap_commitment: swap_commitment__,
+                    swap: swap__,
+                    position: position__.unwrap_or_default(),
+                    nullifier: nullifier__,
+                    output_data: output_data__,
+                    height_claimed: height_claimed__.unwrap_or_default(),
+                    source: source__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.StatusStreamResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.SwapRecord", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapByCommitmentRequest {
+impl serde::Serialize for TransactionHistoryEntry {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3692,39 +5471,90 @@ impl serde::Serialize for SwapByCommitmentRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_commitment.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        if self.await_detection {
+        if self.id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.SwapByCommitmentRequest", len)?;
-        if let Some(v) = self.swap_commitment.as_ref() {
-            struct_ser.serialize_field("swapCommitment", v)?;
+        if !self.balance_deltas.is_empty() {
+            len += 1;
         }
-        if self.await_detection {
-            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        if self.fee.is_some() {
+            len += 1;
+        }
+        if self.return_address.is_some() {
+            len += 1;
+        }
+        if !self.memo_text.is_empty() {
+            len += 1;
+        }
+        if !self.counterparties.is_empty() {
+            len += 1;
+        }
+        if !self.action_kinds.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionHistoryEntry", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
+        }
+        if !self.balance_deltas.is_empty() {
+            struct_ser.serialize_field("balanceDeltas", &self.balance_deltas)?;
+        }
+        if let Some(v) = self.fee.as_ref() {
+            struct_ser.serialize_field("fee", v)?;
+        }
+        if let Some(v) = self.return_address.as_ref() {
+            struct_ser.serialize_field("returnAddress", v)?;
+        }
+        if !self.memo_text.is_empty() {
+            struct_ser.serialize_field("memoText", &self.memo_text)?;
+        }
+        if !self.counterparties.is_empty() {
+            struct_ser.serialize_field("counterparties", &self.counterparties)?;
+        }
+        if !self.action_kinds.is_empty() {
+            struct_ser.serialize_field("actionKinds", &self.action_kinds)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapByCommitmentRequest {
+impl<'de> serde::Deserialize<'de> for TransactionHistoryEntry {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_commitment",
-            "swapCommitment",
-            "await_detection",
-            "awaitDetection",
+            "height",
+            "id",
+            "balance_deltas",
+            "balanceDeltas",
+            "fee",
+            "return_address",
+            "returnAddress",
+            "memo_text",
+            "memoText",
+            "counterparties",
+            "action_kinds",
+            "actionKinds",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapCommitment,
-            AwaitDetection,
+            Height,
+            Id,
+            BalanceDeltas,
+            Fee,
+            ReturnAddress,
+            MemoText,
+            Counterparties,
+            ActionKinds,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3746,8 +5576,14 @@ impl<'de> serde::Deserialize<'de> for SwapByCommitmentRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
-                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "height" => Ok(GeneratedField::Height),
+                            "id" => Ok(GeneratedField::Id),
+                            "balanceDeltas" | "balance_deltas" => Ok(GeneratedField::BalanceDeltas),
+                            "fee" => Ok(GeneratedField::Fee),
+                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
+                            "memoText" | "memo_text" => Ok(GeneratedField::MemoText),
+                            "counterparties" => Ok(GeneratedField::Counterparties),
+                            "actionKinds" | "action_kinds" => Ok(GeneratedField::ActionKinds),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3757,44 +5593,94 @@ impl<'de> serde::Deserialize<'de> for SwapByCommitmentRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapByCommitmentRequest;
+            type Value = TransactionHistoryEntry;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.SwapByCommitmentRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.TransactionHistoryEntry")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapByCommitmentRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionHistoryEntry, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_commitment__ = None;
-                let mut await_detection__ = None;
+                let mut height__ = None;
+                let mut id__ = None;
+                let mut balance_deltas__ = None;
+                let mut fee__ = None;
+                let mut return_address__ = None;
+                let mut memo_text__ = None;
+                let mut counterparties__ = None;
+                let mut action_kinds__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapCommitment => {
-                            if swap_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            swap_commitment__ = map_.next_value()?;
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::AwaitDetection => {
-                            if await_detection__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
                             }
-                            await_detection__ = Some(map_.next_value()?);
+                            id__ = map_.next_value()?;
                         }
-                    }
-                }
-                Ok(SwapByCommitmentRequest {
-                    swap_commitment: swap_commitment__,
-                    await_detection: await_detection__.unwrap_or_default(),
+                        GeneratedField::BalanceDeltas => {
+                            if balance_deltas__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balanceDeltas"));
+                            }
+                            balance_deltas__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::ReturnAddress => {
+                            if return_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnAddress"));
+                            }
+                            return_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::MemoText => {
+                            if memo_text__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memoText"));
+                            }
+                            memo_text__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Counterparties => {
+                            if counterparties__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("counterparties"));
+                            }
+                            counterparties__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ActionKinds => {
+                            if action_kinds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionKinds"));
+                            }
+                            action_kinds__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TransactionHistoryEntry {
+                    height: height__.unwrap_or_default(),
+                    id: id__,
+                    balance_deltas: balance_deltas__.unwrap_or_default(),
+                    fee: fee__,
+                    return_address: return_address__,
+                    memo_text: memo_text__.unwrap_or_default(),
+                    counterparties: counterparties__.unwrap_or_default(),
+                    action_kinds: action_kinds__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.SwapByCommitmentRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionHistoryEntry", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapByCommitmentResponse {
+impl serde::Serialize for TransactionHistoryRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3802,29 +5688,68 @@ impl serde::Serialize for SwapByCommitmentResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap.is_some() {
+        if self.start_height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.SwapByCommitmentResponse", len)?;
-        if let Some(v) = self.swap.as_ref() {
-            struct_ser.serialize_field("swap", v)?;
+        if self.end_height != 0 {
+            len += 1;
+        }
+        if self.account_filter.is_some() {
+            len += 1;
+        }
+        if self.asset_id_filter.is_some() {
+            len += 1;
+        }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionHistoryRequest", len)?;
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        if let Some(v) = self.account_filter.as_ref() {
+            struct_ser.serialize_field("accountFilter", v)?;
+        }
+        if let Some(v) = self.asset_id_filter.as_ref() {
+            struct_ser.serialize_field("assetIdFilter", v)?;
+        }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapByCommitmentResponse {
+impl<'de> serde::Deserialize<'de> for TransactionHistoryRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+            "account_filter",
+            "accountFilter",
+            "asset_id_filter",
+            "assetIdFilter",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Swap,
+            StartHeight,
+            EndHeight,
+            AccountFilter,
+            AssetIdFilter,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3846,7 +5771,11 @@ impl<'de> serde::Deserialize<'de> for SwapByCommitmentResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swap" => Ok(GeneratedField::Swap),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "accountFilter" | "account_filter" => Ok(GeneratedField::AccountFilter),
+                            "assetIdFilter" | "asset_id_filter" => Ok(GeneratedField::AssetIdFilter),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3856,36 +5785,72 @@ impl<'de> serde::Deserialize<'de> for SwapByCommitmentResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapByCommitmentResponse;
+            type Value = TransactionHistoryRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.SwapByCommitmentResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.TransactionHistoryRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapByCommitmentResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionHistoryRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                let mut account_filter__ = None;
+                let mut asset_id_filter__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Swap => {
-                            if swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swap"));
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
                             }
-                            swap__ = map_.next_value()?;
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AccountFilter => {
+                            if account_filter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountFilter"));
+                            }
+                            account_filter__ = map_.next_value()?;
+                        }
+                        GeneratedField::AssetIdFilter => {
+                            if asset_id_filter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetIdFilter"));
+                            }
+                            asset_id_filter__ = map_.next_value()?;
+                        }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(SwapByCommitmentResponse {
-                    swap: swap__,
+                Ok(TransactionHistoryRequest {
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                    account_filter: account_filter__,
+                    asset_id_filter: asset_id_filter__,
+                    wallet_id: wallet_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.SwapByCommitmentResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionHistoryRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapRecord {
+impl serde::Serialize for TransactionHistoryResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3893,82 +5858,29 @@ impl serde::Serialize for SwapRecord {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_commitment.is_some() {
-            len += 1;
-        }
-        if self.swap.is_some() {
-            len += 1;
-        }
-        if self.position != 0 {
-            len += 1;
-        }
-        if self.nullifier.is_some() {
+        if self.entry.is_some() {
             len += 1;
         }
-        if self.output_data.is_some() {
-            len += 1;
-        }
-        if self.height_claimed != 0 {
-            len += 1;
-        }
-        if self.source.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.SwapRecord", len)?;
-        if let Some(v) = self.swap_commitment.as_ref() {
-            struct_ser.serialize_field("swapCommitment", v)?;
-        }
-        if let Some(v) = self.swap.as_ref() {
-            struct_ser.serialize_field("swap", v)?;
-        }
-        if self.position != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
-        }
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
-        }
-        if let Some(v) = self.output_data.as_ref() {
-            struct_ser.serialize_field("outputData", v)?;
-        }
-        if self.height_claimed != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("heightClaimed", ToString::to_string(&self.height_claimed).as_str())?;
-        }
-        if let Some(v) = self.source.as_ref() {
-            struct_ser.serialize_field("source", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionHistoryResponse", len)?;
+        if let Some(v) = self.entry.as_ref() {
+            struct_ser.serialize_field("entry", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapRecord {
+impl<'de> serde::Deserialize<'de> for TransactionHistoryResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_commitment",
-            "swapCommitment",
-            "swap",
-            "position",
-            "nullifier",
-            "output_data",
-            "outputData",
-            "height_claimed",
-            "heightClaimed",
-            "source",
+            "entry",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapCommitment,
-            Swap,
-            Position,
-            Nullifier,
-            OutputData,
-            HeightClaimed,
-            Source,
+            Entry,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3990,13 +5902,7 @@ impl<'de> serde::Deserialize<'de> for SwapRecord {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
-                            "swap" => Ok(GeneratedField::Swap),
-                            "position" => Ok(GeneratedField::Position),
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
-                            "heightClaimed" | "height_claimed" => Ok(GeneratedField::HeightClaimed),
-                            "source" => Ok(GeneratedField::Source),
+                            "entry" => Ok(GeneratedField::Entry),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4006,85 +5912,33 @@ impl<'de> serde::Deserialize<'de> for SwapRecord {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapRecord;
+            type Value = TransactionHistoryResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.SwapRecord")
+                formatter.write_str("struct penumbra.view.v1alpha1.TransactionHistoryResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapRecord, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionHistoryResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_commitment__ = None;
-                let mut swap__ = None;
-                let mut position__ = None;
-                let mut nullifier__ = None;
-                let mut output_data__ = None;
-                let mut height_claimed__ = None;
-                let mut source__ = None;
+                let mut entry__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapCommitment => {
-                            if swap_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
-                            }
-                            swap_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Swap => {
-                            if swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swap"));
-                            }
-                            swap__ = map_.next_value()?;
-                        }
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
-                            }
-                            position__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
-                            }
-                            nullifier__ = map_.next_value()?;
-                        }
-                        GeneratedField::OutputData => {
-                            if output_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outputData"));
+                        GeneratedField::Entry => {
+                            if entry__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entry"));
                             }
-                            output_data__ = map_.next_value()?;
-                        }
-                        GeneratedField::HeightClaimed => {
-                            if height_claimed__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("heightClaimed"));
-                            }
-                            height_claimed__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Source => {
-                            if source__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("source"));
-                            }
-                            source__ = map_.next_value()?;
+                            entry__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(SwapRecord {
-                    swap_commitment: swap_commitment__,
-                    swap: swap__,
-                    position: position__.unwrap_or_default(),
-                    nullifier: nullifier__,
-                    output_data: output_data__,
-                    height_claimed: height_claimed__.unwrap_or_default(),
-                    source: source__,
+                Ok(TransactionHistoryResponse {
+                    entry: entry__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.SwapRecord", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionHistoryResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for TransactionInfo {
@@ -4260,10 +6114,16 @@ impl serde::Serialize for TransactionInfoByHashRequest {
         if self.id.is_some() {
             len += 1;
         }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionInfoByHashRequest", len)?;
         if let Some(v) = self.id.as_ref() {
             struct_ser.serialize_field("id", v)?;
         }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -4275,11 +6135,14 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
     {
         const FIELDS: &[&str] = &[
             "id",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4302,6 +6165,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
                     {
                         match value {
                             "id" => Ok(GeneratedField::Id),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4322,6 +6186,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut id__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -4330,10 +6195,17 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
                             }
                             id__ = map_.next_value()?;
                         }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(TransactionInfoByHashRequest {
                     id: id__,
+                    wallet_id: wallet_id__,
                 })
             }
         }
@@ -4446,6 +6318,9 @@ impl serde::Serialize for TransactionInfoRequest {
         if self.end_height != 0 {
             len += 1;
         }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionInfoRequest", len)?;
         if self.start_height != 0 {
             #[allow(clippy::needless_borrow)]
@@ -4455,6 +6330,9 @@ impl serde::Serialize for TransactionInfoRequest {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
         }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -4469,12 +6347,15 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
             "startHeight",
             "end_height",
             "endHeight",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             StartHeight,
             EndHeight,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4498,6 +6379,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
                         match value {
                             "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
                             "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4519,6 +6401,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
             {
                 let mut start_height__ = None;
                 let mut end_height__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::StartHeight => {
@@ -4537,11 +6420,18 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(TransactionInfoRequest {
                     start_height: start_height__.unwrap_or_default(),
                     end_height: end_height__.unwrap_or_default(),
+                    wallet_id: wallet_id__,
                 })
             }
         }
@@ -4660,6 +6550,12 @@ impl serde::Serialize for TransactionPlannerRequest {
         if self.source.is_some() {
             len += 1;
         }
+        if self.selection_strategy != 0 {
+            len += 1;
+        }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
         if !self.outputs.is_empty() {
             len += 1;
         }
@@ -4675,6 +6571,12 @@ impl serde::Serialize for TransactionPlannerRequest {
         if !self.undelegations.is_empty() {
             len += 1;
         }
+        if !self.redelegations.is_empty() {
+            len += 1;
+        }
+        if !self.redelegate_claims.is_empty() {
+            len += 1;
+        }
         if !self.ibc_relay_actions.is_empty() {
             len += 1;
         }
@@ -4704,6 +6606,14 @@ impl serde::Serialize for TransactionPlannerRequest {
         if let Some(v) = self.source.as_ref() {
             struct_ser.serialize_field("source", v)?;
         }
+        if self.selection_strategy != 0 {
+            let v = transaction_planner_request::SelectionStrategy::try_from(self.selection_strategy)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.selection_strategy)))?;
+            struct_ser.serialize_field("selectionStrategy", &v)?;
+        }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         if !self.outputs.is_empty() {
             struct_ser.serialize_field("outputs", &self.outputs)?;
         }
@@ -4719,6 +6629,12 @@ impl serde::Serialize for TransactionPlannerRequest {
         if !self.undelegations.is_empty() {
             struct_ser.serialize_field("undelegations", &self.undelegations)?;
         }
+        if !self.redelegations.is_empty() {
+            struct_ser.serialize_field("redelegations", &self.redelegations)?;
+        }
+        if !self.redelegate_claims.is_empty() {
+            struct_ser.serialize_field("redelegateClaims", &self.redelegate_claims)?;
+        }
         if !self.ibc_relay_actions.is_empty() {
             struct_ser.serialize_field("ibcRelayActions", &self.ibc_relay_actions)?;
         }
@@ -4749,12 +6665,19 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
             "fee",
             "memo",
             "source",
+            "selection_strategy",
+            "selectionStrategy",
+            "wallet_id",
+            "walletId",
             "outputs",
             "swaps",
             "swap_claims",
             "swapClaims",
             "delegations",
             "undelegations",
+            "redelegations",
+            "redelegate_claims",
+            "redelegateClaims",
             "ibc_relay_actions",
             "ibcRelayActions",
             "ics20_withdrawals",
@@ -4773,11 +6696,15 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
             Fee,
             Memo,
             Source,
+            SelectionStrategy,
+            WalletId,
             Outputs,
             Swaps,
             SwapClaims,
             Delegations,
             Undelegations,
+            Redelegations,
+            RedelegateClaims,
             IbcRelayActions,
             Ics20Withdrawals,
             PositionOpens,
@@ -4808,11 +6735,15 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                             "fee" => Ok(GeneratedField::Fee),
                             "memo" => Ok(GeneratedField::Memo),
                             "source" => Ok(GeneratedField::Source),
+                            "selectionStrategy" | "selection_strategy" => Ok(GeneratedField::SelectionStrategy),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             "outputs" => Ok(GeneratedField::Outputs),
                             "swaps" => Ok(GeneratedField::Swaps),
                             "swapClaims" | "swap_claims" => Ok(GeneratedField::SwapClaims),
                             "delegations" => Ok(GeneratedField::Delegations),
                             "undelegations" => Ok(GeneratedField::Undelegations),
+                            "redelegations" => Ok(GeneratedField::Redelegations),
+                            "redelegateClaims" | "redelegate_claims" => Ok(GeneratedField::RedelegateClaims),
                             "ibcRelayActions" | "ibc_relay_actions" => Ok(GeneratedField::IbcRelayActions),
                             "ics20Withdrawals" | "ics20_withdrawals" => Ok(GeneratedField::Ics20Withdrawals),
                             "positionOpens" | "position_opens" => Ok(GeneratedField::PositionOpens),
@@ -4841,11 +6772,15 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                 let mut fee__ = None;
                 let mut memo__ = None;
                 let mut source__ = None;
+                let mut selection_strategy__ = None;
+                let mut wallet_id__ = None;
                 let mut outputs__ = None;
                 let mut swaps__ = None;
                 let mut swap_claims__ = None;
                 let mut delegations__ = None;
                 let mut undelegations__ = None;
+                let mut redelegations__ = None;
+                let mut redelegate_claims__ = None;
                 let mut ibc_relay_actions__ = None;
                 let mut ics20_withdrawals__ = None;
                 let mut position_opens__ = None;
@@ -4879,6 +6814,18 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                             }
                             source__ = map_.next_value()?;
                         }
+                        GeneratedField::SelectionStrategy => {
+                            if selection_strategy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("selectionStrategy"));
+                            }
+                            selection_strategy__ = Some(map_.next_value::<transaction_planner_request::SelectionStrategy>()? as i32);
+                        }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                         GeneratedField::Outputs => {
                             if outputs__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("outputs"));
@@ -4909,6 +6856,18 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                             }
                             undelegations__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Redelegations => {
+                            if redelegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegations"));
+                            }
+                            redelegations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RedelegateClaims => {
+                            if redelegate_claims__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegateClaims"));
+                            }
+                            redelegate_claims__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::IbcRelayActions => {
                             if ibc_relay_actions__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("ibcRelayActions"));
@@ -4946,11 +6905,15 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                     fee: fee__,
                     memo: memo__,
                     source: source__,
+                    selection_strategy: selection_strategy__.unwrap_or_default(),
+                    wallet_id: wallet_id__,
                     outputs: outputs__.unwrap_or_default(),
                     swaps: swaps__.unwrap_or_default(),
                     swap_claims: swap_claims__.unwrap_or_default(),
                     delegations: delegations__.unwrap_or_default(),
                     undelegations: undelegations__.unwrap_or_default(),
+                    redelegations: redelegations__.unwrap_or_default(),
+                    redelegate_claims: redelegate_claims__.unwrap_or_default(),
                     ibc_relay_actions: ibc_relay_actions__.unwrap_or_default(),
                     ics20_withdrawals: ics20_withdrawals__.unwrap_or_default(),
                     position_opens: position_opens__.unwrap_or_default(),
@@ -5463,30 +7426,409 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithd
                             if position_id__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            position_id__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(transaction_planner_request::PositionWithdraw {
+                    position_id: position_id__,
+                    reserves: reserves__,
+                    trading_pair: trading_pair__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.PositionWithdraw", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::Redelegate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        if self.from_rate_data.is_some() {
+            len += 1;
+        }
+        if self.to_rate_data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.Redelegate", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.from_rate_data.as_ref() {
+            struct_ser.serialize_field("fromRateData", v)?;
+        }
+        if let Some(v) = self.to_rate_data.as_ref() {
+            struct_ser.serialize_field("toRateData", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::Redelegate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+            "from_rate_data",
+            "fromRateData",
+            "to_rate_data",
+            "toRateData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+            FromRateData,
+            ToRateData,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            "fromRateData" | "from_rate_data" => Ok(GeneratedField::FromRateData),
+                            "toRateData" | "to_rate_data" => Ok(GeneratedField::ToRateData),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::Redelegate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.TransactionPlannerRequest.Redelegate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Redelegate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                let mut from_rate_data__ = None;
+                let mut to_rate_data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map_.next_value()?;
+                        }
+                        GeneratedField::FromRateData => {
+                            if from_rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromRateData"));
+                            }
+                            from_rate_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::ToRateData => {
+                            if to_rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toRateData"));
+                            }
+                            to_rate_data__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(transaction_planner_request::Redelegate {
+                    value: value__,
+                    from_rate_data: from_rate_data__,
+                    to_rate_data: to_rate_data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.Redelegate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::RedelegateClaim {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.from_validator_identity.is_some() {
+            len += 1;
+        }
+        if self.to_validator_identity.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        if self.escrowed_amount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.RedelegateClaim", len)?;
+        if let Some(v) = self.from_validator_identity.as_ref() {
+            struct_ser.serialize_field("fromValidatorIdentity", v)?;
+        }
+        if let Some(v) = self.to_validator_identity.as_ref() {
+            struct_ser.serialize_field("toValidatorIdentity", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        if let Some(v) = self.escrowed_amount.as_ref() {
+            struct_ser.serialize_field("escrowedAmount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::RedelegateClaim {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "from_validator_identity",
+            "fromValidatorIdentity",
+            "to_validator_identity",
+            "toValidatorIdentity",
+            "start_epoch_index",
+            "startEpochIndex",
+            "penalty",
+            "escrowed_amount",
+            "escrowedAmount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FromValidatorIdentity,
+            ToValidatorIdentity,
+            StartEpochIndex,
+            Penalty,
+            EscrowedAmount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fromValidatorIdentity" | "from_validator_identity" => Ok(GeneratedField::FromValidatorIdentity),
+                            "toValidatorIdentity" | "to_validator_identity" => Ok(GeneratedField::ToValidatorIdentity),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            "escrowedAmount" | "escrowed_amount" => Ok(GeneratedField::EscrowedAmount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::RedelegateClaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.TransactionPlannerRequest.RedelegateClaim")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::RedelegateClaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut from_validator_identity__ = None;
+                let mut to_validator_identity__ = None;
+                let mut start_epoch_index__ = None;
+                let mut penalty__ = None;
+                let mut escrowed_amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FromValidatorIdentity => {
+                            if from_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromValidatorIdentity"));
+                            }
+                            from_validator_identity__ = map_.next_value()?;
                         }
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
+                        GeneratedField::ToValidatorIdentity => {
+                            if to_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toValidatorIdentity"));
                             }
-                            reserves__ = map_.next_value()?;
+                            to_validator_identity__ = map_.next_value()?;
                         }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
+                            }
+                            penalty__ = map_.next_value()?;
+                        }
+                        GeneratedField::EscrowedAmount => {
+                            if escrowed_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("escrowedAmount"));
+                            }
+                            escrowed_amount__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::PositionWithdraw {
-                    position_id: position_id__,
-                    reserves: reserves__,
-                    trading_pair: trading_pair__,
+                Ok(transaction_planner_request::RedelegateClaim {
+                    from_validator_identity: from_validator_identity__,
+                    to_validator_identity: to_validator_identity__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    penalty: penalty__,
+                    escrowed_amount: escrowed_amount__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.PositionWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.RedelegateClaim", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::SelectionStrategy {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "SELECTION_STRATEGY_UNSPECIFIED",
+            Self::Greedy => "SELECTION_STRATEGY_GREEDY",
+            Self::MinimizeSpends => "SELECTION_STRATEGY_MINIMIZE_SPENDS",
+            Self::PreferSingleAddress => "SELECTION_STRATEGY_PREFER_SINGLE_ADDRESS",
+            Self::SingleAddress => "SELECTION_STRATEGY_SINGLE_ADDRESS",
+            Self::ConsolidateDust => "SELECTION_STRATEGY_CONSOLIDATE_DUST",
+            Self::Random => "SELECTION_STRATEGY_RANDOM",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::SelectionStrategy {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "SELECTION_STRATEGY_UNSPECIFIED",
+            "SELECTION_STRATEGY_GREEDY",
+            "SELECTION_STRATEGY_MINIMIZE_SPENDS",
+            "SELECTION_STRATEGY_PREFER_SINGLE_ADDRESS",
+            "SELECTION_STRATEGY_SINGLE_ADDRESS",
+            "SELECTION_STRATEGY_CONSOLIDATE_DUST",
+            "SELECTION_STRATEGY_RANDOM",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::SelectionStrategy;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "SELECTION_STRATEGY_UNSPECIFIED" => Ok(transaction_planner_request::SelectionStrategy::Unspecified),
+                    "SELECTION_STRATEGY_GREEDY" => Ok(transaction_planner_request::SelectionStrategy::Greedy),
+                    "SELECTION_STRATEGY_MINIMIZE_SPENDS" => Ok(transaction_planner_request::SelectionStrategy::MinimizeSpends),
+                    "SELECTION_STRATEGY_PREFER_SINGLE_ADDRESS" => Ok(transaction_planner_request::SelectionStrategy::PreferSingleAddress),
+                    "SELECTION_STRATEGY_SINGLE_ADDRESS" => Ok(transaction_planner_request::SelectionStrategy::SingleAddress),
+                    "SELECTION_STRATEGY_CONSOLIDATE_DUST" => Ok(transaction_planner_request::SelectionStrategy::ConsolidateDust),
+                    "SELECTION_STRATEGY_RANDOM" => Ok(transaction_planner_request::SelectionStrategy::Random),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
     }
 }
 impl serde::Serialize for transaction_planner_request::Swap {
@@ -5932,8 +8274,14 @@ impl serde::Serialize for UnclaimedSwapsRequest {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.UnclaimedSwapsRequest", len)?;
+        let mut len = 0;
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.UnclaimedSwapsRequest", len)?;
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -5944,10 +8292,13 @@ impl<'de> serde::Deserialize<'de> for UnclaimedSwapsRequest {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5968,7 +8319,10 @@ impl<'de> serde::Deserialize<'de> for UnclaimedSwapsRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -5986,10 +8340,19 @@ impl<'de> serde::Deserialize<'de> for UnclaimedSwapsRequest {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut wallet_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
+                    }
                 }
                 Ok(UnclaimedSwapsRequest {
+                    wallet_id: wallet_id__,
                 })
             }
         }
@@ -6250,6 +8613,169 @@ impl<'de> serde::Deserialize<'de> for WalletIdResponse {
         deserializer.deserialize_struct("penumbra.view.v1alpha1.WalletIdResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for WalletsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.WalletsRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WalletsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WalletsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.WalletsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WalletsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(WalletsRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.WalletsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WalletsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.WalletsResponse", len)?;
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WalletsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "wallet_id",
+            "walletId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WalletId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WalletsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.WalletsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WalletsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut wallet_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(WalletsResponse {
+                    wallet_id: wallet_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.WalletsResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for WitnessAndBuildRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -6264,6 +8790,9 @@ impl serde::Serialize for WitnessAndBuildRequest {
         if self.authorization_data.is_some() {
             len += 1;
         }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.WitnessAndBuildRequest", len)?;
         if let Some(v) = self.transaction_plan.as_ref() {
             struct_ser.serialize_field("transactionPlan", v)?;
@@ -6271,6 +8800,9 @@ impl serde::Serialize for WitnessAndBuildRequest {
         if let Some(v) = self.authorization_data.as_ref() {
             struct_ser.serialize_field("authorizationData", v)?;
         }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -6285,12 +8817,15 @@ impl<'de> serde::Deserialize<'de> for WitnessAndBuildRequest {
             "transactionPlan",
             "authorization_data",
             "authorizationData",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             TransactionPlan,
             AuthorizationData,
+            WalletId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -6314,6 +8849,7 @@ impl<'de> serde::Deserialize<'de> for WitnessAndBuildRequest {
                         match value {
                             "transactionPlan" | "transaction_plan" => Ok(GeneratedField::TransactionPlan),
                             "authorizationData" | "authorization_data" => Ok(GeneratedField::AuthorizationData),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -6335,6 +8871,7 @@ impl<'de> serde::Deserialize<'de> for WitnessAndBuildRequest {
             {
                 let mut transaction_plan__ = None;
                 let mut authorization_data__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::TransactionPlan => {
@@ -6349,11 +8886,18 @@ impl<'de> serde::Deserialize<'de> for WitnessAndBuildRequest {
                             }
                             authorization_data__ = map_.next_value()?;
                         }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(WitnessAndBuildRequest {
                     transaction_plan: transaction_plan__,
                     authorization_data: authorization_data__,
+                    wallet_id: wallet_id__,
                 })
             }
         }
@@ -6465,6 +9009,9 @@ impl serde::Serialize for WitnessRequest {
         if self.transaction_plan.is_some() {
             len += 1;
         }
+        if self.batch_proofs {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.WitnessRequest", len)?;
         if !self.note_commitments.is_empty() {
             struct_ser.serialize_field("noteCommitments", &self.note_commitments)?;
@@ -6472,6 +9019,9 @@ impl serde::Serialize for WitnessRequest {
         if let Some(v) = self.transaction_plan.as_ref() {
             struct_ser.serialize_field("transactionPlan", v)?;
         }
+        if self.batch_proofs {
+            struct_ser.serialize_field("batchProofs", &self.batch_proofs)?;
+        }
         struct_ser.end()
     }
 }
@@ -6486,12 +9036,15 @@ impl<'de> serde::Deserialize<'de> for WitnessRequest {
             "noteCommitments",
             "transaction_plan",
             "transactionPlan",
+            "batch_proofs",
+            "batchProofs",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             NoteCommitments,
             TransactionPlan,
+            BatchProofs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -6515,6 +9068,7 @@ impl<'de> serde::Deserialize<'de> for WitnessRequest {
                         match value {
                             "noteCommitments" | "note_commitments" => Ok(GeneratedField::NoteCommitments),
                             "transactionPlan" | "transaction_plan" => Ok(GeneratedField::TransactionPlan),
+                            "batchProofs" | "batch_proofs" => Ok(GeneratedField::BatchProofs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -6536,6 +9090,7 @@ impl<'de> serde::Deserialize<'de> for WitnessRequest {
             {
                 let mut note_commitments__ = None;
                 let mut transaction_plan__ = None;
+                let mut batch_proofs__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::NoteCommitments => {
@@ -6550,11 +9105,18 @@ impl<'de> serde::Deserialize<'de> for WitnessRequest {
                             }
                             transaction_plan__ = map_.next_value()?;
                         }
+                        GeneratedField::BatchProofs => {
+                            if batch_proofs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchProofs"));
+                            }
+                            batch_proofs__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(WitnessRequest {
                     note_commitments: note_commitments__.unwrap_or_default(),
                     transaction_plan: transaction_plan__,
+                    batch_proofs: batch_proofs__.unwrap_or_default(),
                 })
             }
         }
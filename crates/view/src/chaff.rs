@@ -0,0 +1,99 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Controls decoy ("chaff") downloads performed by the view worker during sync.
+///
+/// The worker only needs the full transactions of blocks in which it detected something relevant
+/// to a tracked wallet, but fetching exactly those blocks reveals to the node which blocks the
+/// wallet cares about. To hide them, the worker also fetches the transactions of a random
+/// selection of the other blocks it scans, in the same way and at the same point in sync as a real
+/// fetch, and discards them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "ChaffPolicyConfig", into = "ChaffPolicyConfig")]
+pub struct ChaffPolicy {
+    rate: f64,
+}
+
+/// The serialized form of a [`ChaffPolicy`], validated by [`ChaffPolicy::new`] on the way in.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct ChaffPolicyConfig {
+    rate: f64,
+}
+
+impl TryFrom<ChaffPolicyConfig> for ChaffPolicy {
+    type Error = anyhow::Error;
+
+    fn try_from(config: ChaffPolicyConfig) -> Result<Self, Self::Error> {
+        Self::new(config.rate)
+    }
+}
+
+impl From<ChaffPolicy> for ChaffPolicyConfig {
+    fn from(policy: ChaffPolicy) -> Self {
+        Self { rate: policy.rate }
+    }
+}
+
+impl ChaffPolicy {
+    /// Creates a policy downloading chaff for the given fraction of irrelevant blocks.
+    ///
+    /// The rate must be between `0.0` and `1.0`. At `0.0` (the default), no chaff downloads are
+    /// made. At `1.0`, every scanned block's transactions are downloaded, so the node learns
+    /// nothing from the fetch pattern, at the cost of bandwidth.
+    pub fn new(rate: f64) -> anyhow::Result<Self> {
+        // Written so that NaN is rejected too.
+        if !(0.0..=1.0).contains(&rate) {
+            anyhow::bail!("chaff rate must be between 0 and 1, got {rate}");
+        }
+        Ok(Self { rate })
+    }
+
+    /// The fraction of scanned blocks with nothing relevant to any tracked wallet whose
+    /// transactions are downloaded anyway.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Decides whether to make a chaff download for an irrelevant block.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> bool {
+        rng.gen_bool(self.rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn new_rejects_rates_outside_unit_interval() {
+        for rate in [f64::NAN, -0.1, 1.1, f64::INFINITY] {
+            assert!(ChaffPolicy::new(rate).is_err(), "accepted {rate}");
+        }
+        for rate in [0.0, 0.25, 1.0] {
+            assert_eq!(ChaffPolicy::new(rate).unwrap().rate(), rate);
+        }
+    }
+
+    #[test]
+    fn deserialization_validates_rate() {
+        let policy: ChaffPolicy = serde_json::from_str(r#"{"rate":0.5}"#).unwrap();
+        assert_eq!(policy.rate(), 0.5);
+        assert_eq!(serde_json::to_string(&policy).unwrap(), r#"{"rate":0.5}"#);
+
+        assert!(serde_json::from_str::<ChaffPolicy>(r#"{"rate":1.5}"#).is_err());
+        assert!(serde_json::from_str::<ChaffPolicy>(r#"{"rate":-1}"#).is_err());
+    }
+
+    #[test]
+    fn sample_respects_extreme_rates() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let never = ChaffPolicy::default();
+        let always = ChaffPolicy::new(1.0).unwrap();
+        for _ in 0..1000 {
+            assert!(!never.sample(&mut rng));
+            assert!(always.sample(&mut rng));
+        }
+    }
+}
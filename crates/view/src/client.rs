@@ -13,7 +13,10 @@ use penumbra_dex::{
     TradingPair,
 };
 use penumbra_fee::GasPrices;
-use penumbra_keys::{keys::AddressIndex, Address};
+use penumbra_keys::{
+    keys::{AddressIndex, WalletId},
+    Address,
+};
 use penumbra_num::Amount;
 use penumbra_proto::view::v1alpha1::{
     self as pb, view_protocol_service_client::ViewProtocolServiceClient, WitnessRequest,
@@ -26,7 +29,10 @@ use penumbra_transaction::{
     plan::TransactionPlan, txhash::TransactionId, Transaction, WitnessData,
 };
 
-use crate::{SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo};
+use crate::{
+    Notification, SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionHistoryEntry,
+    TransactionInfo,
+};
 
 /// The view protocol is used by a view client, who wants to do some
 /// transaction-related actions, to request data from a view service, which is
@@ -61,6 +67,22 @@ pub trait ViewClient {
         >,
     >;
 
+    /// Stream events concerning the tracked wallets as they are detected, or only those
+    /// concerning `wallet_id`, if it is given.
+    fn notifications(
+        &mut self,
+        wallet_id: Option<WalletId>,
+    ) -> Pin<
+        Box<
+            dyn Future<
+                    Output = Result<
+                        Pin<Box<dyn Stream<Item = Result<Notification>> + Send + 'static>>,
+                    >,
+                > + Send
+                + 'static,
+        >,
+    >;
+
     /// Get a copy of the app parameters.
     fn app_params(
         &mut self,
@@ -169,6 +191,16 @@ pub trait ViewClient {
         end_height: Option<u64>,
     ) -> Pin<Box<dyn Future<Output = Result<Vec<TransactionInfo>>> + Send + 'static>>;
 
+    /// Queries for summaries of the transactions in a range of block heights, optionally
+    /// restricted to the balance changes of an account and an asset.
+    fn transaction_history(
+        &mut self,
+        start_height: Option<u64>,
+        end_height: Option<u64>,
+        account: Option<u32>,
+        asset_id: Option<asset::Id>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<TransactionHistoryEntry>>> + Send + 'static>>;
+
     fn broadcast_transaction(
         &mut self,
         transaction: Transaction,
@@ -296,6 +328,13 @@ pub trait ViewClient {
         address_index: AddressIndex,
     ) -> Pin<Box<dyn Future<Output = Result<Address>> + Send + 'static>>;
 
+    /// Queries for the root detection key, from which the detection key of every address derived
+    /// with [`DetectionKeyDerivation::Root`](penumbra_keys::keys::DetectionKeyDerivation::Root) is
+    /// derived.
+    fn root_detection_key(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<decaf377_fmd::DetectionKey>> + Send + 'static>>;
+
     /// Queries for unclaimed Swaps.
     fn unclaimed_swaps(
         &mut self,
@@ -353,6 +392,38 @@ where
         .boxed()
     }
 
+    fn notifications(
+        &mut self,
+        wallet_id: Option<WalletId>,
+    ) -> Pin<
+        Box<
+            dyn Future<
+                    Output = Result<
+                        Pin<Box<dyn Stream<Item = Result<Notification>> + Send + 'static>>,
+                    >,
+                > + Send
+                + 'static,
+        >,
+    > {
+        let mut self2 = self.clone();
+        async move {
+            let stream = self2.notifications(tonic::Request::new(pb::NotificationsRequest {
+                wallet_id: wallet_id.map(Into::into),
+            }));
+            let stream = stream.await?.into_inner();
+
+            Ok(stream
+                .map_err(|e| anyhow::anyhow!("view service error: {}", e))
+                .and_then(|msg| async move {
+                    msg.notification
+                        .ok_or_else(|| anyhow::anyhow!("missing notification"))?
+                        .try_into()
+                })
+                .boxed())
+        }
+        .boxed()
+    }
+
     fn app_params(
         &mut self,
     ) -> Pin<Box<dyn Future<Output = Result<AppParameters>> + Send + 'static>> {
@@ -473,6 +544,7 @@ where
                 tonic::Request::new(pb::NoteByCommitmentRequest {
                     note_commitment: Some(note_commitment.into()),
                     await_detection: false,
+                    wallet_id: None,
                 }),
             );
             let note_commitment_response = note_commitment_response.await?.into_inner();
@@ -497,6 +569,7 @@ where
                 tonic::Request::new(pb::BalancesRequest {
                     account_filter: Some(address_index.into()),
                     asset_id_filter: asset_id.map(Into::into),
+                    wallet_id: None,
                 }),
             );
 
@@ -537,6 +610,7 @@ where
                 tonic::Request::new(pb::SwapByCommitmentRequest {
                     swap_commitment: Some(swap_commitment.into()),
                     await_detection: false,
+                    wallet_id: None,
                 }),
             );
             let swap_commitment_response = swap_commitment_response.await?.into_inner();
@@ -563,6 +637,7 @@ where
                 tonic::Request::new(pb::NoteByCommitmentRequest {
                     note_commitment: Some(note_commitment.into()),
                     await_detection: true,
+                    wallet_id: None,
                 }),
             );
             let spendable_note = spendable_note.await?.into_inner().spendable_note;
@@ -586,6 +661,7 @@ where
                 tonic::Request::new(pb::NullifierStatusRequest {
                     nullifier: Some(nullifier.into()),
                     await_detection: false,
+                    wallet_id: None,
                 }),
             );
             Ok(rsp.await?.into_inner().spent)
@@ -606,6 +682,7 @@ where
                 tonic::Request::new(pb::NullifierStatusRequest {
                     nullifier: Some(nullifier.into()),
                     await_detection: true,
+                    wallet_id: None,
                 }),
             );
             rsp.await?;
@@ -640,6 +717,7 @@ where
         let request = WitnessRequest {
             note_commitments,
             transaction_plan: Some(plan.clone().into()),
+            batch_proofs: true,
         };
 
         let mut self2 = self.clone();
@@ -698,6 +776,7 @@ where
                 tonic::Request::new(pb::OwnedPositionIdsRequest {
                     trading_pair: trading_pair.map(TryInto::try_into).transpose()?,
                     position_state: position_state.map(TryInto::try_into).transpose()?,
+                    wallet_id: None,
                 }),
             );
 
@@ -727,6 +806,7 @@ where
                 &mut self2,
                 tonic::Request::new(pb::TransactionInfoByHashRequest {
                     id: Some(id.into()),
+                    wallet_id: None,
                 }),
             )
             .await?
@@ -779,6 +859,7 @@ where
             let rsp = self2.transaction_info(tonic::Request::new(pb::TransactionInfoRequest {
                 start_height: start_h,
                 end_height: end_h,
+                wallet_id: None,
             }));
             let pb_txs: Vec<_> = rsp.await?.into_inner().try_collect().await?;
 
@@ -823,6 +904,37 @@ where
         .boxed()
     }
 
+    fn transaction_history(
+        &mut self,
+        start_height: Option<u64>,
+        end_height: Option<u64>,
+        account: Option<u32>,
+        asset_id: Option<asset::Id>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<TransactionHistoryEntry>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp =
+                self2.transaction_history(tonic::Request::new(pb::TransactionHistoryRequest {
+                    start_height: start_height.unwrap_or_default(),
+                    end_height: end_height.unwrap_or_default(),
+                    account_filter: account.map(|account| AddressIndex::from(account).into()),
+                    asset_id_filter: asset_id.map(Into::into),
+                    wallet_id: None,
+                }));
+            let pb_entries: Vec<_> = rsp.await?.into_inner().try_collect().await?;
+
+            pb_entries
+                .into_iter()
+                .map(|rsp| {
+                    rsp.entry
+                        .ok_or_else(|| anyhow::anyhow!("empty TransactionHistoryResponse message"))?
+                        .try_into()
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
     fn broadcast_transaction(
         &mut self,
         transaction: Transaction,
@@ -835,6 +947,7 @@ where
                 tonic::Request::new(pb::BroadcastTransactionRequest {
                     transaction: Some(transaction.into()),
                     await_detection,
+                    wallet_id: None,
                 }),
             )
             .await?
@@ -858,6 +971,7 @@ where
         async move {
             let address = self2.address_by_index(tonic::Request::new(pb::AddressByIndexRequest {
                 address_index: Some(address_index.into()),
+                wallet_id: None,
             }));
             let address = address
                 .await?
@@ -870,6 +984,28 @@ where
         .boxed()
     }
 
+    fn root_detection_key(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<decaf377_fmd::DetectionKey>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let detection_key = self2
+                .root_detection_key(tonic::Request::new(pb::RootDetectionKeyRequest {
+                    wallet_id: None,
+                }))
+                .await?
+                .into_inner()
+                .detection_key
+                .ok_or_else(|| anyhow::anyhow!("empty RootDetectionKeyResponse message"))?;
+            let bytes: [u8; 32] = detection_key
+                .inner
+                .try_into()
+                .map_err(|_| anyhow::anyhow!("detection key must be 32 bytes"))?;
+            Ok(decaf377_fmd::DetectionKey::from_bytes(bytes)?)
+        }
+        .boxed()
+    }
+
     fn witness_and_build(
         &mut self,
         transaction_plan: TransactionPlan,
@@ -878,6 +1014,7 @@ where
         let request = pb::WitnessAndBuildRequest {
             transaction_plan: Some(transaction_plan.into()),
             authorization_data: Some(authorization_data.into()),
+            wallet_id: None,
         };
         let mut self2 = self.clone();
         async move {
@@ -902,7 +1039,7 @@ where
         async move {
             let swaps_response = ViewProtocolServiceClient::unclaimed_swaps(
                 &mut self2,
-                tonic::Request::new(pb::UnclaimedSwapsRequest {}),
+                tonic::Request::new(pb::UnclaimedSwapsRequest { wallet_id: None }),
             );
             let pb_swaps: Vec<_> = swaps_response.await?.into_inner().try_collect().await?;
 
@@ -0,0 +1,514 @@
+use std::collections::BTreeMap;
+
+use penumbra_asset::{asset, Value};
+use penumbra_dex::swap_claim::SwapClaimView;
+use penumbra_fee::Fee;
+use penumbra_keys::{keys::AddressIndex, Address, AddressView};
+use penumbra_num::Amount;
+use penumbra_proto::{view::v1alpha1 as pb, DomainType};
+use penumbra_shielded_pool::{NoteView, OutputView, SpendView};
+use penumbra_transaction::{txhash::TransactionId, ActionView, MemoView};
+
+use crate::TransactionInfo;
+
+/// A summary of a transaction, as seen by the view service, suitable for exporting the history
+/// of a wallet.
+///
+/// Corresponds to the TransactionHistoryEntry proto.
+#[derive(Debug, Clone)]
+pub struct TransactionHistoryEntry {
+    /// The height the transaction was included in a block.
+    pub height: u64,
+    /// The hash of the transaction.
+    pub id: TransactionId,
+    /// The change the transaction made to the balance of each account, ordered by account.
+    pub balance_deltas: Vec<AccountBalanceDelta>,
+    /// The fee paid by the transaction.
+    pub fee: Fee,
+    /// The return address from the memo, if visible.
+    pub return_address: Option<Address>,
+    /// The text of the memo, if visible.
+    pub memo_text: Option<String>,
+    /// The addresses outside the wallet that the transaction sent outputs to.
+    pub counterparties: Vec<Address>,
+    /// The kinds of the transaction's actions, in order, e.g. `spend` or `output`.
+    pub action_kinds: Vec<String>,
+}
+
+/// The change a transaction made to the balance of an account.
+///
+/// Values received and sent are kept apart rather than netted, so that e.g. change outputs
+/// remain visible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountBalanceDelta {
+    pub account: u32,
+    /// The values the account received, ordered by asset.
+    pub received: Vec<Value>,
+    /// The values the account spent, ordered by asset.
+    pub sent: Vec<Value>,
+}
+
+impl TransactionHistoryEntry {
+    /// Restrict the entry to the balance changes of `account` and `asset_id`, if given.
+    ///
+    /// Returns `None` if the transaction didn't change any of the selected balances.
+    pub fn filtered(mut self, account: Option<u32>, asset_id: Option<asset::Id>) -> Option<Self> {
+        if account.is_none() && asset_id.is_none() {
+            return Some(self);
+        }
+
+        let matches_asset = |value: &Value| asset_id.map_or(true, |id| value.asset_id == id);
+        self.balance_deltas.retain_mut(|delta| {
+            delta.received.retain(matches_asset);
+            delta.sent.retain(matches_asset);
+            account.map_or(true, |account| delta.account == account)
+                && !(delta.received.is_empty() && delta.sent.is_empty())
+        });
+
+        (!self.balance_deltas.is_empty()).then_some(self)
+    }
+}
+
+impl From<&TransactionInfo> for TransactionHistoryEntry {
+    fn from(info: &TransactionInfo) -> Self {
+        #[derive(Default)]
+        struct Delta {
+            received: BTreeMap<asset::Id, Amount>,
+            sent: BTreeMap<asset::Id, Amount>,
+        }
+
+        let mut deltas: BTreeMap<u32, Delta> = BTreeMap::new();
+        let mut counterparties = Vec::new();
+
+        // Only notes at the wallet's own addresses change its balances.
+        let mut record = |note: &NoteView, received: bool| {
+            let AddressView::Visible { index, .. } = &note.address else {
+                return;
+            };
+            let value = note.value.value();
+            let delta = deltas.entry(index.account).or_default();
+            let amounts = if received {
+                &mut delta.received
+            } else {
+                &mut delta.sent
+            };
+            *amounts.entry(value.asset_id).or_default() += value.amount;
+        };
+
+        let body = &info.view.body_view;
+        for action in &body.action_views {
+            match action {
+                ActionView::Spend(SpendView::Visible { note, .. }) => record(note, false),
+                ActionView::Output(OutputView::Visible { note, .. }) => {
+                    if let AddressView::Opaque { address } = &note.address {
+                        if !counterparties.contains(address) {
+                            counterparties.push(address.clone());
+                        }
+                    }
+                    record(note, true)
+                }
+                ActionView::SwapClaim(SwapClaimView::Visible {
+                    output_1, output_2, ..
+                }) => {
+                    record(output_1, true);
+                    record(output_2, true);
+                }
+                _ => {}
+            }
+        }
+
+        let (return_address, memo_text) = match &body.memo_view {
+            Some(MemoView::Visible { plaintext, .. }) => (
+                Some(plaintext.return_address.address()),
+                Some(plaintext.text.clone()),
+            ),
+            _ => (None, None),
+        };
+
+        let to_values = |amounts: BTreeMap<asset::Id, Amount>| {
+            amounts
+                .into_iter()
+                .map(|(asset_id, amount)| Value { amount, asset_id })
+                .collect()
+        };
+
+        TransactionHistoryEntry {
+            height: info.height,
+            id: info.id,
+            balance_deltas: deltas
+                .into_iter()
+                .map(|(account, delta)| AccountBalanceDelta {
+                    account,
+                    received: to_values(delta.received),
+                    sent: to_values(delta.sent),
+                })
+                .collect(),
+            fee: body.transaction_parameters.fee.clone(),
+            return_address,
+            memo_text,
+            counterparties,
+            action_kinds: body
+                .action_views
+                .iter()
+                .map(|action| action_kind(action).to_string())
+                .collect(),
+        }
+    }
+}
+
+/// The name of the kind of an action, as used in the history export.
+fn action_kind(action: &ActionView) -> &'static str {
+    match action {
+        ActionView::Spend(_) => "spend",
+        ActionView::Output(_) => "output",
+        ActionView::Swap(_) => "swap",
+        ActionView::SwapClaim(_) => "swap_claim",
+        ActionView::Delegate(_) => "delegate",
+        ActionView::Undelegate(_) => "undelegate",
+        ActionView::UndelegateClaim(_) => "undelegate_claim",
+        ActionView::Redelegate(_) => "redelegate",
+        ActionView::RedelegateClaim(_) => "redelegate_claim",
+        ActionView::ValidatorDefinition(_) => "validator_definition",
+        ActionView::IbcRelay(_) => "ibc_relay",
+        ActionView::ProposalSubmit(_) => "proposal_submit",
+        ActionView::ProposalWithdraw(_) => "proposal_withdraw",
+        ActionView::ProposalDepositClaim(_) => "proposal_deposit_claim",
+        ActionView::ValidatorVote(_) => "validator_vote",
+        ActionView::DelegatorVote(_) => "delegator_vote",
+        ActionView::PositionOpen(_) => "position_open",
+        ActionView::PositionClose(_) => "position_close",
+        ActionView::PositionWithdraw(_) => "position_withdraw",
+        ActionView::PositionRewardClaim(_) => "position_reward_claim",
+        ActionView::Ics20Withdrawal(_) => "ics20_withdrawal",
+        ActionView::CommunityPoolDeposit(_) => "community_pool_deposit",
+        ActionView::CommunityPoolSpend(_) => "community_pool_spend",
+        ActionView::CommunityPoolOutput(_) => "community_pool_output",
+    }
+}
+
+impl DomainType for TransactionHistoryEntry {
+    type Proto = pb::TransactionHistoryEntry;
+}
+
+impl From<TransactionHistoryEntry> for pb::TransactionHistoryEntry {
+    fn from(v: TransactionHistoryEntry) -> Self {
+        pb::TransactionHistoryEntry {
+            height: v.height,
+            id: Some(v.id.into()),
+            balance_deltas: v.balance_deltas.into_iter().map(Into::into).collect(),
+            fee: Some(v.fee.into()),
+            return_address: v.return_address.map(Into::into),
+            memo_text: v.memo_text.unwrap_or_default(),
+            counterparties: v.counterparties.into_iter().map(Into::into).collect(),
+            action_kinds: v.action_kinds,
+        }
+    }
+}
+
+impl TryFrom<pb::TransactionHistoryEntry> for TransactionHistoryEntry {
+    type Error = anyhow::Error;
+
+    fn try_from(v: pb::TransactionHistoryEntry) -> Result<Self, Self::Error> {
+        Ok(TransactionHistoryEntry {
+            height: v.height,
+            id: v
+                .id
+                .ok_or_else(|| anyhow::anyhow!("missing id"))?
+                .try_into()?,
+            balance_deltas: v
+                .balance_deltas
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            fee: v
+                .fee
+                .ok_or_else(|| anyhow::anyhow!("missing fee"))?
+                .try_into()?,
+            return_address: v.return_address.map(TryInto::try_into).transpose()?,
+            // The memo text is empty both when the memo is absent and when it has no text, and
+            // there is nothing to tell apart when exporting.
+            memo_text: Some(v.memo_text).filter(|text| !text.is_empty()),
+            counterparties: v
+                .counterparties
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            action_kinds: v.action_kinds,
+        })
+    }
+}
+
+impl DomainType for AccountBalanceDelta {
+    type Proto = pb::AccountBalanceDelta;
+}
+
+impl From<AccountBalanceDelta> for pb::AccountBalanceDelta {
+    fn from(v: AccountBalanceDelta) -> Self {
+        pb::AccountBalanceDelta {
+            account: Some(AddressIndex::from(v.account).into()),
+            received: v.received.into_iter().map(Into::into).collect(),
+            sent: v.sent.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::AccountBalanceDelta> for AccountBalanceDelta {
+    type Error = anyhow::Error;
+
+    fn try_from(v: pb::AccountBalanceDelta) -> Result<Self, Self::Error> {
+        let account: AddressIndex = v
+            .account
+            .ok_or_else(|| anyhow::anyhow!("missing account"))?
+            .try_into()?;
+        Ok(AccountBalanceDelta {
+            account: account.account,
+            received: v
+                .received
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            sent: v
+                .sent
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_keys::{symmetric::PayloadKey, test_keys, FullViewingKey};
+    use penumbra_proto::core::component::shielded_pool::v1alpha1 as pb_sp;
+    use penumbra_shielded_pool::{Note, Output, OutputPlan, Spend, SpendPlan};
+    use penumbra_transaction::{
+        memo::MemoPlaintext, plan::MemoPlan, MemoPlaintextView, Transaction, TransactionParameters,
+        TransactionPerspective, TransactionView,
+    };
+    use rand_core::OsRng;
+
+    use super::*;
+
+    const PROOF_LENGTH: usize = 192;
+
+    fn fvk() -> &'static FullViewingKey {
+        &test_keys::FULL_VIEWING_KEY
+    }
+
+    fn gm() -> asset::Id {
+        asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .expect("gm is a known asset")
+            .id()
+    }
+
+    fn value(amount: u64, asset_id: asset::Id) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id,
+        }
+    }
+
+    fn address(account: u32) -> Address {
+        fvk().payment_address(AddressIndex::new(account)).0
+    }
+
+    fn external_address() -> Address {
+        penumbra_keys::keys::SpendKey::from_seed_phrase_bip44(
+            penumbra_keys::keys::SeedPhrase::generate(OsRng),
+            &penumbra_keys::keys::Bip44Path::new(0),
+        )
+        .full_viewing_key()
+        .payment_address(AddressIndex::new(0))
+        .0
+    }
+
+    fn note_view(note: Note) -> NoteView {
+        NoteView {
+            address: fvk().view_address(note.address()),
+            value: note
+                .value()
+                .view_with_cache(&asset::Cache::with_known_assets()),
+            rseed: note.rseed(),
+        }
+    }
+
+    /// A visible spend of a note of `value` at `account`; the proof and signature aren't looked at.
+    fn spend(account: u32, value: Value) -> ActionView {
+        let note = Note::generate(&mut OsRng, &address(account), value);
+        let plan = SpendPlan::new(&mut OsRng, note.clone(), 0u64.into());
+        let spend = Spend {
+            body: plan.spend_body(fvk()),
+            auth_sig: [0u8; 64].into(),
+            proof: pb_sp::ZkSpendProof {
+                inner: vec![0; PROOF_LENGTH],
+            }
+            .try_into()
+            .unwrap(),
+        };
+        ActionView::Spend(SpendView::Visible {
+            spend,
+            note: note_view(note),
+        })
+    }
+
+    /// A visible output of `value` to `address`.
+    fn output(address: Address, value: Value) -> ActionView {
+        let plan = OutputPlan::new(&mut OsRng, value, address);
+        let payload_key = PayloadKey::random_key(&mut OsRng);
+        let output = Output {
+            body: plan.output_body(fvk().outgoing(), &payload_key),
+            proof: pb_sp::ZkOutputProof {
+                inner: vec![0; PROOF_LENGTH],
+            }
+            .try_into()
+            .unwrap(),
+        };
+        ActionView::Output(OutputView::Visible {
+            output,
+            note: note_view(plan.output_note()),
+            payload_key,
+        })
+    }
+
+    fn info(action_views: Vec<ActionView>, memo_view: Option<MemoView>) -> TransactionInfo {
+        let transaction = Transaction::default();
+        TransactionInfo {
+            height: 42,
+            id: TransactionId([7; 32]),
+            view: TransactionView {
+                body_view: penumbra_transaction::view::TransactionBodyView {
+                    action_views,
+                    transaction_parameters: TransactionParameters {
+                        fee: Fee::from_staking_token_amount(1u64.into()),
+                        ..Default::default()
+                    },
+                    detection_data: None,
+                    memo_view,
+                },
+                binding_sig: transaction.binding_sig,
+                anchor: transaction.anchor,
+            },
+            transaction,
+            perspective: TransactionPerspective::default(),
+        }
+    }
+
+    /// Account 0 pays 3 penumbra to an external address, with 6 penumbra of change and a fee of
+    /// 1 penumbra, and sends 5 gm from account 0 to account 1.
+    fn payment() -> (TransactionInfo, Address) {
+        let counterparty = external_address();
+        let penumbra = *STAKING_TOKEN_ASSET_ID;
+        let info = info(
+            vec![
+                spend(0, value(10, penumbra)),
+                spend(0, value(5, gm())),
+                output(counterparty, value(3, penumbra)),
+                output(address(0), value(6, penumbra)),
+                output(address(1), value(5, gm())),
+                output(counterparty, value(0, penumbra)),
+            ],
+            None,
+        );
+        (info, counterparty)
+    }
+
+    #[test]
+    fn entry_summarizes_balance_changes_by_account() {
+        let (info, counterparty) = payment();
+        let entry = TransactionHistoryEntry::from(&info);
+
+        assert_eq!(entry.height, 42);
+        assert_eq!(entry.id, TransactionId([7; 32]));
+        assert_eq!(entry.fee, Fee::from_staking_token_amount(1u64.into()));
+        assert_eq!(
+            entry.balance_deltas,
+            vec![
+                AccountBalanceDelta {
+                    account: 0,
+                    received: vec![value(6, *STAKING_TOKEN_ASSET_ID)],
+                    sent: {
+                        let mut sent = vec![value(10, *STAKING_TOKEN_ASSET_ID), value(5, gm())];
+                        sent.sort_by_key(|value| value.asset_id);
+                        sent
+                    },
+                },
+                AccountBalanceDelta {
+                    account: 1,
+                    received: vec![value(5, gm())],
+                    sent: vec![],
+                },
+            ]
+        );
+        // Each external address is listed once, however many outputs it received.
+        assert_eq!(entry.counterparties, vec![counterparty]);
+        assert_eq!(
+            entry.action_kinds,
+            vec!["spend", "spend", "output", "output", "output", "output"]
+        );
+        assert_eq!(entry.return_address, None);
+        assert_eq!(entry.memo_text, None);
+    }
+
+    #[test]
+    fn entry_includes_visible_memo() {
+        let plaintext = MemoPlaintext::new(address(2), "for lunch".to_string()).unwrap();
+        let ciphertext = MemoPlan::new(&mut OsRng, plaintext)
+            .unwrap()
+            .memo()
+            .unwrap();
+        let memo_view = MemoView::Visible {
+            plaintext: MemoPlaintextView {
+                return_address: fvk().view_address(address(2)),
+                text: "for lunch".to_string(),
+            },
+            ciphertext: ciphertext.clone(),
+        };
+        let entry = TransactionHistoryEntry::from(&info(vec![], Some(memo_view)));
+        assert_eq!(entry.return_address, Some(address(2)));
+        assert_eq!(entry.memo_text.as_deref(), Some("for lunch"));
+
+        // An opaque memo reveals nothing.
+        let entry =
+            TransactionHistoryEntry::from(&info(vec![], Some(MemoView::Opaque { ciphertext })));
+        assert_eq!(entry.return_address, None);
+        assert_eq!(entry.memo_text, None);
+    }
+
+    #[test]
+    fn filtered_restricts_to_account_and_asset() {
+        let (info, _) = payment();
+        let entry = TransactionHistoryEntry::from(&info);
+
+        let unfiltered = entry.clone().filtered(None, None).unwrap();
+        assert_eq!(unfiltered.balance_deltas, entry.balance_deltas);
+
+        let account_1 = entry.clone().filtered(Some(1), None).unwrap();
+        assert_eq!(account_1.balance_deltas, entry.balance_deltas[1..]);
+
+        // Account 0 only received the staking token, but it also sent gm.
+        let gm_only = entry.clone().filtered(None, Some(gm())).unwrap();
+        assert_eq!(
+            gm_only.balance_deltas,
+            vec![
+                AccountBalanceDelta {
+                    account: 0,
+                    received: vec![],
+                    sent: vec![value(5, gm())],
+                },
+                AccountBalanceDelta {
+                    account: 1,
+                    received: vec![value(5, gm())],
+                    sent: vec![],
+                },
+            ]
+        );
+
+        let staking_at_1 = entry
+            .clone()
+            .filtered(Some(1), Some(*STAKING_TOKEN_ASSET_ID));
+        assert!(staking_at_1.is_none());
+        assert!(entry.filtered(Some(3), None).is_none());
+    }
+}
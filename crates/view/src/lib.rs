@@ -1,9 +1,13 @@
 #![deny(clippy::unwrap_used)]
 #![recursion_limit = "256"]
+mod chaff;
 mod client;
+mod history;
 mod metrics;
 mod note_record;
+mod notification;
 mod planner;
+mod selection;
 mod service;
 mod status;
 mod storage;
@@ -15,11 +19,17 @@ mod worker;
 use worker::Worker;
 
 pub use crate::metrics::register_metrics;
+pub use chaff::ChaffPolicy;
 pub use client::ViewClient;
+pub use history::{AccountBalanceDelta, TransactionHistoryEntry};
 pub use note_record::SpendableNoteRecord;
+pub use notification::{Notification, NotificationEvent};
 pub use planner::Planner;
+pub use selection::SelectionStrategy;
 pub use service::ViewService;
 pub use status::StatusStreamResponse;
-pub use storage::Storage;
+pub use storage::{
+    migrate_encryption, DatabaseEncryption, DatabaseKey, SctFrontier, Storage, PASSPHRASE_ENV_VAR,
+};
 pub use swap_record::SwapRecord;
 pub use transaction_info::TransactionInfo;
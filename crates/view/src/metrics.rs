@@ -11,20 +11,33 @@
 //! This trick is probably good to avoid in general, because it could be
 //! confusing, but in this limited case, it seems like a clean option.
 
-#[allow(unused_imports)]
 pub use metrics::*;
 
 /// Registers all metrics used by this crate.
 pub fn register_metrics() {
-    /*
-    // Sample code for reference -- delete when adding the first metric
-    register_counter!(MEMPOOL_CHECKTX_TOTAL);
+    register_counter!(VIEW_TRANSACTION_FETCHES_TOTAL);
     describe_counter!(
-        MEMPOOL_CHECKTX_TOTAL,
-        "The total number of checktx requests made to the mempool"
+        VIEW_TRANSACTION_FETCHES_TOTAL,
+        Unit::Count,
+        "The total number of blocks whose transactions were fetched because they were relevant to a tracked wallet"
+    );
+
+    register_counter!(VIEW_CHAFF_TRANSACTION_FETCHES_TOTAL);
+    describe_counter!(
+        VIEW_CHAFF_TRANSACTION_FETCHES_TOTAL,
+        Unit::Count,
+        "The total number of blocks whose transactions were fetched as chaff, to hide the relevant ones"
+    );
+
+    register_gauge!(VIEW_CHAFF_RATE);
+    describe_gauge!(
+        VIEW_CHAFF_RATE,
+        Unit::Count,
+        "The configured fraction of irrelevant blocks whose transactions are fetched as chaff"
     );
-     */
 }
 
-// Sample code for reference -- delete when adding the first metric
-// pub const MEMPOOL_CHECKTX_TOTAL: &str = "penumbra_pd_mempool_checktx_total";
+pub const VIEW_TRANSACTION_FETCHES_TOTAL: &str = "penumbra_view_transaction_fetches_total";
+pub const VIEW_CHAFF_TRANSACTION_FETCHES_TOTAL: &str =
+    "penumbra_view_chaff_transaction_fetches_total";
+pub const VIEW_CHAFF_RATE: &str = "penumbra_view_chaff_rate";
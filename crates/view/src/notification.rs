@@ -0,0 +1,131 @@
+use penumbra_dex::lp::position;
+use penumbra_keys::keys::WalletId;
+use penumbra_proto::{view::v1alpha1 as pb, DomainType};
+
+use crate::{SpendableNoteRecord, SwapRecord};
+
+/// An event concerning a tracked wallet, detected while scanning a block.
+///
+/// Corresponds to the Notification proto.
+#[derive(Debug, Clone)]
+pub struct Notification {
+    /// The wallet the event concerns.
+    pub wallet_id: WalletId,
+    /// The height of the block in which the event was detected.
+    pub height: u64,
+    pub event: NotificationEvent,
+}
+
+/// The kind of event a [`Notification`] describes.
+#[derive(Debug, Clone)]
+pub enum NotificationEvent {
+    /// A new note was received.
+    NoteReceived(SpendableNoteRecord),
+    /// A note was spent.
+    NoteSpent(SpendableNoteRecord),
+    /// A swap was detected, and its outputs can now be claimed.
+    SwapClaimable(SwapRecord),
+    /// The unbonding period of the unbonding tokens in this note has elapsed.
+    UndelegationClaimable(SpendableNoteRecord),
+    /// A position owned by the wallet changed state.
+    PositionStateChanged {
+        position_id: position::Id,
+        state: position::State,
+    },
+}
+
+impl DomainType for Notification {
+    type Proto = pb::Notification;
+}
+
+impl From<Notification> for pb::Notification {
+    fn from(v: Notification) -> Self {
+        use pb::notification as pbn;
+
+        let event = match v.event {
+            NotificationEvent::NoteReceived(note_record) => {
+                pbn::Event::NoteReceived(pbn::NoteReceived {
+                    note_record: Some(note_record.into()),
+                })
+            }
+            NotificationEvent::NoteSpent(note_record) => pbn::Event::NoteSpent(pbn::NoteSpent {
+                note_record: Some(note_record.into()),
+            }),
+            NotificationEvent::SwapClaimable(swap_record) => {
+                pbn::Event::SwapClaimable(pbn::SwapClaimable {
+                    swap_record: Some(swap_record.into()),
+                })
+            }
+            NotificationEvent::UndelegationClaimable(note_record) => {
+                pbn::Event::UndelegationClaimable(pbn::UndelegationClaimable {
+                    note_record: Some(note_record.into()),
+                })
+            }
+            NotificationEvent::PositionStateChanged { position_id, state } => {
+                pbn::Event::PositionStateChanged(pbn::PositionStateChanged {
+                    position_id: Some(position_id.into()),
+                    state: Some(state.into()),
+                })
+            }
+        };
+
+        pb::Notification {
+            wallet_id: Some(v.wallet_id.into()),
+            height: v.height,
+            event: Some(event),
+        }
+    }
+}
+
+impl TryFrom<pb::Notification> for Notification {
+    type Error = anyhow::Error;
+
+    fn try_from(v: pb::Notification) -> Result<Self, Self::Error> {
+        use pb::notification as pbn;
+
+        let event = match v
+            .event
+            .ok_or_else(|| anyhow::anyhow!("missing notification event"))?
+        {
+            pbn::Event::NoteReceived(e) => NotificationEvent::NoteReceived(
+                e.note_record
+                    .ok_or_else(|| anyhow::anyhow!("missing note record"))?
+                    .try_into()?,
+            ),
+            pbn::Event::NoteSpent(e) => NotificationEvent::NoteSpent(
+                e.note_record
+                    .ok_or_else(|| anyhow::anyhow!("missing note record"))?
+                    .try_into()?,
+            ),
+            pbn::Event::SwapClaimable(e) => NotificationEvent::SwapClaimable(
+                e.swap_record
+                    .ok_or_else(|| anyhow::anyhow!("missing swap record"))?
+                    .try_into()?,
+            ),
+            pbn::Event::UndelegationClaimable(e) => NotificationEvent::UndelegationClaimable(
+                e.note_record
+                    .ok_or_else(|| anyhow::anyhow!("missing note record"))?
+                    .try_into()?,
+            ),
+            pbn::Event::PositionStateChanged(e) => NotificationEvent::PositionStateChanged {
+                position_id: e
+                    .position_id
+                    .ok_or_else(|| anyhow::anyhow!("missing position id"))?
+                    .try_into()?,
+                state: e
+                    .state
+                    .ok_or_else(|| anyhow::anyhow!("missing position state"))?
+                    .try_into()?,
+            },
+        };
+
+        Ok(Notification {
+            wallet_id: v
+                .wallet_id
+                .ok_or_else(|| anyhow::anyhow!("missing wallet id"))?
+                .try_into()?,
+            height: v.height,
+            event,
+        })
+    }
+}
@@ -1,10 +1,10 @@
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     fmt::{self, Debug, Formatter},
     mem,
 };
 
-use anyhow::Result;
+use anyhow::{Context as _, Result};
 use rand::{CryptoRng, RngCore};
 use tracing::instrument;
 
@@ -23,8 +23,8 @@ use penumbra_dex::{
 };
 use penumbra_fee::{Fee, GasPrices};
 use penumbra_governance::{
-    proposal_state, DelegatorVotePlan, Proposal, ProposalDepositClaim, ProposalSubmit,
-    ProposalWithdraw, ValidatorVote, Vote,
+    proposal_state, Ballot, DelegatorVotePlan, Proposal, ProposalDepositClaim, ProposalSubmit,
+    ProposalWithdraw, ValidatorVote,
 };
 use penumbra_ibc::IbcRelay;
 use penumbra_keys::{keys::AddressIndex, Address};
@@ -32,7 +32,7 @@ use penumbra_num::Amount;
 use penumbra_proto::view::v1alpha1::{NotesForVotingRequest, NotesRequest};
 use penumbra_shielded_pool::{Ics20Withdrawal, Note, OutputPlan, SpendPlan};
 use penumbra_stake::{rate::RateData, validator};
-use penumbra_stake::{IdentityKey, UndelegateClaimPlan};
+use penumbra_stake::{IdentityKey, Penalty, RedelegateClaim, UndelegateClaimPlan};
 use penumbra_tct as tct;
 use penumbra_transaction::{
     gas::GasCost,
@@ -40,7 +40,7 @@ use penumbra_transaction::{
     plan::{ActionPlan, MemoPlan, TransactionPlan},
 };
 
-use crate::{SpendableNoteRecord, ViewClient};
+use crate::{SelectionStrategy, SpendableNoteRecord, ViewClient};
 
 /// A planner for a [`TransactionPlan`] that can fill in the required spends and change outputs upon
 /// finalization to make a transaction balance.
@@ -51,6 +51,8 @@ pub struct Planner<R: RngCore + CryptoRng> {
     plan: TransactionPlan,
     ibc_actions: Vec<IbcRelay>,
     gas_prices: GasPrices,
+    selection_strategy: SelectionStrategy,
+    excluded_notes: BTreeSet<tct::Position>,
     // IMPORTANT: if you add more fields here, make sure to clear them when the planner is finished
 }
 
@@ -59,7 +61,7 @@ struct VoteIntent {
     start_block_height: u64,
     start_position: tct::Position,
     rate_data: BTreeMap<IdentityKey, RateData>,
-    vote: Vote,
+    vote: Ballot,
 }
 
 impl<R: RngCore + CryptoRng> Debug for Planner<R> {
@@ -81,6 +83,8 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             plan: TransactionPlan::default(),
             ibc_actions: Vec::new(),
             gas_prices: GasPrices::zero(),
+            selection_strategy: SelectionStrategy::default(),
+            excluded_notes: BTreeSet::new(),
         }
     }
 
@@ -91,24 +95,48 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Set the strategy used to select the notes spent to balance the transaction.
+    #[instrument(skip(self))]
+    pub fn selection_strategy(&mut self, selection_strategy: SelectionStrategy) -> &mut Self {
+        self.selection_strategy = selection_strategy;
+        self
+    }
+
+    /// Never select the notes at these `positions` to balance the transaction, such as notes spent
+    /// by other plans which haven't been broadcast yet.
+    #[instrument(skip(self, positions))]
+    pub fn exclude_notes(
+        &mut self,
+        positions: impl IntoIterator<Item = tct::Position>,
+    ) -> &mut Self {
+        self.excluded_notes.extend(positions);
+        self
+    }
+
     /// Get the current transaction balance of the planner.
     pub fn balance(&self) -> &Balance {
         &self.balance
     }
 
     /// Get all the note requests necessary to fulfill the current [`Balance`].
+    ///
+    /// Unless the [`SelectionStrategy`] spends notes in the order they are stored and no notes are
+    /// excluded, the requests return all the spendable notes of each required asset, to select
+    /// from.
     pub fn notes_requests(
         &self,
         source: AddressIndex,
     ) -> (Vec<NotesRequest>, Vec<NotesForVotingRequest>) {
+        let cutoff = self.selection_strategy.uses_stored_order() && self.excluded_notes.is_empty();
         (
             self.balance
                 .required()
                 .map(|Value { asset_id, amount }| NotesRequest {
                     asset_id: Some(asset_id.into()),
                     address_index: Some(source.into()),
-                    amount_to_spend: Some(amount.into()),
+                    amount_to_spend: cutoff.then(|| amount.into()),
                     include_spent: false,
+                    wallet_id: None,
                 })
                 .collect(),
             self.vote_intents
@@ -122,6 +150,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
                     )| NotesForVotingRequest {
                         votable_at_height: *start_block_height,
                         address_index: Some(source.into()),
+                        wallet_id: None,
                     },
                 )
                 .collect(),
@@ -301,6 +330,51 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Add a redelegation to this transaction.
+    ///
+    /// Moves `delegation_amount` of the source validator's delegation tokens,
+    /// described by `from_rate_data`, to the validator described by `to_rate_data`.
+    #[instrument(skip(self))]
+    pub fn redelegate(
+        &mut self,
+        delegation_amount: Amount,
+        from_rate_data: RateData,
+        to_rate_data: RateData,
+    ) -> &mut Self {
+        let redelegation = from_rate_data
+            .build_redelegate(&to_rate_data, delegation_amount)
+            .into();
+        self.action(redelegation);
+        self
+    }
+
+    /// Add a redelegation claim to this transaction.
+    ///
+    /// Converts `escrowed_amount` of the redelegation tokens produced by a
+    /// redelegation in the epoch with index `start_epoch_index` into the
+    /// destination validator's delegation tokens, less the `penalty` applied to
+    /// the source validator over the redelegation's unbonding period.
+    #[instrument(skip(self))]
+    pub fn redelegate_claim(
+        &mut self,
+        from_validator_identity: IdentityKey,
+        to_validator_identity: IdentityKey,
+        start_epoch_index: u64,
+        penalty: Penalty,
+        escrowed_amount: Amount,
+    ) -> &mut Self {
+        let claim = RedelegateClaim::new(
+            from_validator_identity,
+            to_validator_identity,
+            start_epoch_index,
+            penalty,
+            escrowed_amount,
+        )
+        .into();
+        self.action(claim);
+        self
+    }
+
     /// Add an undelegate claim to this transaction.
     #[instrument(skip(self))]
     pub fn undelegate_claim(&mut self, claim_plan: UndelegateClaimPlan) -> &mut Self {
@@ -383,6 +457,9 @@ impl<R: RngCore + CryptoRng> Planner<R> {
 
     /// Vote with all possible vote weight on a given proposal.
     ///
+    /// If `vote` is a weighted vote, the voting power of each note is split between the options in
+    /// proportion to its weights.
+    ///
     /// Voting twice on the same proposal in the same planner will overwrite the previous vote.
     #[instrument(skip(self, start_position, start_rate_data))]
     pub fn delegator_vote(
@@ -391,7 +468,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         start_block_height: u64,
         start_position: tct::Position,
         start_rate_data: BTreeMap<IdentityKey, RateData>,
-        vote: Vote,
+        vote: Ballot,
     ) -> &mut Self {
         self.vote_intents.insert(
             proposal,
@@ -415,7 +492,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         &mut self,
         proposal: u64,
         start_position: tct::Position,
-        vote: Vote,
+        vote: Ballot,
         note: Note,
         position: tct::Position,
         unbonded_amount: Amount,
@@ -513,8 +590,34 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         // Fill in the chain id based on the view service
         self.plan.transaction_parameters.chain_id = chain_params.chain_id.clone();
 
-        // Add the required spends to the planner
-        for record in spendable_notes {
+        // Select the notes to spend from those provided, and add the spends to the planner
+        let required = self.balance.required().collect::<Vec<_>>();
+        let spendable_notes = spendable_notes
+            .into_iter()
+            .filter(|record| !self.excluded_notes.contains(&record.position))
+            .collect::<Vec<_>>();
+        let selected_notes =
+            self.selection_strategy
+                .select(&mut self.rng, &required, spendable_notes.clone())?;
+        let selected_positions = selected_notes
+            .iter()
+            .map(|record| record.position)
+            .collect::<BTreeSet<_>>();
+        // Keep the staking token notes left over, in case spending the selected notes costs more
+        // than the fee estimated for them.
+        let mut fee_notes = spendable_notes
+            .into_iter()
+            .filter(|record| {
+                record.note.asset_id() == *STAKING_TOKEN_ASSET_ID
+                    && !selected_positions.contains(&record.position)
+            })
+            .collect::<Vec<_>>();
+        if self.selection_strategy == SelectionStrategy::SingleAddress {
+            if let Some(selected) = selected_notes.first() {
+                fee_notes.retain(|record| record.address_index == selected.address_index);
+            }
+        }
+        for record in selected_notes {
             self.spend(record.note, record.position);
         }
         // Add any IBC actions to the planner
@@ -585,11 +688,36 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             }
         }
 
+        // Spending many small notes can cost more than even the over-estimated fee, in which case
+        // we raise the fee to the real fee and spend further notes to pay the difference, until
+        // the fee covers every spend.
+        let mut tx_real_fee = self.gas_prices.price(&self.plan.gas_cost());
+        while tx_real_fee > self.plan.transaction_parameters.fee.amount() {
+            let shortfall = Value {
+                amount: tx_real_fee - self.plan.transaction_parameters.fee.amount(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            };
+            let selected_notes = self
+                .selection_strategy
+                .select(&mut self.rng, &[shortfall], fee_notes.clone())
+                .context("not enough notes to pay the fee for spending the notes selected")?;
+            let selected_positions = selected_notes
+                .iter()
+                .map(|record| record.position)
+                .collect::<BTreeSet<_>>();
+            fee_notes.retain(|record| !selected_positions.contains(&record.position));
+            self.balance += shortfall;
+            self.plan.transaction_parameters.fee = Fee::from_staking_token_amount(tx_real_fee);
+            for record in selected_notes {
+                self.spend(record.note, record.position);
+            }
+            tx_real_fee = self.gas_prices.price(&self.plan.gas_cost());
+        }
+
         // Since we over-estimate the fees to be paid upfront by a fixed multiple to account
         // for the cost of any additional `Spend` actions necessary to pay the fee, we need
         // to now calculate the transaction's fee again and capture the excess as change
         // by subtracting the excess from the required value balance.
-        let tx_real_fee = self.gas_prices.price(&self.plan.gas_cost());
         let excess_fee_spent = self.plan.transaction_parameters.fee.amount() - tx_real_fee;
         self.balance -= Value {
             amount: excess_fee_spent,
@@ -636,8 +764,89 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self.vote_intents = BTreeMap::new();
         self.ibc_actions = Vec::new();
         self.gas_prices = GasPrices::zero();
+        self.selection_strategy = SelectionStrategy::default();
+        self.excluded_notes = BTreeSet::new();
         let plan = mem::take(&mut self.plan);
 
         Ok(plan)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_keys::{test_keys, FullViewingKey};
+    use penumbra_sct::{CommitmentSource, Nullifier};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    /// Records of notes of `amount` staking tokens each, received at the first address.
+    fn records(count: u64, amount: u64) -> Vec<SpendableNoteRecord> {
+        let fvk: &FullViewingKey = &test_keys::FULL_VIEWING_KEY;
+        (0..count)
+            .map(|position| {
+                let value = Value {
+                    amount: amount.into(),
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                };
+                let note = Note::generate(&mut OsRng, &test_keys::ADDRESS_0, value);
+                let note_commitment = note.commit();
+                let position = position.into();
+                SpendableNoteRecord {
+                    note_commitment,
+                    nullifier: Nullifier::derive(fvk.nullifier_key(), position, &note_commitment),
+                    note,
+                    address_index: AddressIndex::new(0),
+                    height_created: 1,
+                    height_spent: None,
+                    position,
+                    source: CommitmentSource::Genesis,
+                }
+            })
+            .collect()
+    }
+
+    /// Plan sending `amount` staking tokens, where each proof costs one staking token to verify,
+    /// funded by consolidating the given notes.
+    fn plan_send(amount: u64, notes: Vec<SpendableNoteRecord>) -> Result<TransactionPlan> {
+        let gas_prices = GasPrices {
+            verification_price: 1,
+            ..GasPrices::zero()
+        };
+        let mut planner = Planner::new(OsRng);
+        planner
+            .set_gas_prices(gas_prices)
+            .selection_strategy(SelectionStrategy::ConsolidateDust)
+            .output(
+                Value {
+                    amount: amount.into(),
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                },
+                test_keys::ADDRESS_1.clone(),
+            )
+            .add_gas_fees();
+        planner.plan_with_spendable_and_votable_notes(
+            &ChainParameters::default(),
+            &FmdParameters::default(),
+            notes,
+            Vec::new(),
+            test_keys::ADDRESS_0.clone(),
+        )
+    }
+
+    #[test]
+    fn many_dust_notes_pay_for_their_own_spends() {
+        let plan = plan_send(10_000, records(100, 1_500)).unwrap();
+
+        // The fee estimated for the output alone covers the proofs of at most two spends, but the
+        // fee paid covers the proof of every note spent.
+        let spends = plan.spend_plans().count() as u64;
+        assert!(spends > 2);
+        assert!(plan.transaction_parameters.fee.amount() >= Amount::from(spends * 1_000));
+    }
+
+    #[test]
+    fn dust_notes_worth_less_than_their_spends_are_an_error() {
+        assert!(plan_send(5_000, records(100, 100)).is_err());
+    }
+}
@@ -0,0 +1,351 @@
+use std::collections::BTreeMap;
+
+use penumbra_asset::Value;
+use penumbra_keys::keys::AddressIndex;
+use penumbra_num::Amount;
+use penumbra_proto::view::v1alpha1::transaction_planner_request::SelectionStrategy as PbSelectionStrategy;
+use rand::{seq::SliceRandom, RngCore};
+
+use crate::SpendableNoteRecord;
+
+/// A strategy for selecting the notes the [`Planner`](crate::Planner) spends to fund a
+/// transaction.
+///
+/// Every note spent in a transaction is linked to the others by the transaction itself, so the
+/// choice of notes affects what an observer who later learns about some of them can infer. Notes
+/// are only ever selected from the account funding the transaction, whatever the strategy, so
+/// notes belonging to different accounts are never linked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum SelectionStrategy {
+    /// Spend notes in the order the view service stores them, until the transaction is funded.
+    Greedy,
+    /// Spend the largest notes first, minimizing the number of spends.
+    MinimizeSpends,
+    /// Prefer spending notes received at a single address, falling back to the whole account if
+    /// no single address holds enough funds.
+    PreferSingleAddress,
+    /// Only spend notes received at a single address, failing if no single address holds enough
+    /// funds, so that different addresses are never linked.
+    SingleAddress,
+    /// Spend the smallest notes first, consolidating dust into the change output.
+    ConsolidateDust,
+    /// Spend notes in a random order.
+    Random,
+}
+
+impl Default for SelectionStrategy {
+    fn default() -> Self {
+        Self::Greedy
+    }
+}
+
+impl SelectionStrategy {
+    /// Whether the view service can stop returning notes once it has enough to fund the
+    /// transaction, because the strategy spends them in the order they are stored.
+    pub(crate) fn uses_stored_order(&self) -> bool {
+        matches!(self, SelectionStrategy::Greedy)
+    }
+
+    /// Select, from the candidate `notes`, the notes to spend to provide the `required` values.
+    pub(crate) fn select<R: RngCore>(
+        &self,
+        rng: &mut R,
+        required: &[Value],
+        mut notes: Vec<SpendableNoteRecord>,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
+        match self {
+            // The view service returns the notes in the order they are stored, usually stopping
+            // once it has returned enough.
+            SelectionStrategy::Greedy => {}
+            SelectionStrategy::MinimizeSpends => {
+                notes.sort_by_key(|record| std::cmp::Reverse(record.note.amount()));
+            }
+            SelectionStrategy::ConsolidateDust => {
+                notes.sort_by_key(|record| record.note.amount());
+            }
+            SelectionStrategy::Random => notes.shuffle(rng),
+            SelectionStrategy::PreferSingleAddress | SelectionStrategy::SingleAddress => {
+                if let Some(selected) = select_from_single_address(required, &notes) {
+                    return Ok(selected);
+                }
+                if *self == SelectionStrategy::SingleAddress {
+                    anyhow::bail!(
+                        "no single address holds enough funds for the transaction; try another selection strategy"
+                    );
+                }
+                notes.sort_by_key(|record| std::cmp::Reverse(record.note.amount()));
+            }
+        }
+
+        take_in_order(required, &notes).ok_or_else(|| {
+            anyhow::anyhow!("not enough notes to fund the transaction: {required:?} are required")
+        })
+    }
+}
+
+/// Select the notes received at the single address that can fund the transaction with the fewest
+/// spends, if any.
+fn select_from_single_address(
+    required: &[Value],
+    notes: &[SpendableNoteRecord],
+) -> Option<Vec<SpendableNoteRecord>> {
+    let mut by_address: BTreeMap<AddressIndex, Vec<SpendableNoteRecord>> = BTreeMap::new();
+    for record in notes {
+        by_address
+            .entry(record.address_index)
+            .or_default()
+            .push(record.clone());
+    }
+
+    by_address
+        .into_values()
+        .filter_map(|mut notes| {
+            notes.sort_by_key(|record| std::cmp::Reverse(record.note.amount()));
+            take_in_order(required, &notes)
+        })
+        .min_by_key(|selected| selected.len())
+}
+
+/// Take notes in order, for each required value, until the required amount is reached.
+///
+/// Returns `None` if the notes don't provide enough of some required value.
+fn take_in_order(
+    required: &[Value],
+    notes: &[SpendableNoteRecord],
+) -> Option<Vec<SpendableNoteRecord>> {
+    let mut selected = Vec::new();
+    for value in required {
+        let mut total = Amount::zero();
+        for record in notes
+            .iter()
+            .filter(|record| record.note.asset_id() == value.asset_id)
+        {
+            if total >= value.amount {
+                break;
+            }
+            total += record.note.amount();
+            selected.push(record.clone());
+        }
+        if total < value.amount {
+            return None;
+        }
+    }
+    Some(selected)
+}
+
+impl From<SelectionStrategy> for PbSelectionStrategy {
+    fn from(strategy: SelectionStrategy) -> Self {
+        match strategy {
+            SelectionStrategy::Greedy => PbSelectionStrategy::Greedy,
+            SelectionStrategy::MinimizeSpends => PbSelectionStrategy::MinimizeSpends,
+            SelectionStrategy::PreferSingleAddress => PbSelectionStrategy::PreferSingleAddress,
+            SelectionStrategy::SingleAddress => PbSelectionStrategy::SingleAddress,
+            SelectionStrategy::ConsolidateDust => PbSelectionStrategy::ConsolidateDust,
+            SelectionStrategy::Random => PbSelectionStrategy::Random,
+        }
+    }
+}
+
+impl From<PbSelectionStrategy> for SelectionStrategy {
+    fn from(strategy: PbSelectionStrategy) -> Self {
+        match strategy {
+            PbSelectionStrategy::Unspecified | PbSelectionStrategy::Greedy => {
+                SelectionStrategy::Greedy
+            }
+            PbSelectionStrategy::MinimizeSpends => SelectionStrategy::MinimizeSpends,
+            PbSelectionStrategy::PreferSingleAddress => SelectionStrategy::PreferSingleAddress,
+            PbSelectionStrategy::SingleAddress => SelectionStrategy::SingleAddress,
+            PbSelectionStrategy::ConsolidateDust => SelectionStrategy::ConsolidateDust,
+            PbSelectionStrategy::Random => SelectionStrategy::Random,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
+    use penumbra_keys::{test_keys, FullViewingKey};
+    use penumbra_sct::{CommitmentSource, Nullifier};
+    use penumbra_shielded_pool::Note;
+    use rand::{rngs::StdRng, SeedableRng};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn value(amount: u64, asset_id: asset::Id) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id,
+        }
+    }
+
+    /// A record of a note of `amount` staking tokens received at the given address.
+    fn record(address: u32, amount: u64, position: u64) -> SpendableNoteRecord {
+        record_of(address, value(amount, *STAKING_TOKEN_ASSET_ID), position)
+    }
+
+    fn record_of(address: u32, value: Value, position: u64) -> SpendableNoteRecord {
+        let fvk: &FullViewingKey = &test_keys::FULL_VIEWING_KEY;
+        let address_index = AddressIndex::from(address);
+        let (address, _) = fvk.payment_address(address_index);
+        let note = Note::generate(&mut OsRng, &address, value);
+        let note_commitment = note.commit();
+        let position = position.into();
+        SpendableNoteRecord {
+            note_commitment,
+            nullifier: Nullifier::derive(fvk.nullifier_key(), position, &note_commitment),
+            note,
+            address_index,
+            height_created: 1,
+            height_spent: None,
+            position,
+            source: CommitmentSource::Genesis,
+        }
+    }
+
+    fn amounts(selected: &[SpendableNoteRecord]) -> Vec<u64> {
+        selected
+            .iter()
+            .map(|record| record.note.amount().value() as u64)
+            .collect()
+    }
+
+    fn select(
+        strategy: SelectionStrategy,
+        required: u64,
+        notes: &[SpendableNoteRecord],
+    ) -> anyhow::Result<Vec<u64>> {
+        let required = [value(required, *STAKING_TOKEN_ASSET_ID)];
+        strategy
+            .select(&mut StdRng::seed_from_u64(0), &required, notes.to_vec())
+            .map(|selected| amounts(&selected))
+    }
+
+    #[test]
+    fn greedy_spends_notes_in_the_order_they_are_stored() {
+        let notes = [record(0, 1, 0), record(0, 5, 1), record(0, 3, 2)];
+        assert_eq!(
+            select(SelectionStrategy::Greedy, 7, &notes).unwrap(),
+            vec![1, 5, 3]
+        );
+        assert_eq!(
+            select(SelectionStrategy::Greedy, 2, &notes).unwrap(),
+            vec![1, 5]
+        );
+    }
+
+    #[test]
+    fn minimize_spends_spends_the_largest_notes_first() {
+        let notes = [record(0, 1, 0), record(0, 5, 1), record(0, 3, 2)];
+        assert_eq!(
+            select(SelectionStrategy::MinimizeSpends, 4, &notes).unwrap(),
+            vec![5]
+        );
+        assert_eq!(
+            select(SelectionStrategy::MinimizeSpends, 7, &notes).unwrap(),
+            vec![5, 3]
+        );
+    }
+
+    #[test]
+    fn consolidate_dust_spends_the_smallest_notes_first() {
+        let notes = [record(0, 5, 0), record(0, 1, 1), record(0, 3, 2)];
+        assert_eq!(
+            select(SelectionStrategy::ConsolidateDust, 4, &notes).unwrap(),
+            vec![1, 3]
+        );
+    }
+
+    #[test]
+    fn exact_change_spends_no_more_notes_than_needed() {
+        let notes = [record(0, 5, 0), record(0, 3, 1), record(0, 2, 2)];
+        assert_eq!(
+            select(SelectionStrategy::MinimizeSpends, 5, &notes).unwrap(),
+            vec![5]
+        );
+        assert_eq!(
+            select(SelectionStrategy::ConsolidateDust, 5, &notes).unwrap(),
+            vec![2, 3]
+        );
+        assert_eq!(
+            select(SelectionStrategy::SingleAddress, 10, &notes).unwrap(),
+            vec![5, 3, 2]
+        );
+    }
+
+    #[test]
+    fn single_address_strategies_prefer_the_fewest_spends_at_one_address() {
+        let notes = [record(0, 2, 0), record(0, 2, 1), record(1, 3, 2)];
+        for strategy in [
+            SelectionStrategy::PreferSingleAddress,
+            SelectionStrategy::SingleAddress,
+        ] {
+            assert_eq!(select(strategy, 3, &notes).unwrap(), vec![3]);
+            assert_eq!(select(strategy, 4, &notes).unwrap(), vec![2, 2]);
+        }
+    }
+
+    #[test]
+    fn only_prefer_single_address_falls_back_to_the_whole_account() {
+        let notes = [record(0, 2, 0), record(0, 2, 1), record(1, 3, 2)];
+        assert_eq!(
+            select(SelectionStrategy::PreferSingleAddress, 6, &notes).unwrap(),
+            vec![3, 2, 2]
+        );
+        assert!(select(SelectionStrategy::SingleAddress, 6, &notes).is_err());
+    }
+
+    #[test]
+    fn random_spends_enough_of_the_candidates() {
+        let notes: Vec<_> = (0..10).map(|i| record(0, i + 1, i)).collect();
+        for seed in 0..10 {
+            let required = [value(20, *STAKING_TOKEN_ASSET_ID)];
+            let selected = SelectionStrategy::Random
+                .select(&mut StdRng::seed_from_u64(seed), &required, notes.clone())
+                .unwrap();
+            let total: u64 = amounts(&selected).iter().sum();
+            assert!(total >= 20);
+            // Dropping the last note selected leaves too little, so no note is spent needlessly.
+            assert!(total - amounts(&selected).last().unwrap() < 20);
+        }
+    }
+
+    #[test]
+    fn insufficient_funds_are_an_error() {
+        let notes = [record(0, 2, 0), record(1, 3, 1)];
+        for strategy in [
+            SelectionStrategy::MinimizeSpends,
+            SelectionStrategy::PreferSingleAddress,
+            SelectionStrategy::SingleAddress,
+            SelectionStrategy::ConsolidateDust,
+            SelectionStrategy::Random,
+        ] {
+            assert!(select(strategy, 6, &notes).is_err(), "{strategy:?}");
+        }
+    }
+
+    #[test]
+    fn each_required_asset_is_funded_from_its_own_notes() {
+        let other_asset = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .unwrap()
+            .id();
+        let notes = vec![
+            record(0, 5, 0),
+            record_of(0, value(4, other_asset), 1),
+            record(0, 1, 2),
+            record_of(0, value(2, other_asset), 3),
+        ];
+        let required = [value(6, *STAKING_TOKEN_ASSET_ID), value(3, other_asset)];
+        let selected = SelectionStrategy::ConsolidateDust
+            .select(&mut OsRng, &required, notes)
+            .unwrap();
+        assert_eq!(amounts(&selected), vec![1, 5, 2, 4]);
+
+        let required = [value(7, other_asset)];
+        let notes = vec![record(0, 10, 0), record_of(0, value(4, other_asset), 1)];
+        assert!(SelectionStrategy::MinimizeSpends
+            .select(&mut OsRng, &required, notes)
+            .is_err());
+    }
+}
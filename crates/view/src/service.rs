@@ -12,7 +12,7 @@ use decaf377::Fq;
 use futures::stream::{StreamExt, TryStreamExt};
 use rand::Rng;
 use rand_core::OsRng;
-use tokio::sync::{watch, RwLock};
+use tokio::sync::{broadcast::error::RecvError, watch, RwLock};
 use tokio_stream::wrappers::WatchStream;
 use tonic::{async_trait, transport::Channel, Request, Response, Status};
 use tracing::instrument;
@@ -29,12 +29,13 @@ use penumbra_dex::{
 };
 use penumbra_fee::Fee;
 use penumbra_keys::{
-    keys::{AddressIndex, FullViewingKey},
+    keys::{AddressIndex, FullViewingKey, WalletId},
     Address,
 };
 use penumbra_num::Amount;
 use penumbra_proto::view::v1alpha1::{WalletIdRequest, WalletIdResponse};
 use penumbra_proto::{
+    core::keys::v1alpha1 as keys_pb,
     util::tendermint_proxy::v1alpha1::{
         tendermint_proxy_service_client::TendermintProxyServiceClient, BroadcastTxSyncRequest,
         GetStatusRequest,
@@ -48,14 +49,14 @@ use penumbra_proto::{
     },
     DomainType,
 };
-use penumbra_stake::rate::RateData;
+use penumbra_stake::{rate::RateData, IdentityKey, Penalty};
 use penumbra_tct::{Proof, StateCommitment};
 use penumbra_transaction::{
     plan::TransactionPlan, txhash::TransactionId, AuthorizationData, Transaction,
     TransactionPerspective, WitnessData,
 };
 
-use crate::{Planner, Storage, Worker};
+use crate::{ChaffPolicy, Planner, Storage, TransactionHistoryEntry, TransactionInfo, Worker};
 
 /// A service that synchronizes private chain state and responds to queries
 /// about it.
@@ -65,9 +66,18 @@ use crate::{Planner, Storage, Worker};
 /// internally that performs synchronization and scanning.  The
 /// [`ViewService`] can be cloned; each clone will read from the same shared
 /// state, but there will only be a single scanning task.
+///
+/// A single [`ViewService`] can track several wallets, scanning each block once
+/// for all of them. Requests name the wallet they concern, and may omit it if
+/// the service tracks a single wallet, or if the service was scoped to one
+/// wallet with [`ViewService::for_wallet`].
 #[derive(Clone)]
 pub struct ViewService {
     storage: Storage,
+    /// The full viewing keys of the tracked wallets.
+    fvks: Arc<BTreeMap<WalletId, FullViewingKey>>,
+    /// The wallet that requests which don't name one are scoped to, if any.
+    default_wallet: Option<WalletId>,
     // A shared error slot for errors bubbled up by the worker. This is a regular Mutex
     // rather than a Tokio Mutex because it should be uncontended.
     error_slot: Arc<Mutex<Option<anyhow::Error>>>,
@@ -81,31 +91,55 @@ pub struct ViewService {
 
 impl ViewService {
     /// Convenience method that calls [`Storage::load_or_initialize`] and then [`Self::new`].
+    ///
+    /// The returned service is scoped to `fvk`, even if the storage tracks other wallets too.
     pub async fn load_or_initialize(
         storage_path: Option<impl AsRef<Utf8Path>>,
+        key: Option<DatabaseKey>,
         fvk: &FullViewingKey,
         node: Url,
+        birthday: Option<u64>,
+        chaff: ChaffPolicy,
     ) -> anyhow::Result<Self> {
-        let storage = Storage::load_or_initialize(storage_path, fvk, node.clone()).await?;
-
-        Self::new(storage, node).await
+        let storage = Storage::load_or_initialize(
+            storage_path,
+            key,
+            std::slice::from_ref(fvk),
+            node.clone(),
+            birthday,
+        )
+        .await?;
+
+        Self::new(storage, node, chaff)
+            .await?
+            .for_wallet(fvk.wallet_id())
     }
 
     /// Constructs a new [`ViewService`], spawning a sync task internally.
     ///
-    /// The sync task uses the provided `client` to sync with the chain.
+    /// The sync task uses the provided `client` to sync with the chain, making
+    /// decoy downloads according to the `chaff` policy.
     ///
     /// To create multiple [`ViewService`]s, clone the [`ViewService`] returned
     /// by this method, rather than calling it multiple times.  That way, each clone
     /// will be backed by the same scanning task, rather than each spawning its own.
-    pub async fn new(storage: Storage, node: Url) -> anyhow::Result<Self> {
+    pub async fn new(storage: Storage, node: Url, chaff: ChaffPolicy) -> anyhow::Result<Self> {
         let (worker, sct, error_slot, sync_height_rx) =
-            Worker::new(storage.clone(), node.clone()).await?;
+            Worker::new(storage.clone(), node.clone(), chaff).await?;
 
         tokio::spawn(worker.run());
 
+        let fvks = storage
+            .full_viewing_keys()
+            .await?
+            .into_iter()
+            .map(|fvk| (fvk.wallet_id(), fvk))
+            .collect::<BTreeMap<_, _>>();
+
         Ok(Self {
             storage,
+            default_wallet: default_wallet(&fvks),
+            fvks: Arc::new(fvks),
             error_slot,
             sync_height_rx,
             state_commitment_tree: sct,
@@ -113,6 +147,44 @@ impl ViewService {
         })
     }
 
+    /// Returns a clone of this service whose requests default to the given wallet.
+    ///
+    /// The clone shares the scanning task with this service.
+    pub fn for_wallet(&self, wallet_id: WalletId) -> anyhow::Result<Self> {
+        if !self.fvks.contains_key(&wallet_id) {
+            anyhow::bail!("wallet {} is not tracked by this view service", wallet_id);
+        }
+
+        Ok(Self {
+            default_wallet: Some(wallet_id),
+            ..self.clone()
+        })
+    }
+
+    /// Resolves the wallet a request concerns, falling back to the default wallet if the request
+    /// doesn't name one.
+    fn wallet(
+        &self,
+        wallet_id: Option<keys_pb::WalletId>,
+    ) -> Result<(WalletId, FullViewingKey), tonic::Status> {
+        let wallet_id = match wallet_id {
+            Some(wallet_id) => WalletId::try_from(wallet_id).map_err(|e| {
+                tonic::Status::invalid_argument(format!("Could not parse wallet id: {e:#}"))
+            })?,
+            None => self.default_wallet.ok_or_else(|| {
+                tonic::Status::invalid_argument(
+                    "Missing wallet id, which is required when tracking multiple wallets",
+                )
+            })?,
+        };
+
+        let fvk = self.fvks.get(&wallet_id).cloned().ok_or_else(|| {
+            tonic::Status::not_found(format!("Wallet {wallet_id} is not tracked"))
+        })?;
+
+        Ok((wallet_id, fvk))
+    }
+
     async fn check_worker(&self) -> Result<(), tonic::Status> {
         // If the shared error slot is set, then an error has occurred in the worker
         // that we should bubble up.
@@ -153,6 +225,7 @@ impl ViewService {
     #[instrument(skip(self, transaction), fields(id = %transaction.id()))]
     async fn broadcast_transaction(
         &self,
+        wallet_id: WalletId,
         transaction: Transaction,
         await_detection: bool,
     ) -> anyhow::Result<TransactionId> {
@@ -208,7 +281,7 @@ impl ViewService {
 
         if let Some(nullifier) = nullifier {
             tracing::info!(?nullifier, "waiting for detection of nullifier");
-            let detection = self.storage.nullifier_status(nullifier, true);
+            let detection = self.storage.nullifier_status(wallet_id, nullifier, true);
             tokio::time::timeout(std::time::Duration::from_secs(20), detection)
                 .await
                 .context("timeout waiting to detect nullifier of submitted transaction")?
@@ -304,6 +377,12 @@ impl ViewProtocolService for ViewService {
     type TransactionInfoStream = Pin<
         Box<dyn futures::Stream<Item = Result<pb::TransactionInfoResponse, tonic::Status>> + Send>,
     >;
+    type TransactionHistoryStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<pb::TransactionHistoryResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
     type BalancesStream =
         Pin<Box<dyn futures::Stream<Item = Result<pb::BalancesResponse, tonic::Status>> + Send>>;
     type OwnedPositionIdsStream = Pin<
@@ -312,6 +391,11 @@ impl ViewProtocolService for ViewService {
     type UnclaimedSwapsStream = Pin<
         Box<dyn futures::Stream<Item = Result<pb::UnclaimedSwapsResponse, tonic::Status>> + Send>,
     >;
+    type WalletsStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::WalletsResponse, tonic::Status>> + Send>>;
+    type NotificationsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<pb::NotificationsResponse, tonic::Status>> + Send>,
+    >;
 
     async fn broadcast_transaction(
         &self,
@@ -320,8 +404,11 @@ impl ViewProtocolService for ViewService {
         let pb::BroadcastTransactionRequest {
             transaction,
             await_detection,
+            wallet_id,
         } = request.into_inner();
 
+        let (wallet_id, _) = self.wallet(wallet_id)?;
+
         let transaction: Transaction = transaction
             .ok_or_else(|| tonic::Status::invalid_argument("missing transaction"))?
             .try_into()
@@ -329,7 +416,7 @@ impl ViewProtocolService for ViewService {
             .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?;
 
         let id = self
-            .broadcast_transaction(transaction, await_detection)
+            .broadcast_transaction(wallet_id, transaction, await_detection)
             .await
             .map_err(|e| {
                 tonic::Status::internal(format!("could not broadcast transaction: {:#}", e))
@@ -338,7 +425,7 @@ impl ViewProtocolService for ViewService {
         let detection_height = if await_detection {
             // We already awaited detection, so we expect to know about the transaction:
             self.storage
-                .transaction_by_hash(&id.0)
+                .transaction_by_hash(wallet_id, &id.0)
                 .await
                 .map_err(|e| tonic::Status::internal(format!("error querying storage: {:#}", e)))?
                 .map(|(height, _tx)| height)
@@ -361,6 +448,8 @@ impl ViewProtocolService for ViewService {
     ) -> Result<tonic::Response<pb::TransactionPlannerResponse>, tonic::Status> {
         let prq = request.into_inner();
 
+        let (wallet_id, _) = self.wallet(prq.wallet_id.clone())?;
+
         let app_params =
             self.storage.app_params().await.map_err(|e| {
                 tonic::Status::internal(format!("could not get app params: {:#}", e))
@@ -378,7 +467,18 @@ impl ViewProtocolService for ViewService {
                     tonic::Status::invalid_argument(format!("Could not parse fee: {e:#}"))
                 })?,
             )
-            .expiry_height(prq.expiry_height);
+            .expiry_height(prq.expiry_height)
+            .selection_strategy(
+                pb::transaction_planner_request::SelectionStrategy::try_from(
+                    prq.selection_strategy,
+                )
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!(
+                        "Could not parse selection strategy: {e}"
+                    ))
+                })?
+                .into(),
+            );
 
         for output in prq.outputs {
             let address: Address = output
@@ -453,7 +553,7 @@ impl ViewProtocolService for ViewService {
             let swap_record = self
                 .storage
                 // TODO: should there be a timeout on detection here instead?
-                .swap_by_commitment(swap_commitment, false)
+                .swap_by_commitment(wallet_id, swap_commitment, false)
                 .await
                 .map_err(|e| {
                     tonic::Status::invalid_argument(format!(
@@ -513,6 +613,76 @@ impl ViewProtocolService for ViewService {
             planner.undelegate(value.amount, rate_data);
         }
 
+        for redelegation in prq.redelegations {
+            let value: Value = redelegation
+                .value
+                .ok_or_else(|| tonic::Status::invalid_argument("Missing value"))?
+                .try_into()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!("Could not parse value: {e:#}"))
+                })?;
+
+            let from_rate_data: RateData = redelegation
+                .from_rate_data
+                .ok_or_else(|| tonic::Status::invalid_argument("Missing source rate data"))?
+                .try_into()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!("Could not parse rate data: {e:#}"))
+                })?;
+
+            let to_rate_data: RateData = redelegation
+                .to_rate_data
+                .ok_or_else(|| tonic::Status::invalid_argument("Missing destination rate data"))?
+                .try_into()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!("Could not parse rate data: {e:#}"))
+                })?;
+
+            planner.redelegate(value.amount, from_rate_data, to_rate_data);
+        }
+
+        for claim in prq.redelegate_claims {
+            let from_validator_identity: IdentityKey = claim
+                .from_validator_identity
+                .ok_or_else(|| tonic::Status::invalid_argument("Missing source validator"))?
+                .try_into()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!("Could not parse identity key: {e:#}"))
+                })?;
+
+            let to_validator_identity: IdentityKey = claim
+                .to_validator_identity
+                .ok_or_else(|| tonic::Status::invalid_argument("Missing destination validator"))?
+                .try_into()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!("Could not parse identity key: {e:#}"))
+                })?;
+
+            let penalty: Penalty = claim
+                .penalty
+                .ok_or_else(|| tonic::Status::invalid_argument("Missing penalty"))?
+                .try_into()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!("Could not parse penalty: {e:#}"))
+                })?;
+
+            let escrowed_amount: Amount = claim
+                .escrowed_amount
+                .ok_or_else(|| tonic::Status::invalid_argument("Missing escrowed amount"))?
+                .try_into()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!("Could not parse amount: {e:#}"))
+                })?;
+
+            planner.redelegate_claim(
+                from_validator_identity,
+                to_validator_identity,
+                claim.start_epoch_index,
+                penalty,
+                escrowed_amount,
+            );
+        }
+
         for position_open in prq.position_opens {
             let position: Position = position_open
                 .position
@@ -583,8 +753,12 @@ impl ViewProtocolService for ViewService {
             );
         }
 
-        let mut client_of_self =
-            ViewProtocolServiceClient::new(ViewProtocolServiceServer::new(self.clone()));
+        // Plan against a copy of ourselves scoped to the requested wallet, so that the planner
+        // only selects that wallet's notes.
+        let mut client_of_self = ViewProtocolServiceClient::new(ViewProtocolServiceServer::new(
+            self.for_wallet(wallet_id)
+                .map_err(|e| tonic::Status::not_found(format!("{e:#}")))?,
+        ));
 
         let source = prq
             .source
@@ -608,13 +782,11 @@ impl ViewProtocolService for ViewService {
         &self,
         request: tonic::Request<pb::AddressByIndexRequest>,
     ) -> Result<tonic::Response<pb::AddressByIndexResponse>, tonic::Status> {
-        let fvk =
-            self.storage.full_viewing_key().await.map_err(|_| {
-                tonic::Status::failed_precondition("Error retrieving full viewing key")
-            })?;
+        let request = request.into_inner();
+
+        let (_, fvk) = self.wallet(request.wallet_id)?;
 
         let address_index = request
-            .into_inner()
             .address_index
             .ok_or_else(|| tonic::Status::invalid_argument("Missing address index"))?
             .try_into()
@@ -627,17 +799,28 @@ impl ViewProtocolService for ViewService {
         }))
     }
 
+    async fn root_detection_key(
+        &self,
+        request: tonic::Request<pb::RootDetectionKeyRequest>,
+    ) -> Result<tonic::Response<pb::RootDetectionKeyResponse>, tonic::Status> {
+        let (_, fvk) = self.wallet(request.into_inner().wallet_id)?;
+
+        Ok(tonic::Response::new(pb::RootDetectionKeyResponse {
+            detection_key: Some(keys_pb::DetectionKey {
+                inner: fvk.root_detection_key().to_bytes().to_vec(),
+            }),
+        }))
+    }
+
     async fn index_by_address(
         &self,
         request: tonic::Request<pb::IndexByAddressRequest>,
     ) -> Result<tonic::Response<pb::IndexByAddressResponse>, tonic::Status> {
-        let fvk =
-            self.storage.full_viewing_key().await.map_err(|_| {
-                tonic::Status::failed_precondition("Error retrieving full viewing key")
-            })?;
+        let request = request.into_inner();
+
+        let (_, fvk) = self.wallet(request.wallet_id)?;
 
         let address: Address = request
-            .into_inner()
             .address
             .ok_or_else(|| tonic::Status::invalid_argument("Missing address"))?
             .try_into()
@@ -654,13 +837,11 @@ impl ViewProtocolService for ViewService {
         &self,
         request: tonic::Request<pb::EphemeralAddressRequest>,
     ) -> Result<tonic::Response<pb::EphemeralAddressResponse>, tonic::Status> {
-        let fvk =
-            self.storage.full_viewing_key().await.map_err(|_| {
-                tonic::Status::failed_precondition("Error retrieving full viewing key")
-            })?;
+        let request = request.into_inner();
+
+        let (_, fvk) = self.wallet(request.wallet_id)?;
 
         let address_index = request
-            .into_inner()
             .address_index
             .ok_or_else(|| tonic::Status::invalid_argument("Missing address index"))?
             .try_into()
@@ -681,14 +862,12 @@ impl ViewProtocolService for ViewService {
 
         let request = request.into_inner();
 
-        let fvk =
-            self.storage.full_viewing_key().await.map_err(|_| {
-                tonic::Status::failed_precondition("Error retrieving full viewing key")
-            })?;
+        let (wallet_id, fvk) = self.wallet(request.wallet_id.clone())?;
 
         let maybe_tx = self
             .storage
             .transaction_by_hash(
+                wallet_id,
                 &request
                     .id
                     .clone()
@@ -729,8 +908,10 @@ impl ViewProtocolService for ViewService {
                 Action::Spend(spend) => {
                     let nullifier = spend.body.nullifier;
                     // An error here indicates we don't know the nullifier, so we omit it from the Perspective.
-                    if let Ok(spendable_note_record) =
-                        self.storage.note_by_nullifier(nullifier, false).await
+                    if let Ok(spendable_note_record) = self
+                        .storage
+                        .note_by_nullifier(wallet_id, nullifier, false)
+                        .await
                     {
                         txp.spend_nullifiers
                             .insert(nullifier, spendable_note_record.note);
@@ -739,7 +920,7 @@ impl ViewProtocolService for ViewService {
                 Action::SwapClaim(claim) => {
                     let output_1_record = self
                         .storage
-                        .note_by_commitment(claim.body.output_1_commitment, false)
+                        .note_by_commitment(wallet_id, claim.body.output_1_commitment, false)
                         .await
                         .map_err(|e| {
                             tonic::Status::internal(format!(
@@ -749,7 +930,7 @@ impl ViewProtocolService for ViewService {
                         })?;
                     let output_2_record = self
                         .storage
-                        .note_by_commitment(claim.body.output_2_commitment, false)
+                        .note_by_commitment(wallet_id, claim.body.output_2_commitment, false)
                         .await
                         .map_err(|e| {
                             tonic::Status::internal(format!(
@@ -859,6 +1040,8 @@ impl ViewProtocolService for ViewService {
 
         let request = request.into_inner();
 
+        let (wallet_id, _) = self.wallet(request.wallet_id.clone())?;
+
         let swap_commitment = request
             .swap_commitment
             .ok_or_else(|| {
@@ -871,7 +1054,7 @@ impl ViewProtocolService for ViewService {
 
         let swap = pb::SwapRecord::from(
             self.storage
-                .swap_by_commitment(swap_commitment, request.await_detection)
+                .swap_by_commitment(wallet_id, swap_commitment, request.await_detection)
                 .await
                 .map_err(|e| tonic::Status::internal(format!("error: {e}")))?,
         );
@@ -888,6 +1071,8 @@ impl ViewProtocolService for ViewService {
     ) -> Result<tonic::Response<Self::BalancesStream>, tonic::Status> {
         let request = request.into_inner();
 
+        let (wallet_id, _) = self.wallet(request.wallet_id)?;
+
         let account_filter = request.account_filter.and_then(|x| {
             AddressIndex::try_from(x)
                 .map_err(|_| {
@@ -906,7 +1091,7 @@ impl ViewProtocolService for ViewService {
 
         let result = self
             .storage
-            .balances(account_filter, asset_id_filter)
+            .balances(wallet_id, account_filter, asset_id_filter)
             .await
             .map_err(|e| tonic::Status::internal(format!("error: {e}")))?;
 
@@ -942,6 +1127,8 @@ impl ViewProtocolService for ViewService {
 
         let request = request.into_inner();
 
+        let (wallet_id, _) = self.wallet(request.wallet_id.clone())?;
+
         let note_commitment = request
             .note_commitment
             .ok_or_else(|| {
@@ -954,7 +1141,7 @@ impl ViewProtocolService for ViewService {
 
         let spendable_note = pb::SpendableNoteRecord::from(
             self.storage
-                .note_by_commitment(note_commitment, request.await_detection)
+                .note_by_commitment(wallet_id, note_commitment, request.await_detection)
                 .await
                 .map_err(|e| tonic::Status::internal(format!("error: {e}")))?,
         );
@@ -972,6 +1159,8 @@ impl ViewProtocolService for ViewService {
 
         let request = request.into_inner();
 
+        let (wallet_id, _) = self.wallet(request.wallet_id.clone())?;
+
         let nullifier = request
             .nullifier
             .ok_or_else(|| tonic::Status::failed_precondition("Missing nullifier in request"))?
@@ -981,7 +1170,7 @@ impl ViewProtocolService for ViewService {
         Ok(tonic::Response::new(pb::NullifierStatusResponse {
             spent: self
                 .storage
-                .nullifier_status(nullifier, request.await_detection)
+                .nullifier_status(wallet_id, nullifier, request.await_detection)
                 .await
                 .map_err(|e| tonic::Status::internal(format!("error: {e}")))?,
         }))
@@ -1030,6 +1219,50 @@ impl ViewProtocolService for ViewService {
         Ok(tonic::Response::new(stream.boxed()))
     }
 
+    async fn notifications(
+        &self,
+        request: tonic::Request<pb::NotificationsRequest>,
+    ) -> Result<tonic::Response<Self::NotificationsStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        // Unlike other requests, leaving the wallet unset means all wallets rather than the
+        // default one, since each notification names the wallet it concerns.
+        let wallet_filter = match request.into_inner().wallet_id {
+            Some(wallet_id) => Some(self.wallet(Some(wallet_id))?.0),
+            None => None,
+        };
+
+        let mut rx = self.storage.subscribe_notifications();
+        let stream = try_stream! {
+            loop {
+                match rx.recv().await {
+                    Ok(notification) => {
+                        if wallet_filter.map_or(true, |wallet_id| wallet_id == notification.wallet_id) {
+                            yield pb::NotificationsResponse {
+                                notification: Some(notification.into()),
+                            };
+                        }
+                    }
+                    // Skip the notifications this subscriber was too slow to receive, rather than
+                    // ending its stream.
+                    Err(RecvError::Lagged(count)) => {
+                        tracing::warn!(count, "notification subscriber lagged, skipping missed notifications");
+                    }
+                    // The worker has shut down, so there will be no more notifications.
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::data_loss(format!("error streaming notifications: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
     async fn notes(
         &self,
         request: tonic::Request<pb::NotesRequest>,
@@ -1038,6 +1271,8 @@ impl ViewProtocolService for ViewService {
 
         let request = request.into_inner();
 
+        let (wallet_id, _) = self.wallet(request.wallet_id.clone())?;
+
         let include_spent = request.include_spent;
         let asset_id = request
             .asset_id
@@ -1060,7 +1295,13 @@ impl ViewProtocolService for ViewService {
 
         let notes = self
             .storage
-            .notes(include_spent, asset_id, address_index, amount_to_spend)
+            .notes(
+                wallet_id,
+                include_spent,
+                asset_id,
+                address_index,
+                amount_to_spend,
+            )
             .await
             .map_err(|e| tonic::Status::unavailable(format!("error fetching notes: {e}")))?;
 
@@ -1087,6 +1328,8 @@ impl ViewProtocolService for ViewService {
     ) -> Result<tonic::Response<Self::NotesForVotingStream>, tonic::Status> {
         self.check_worker().await?;
 
+        let (wallet_id, _) = self.wallet(request.get_ref().wallet_id.clone())?;
+
         let address_index = request
             .get_ref()
             .address_index
@@ -1099,7 +1342,7 @@ impl ViewProtocolService for ViewService {
 
         let notes = self
             .storage
-            .notes_for_voting(address_index, votable_at_height)
+            .notes_for_voting(wallet_id, address_index, votable_at_height)
             .await
             .map_err(|e| tonic::Status::unavailable(format!("error fetching notes: {e}")))?;
 
@@ -1194,6 +1437,9 @@ impl ViewProtocolService for ViewService {
         request: tonic::Request<pb::TransactionInfoRequest>,
     ) -> Result<tonic::Response<Self::TransactionInfoStream>, tonic::Status> {
         self.check_worker().await?;
+
+        let (wallet_id, _) = self.wallet(request.get_ref().wallet_id.clone())?;
+
         // Unpack optional start/end heights.
         let start_height = if request.get_ref().start_height == 0 {
             None
@@ -1209,7 +1455,7 @@ impl ViewProtocolService for ViewService {
         // Fetch transactions from storage.
         let txs = self
             .storage
-            .transactions(start_height, end_height)
+            .transactions(wallet_id, start_height, end_height)
             .await
             .map_err(|e| tonic::Status::unavailable(format!("error fetching transactions: {e}")))?;
 
@@ -1219,6 +1465,7 @@ impl ViewProtocolService for ViewService {
 
                 let rsp = self2.transaction_info_by_hash(tonic::Request::new(pb::TransactionInfoByHashRequest {
                     id: Some(tx.2.id().into()),
+                    wallet_id: Some(wallet_id.into()),
                 })).await?.into_inner();
 
                 yield pb::TransactionInfoResponse {
@@ -1236,6 +1483,58 @@ impl ViewProtocolService for ViewService {
         ))
     }
 
+    async fn transaction_history(
+        &self,
+        request: tonic::Request<pb::TransactionHistoryRequest>,
+    ) -> Result<tonic::Response<Self::TransactionHistoryStream>, tonic::Status> {
+        let pb::TransactionHistoryRequest {
+            start_height,
+            end_height,
+            account_filter,
+            asset_id_filter,
+            wallet_id,
+        } = request.into_inner();
+
+        let account_filter = account_filter
+            .map(AddressIndex::try_from)
+            .transpose()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid account: {e:#}")))?
+            .map(|index| index.account);
+        let asset_id_filter = asset_id_filter
+            .map(asset::Id::try_from)
+            .transpose()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid asset id: {e:#}")))?;
+
+        // The entries are summaries of the transaction infos, so reuse their stream.
+        let infos = self
+            .transaction_info(tonic::Request::new(pb::TransactionInfoRequest {
+                start_height,
+                end_height,
+                wallet_id,
+            }))
+            .await?
+            .into_inner();
+
+        let stream = infos.try_filter_map(move |rsp| async move {
+            let info = rsp
+                .tx_info
+                .ok_or_else(|| tonic::Status::internal("empty TransactionInfoResponse message"))?;
+            let info = TransactionInfo::try_from(info).map_err(|e| {
+                tonic::Status::internal(format!("could not decode transaction info: {e:#}"))
+            })?;
+
+            Ok::<_, tonic::Status>(
+                TransactionHistoryEntry::from(&info)
+                    .filtered(account_filter, asset_id_filter)
+                    .map(|entry| pb::TransactionHistoryResponse {
+                        entry: Some(entry.into()),
+                    }),
+            )
+        });
+
+        Ok(tonic::Response::new(stream.boxed()))
+    }
+
     async fn witness(
         &self,
         request: tonic::Request<pb::WitnessRequest>,
@@ -1246,9 +1545,6 @@ impl ViewProtocolService for ViewService {
         // so that all auth paths are relative to the same SCT root.
         let sct = self.state_commitment_tree.read().await;
 
-        // Read the SCT root
-        let anchor = sct.root();
-
         // Obtain an auth path for each requested note commitment
         let requested_note_commitments = request
             .get_ref()
@@ -1265,25 +1561,18 @@ impl ViewProtocolService for ViewService {
 
         tracing::debug!(?requested_note_commitments);
 
-        let auth_paths: Vec<Proof> = requested_note_commitments
-            .iter()
-            .map(|nc| {
-                sct.witness(*nc).ok_or_else(|| {
-                    tonic::Status::new(tonic::Code::InvalidArgument, "Note commitment missing")
-                })
-            })
-            .collect::<Result<Vec<Proof>, tonic::Status>>()?;
+        // Witness all the note commitments together, relative to the current SCT root, so that
+        // the parts of their auth paths they share can be sent once if the client asks for that.
+        let batch = sct
+            .witness_batch(requested_note_commitments)
+            .ok_or_else(|| {
+                tonic::Status::new(tonic::Code::InvalidArgument, "Note commitment missing")
+            })?;
 
         // Release the read lock on the SCT
         drop(sct);
 
-        let mut witness_data = WitnessData {
-            anchor,
-            state_commitment_proofs: auth_paths
-                .into_iter()
-                .map(|proof| (proof.commitment(), proof))
-                .collect(),
-        };
+        let mut witness_data = WitnessData::from(batch);
 
         tracing::debug!(?witness_data);
 
@@ -1307,8 +1596,13 @@ impl ViewProtocolService for ViewService {
             witness_data.add_proof(nc, Proof::dummy(&mut OsRng, nc));
         }
 
+        let witness_data = if request.get_ref().batch_proofs {
+            witness_data.into_batched_proto()
+        } else {
+            witness_data.into()
+        };
         let witness_response = WitnessResponse {
-            witness_data: Some(witness_data.into()),
+            witness_data: Some(witness_data),
         };
         Ok(tonic::Response::new(witness_response))
     }
@@ -1320,8 +1614,11 @@ impl ViewProtocolService for ViewService {
         let pb::WitnessAndBuildRequest {
             transaction_plan,
             authorization_data,
+            wallet_id,
         } = request.into_inner();
 
+        let (_, fvk) = self.wallet(wallet_id)?;
+
         let transaction_plan: TransactionPlan = transaction_plan
             .ok_or_else(|| tonic::Status::invalid_argument("missing transaction plan"))?
             .try_into()
@@ -1355,6 +1652,7 @@ impl ViewProtocolService for ViewService {
         let witness_request = pb::WitnessRequest {
             note_commitments,
             transaction_plan: Some(transaction_plan.clone().into()),
+            batch_proofs: true,
         };
 
         let witness_data: WitnessData = self
@@ -1367,11 +1665,6 @@ impl ViewProtocolService for ViewService {
             .map_err(|e: anyhow::Error| e.context("could not decode witness data"))
             .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?;
 
-        let fvk =
-            self.storage.full_viewing_key().await.map_err(|_| {
-                tonic::Status::failed_precondition("Error retrieving full viewing key")
-            })?;
-
         let transaction = Some(
             transaction_plan
                 .build(&fvk, &witness_data, &authorization_data)
@@ -1447,8 +1740,11 @@ impl ViewProtocolService for ViewService {
         let pb::OwnedPositionIdsRequest {
             position_state,
             trading_pair,
+            wallet_id,
         } = request.into_inner();
 
+        let (wallet_id, _) = self.wallet(wallet_id)?;
+
         let position_state: Option<position::State> = position_state
             .map(|state| state.try_into())
             .transpose()
@@ -1463,7 +1759,7 @@ impl ViewProtocolService for ViewService {
 
         let ids = self
             .storage
-            .owned_position_ids(position_state, trading_pair)
+            .owned_position_ids(wallet_id, position_state, trading_pair)
             .await
             .map_err(|e| tonic::Status::unavailable(format!("error getting position ids: {e}")))?;
 
@@ -1493,11 +1789,13 @@ impl ViewProtocolService for ViewService {
 
     async fn unclaimed_swaps(
         &self,
-        _: tonic::Request<pb::UnclaimedSwapsRequest>,
+        request: tonic::Request<pb::UnclaimedSwapsRequest>,
     ) -> Result<tonic::Response<Self::UnclaimedSwapsStream>, tonic::Status> {
         self.check_worker().await?;
 
-        let swaps = self.storage.unclaimed_swaps().await.map_err(|e| {
+        let (wallet_id, _) = self.wallet(request.into_inner().wallet_id)?;
+
+        let swaps = self.storage.unclaimed_swaps(wallet_id).await.map_err(|e| {
             tonic::Status::unavailable(format!("error fetching unclaimed swaps: {e}"))
         })?;
 
@@ -1522,12 +1820,246 @@ impl ViewProtocolService for ViewService {
         &self,
         _: Request<WalletIdRequest>,
     ) -> Result<Response<WalletIdResponse>, Status> {
-        let fvk = self.storage.full_viewing_key().await.map_err(|e| {
-            Status::failed_precondition(format!("Error retrieving full viewing key: {e}"))
+        let wallet_id = self.default_wallet.ok_or_else(|| {
+            Status::failed_precondition(
+                "Tracking multiple wallets, use the Wallets query to list them",
+            )
         })?;
 
         Ok(Response::new(WalletIdResponse {
-            wallet_id: Some(fvk.wallet_id().into()),
+            wallet_id: Some(wallet_id.into()),
         }))
     }
+
+    async fn wallets(
+        &self,
+        _: Request<pb::WalletsRequest>,
+    ) -> Result<Response<Self::WalletsStream>, Status> {
+        let wallet_ids = self.fvks.keys().copied().collect::<Vec<_>>();
+
+        let stream = try_stream! {
+            for wallet_id in wallet_ids {
+                yield pb::WalletsResponse {
+                    wallet_id: Some(wallet_id.into()),
+                }
+            }
+        };
+
+        Ok(Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    Status::unavailable(format!("error getting wallets: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+}
+
+/// The wallet that requests which don't name one concern, given the tracked wallets.
+///
+/// Requests may only omit the wallet if there's no ambiguity about which one they mean.
+fn default_wallet(fvks: &BTreeMap<WalletId, FullViewingKey>) -> Option<WalletId> {
+    match fvks.keys().collect::<Vec<_>>()[..] {
+        [wallet_id] => Some(*wallet_id),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_app::params::AppParameters;
+    use penumbra_keys::{
+        keys::{SpendKey, SpendKeyBytes},
+        test_keys,
+    };
+
+    use crate::{Notification, NotificationEvent};
+
+    use super::*;
+
+    fn other_fvk() -> FullViewingKey {
+        SpendKey::from(SpendKeyBytes([7; 32]))
+            .full_viewing_key()
+            .clone()
+    }
+
+    /// Builds a service tracking the given wallets, without a scanning task.
+    async fn service(fvks: Vec<FullViewingKey>) -> ViewService {
+        let storage = Storage::initialize(
+            None::<&str>,
+            None,
+            fvks.clone(),
+            AppParameters::default(),
+            None,
+        )
+        .await
+        .unwrap();
+        let fvks = fvks
+            .into_iter()
+            .map(|fvk| (fvk.wallet_id(), fvk))
+            .collect::<BTreeMap<_, _>>();
+
+        ViewService {
+            storage,
+            default_wallet: default_wallet(&fvks),
+            fvks: Arc::new(fvks),
+            error_slot: Default::default(),
+            state_commitment_tree: Default::default(),
+            node: "http://127.0.0.1:8080".parse().unwrap(),
+            sync_height_rx: watch::channel(0).1,
+        }
+    }
+
+    #[tokio::test]
+    async fn single_wallet_is_the_default() {
+        let fvk = test_keys::FULL_VIEWING_KEY.clone();
+        let service = service(vec![fvk.clone()]).await;
+
+        let (wallet_id, resolved) = service.wallet(None).unwrap();
+        assert_eq!(wallet_id, fvk.wallet_id());
+        assert_eq!(resolved, fvk);
+
+        let (wallet_id, _) = service.wallet(Some(fvk.wallet_id().into())).unwrap();
+        assert_eq!(wallet_id, fvk.wallet_id());
+    }
+
+    #[tokio::test]
+    async fn untracked_wallets_are_not_found() {
+        let service = service(vec![test_keys::FULL_VIEWING_KEY.clone()]).await;
+
+        let status = service
+            .wallet(Some(other_fvk().wallet_id().into()))
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        assert!(service.for_wallet(other_fvk().wallet_id()).is_err());
+    }
+
+    #[tokio::test]
+    async fn malformed_wallet_ids_are_rejected() {
+        let service = service(vec![test_keys::FULL_VIEWING_KEY.clone()]).await;
+
+        let status = service
+            .wallet(Some(keys_pb::WalletId { inner: vec![0; 5] }))
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn multiple_wallets_require_a_wallet_id() {
+        let fvk = test_keys::FULL_VIEWING_KEY.clone();
+        let other = other_fvk();
+        let service = service(vec![fvk.clone(), other.clone()]).await;
+
+        let status = service.wallet(None).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        for fvk in [&fvk, &other] {
+            let (wallet_id, resolved) = service.wallet(Some(fvk.wallet_id().into())).unwrap();
+            assert_eq!(wallet_id, fvk.wallet_id());
+            assert_eq!(&resolved, fvk);
+        }
+    }
+
+    #[tokio::test]
+    async fn for_wallet_sets_the_default() {
+        let fvk = test_keys::FULL_VIEWING_KEY.clone();
+        let other = other_fvk();
+        let service = service(vec![fvk.clone(), other.clone()]).await;
+
+        let scoped = service.for_wallet(other.wallet_id()).unwrap();
+        let (wallet_id, _) = scoped.wallet(None).unwrap();
+        assert_eq!(wallet_id, other.wallet_id());
+
+        // Requests naming another wallet are still routed to it.
+        let (wallet_id, _) = scoped.wallet(Some(fvk.wallet_id().into())).unwrap();
+        assert_eq!(wallet_id, fvk.wallet_id());
+
+        // The original service is unaffected.
+        assert!(service.wallet(None).is_err());
+    }
+
+    fn notification(wallet_id: WalletId, height: u64) -> Notification {
+        Notification {
+            wallet_id,
+            height,
+            event: NotificationEvent::PositionStateChanged {
+                position_id: position::Id([1; 32]),
+                state: position::State::Closed,
+            },
+        }
+    }
+
+    /// The heights of the notifications in a stream of responses.
+    async fn heights(
+        stream: <ViewService as ViewProtocolService>::NotificationsStream,
+        count: usize,
+    ) -> Vec<u64> {
+        stream
+            .take(count)
+            .map(|response| {
+                Notification::try_from(response.unwrap().notification.unwrap())
+                    .unwrap()
+                    .height
+            })
+            .collect::<Vec<_>>()
+            .await
+    }
+
+    #[tokio::test]
+    async fn notifications_are_streamed_to_subscribers() {
+        let fvk = test_keys::FULL_VIEWING_KEY.clone();
+        let other = other_fvk();
+        let service = service(vec![fvk.clone(), other.clone()]).await;
+
+        let all = service
+            .notifications(tonic::Request::new(pb::NotificationsRequest {
+                wallet_id: None,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        let filtered = service
+            .notifications(tonic::Request::new(pb::NotificationsRequest {
+                wallet_id: Some(other.wallet_id().into()),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        service.storage.notify(notification(fvk.wallet_id(), 1));
+        service.storage.notify(notification(other.wallet_id(), 2));
+        service.storage.notify(notification(fvk.wallet_id(), 3));
+        service.storage.notify(notification(other.wallet_id(), 4));
+
+        assert_eq!(heights(all, 4).await, vec![1, 2, 3, 4]);
+        assert_eq!(heights(filtered, 2).await, vec![2, 4]);
+    }
+
+    #[tokio::test]
+    async fn lagging_subscribers_skip_missed_notifications() {
+        let fvk = test_keys::FULL_VIEWING_KEY.clone();
+        let service = service(vec![fvk.clone()]).await;
+        let stream = service
+            .notifications(tonic::Request::new(pb::NotificationsRequest {
+                wallet_id: None,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        // Overflow the subscriber's buffer before it receives anything, so it lags.
+        let mut rx = service.storage.subscribe_notifications();
+        for height in 0..1_000 {
+            service
+                .storage
+                .notify(notification(fvk.wallet_id(), height));
+        }
+        assert!(matches!(rx.recv().await, Err(RecvError::Lagged(_))));
+
+        // The stream carries on with the notifications still buffered, rather than ending.
+        let heights = heights(stream, 2).await;
+        assert!(heights[0] > 0);
+        assert_eq!(heights[1], heights[0] + 1);
+    }
 }
@@ -15,7 +15,10 @@ use penumbra_distributions::params::DistributionsParameters;
 use penumbra_fee::{FeeParameters, GasPrices};
 use penumbra_governance::params::GovernanceParameters;
 use penumbra_ibc::params::IBCParameters;
-use penumbra_keys::{keys::AddressIndex, Address, FullViewingKey};
+use penumbra_keys::{
+    keys::{AddressIndex, WalletId},
+    Address, FullViewingKey,
+};
 use penumbra_num::Amount;
 use penumbra_proto::{
     core::app::v1alpha1::{
@@ -25,7 +28,7 @@ use penumbra_proto::{
 };
 use penumbra_sct::Nullifier;
 use penumbra_shielded_pool::{note, Note, Rseed};
-use penumbra_stake::{params::StakeParameters, DelegationToken, IdentityKey};
+use penumbra_stake::{params::StakeParameters, DelegationToken, IdentityKey, UnbondingToken};
 use penumbra_tct as tct;
 use penumbra_transaction::Transaction;
 use r2d2_sqlite::{
@@ -33,7 +36,13 @@ use r2d2_sqlite::{
     SqliteConnectionManager,
 };
 use sha2::{Digest, Sha256};
-use std::{collections::BTreeMap, num::NonZeroU64, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroU64,
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 use tct::StateCommitment;
 use tokio::{
     sync::broadcast::{self, error::RecvError},
@@ -41,9 +50,18 @@ use tokio::{
 };
 use url::Url;
 
-use crate::{sync::FilteredBlock, SpendableNoteRecord, SwapRecord};
+use crate::{
+    notification::{Notification, NotificationEvent},
+    sync::FilteredBlock,
+    SpendableNoteRecord, SwapRecord,
+};
 
+mod encryption;
+mod frontier;
+mod migration;
 mod sct;
+pub use encryption::{migrate_encryption, DatabaseEncryption, DatabaseKey, PASSPHRASE_ENV_VAR};
+pub use frontier::SctFrontier;
 use sct::TreeStore;
 
 /// The hash of the schema for the database.
@@ -63,31 +81,60 @@ pub struct Storage {
     /// Using a `NonZeroU64` ensures that `Option<NonZeroU64>` fits in 8 bytes.
     uncommitted_height: Arc<Mutex<Option<NonZeroU64>>>,
 
-    scanned_notes_tx: tokio::sync::broadcast::Sender<SpendableNoteRecord>,
-    scanned_nullifiers_tx: tokio::sync::broadcast::Sender<Nullifier>,
-    scanned_swaps_tx: tokio::sync::broadcast::Sender<SwapRecord>,
+    scanned_notes_tx: tokio::sync::broadcast::Sender<(WalletId, SpendableNoteRecord)>,
+    scanned_nullifiers_tx: tokio::sync::broadcast::Sender<(WalletId, Nullifier)>,
+    scanned_swaps_tx: tokio::sync::broadcast::Sender<(WalletId, SwapRecord)>,
+    notifications_tx: tokio::sync::broadcast::Sender<Notification>,
 }
 
 impl Storage {
     /// If the database at `storage_path` exists, [`Self::load`] it, otherwise, [`Self::initialize`] it.
+    ///
+    /// The set of wallets tracked by a database is fixed when it is initialized, so loading an
+    /// existing database fails if it does not track every one of the given `fvks`.
+    ///
+    /// If a `birthday` height is given, before which none of the wallets can have received funds, a
+    /// new database starts synchronizing from the [`SctFrontier`] the node serves for it.
+    ///
+    /// If a `key` is given, the database is encrypted with it.
     pub async fn load_or_initialize(
         storage_path: Option<impl AsRef<Utf8Path>>,
-        fvk: &FullViewingKey,
+        key: Option<DatabaseKey>,
+        fvks: &[FullViewingKey],
         node: Url,
+        birthday: Option<u64>,
     ) -> anyhow::Result<Self> {
         if let Some(path) = storage_path.as_ref() {
             if path.as_ref().exists() {
-                return Self::load(
+                let storage = Self::load(
                     storage_path.expect(
                         "storage path is not `None` because we already matched on it above",
                     ),
+                    key,
                 )
-                .await;
+                .await?;
+
+                let tracked = storage
+                    .full_viewing_keys()
+                    .await?
+                    .iter()
+                    .map(FullViewingKey::wallet_id)
+                    .collect::<BTreeSet<_>>();
+                for fvk in fvks {
+                    if !tracked.contains(&fvk.wallet_id()) {
+                        anyhow::bail!(
+                            "view database does not track wallet {}: you need to reset your view database and resynchronize to add it",
+                            fvk.wallet_id()
+                        );
+                    }
+                }
+
+                return Ok(storage);
             }
         };
 
         let mut client = AppQueryServiceClient::connect(node.to_string()).await?;
-        let params = client
+        let params: AppParameters = client
             .app_parameters(tonic::Request::new(AppParametersRequest {
                 chain_id: String::new(),
             }))
@@ -95,20 +142,36 @@ impl Storage {
             .into_inner()
             .try_into()?;
 
-        Self::initialize(storage_path, fvk.clone(), params).await
+        let frontier = match birthday {
+            Some(birthday) => {
+                SctFrontier::fetch(node, params.chain_params.chain_id.clone(), birthday).await?
+            }
+            None => None,
+        };
+
+        Self::initialize(storage_path, key, fvks.to_vec(), params, frontier).await
     }
 
     fn connect(
         path: Option<impl AsRef<Utf8Path>>,
+        key: Option<DatabaseKey>,
     ) -> anyhow::Result<r2d2::Pool<SqliteConnectionManager>> {
         if let Some(path) = path {
+            let encrypted = key.is_some();
+            if encrypted {
+                encryption::ensure_supported()?;
+            }
             let manager = SqliteConnectionManager::file(path.as_ref())
                 .with_flags(
                     // Don't allow opening URIs, because they can change the behavior of the database; we
                     // just want to open normal filepaths.
                     OpenFlags::default() & !OpenFlags::SQLITE_OPEN_URI,
                 )
-                .with_init(|conn| {
+                .with_init(move |conn| {
+                    // The key must be set before the database is first read.
+                    if let Some(key) = &key {
+                        key.apply(conn)?;
+                    }
                     // "NORMAL" will be consistent, but maybe not durable -- this is fine,
                     // since all our data is being synced from the chain, so if we lose a dbtx,
                     // it's like we're resuming sync from a previous height.
@@ -118,11 +181,18 @@ impl Storage {
                     conn.set_prepared_statement_cache_capacity(32);
                     Ok(())
                 });
-            Ok(r2d2::Pool::builder()
+            r2d2::Pool::builder()
                 // We set max_size=1 to avoid "database is locked" sqlite errors,
                 // when accessing across multiple threads.
                 .max_size(1)
-                .build(manager)?)
+                .build(manager)
+                .with_context(|| {
+                    if encrypted {
+                        "could not open the encrypted view database: is its key correct?"
+                    } else {
+                        "could not open the view database: if it is encrypted, its key must be configured"
+                    }
+                })
         } else {
             let manager = SqliteConnectionManager::memory();
             // Max size needs to be set to 1, otherwise a new in-memory database is created for each
@@ -139,18 +209,23 @@ impl Storage {
         }
     }
 
-    pub async fn load(path: impl AsRef<Utf8Path>) -> anyhow::Result<Self> {
+    pub async fn load(
+        path: impl AsRef<Utf8Path>,
+        key: Option<DatabaseKey>,
+    ) -> anyhow::Result<Self> {
         let storage = Self {
-            pool: Self::connect(Some(path))?,
+            pool: Self::connect(Some(path), key)?,
             uncommitted_height: Arc::new(Mutex::new(None)),
             scanned_notes_tx: broadcast::channel(128).0,
             scanned_nullifiers_tx: broadcast::channel(512).0,
             scanned_swaps_tx: broadcast::channel(128).0,
+            notifications_tx: broadcast::channel(512).0,
         };
 
         spawn_blocking(move || {
             // Check the version of the software used when first initializing this database.
-            // If it doesn't match the current version, we should report the error to the user.
+            // If it doesn't match the current version, we migrate the database if we can, and
+            // otherwise report the error to the user.
             let actual_schema_hash: String = storage
                 .pool
                 .get()?
@@ -159,7 +234,9 @@ impl Storage {
                 })
                 .context("failed to query database schema version: the database was probably created by an old client version, and needs to be reset and resynchronized")?;
 
-            if actual_schema_hash != *SCHEMA_HASH {
+            if actual_schema_hash != *SCHEMA_HASH
+                && !migration::migrate(&mut storage.pool.get()?, &actual_schema_hash)?
+            {
                 let database_client_version: String = storage
                     .pool
                     .get()?
@@ -180,15 +257,30 @@ impl Storage {
         .await?
     }
 
+    /// Initialize a new view database tracking the given `fvks`.
+    ///
+    /// If a `frontier` is given, synchronization starts from the block after it rather than from
+    /// genesis. If a `key` is given, the database is encrypted with it.
     pub async fn initialize(
         storage_path: Option<impl AsRef<Utf8Path>>,
-        fvk: FullViewingKey,
+        key: Option<DatabaseKey>,
+        fvks: Vec<FullViewingKey>,
         params: AppParameters,
+        frontier: Option<SctFrontier>,
     ) -> anyhow::Result<Self> {
-        tracing::debug!(storage_path = ?storage_path.as_ref().map(AsRef::as_ref), ?fvk, ?params);
+        tracing::debug!(
+            storage_path = ?storage_path.as_ref().map(AsRef::as_ref),
+            ?fvks,
+            ?params,
+            frontier_height = ?frontier.as_ref().map(|frontier| frontier.height),
+        );
+
+        if fvks.is_empty() {
+            anyhow::bail!("a view database must track at least one wallet");
+        }
 
         // Connect to the database (or create it)
-        let pool = Self::connect(storage_path)?;
+        let pool = Self::connect(storage_path, key)?;
 
         spawn_blocking(move || {
             // In one database transaction, populate everything
@@ -243,16 +335,36 @@ impl Storage {
                 [governance_params_bytes],
             )?;
 
-            let fvk_bytes = &FullViewingKey::encode_to_vec(&fvk)[..];
-            tx.execute(
-                "INSERT INTO full_viewing_key (bytes) VALUES (?1)",
-                [fvk_bytes],
-            )?;
+            for fvk in &fvks {
+                let wallet_id = fvk.wallet_id().0.to_vec();
+                let fvk_bytes = FullViewingKey::encode_to_vec(fvk);
+                tx.execute(
+                    "INSERT OR IGNORE INTO full_viewing_keys (wallet_id, bytes) VALUES (?1, ?2)",
+                    (wallet_id, fvk_bytes),
+                )?;
+            }
 
-            // Insert -1 as a signaling value for pre-genesis.
-            // We just have to be careful to treat negative values as None
-            // in last_sync_height.
-            tx.execute("INSERT INTO sync_height (height) VALUES (-1)", ())?;
+            if let Some(frontier) = frontier {
+                // Start from the frontier, as though every block up to it had been scanned.
+                frontier.tree.to_writer(&mut TreeStore(&mut tx))?;
+                tx.execute(
+                    "INSERT INTO fmd_parameters (bytes) VALUES (?1)",
+                    [&FmdParameters::encode_to_vec(&frontier.fmd_parameters)[..]],
+                )?;
+                tx.execute(
+                    "INSERT INTO gas_prices (bytes) VALUES (?1)",
+                    [&GasPrices::encode_to_vec(&frontier.gas_prices)[..]],
+                )?;
+                tx.execute(
+                    "INSERT INTO sync_height (height) VALUES (?1)",
+                    [frontier.height as i64],
+                )?;
+            } else {
+                // Insert -1 as a signaling value for pre-genesis.
+                // We just have to be careful to treat negative values as None
+                // in last_sync_height.
+                tx.execute("INSERT INTO sync_height (height) VALUES (-1)", ())?;
+            }
 
             // Insert the schema hash into the database
             tx.execute(
@@ -275,36 +387,44 @@ impl Storage {
                 scanned_notes_tx: broadcast::channel(128).0,
                 scanned_nullifiers_tx: broadcast::channel(512).0,
                 scanned_swaps_tx: broadcast::channel(128).0,
+                notifications_tx: broadcast::channel(512).0,
             })
         })
         .await?
     }
 
-    /// Query for account balance by address
+    /// Query for a wallet's account balance by address
     pub async fn balances(
         &self,
+        wallet_id: WalletId,
         address_index: Option<AddressIndex>,
         asset_id: Option<asset::Id>,
     ) -> anyhow::Result<BTreeMap<Id, u128>> {
         let pool = self.pool.clone();
+        let wallet_id = wallet_id.0.to_vec();
 
         spawn_blocking(move || {
             let query = "SELECT notes.asset_id, notes.amount, spendable_notes.address_index
                 FROM    notes
                 JOIN    spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
-                WHERE   spendable_notes.height_spent IS NULL";
+                WHERE   spendable_notes.height_spent IS NULL
+                AND     spendable_notes.wallet_id = ?1";
 
             tracing::debug!(?query);
 
             let mut balances = BTreeMap::new();
 
-            for result in pool.get()?.prepare_cached(query)?.query_map([], |row| {
-                let asset_id = row.get::<&str, Vec<u8>>("asset_id")?;
-                let amount = row.get::<&str, Vec<u8>>("amount")?;
-                let address_index = row.get::<&str, Vec<u8>>("address_index")?;
+            for result in pool
+                .get()?
+                .prepare_cached(query)?
+                .query_map([wallet_id], |row| {
+                    let asset_id = row.get::<&str, Vec<u8>>("asset_id")?;
+                    let amount = row.get::<&str, Vec<u8>>("amount")?;
+                    let address_index = row.get::<&str, Vec<u8>>("address_index")?;
 
-                Ok((asset_id, amount, address_index))
-            })? {
+                    Ok((asset_id, amount, address_index))
+                })?
+            {
                 let (id, amount, index) = result?;
 
                 let id = Id::try_from(id.as_slice())?;
@@ -342,9 +462,10 @@ impl Storage {
         .await?
     }
 
-    /// Query for a note by its note commitment, optionally waiting until the note is detected.
+    /// Query for a wallet's note by its note commitment, optionally waiting until the note is detected.
     pub async fn note_by_commitment(
         &self,
+        wallet_id: WalletId,
         note_commitment: tct::StateCommitment,
         await_detection: bool,
     ) -> anyhow::Result<SpendableNoteRecord> {
@@ -372,8 +493,10 @@ impl Storage {
                         spendable_notes.position
                     FROM notes
                     JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
-                    WHERE notes.note_commitment = x'{}'",
-                    hex::encode(note_commitment.0.to_bytes())
+                    WHERE notes.note_commitment = x'{}'
+                    AND spendable_notes.wallet_id = x'{}'",
+                    hex::encode(note_commitment.0.to_bytes()),
+                    hex::encode(wallet_id.0),
                 ))?
                 .query_and_then((), |record| record.try_into())?
                 .next()
@@ -393,8 +516,8 @@ impl Storage {
 
         loop {
             match rx.recv().await {
-                Ok(record) => {
-                    if record.note_commitment == note_commitment {
+                Ok((record_wallet_id, record)) => {
+                    if record_wallet_id == wallet_id && record.note_commitment == note_commitment {
                         return Ok(record);
                     }
                 }
@@ -416,9 +539,10 @@ impl Storage {
         }
     }
 
-    /// Query for a swap by its swap commitment, optionally waiting until the note is detected.
+    /// Query for a wallet's swap by its swap commitment, optionally waiting until the note is detected.
     pub async fn swap_by_commitment(
         &self,
+        wallet_id: WalletId,
         swap_commitment: tct::StateCommitment,
         await_detection: bool,
     ) -> anyhow::Result<SwapRecord> {
@@ -432,8 +556,9 @@ impl Storage {
             // Check if we already have the swap record
             pool.get()?
                 .prepare(&format!(
-                    "SELECT * FROM swaps WHERE swaps.swap_commitment = x'{}'",
-                    hex::encode(swap_commitment.0.to_bytes())
+                    "SELECT * FROM swaps WHERE swaps.swap_commitment = x'{}' AND swaps.wallet_id = x'{}'",
+                    hex::encode(swap_commitment.0.to_bytes()),
+                    hex::encode(wallet_id.0),
                 ))?
                 .query_and_then((), |record| record.try_into())?
                 .next()
@@ -453,8 +578,8 @@ impl Storage {
 
         loop {
             match rx.recv().await {
-                Ok(record) => {
-                    if record.swap_commitment == swap_commitment {
+                Ok((record_wallet_id, record)) => {
+                    if record_wallet_id == wallet_id && record.swap_commitment == swap_commitment {
                         return Ok(record);
                     }
                 }
@@ -476,15 +601,16 @@ impl Storage {
         }
     }
 
-    /// Query for all unclaimed swaps.
-    pub async fn unclaimed_swaps(&self) -> anyhow::Result<Vec<SwapRecord>> {
+    /// Query for all of a wallet's unclaimed swaps.
+    pub async fn unclaimed_swaps(&self, wallet_id: WalletId) -> anyhow::Result<Vec<SwapRecord>> {
         let pool = self.pool.clone();
+        let wallet_id = wallet_id.0.to_vec();
 
         let records = spawn_blocking(move || {
             // Check if we already have the swap record
             pool.get()?
-                .prepare("SELECT * FROM swaps WHERE swaps.height_claimed is NULL")?
-                .query_and_then((), |record| record.try_into())?
+                .prepare("SELECT * FROM swaps WHERE swaps.height_claimed is NULL AND swaps.wallet_id = ?1")?
+                .query_and_then([wallet_id], |record| record.try_into())?
                 .collect::<anyhow::Result<Vec<_>>>()
         })
         .await??;
@@ -492,9 +618,10 @@ impl Storage {
         Ok(records)
     }
 
-    /// Query for a nullifier's status, optionally waiting until the nullifier is detected.
+    /// Query for the status of a wallet's nullifier, optionally waiting until the nullifier is detected.
     pub async fn nullifier_status(
         &self,
+        wallet_id: WalletId,
         nullifier: Nullifier,
         await_detection: bool,
     ) -> anyhow::Result<bool> {
@@ -506,12 +633,15 @@ impl Storage {
         let pool = self.pool.clone();
 
         let nullifier_bytes = nullifier.0.to_bytes().to_vec();
+        let wallet_id_bytes = wallet_id.0.to_vec();
 
         // Check if we already have the nullifier in the set of spent notes
         if let Some(height_spent) = spawn_blocking(move || {
             pool.get()?
-                .prepare_cached("SELECT height_spent FROM spendable_notes WHERE nullifier = ?1")?
-                .query_and_then([nullifier_bytes], |row| {
+                .prepare_cached(
+                    "SELECT height_spent FROM spendable_notes WHERE nullifier = ?1 AND wallet_id = ?2",
+                )?
+                .query_and_then([nullifier_bytes, wallet_id_bytes], |row| {
                     let height_spent: Option<u64> = row.get("height_spent")?;
                     anyhow::Ok(height_spent)
                 })?
@@ -537,9 +667,10 @@ impl Storage {
         // Otherwise, wait for newly detected nullifiers and check whether they're the requested
         // one.
         loop {
-            let new_nullifier = rx.recv().await.context("change subscriber failed")?;
+            let (new_wallet_id, new_nullifier) =
+                rx.recv().await.context("change subscriber failed")?;
 
-            if new_nullifier == nullifier {
+            if new_wallet_id == wallet_id && new_nullifier == nullifier {
                 return Ok(true);
             }
         }
@@ -652,17 +783,18 @@ impl Storage {
         .await?
     }
 
-    pub async fn full_viewing_key(&self) -> anyhow::Result<FullViewingKey> {
+    /// The full viewing keys of all wallets tracked by this database.
+    pub async fn full_viewing_keys(&self) -> anyhow::Result<Vec<FullViewingKey>> {
         let pool = self.pool.clone();
 
         spawn_blocking(move || {
-            let bytes = pool
-                .get()?
-                .prepare_cached("SELECT bytes FROM full_viewing_key LIMIT 1")?
-                .query_row([], |row| row.get::<_, Option<Vec<u8>>>("bytes"))?
-                .ok_or_else(|| anyhow!("missing full viewing key"))?;
-
-            FullViewingKey::decode(bytes.as_slice())
+            pool.get()?
+                .prepare_cached("SELECT bytes FROM full_viewing_keys")?
+                .query_and_then([], |row| {
+                    let bytes: Vec<u8> = row.get("bytes")?;
+                    FullViewingKey::decode(bytes.as_slice())
+                })?
+                .collect()
         })
         .await?
     }
@@ -675,9 +807,10 @@ impl Storage {
         .await?
     }
 
-    /// Returns a tuple of (block height, transaction hash) for all transactions in a given range of block heights.
+    /// Returns a tuple of (block height, transaction hash) for all of a wallet's transactions in a given range of block heights.
     pub async fn transaction_hashes(
         &self,
+        wallet_id: WalletId,
         start_height: Option<u64>,
         end_height: Option<u64>,
     ) -> anyhow::Result<Vec<(u64, Vec<u8>)>> {
@@ -689,23 +822,29 @@ impl Storage {
         spawn_blocking(move || {
             pool.get()?
                 .prepare_cached(
-                    "SELECT block_height, tx_hash
+                    "SELECT tx.block_height, tx.tx_hash
                     FROM tx
-                    WHERE block_height BETWEEN ?1 AND ?2",
+                    JOIN wallet_tx ON tx.tx_hash = wallet_tx.tx_hash
+                    WHERE tx.block_height BETWEEN ?1 AND ?2
+                    AND wallet_tx.wallet_id = ?3",
+                )?
+                .query_and_then(
+                    (starting_block, ending_block, wallet_id.0.to_vec()),
+                    |row| {
+                        let block_height: u64 = row.get("block_height")?;
+                        let tx_hash: Vec<u8> = row.get("tx_hash")?;
+                        anyhow::Ok((block_height, tx_hash))
+                    },
                 )?
-                .query_and_then([starting_block, ending_block], |row| {
-                    let block_height: u64 = row.get("block_height")?;
-                    let tx_hash: Vec<u8> = row.get("tx_hash")?;
-                    anyhow::Ok((block_height, tx_hash))
-                })?
                 .collect()
         })
         .await?
     }
 
-    /// Returns a tuple of (block height, transaction hash, transaction) for all transactions in a given range of block heights.
+    /// Returns a tuple of (block height, transaction hash, transaction) for all of a wallet's transactions in a given range of block heights.
     pub async fn transactions(
         &self,
+        wallet_id: WalletId,
         start_height: Option<u64>,
         end_height: Option<u64>,
     ) -> anyhow::Result<Vec<(u64, Vec<u8>, Transaction)>> {
@@ -717,17 +856,22 @@ impl Storage {
         spawn_blocking(move || {
             pool.get()?
                 .prepare_cached(
-                    "SELECT block_height, tx_hash, tx_bytes
+                    "SELECT tx.block_height, tx.tx_hash, tx.tx_bytes
                     FROM tx
-                    WHERE block_height BETWEEN ?1 AND ?2",
+                    JOIN wallet_tx ON tx.tx_hash = wallet_tx.tx_hash
+                    WHERE tx.block_height BETWEEN ?1 AND ?2
+                    AND wallet_tx.wallet_id = ?3",
+                )?
+                .query_and_then(
+                    (starting_block, ending_block, wallet_id.0.to_vec()),
+                    |row| {
+                        let block_height: u64 = row.get("block_height")?;
+                        let tx_hash: Vec<u8> = row.get("tx_hash")?;
+                        let tx_bytes: Vec<u8> = row.get("tx_bytes")?;
+                        let tx = Transaction::decode(tx_bytes.as_slice())?;
+                        anyhow::Ok((block_height, tx_hash, tx))
+                    },
                 )?
-                .query_and_then([starting_block, ending_block], |row| {
-                    let block_height: u64 = row.get("block_height")?;
-                    let tx_hash: Vec<u8> = row.get("tx_hash")?;
-                    let tx_bytes: Vec<u8> = row.get("tx_bytes")?;
-                    let tx = Transaction::decode(tx_bytes.as_slice())?;
-                    anyhow::Ok((block_height, tx_hash, tx))
-                })?
                 .collect()
         })
         .await?
@@ -735,16 +879,23 @@ impl Storage {
 
     pub async fn transaction_by_hash(
         &self,
+        wallet_id: WalletId,
         tx_hash: &[u8],
     ) -> anyhow::Result<Option<(u64, Transaction)>> {
         let pool = self.pool.clone();
         let tx_hash = tx_hash.to_vec();
+        let wallet_id = wallet_id.0.to_vec();
 
         spawn_blocking(move || {
             if let Some((block_height, tx_bytes)) = pool
                 .get()?
-                .prepare_cached("SELECT block_height, tx_bytes FROM tx WHERE tx_hash = ?1")?
-                .query_row([tx_hash], |row| {
+                .prepare_cached(
+                    "SELECT tx.block_height, tx.tx_bytes
+                    FROM tx
+                    JOIN wallet_tx ON tx.tx_hash = wallet_tx.tx_hash
+                    WHERE tx.tx_hash = ?1 AND wallet_tx.wallet_id = ?2",
+                )?
+                .query_row([tx_hash, wallet_id], |row| {
                     let block_height: u64 = row.get("block_height")?;
                     let tx_bytes: Vec<u8> = row.get("tx_bytes")?;
                     Ok((block_height, tx_bytes))
@@ -760,9 +911,10 @@ impl Storage {
         .await?
     }
 
-    // Query for a note by its note commitment, optionally waiting until the note is detected.
+    // Query for a wallet's note by its nullifier, optionally waiting until the note is detected.
     pub async fn note_by_nullifier(
         &self,
+        wallet_id: WalletId,
         nullifier: Nullifier,
         await_detection: bool,
     ) -> anyhow::Result<SpendableNoteRecord> {
@@ -793,8 +945,10 @@ impl Storage {
                         spendable_notes.position
                     FROM notes
                     JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
-                    WHERE hex(spendable_notes.nullifier) = \"{}\"",
-                    hex::encode_upper(nullifier_bytes)
+                    WHERE hex(spendable_notes.nullifier) = \"{}\"
+                    AND spendable_notes.wallet_id = x'{}'",
+                    hex::encode_upper(nullifier_bytes),
+                    hex::encode(wallet_id.0),
                 ))?
                 .query_and_then((), |row| SpendableNoteRecord::try_from(row))?
                 .next()
@@ -816,8 +970,8 @@ impl Storage {
 
         loop {
             match rx.recv().await {
-                Ok(record) => {
-                    if record.nullifier == nullifier {
+                Ok((record_wallet_id, record)) => {
+                    if record_wallet_id == wallet_id && record.nullifier == nullifier {
                         return Ok(record);
                     }
                 }
@@ -907,6 +1061,7 @@ impl Storage {
 
     pub async fn notes(
         &self,
+        wallet_id: WalletId,
         include_spent: bool,
         asset_id: Option<asset::Id>,
         address_index: Option<penumbra_keys::keys::AddressIndex>,
@@ -968,7 +1123,9 @@ impl Storage {
                 JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
                 WHERE spendable_notes.height_spent IS {spent_clause}
                 AND notes.asset_id IS {asset_clause}
-                AND spendable_notes.address_index IS {address_clause}"
+                AND spendable_notes.address_index IS {address_clause}
+                AND spendable_notes.wallet_id = x'{}'",
+                    hex::encode(wallet_id.0),
                 ))?
                 .query_and_then((), |row| SpendableNoteRecord::try_from(row))?
             {
@@ -1015,6 +1172,7 @@ impl Storage {
 
     pub async fn notes_for_voting(
         &self,
+        wallet_id: WalletId,
         address_index: Option<penumbra_keys::keys::AddressIndex>,
         votable_at_height: u64,
     ) -> anyhow::Result<Vec<(SpendableNoteRecord, IdentityKey)>> {
@@ -1023,6 +1181,7 @@ impl Storage {
         let address_clause = address_index
             .map(|d| format!("x'{}'", hex::encode(d.to_bytes())))
             .unwrap_or_else(|| "address_index".to_string());
+        let wallet_clause = hex::encode(wallet_id.0);
 
         let pool = self.pool.clone();
 
@@ -1047,6 +1206,7 @@ impl Storage {
                         notes JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
                     WHERE
                         spendable_notes.address_index IS {address_clause}
+                        AND spendable_notes.wallet_id = x'{wallet_clause}'
                         AND notes.asset_id IN (
                             SELECT asset_id FROM assets WHERE denom LIKE '_delegation\\_%' ESCAPE '\\'
                         )
@@ -1119,7 +1279,12 @@ impl Storage {
         Ok(())
     }
 
-    pub async fn record_position(&self, position: Position) -> anyhow::Result<()> {
+    pub async fn record_position(
+        &self,
+        wallet_id: WalletId,
+        position: Position,
+    ) -> anyhow::Result<()> {
+        let wallet_id = wallet_id.0.to_vec();
         let position_id = position.id().0.to_vec();
 
         let position_state = position.state.to_string();
@@ -1130,8 +1295,8 @@ impl Storage {
         spawn_blocking(move || {
             pool.get()?
                 .execute(
-                    "INSERT OR REPLACE INTO positions (position_id, position_state, trading_pair) VALUES (?1, ?2, ?3)",
-                    (position_id, position_state, trading_pair),
+                    "INSERT OR REPLACE INTO positions (wallet_id, position_id, position_state, trading_pair) VALUES (?1, ?2, ?3, ?4)",
+                    (wallet_id, position_id, position_state, trading_pair),
                 )
                 .map_err(anyhow::Error::from)
         })
@@ -1298,10 +1463,43 @@ impl Storage {
         .await?
     }
 
+    /// Returns the wallets owning the notes or swaps with the given nullifiers, if any.
+    pub async fn nullifier_wallets(
+        &self,
+        nullifiers: Vec<Nullifier>,
+    ) -> anyhow::Result<BTreeMap<Nullifier, WalletId>> {
+        if nullifiers.is_empty() {
+            return Ok(BTreeMap::new());
+        }
+
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare(&format!(
+                    "SELECT nullifier, wallet_id FROM (SELECT nullifier, wallet_id FROM spendable_notes UNION SELECT nullifier, wallet_id FROM swaps) WHERE nullifier IN ({})",
+                    nullifiers
+                        .iter()
+                        .map(|x| format!("x'{}'", hex::encode(x.0.to_bytes())))
+                        .collect::<Vec<String>>()
+                        .join(",")
+                ))?
+                .query_and_then((), |row| {
+                    let nullifier: Vec<u8> = row.get("nullifier")?;
+                    let wallet_id: [u8; 32] = row.get("wallet_id")?;
+                    anyhow::Ok((nullifier.as_slice().try_into()?, WalletId(wallet_id)))
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Records a scanned block, along with the relevant transactions in it and the wallets each
+    /// of those transactions is relevant to.
     pub async fn record_block(
         &self,
         filtered_block: FilteredBlock,
-        transactions: Vec<Transaction>,
+        transactions: Vec<(Transaction, BTreeSet<WalletId>)>,
         sct: &mut tct::Tree,
         // TODO: sucks passing this around, figure something better out
         node: Url,
@@ -1329,8 +1527,14 @@ impl Storage {
         let scanned_notes_tx = self.scanned_notes_tx.clone();
         let scanned_nullifiers_tx = self.scanned_nullifiers_tx.clone();
         let scanned_swaps_tx = self.scanned_swaps_tx.clone();
+        let notifications_tx = self.notifications_tx.clone();
 
-        let fvk = self.full_viewing_key().await?;
+        let fvks = self
+            .full_viewing_keys()
+            .await?
+            .into_iter()
+            .map(|fvk| (fvk.wallet_id(), fvk))
+            .collect::<BTreeMap<_, _>>();
 
         // If the app parameters have changed, update them.
         let new_app_parameters: Option<AppParameters> = if filtered_block.app_parameters_updated {
@@ -1410,6 +1614,12 @@ impl Storage {
 
             // Insert new note records into storage
             for note_record in filtered_block.new_notes.values() {
+                let wallet_id = filtered_block
+                    .wallets
+                    .get(&note_record.note_commitment)
+                    .ok_or_else(|| anyhow!("no wallet recorded for new note"))?
+                    .0
+                    .to_vec();
                 let note_commitment = note_record.note_commitment.0.to_bytes().to_vec();
                 let height_created = filtered_block.height as i64;
                 let address_index = note_record.address_index.to_bytes().to_vec();
@@ -1423,10 +1633,11 @@ impl Storage {
 
                 dbtx.execute(
                     "INSERT INTO spendable_notes
-                    (note_commitment, nullifier, position, height_created, address_index, source, height_spent)
-                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, NULL)",
+                    (note_commitment, wallet_id, nullifier, position, height_created, address_index, source, height_spent)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, NULL)",
                     (
                         &note_commitment,
+                        &wallet_id,
                         &nullifier,
                         &position,
                         &height_created,
@@ -1439,6 +1650,12 @@ impl Storage {
 
             // Insert new swap records into storage
             for swap in filtered_block.new_swaps.values() {
+                let wallet_id = filtered_block
+                    .wallets
+                    .get(&swap.swap_commitment)
+                    .ok_or_else(|| anyhow!("no wallet recorded for new swap"))?
+                    .0
+                    .to_vec();
                 let swap_commitment = swap.swap_commitment.0.to_bytes().to_vec();
                 let swap_bytes = swap.swap.encode_to_vec();
                 let position = (u64::from(swap.position)) as i64;
@@ -1447,10 +1664,11 @@ impl Storage {
                 let output_data = swap.output_data.encode_to_vec();
 
                 dbtx.execute(
-                    "INSERT INTO swaps (swap_commitment, swap, position, nullifier, output_data, height_claimed, source)
-                    VALUES (?1, ?2, ?3, ?4, ?5, NULL, ?6)",
+                    "INSERT INTO swaps (swap_commitment, wallet_id, swap, position, nullifier, output_data, height_claimed, source)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, NULL, ?7)",
                     (
                         &swap_commitment,
+                        &wallet_id,
                         &swap_bytes,
                         &position,
                         &nullifier,
@@ -1461,32 +1679,45 @@ impl Storage {
                 )?;
             }
 
+            // The nullifiers spent in this block, along with the wallet each of them belongs to
+            let mut spent_nullifiers = Vec::new();
+            // The notes spent in this block, along with the wallet each of them belongs to
+            let mut spent_notes = Vec::new();
+
             // Update any rows of the table with matching nullifiers to have height_spent
             for nullifier in &filtered_block.spent_nullifiers {
                 let height_spent = filtered_block.height as i64;
                 let nullifier_bytes = nullifier.to_bytes().to_vec();
 
-                let spent_commitment: Option<StateCommitment> = dbtx.prepare_cached(
-                    "UPDATE spendable_notes SET height_spent = ?1 WHERE nullifier = ?2 RETURNING note_commitment"
+                let spent_commitment: Option<(StateCommitment, WalletId)> = dbtx.prepare_cached(
+                    "UPDATE spendable_notes SET height_spent = ?1 WHERE nullifier = ?2 RETURNING note_commitment, wallet_id"
                 )?
                 .query_and_then(
                     (height_spent, &nullifier_bytes),
                     |row| {
                         let bytes: Vec<u8> = row.get("note_commitment")?;
-                        StateCommitment::try_from(&bytes[..]).context("invalid commitment bytes")
+                        let wallet_id: [u8; 32] = row.get("wallet_id")?;
+                        anyhow::Ok((
+                            StateCommitment::try_from(&bytes[..]).context("invalid commitment bytes")?,
+                            WalletId(wallet_id),
+                        ))
                     }
                 )?
                 .next()
                 .transpose()?;
 
-                let swap_commitment: Option<StateCommitment> = dbtx.prepare_cached(
-                        "UPDATE swaps SET height_claimed = ?1 WHERE nullifier = ?2 RETURNING swap_commitment"
+                let swap_commitment: Option<(StateCommitment, WalletId)> = dbtx.prepare_cached(
+                        "UPDATE swaps SET height_claimed = ?1 WHERE nullifier = ?2 RETURNING swap_commitment, wallet_id"
                     )?
                     .query_and_then(
                         (height_spent, &nullifier_bytes),
                         |row| {
                             let bytes: Vec<u8> = row.get("swap_commitment")?;
-                            StateCommitment::try_from(&bytes[..]).context("invalid commitment bytes")
+                            let wallet_id: [u8; 32] = row.get("wallet_id")?;
+                            anyhow::Ok((
+                                StateCommitment::try_from(&bytes[..]).context("invalid commitment bytes")?,
+                                WalletId(wallet_id),
+                            ))
                         }
                     )?
                     .next()
@@ -1510,7 +1741,31 @@ impl Storage {
                     .unwrap_or("unknown".to_string());
 
                 // Mark spent notes as spent
-                if let Some(spent_commitment) = spent_commitment {
+                if let Some((spent_commitment, wallet_id)) = spent_commitment {
+                    spent_nullifiers.push((wallet_id, *nullifier));
+
+                    let spent_note = dbtx.prepare_cached(
+                        "SELECT
+                            notes.note_commitment,
+                            spendable_notes.height_created,
+                            notes.address,
+                            notes.amount,
+                            notes.asset_id,
+                            notes.rseed,
+                            spendable_notes.address_index,
+                            spendable_notes.source,
+                            spendable_notes.height_spent,
+                            spendable_notes.nullifier,
+                            spendable_notes.position
+                        FROM notes
+                        JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+                        WHERE spendable_notes.nullifier = ?1"
+                    )?
+                    .query_and_then([&nullifier_bytes], |row| SpendableNoteRecord::try_from(row))?
+                    .next()
+                    .transpose()?;
+                    spent_notes.extend(spent_note.map(|record| (wallet_id, record)));
+
                     tracing::debug!(?nullifier, ?spent_commitment, ?spent_denom, "detected spent note commitment");
                     // Forget spent note commitments from the SCT unless they are delegation tokens,
                     // which must be saved to allow voting on proposals that might or might not be
@@ -1523,7 +1778,8 @@ impl Storage {
                 };
 
                 // Mark spent swaps as spent
-                if let Some(spent_swap_commitment) = swap_commitment {
+                if let Some((spent_swap_commitment, wallet_id)) = swap_commitment {
+                    spent_nullifiers.push((wallet_id, *nullifier));
                     tracing::debug!(?nullifier, ?spent_swap_commitment, "detected and forgetting spent swap commitment");
                     new_sct.forget(spent_swap_commitment);
                 };
@@ -1533,13 +1789,18 @@ impl Storage {
             new_sct.to_writer(&mut TreeStore(&mut dbtx))?;
 
             // Record all transactions
-            for transaction in transactions {
+            for (transaction, wallets) in transactions {
                 let tx_bytes = transaction.encode_to_vec();
                 // We have to create an explicit temporary borrow, because the sqlx api is bad (see above)
                 let tx_hash_owned = sha2::Sha256::digest(&tx_bytes);
                 let tx_hash = tx_hash_owned.as_slice();
                 let tx_block_height = filtered_block.height as i64;
-                let return_address = transaction.decrypt_memo(&fvk).map_or(None, |x| Some(x.return_address().to_vec()));
+                // The memo is the same for every wallet able to decrypt it.
+                let return_address = wallets
+                    .iter()
+                    .filter_map(|wallet_id| fvks.get(wallet_id))
+                    .find_map(|fvk| transaction.decrypt_memo(fvk).ok())
+                    .map(|memo| memo.return_address().to_vec());
 
                 tracing::debug!(tx_hash = ?hex::encode(tx_hash), "recording extended transaction");
 
@@ -1548,6 +1809,13 @@ impl Storage {
                     (&tx_hash, &tx_bytes, tx_block_height, return_address),
                 )?;
 
+                for wallet_id in &wallets {
+                    dbtx.execute(
+                        "INSERT OR IGNORE INTO wallet_tx (wallet_id, tx_hash) VALUES (?1, ?2)",
+                        (wallet_id.0.to_vec(), &tx_hash),
+                    )?;
+                }
+
                 // Associate all of the spent nullifiers with the transaction by hash.
                 for nf in transaction.spent_nullifiers() {
                     let nf_bytes = nf.0.to_bytes().to_vec();
@@ -1593,25 +1861,54 @@ impl Storage {
             // Broadcast all committed note records to channel
             // Done following tx.commit() to avoid notifying of a new SpendableNoteRecord before it is actually committed to the database
 
-            for note_record in filtered_block.new_notes.values() {
+            for (commitment, note_record) in filtered_block.new_notes.iter() {
                 // This will fail to be broadcast if there is no active receiver (such as on initial
                 // sync) The error is ignored, as this isn't a problem, because if there is no
                 // active receiver there is nothing to do
-                let _ = scanned_notes_tx.send(note_record.clone());
+                if let Some(wallet_id) = filtered_block.wallets.get(commitment) {
+                    let _ = scanned_notes_tx.send((*wallet_id, note_record.clone()));
+                }
             }
 
-            for nullifier in filtered_block.spent_nullifiers.iter() {
+            for spent_nullifier in spent_nullifiers {
                 // This will fail to be broadcast if there is no active receiver (such as on initial
                 // sync) The error is ignored, as this isn't a problem, because if there is no
                 // active receiver there is nothing to do
-                let _ = scanned_nullifiers_tx.send(*nullifier);
+                let _ = scanned_nullifiers_tx.send(spent_nullifier);
             }
 
-            for swap_record in filtered_block.new_swaps.values() {
+            for (commitment, swap_record) in filtered_block.new_swaps.iter() {
                 // This will fail to be broadcast if there is no active rece∑iver (such as on initial
                 // sync) The error is ignored, as this isn't a problem, because if there is no
                 // active receiver there is nothing to do
-                let _ = scanned_swaps_tx.send(swap_record.clone());
+                if let Some(wallet_id) = filtered_block.wallets.get(commitment) {
+                    let _ = scanned_swaps_tx.send((*wallet_id, swap_record.clone()));
+                }
+            }
+
+            // Notify subscribers of the events detected in this block, in the same way.
+            let height = filtered_block.height;
+            let received = filtered_block.new_notes.iter().filter_map(|(commitment, record)| {
+                filtered_block.wallets.get(commitment).map(|wallet_id| Notification {
+                    wallet_id: *wallet_id,
+                    height,
+                    event: NotificationEvent::NoteReceived(record.clone()),
+                })
+            });
+            let spent = spent_notes.into_iter().map(|(wallet_id, record)| Notification {
+                wallet_id,
+                height,
+                event: NotificationEvent::NoteSpent(record),
+            });
+            let claimable = filtered_block.new_swaps.iter().filter_map(|(commitment, record)| {
+                filtered_block.wallets.get(commitment).map(|wallet_id| Notification {
+                    wallet_id: *wallet_id,
+                    height,
+                    event: NotificationEvent::SwapClaimable(record.clone()),
+                })
+            });
+            for notification in received.chain(spent).chain(claimable) {
+                let _ = notifications_tx.send(notification);
             }
 
             anyhow::Ok(new_sct)
@@ -1621,12 +1918,88 @@ impl Storage {
         Ok(())
     }
 
+    /// Subscribes to the events detected while scanning blocks.
+    pub fn subscribe_notifications(&self) -> broadcast::Receiver<Notification> {
+        self.notifications_tx.subscribe()
+    }
+
+    /// Notifies subscribers of an event detected outside of [`Self::record_block`].
+    ///
+    /// This should only be called once the changes the event describes have been committed.
+    pub fn notify(&self, notification: Notification) {
+        // This will fail to be broadcast if there is no active receiver, which isn't a problem.
+        let _ = self.notifications_tx.send(notification);
+    }
+
+    /// Returns the unspent notes of unbonding tokens, along with the wallet holding each, whose
+    /// unbonding period ends at the start of the given epoch.
+    ///
+    /// This assumes the default unbonding period: tokens unbonding from a validator which left
+    /// the active set may become claimable earlier.
+    pub async fn unbonding_notes_claimable_at(
+        &self,
+        epoch_index: u64,
+    ) -> anyhow::Result<Vec<(WalletId, SpendableNoteRecord)>> {
+        let unbonding_epochs = self.app_params().await?.stake_params.unbonding_epochs;
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut output = Vec::new();
+
+            for result in pool
+                .get()?
+                .prepare(
+                    "SELECT notes.note_commitment,
+                        spendable_notes.height_created,
+                        notes.address,
+                        notes.amount,
+                        notes.asset_id,
+                        notes.rseed,
+                        spendable_notes.address_index,
+                        spendable_notes.source,
+                        spendable_notes.height_spent,
+                        spendable_notes.nullifier,
+                        spendable_notes.position,
+                        spendable_notes.wallet_id,
+                        assets.denom
+                FROM notes
+                JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+                JOIN assets ON notes.asset_id = assets.asset_id
+                WHERE spendable_notes.height_spent IS NULL
+                AND assets.denom LIKE 'uunbonding_%'",
+                )?
+                .query_and_then((), |row| {
+                    let wallet_id: [u8; 32] = row.get("wallet_id")?;
+                    let denom: String = row.get("denom")?;
+                    anyhow::Ok((
+                        WalletId(wallet_id),
+                        denom,
+                        SpendableNoteRecord::try_from(row)?,
+                    ))
+                })?
+            {
+                let (wallet_id, denom, record) = result?;
+                let Ok(token) = UnbondingToken::from_str(&denom) else {
+                    continue;
+                };
+                if token.start_epoch_index() + unbonding_epochs == epoch_index {
+                    output.push((wallet_id, record));
+                }
+            }
+
+            anyhow::Ok(output)
+        })
+        .await?
+    }
+
     pub async fn owned_position_ids(
         &self,
+        wallet_id: WalletId,
         position_state: Option<State>,
         trading_pair: Option<TradingPair>,
     ) -> anyhow::Result<Vec<position::Id>> {
         let pool = self.pool.clone();
+        let wallet_id = wallet_id.0.to_vec();
 
         let state_clause = match position_state {
             Some(state) => format!("position_state = \"{}\"", state),
@@ -1639,23 +2012,23 @@ impl Storage {
         };
 
         spawn_blocking(move || {
-            let mut q = "SELECT position_id FROM positions".to_string();
+            let mut q = "SELECT position_id FROM positions WHERE wallet_id = ?1".to_string();
             match (position_state.is_some(), trading_pair.is_some()) {
                 (true, true) => {
-                    q = q + " WHERE " + &state_clause + " AND " + &pair_clause;
+                    q = q + " AND " + &state_clause + " AND " + &pair_clause;
                 }
                 (true, false) => {
-                    q = q + " WHERE " + &state_clause;
+                    q = q + " AND " + &state_clause;
                 }
                 (false, true) => {
-                    q = q + " WHERE " + &pair_clause;
+                    q = q + " AND " + &pair_clause;
                 }
                 (false, false) => (),
             };
 
             pool.get()?
                 .prepare_cached(&q)?
-                .query_and_then([], |row| {
+                .query_and_then([wallet_id], |row| {
                     let position_id: Vec<u8> = row.get("position_id")?;
                     Ok(position::Id(position_id.as_slice().try_into()?))
                 })?
@@ -1666,6 +2039,7 @@ impl Storage {
 
     pub async fn notes_by_sender(
         &self,
+        wallet_id: WalletId,
         return_address: &Address,
     ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
         let pool = self.pool.clone();
@@ -1684,14 +2058,16 @@ impl Storage {
             FROM notes
             JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
             JOIN tx ON spendable_notes.source = tx.tx_hash
-            WHERE tx.return_address = ?1";
+            WHERE tx.return_address = ?1
+            AND spendable_notes.wallet_id = ?2";
 
         let return_address = return_address.to_vec();
+        let wallet_id = wallet_id.0.to_vec();
 
         let records = spawn_blocking(move || {
             pool.get()?
                 .prepare(query)?
-                .query_and_then([return_address], |record| record.try_into())?
+                .query_and_then([return_address, wallet_id], |record| record.try_into())?
                 .collect::<anyhow::Result<Vec<_>>>()
         })
         .await??;
@@ -0,0 +1,301 @@
+use std::{
+    fs::{self, File},
+    io::Write,
+};
+
+use anyhow::Context;
+use camino::{Utf8Path, Utf8PathBuf};
+use r2d2_sqlite::rusqlite::{Connection, OpenFlags};
+use rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+/// The environment variable a view database passphrase is read from, if set.
+pub const PASSPHRASE_ENV_VAR: &str = "PENUMBRA_VIEW_PASSPHRASE";
+
+/// How a view database is encrypted at rest, as recorded in client configs.
+///
+/// The database is encrypted page by page with SQLCipher, so that nothing in it, from the full
+/// viewing keys to the notes and transactions, is readable without the key.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum DatabaseEncryption {
+    /// The key is derived from a passphrase, read from [`PASSPHRASE_ENV_VAR`] or entered by the
+    /// user.
+    Passphrase,
+    /// The key is read from a file, which can be provided by the OS, e.g. as a systemd
+    /// credential or on a secrets mount.
+    KeyFile { path: Utf8PathBuf },
+}
+
+impl DatabaseEncryption {
+    /// Obtain the database key, calling `prompt` for the passphrase if it isn't set in the
+    /// environment.
+    pub fn key(
+        &self,
+        prompt: impl FnOnce() -> anyhow::Result<String>,
+    ) -> anyhow::Result<DatabaseKey> {
+        match self {
+            DatabaseEncryption::Passphrase => {
+                let passphrase = match std::env::var(PASSPHRASE_ENV_VAR) {
+                    Ok(passphrase) => passphrase,
+                    Err(_) => prompt()?,
+                };
+                DatabaseKey::passphrase(passphrase)
+            }
+            DatabaseEncryption::KeyFile { path } => DatabaseKey::read_key_file(path),
+        }
+    }
+}
+
+/// The key a view database is encrypted with.
+#[derive(Clone)]
+pub enum DatabaseKey {
+    /// A passphrase, which SQLCipher stretches into a key with PBKDF2.
+    Passphrase(String),
+    /// A raw 256-bit key.
+    Raw([u8; 32]),
+}
+
+impl std::fmt::Debug for DatabaseKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DatabaseKey::Passphrase(_) => f.write_str("DatabaseKey::Passphrase(..)"),
+            DatabaseKey::Raw(_) => f.write_str("DatabaseKey::Raw(..)"),
+        }
+    }
+}
+
+impl DatabaseKey {
+    pub fn passphrase(passphrase: String) -> anyhow::Result<Self> {
+        if passphrase.is_empty() {
+            anyhow::bail!("view database passphrase must not be empty");
+        }
+        Ok(DatabaseKey::Passphrase(passphrase))
+    }
+
+    /// Read a raw key from a file holding it in hex.
+    pub fn read_key_file(path: impl AsRef<Utf8Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("could not read view database key file {path}"))?;
+        let key = hex::decode(contents.trim())
+            .ok()
+            .and_then(|key| key.try_into().ok())
+            .with_context(|| format!("view database key file {path} must hold 32 bytes in hex"))?;
+        Ok(DatabaseKey::Raw(key))
+    }
+
+    /// Generate a random raw key and write it to a new file, readable only by its owner.
+    pub fn generate_key_file(path: impl AsRef<Utf8Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        let mut options = File::options();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options
+            .open(path)
+            .with_context(|| format!("could not create view database key file {path}"))?;
+        writeln!(file, "{}", hex::encode(key))?;
+
+        Ok(DatabaseKey::Raw(key))
+    }
+
+    /// The value of the SQLCipher `key` pragma for this key.
+    fn pragma_value(&self) -> String {
+        match self {
+            DatabaseKey::Passphrase(passphrase) => passphrase.clone(),
+            // SQLCipher uses a key written as a blob literal as is, without deriving it.
+            DatabaseKey::Raw(key) => format!("x'{}'", hex::encode(key)),
+        }
+    }
+
+    /// Key a new connection. This must be done before anything else is done with it.
+    pub(crate) fn apply(&self, conn: &Connection) -> r2d2_sqlite::rusqlite::Result<()> {
+        conn.pragma_update(None, "key", self.pragma_value())
+    }
+}
+
+/// Fail unless this build can encrypt view databases, with the `sqlcipher` feature.
+///
+/// Plain SQLite ignores the `key` pragma, so without this check a configured key would quietly
+/// leave the database unencrypted.
+pub(crate) fn ensure_supported() -> anyhow::Result<()> {
+    if !cfg!(feature = "sqlcipher") {
+        anyhow::bail!(
+            "view database encryption is not supported by this build; rebuild with the `sqlcipher` feature"
+        );
+    }
+    Ok(())
+}
+
+/// Rewrite the view database at `path`, encrypted with `from` if any, so that it is encrypted
+/// with `to`, or not encrypted if `to` is `None`.
+///
+/// The database is exported to a new file which then replaces the original, so it must not be
+/// in use.
+pub fn migrate_encryption(
+    path: impl AsRef<Utf8Path>,
+    from: Option<&DatabaseKey>,
+    to: Option<&DatabaseKey>,
+) -> anyhow::Result<()> {
+    ensure_supported()?;
+    let path = path.as_ref();
+    if !path.is_file() {
+        anyhow::bail!("no view database exists at {path}");
+    }
+    let migrated_path = Utf8PathBuf::from(format!("{path}.migrating"));
+    if migrated_path.exists() {
+        anyhow::bail!(
+            "{migrated_path} exists, maybe from an interrupted migration; remove it and try again"
+        );
+    }
+
+    let conn =
+        Connection::open_with_flags(path, OpenFlags::default() & !OpenFlags::SQLITE_OPEN_URI)?;
+    if let Some(from) = from {
+        from.apply(&conn)?;
+    }
+    // Check the key before exporting, so that a wrong one gets a clear error.
+    conn.query_row("SELECT count(*) FROM sqlite_master", (), |_| Ok(()))
+        .context("could not read the view database: is its key correct?")?;
+
+    // An empty key attaches the database without encryption.
+    let to_key = to.map(DatabaseKey::pragma_value).unwrap_or_default();
+    conn.execute(
+        "ATTACH DATABASE ?1 AS migrated KEY ?2",
+        (migrated_path.as_str(), to_key),
+    )?;
+    conn.query_row("SELECT sqlcipher_export('migrated')", (), |_| Ok(()))?;
+    conn.execute("DETACH DATABASE migrated", ())?;
+    // Fold the write-ahead log into the original database, so nothing is left next to it once
+    // it's replaced.
+    conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", (), |_| Ok(()))?;
+    conn.close().map_err(|(_, e)| e)?;
+
+    fs::rename(&migrated_path, path)?;
+    for suffix in ["-wal", "-shm"] {
+        let stale = Utf8PathBuf::from(format!("{path}{suffix}"));
+        if stale.exists() {
+            fs::remove_file(stale)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "sqlcipher"))]
+mod tests {
+    use super::*;
+
+    /// Create a database at `path` holding a single row, encrypted with `key` if given.
+    fn create(path: &Utf8Path, key: Option<&DatabaseKey>) {
+        let conn = Connection::open(path).unwrap();
+        if let Some(key) = key {
+            key.apply(&conn).unwrap();
+        }
+        conn.execute_batch("CREATE TABLE notes (text TEXT); INSERT INTO notes VALUES ('hello');")
+            .unwrap();
+    }
+
+    /// Read the row back from the database at `path`, opened with `key` if given.
+    fn read(path: &Utf8Path, key: Option<&DatabaseKey>) -> anyhow::Result<String> {
+        let conn = Connection::open(path)?;
+        if let Some(key) = key {
+            key.apply(&conn)?;
+        }
+        Ok(conn.query_row("SELECT text FROM notes", (), |row| row.get(0))?)
+    }
+
+    fn temp_path(dir: &tempfile::TempDir) -> Utf8PathBuf {
+        Utf8PathBuf::from_path_buf(dir.path().join("view.sqlite")).unwrap()
+    }
+
+    #[test]
+    fn encrypted_database_needs_its_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = temp_path(&dir);
+        let key = DatabaseKey::passphrase("correct horse".to_string()).unwrap();
+        create(&path, Some(&key));
+
+        assert_eq!(read(&path, Some(&key)).unwrap(), "hello");
+        assert!(read(&path, None).is_err());
+        let wrong = DatabaseKey::passphrase("battery staple".to_string()).unwrap();
+        assert!(read(&path, Some(&wrong)).is_err());
+        // Nothing in the file is readable without the key.
+        let contents = fs::read(&path).unwrap();
+        assert!(!contents.windows(5).any(|window| window == b"hello"));
+    }
+
+    #[test]
+    fn storage_connections_are_keyed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = temp_path(&dir);
+        let key = DatabaseKey::Raw([1; 32]);
+        create(&path, Some(&key));
+
+        let pool = crate::Storage::connect(Some(&path), Some(key)).unwrap();
+        let text: String = pool
+            .get()
+            .unwrap()
+            .query_row("SELECT text FROM notes", (), |row| row.get(0))
+            .unwrap();
+        assert_eq!(text, "hello");
+    }
+
+    #[test]
+    fn migration_encrypts_rekeys_and_decrypts() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = temp_path(&dir);
+        create(&path, None);
+
+        let first = DatabaseKey::passphrase("first".to_string()).unwrap();
+        migrate_encryption(&path, None, Some(&first)).unwrap();
+        assert!(read(&path, None).is_err());
+        assert_eq!(read(&path, Some(&first)).unwrap(), "hello");
+
+        let second =
+            DatabaseKey::generate_key_file(dir.path().join("key").to_str().unwrap()).unwrap();
+        migrate_encryption(&path, Some(&first), Some(&second)).unwrap();
+        assert!(read(&path, Some(&first)).is_err());
+        assert_eq!(read(&path, Some(&second)).unwrap(), "hello");
+
+        migrate_encryption(&path, Some(&second), None).unwrap();
+        assert_eq!(read(&path, None).unwrap(), "hello");
+        assert!(!Utf8PathBuf::from(format!("{path}.migrating")).exists());
+    }
+
+    #[test]
+    fn migration_with_the_wrong_key_leaves_the_database_alone() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = temp_path(&dir);
+        let key = DatabaseKey::passphrase("right".to_string()).unwrap();
+        create(&path, Some(&key));
+
+        let wrong = DatabaseKey::passphrase("wrong".to_string()).unwrap();
+        assert!(migrate_encryption(&path, Some(&wrong), None).is_err());
+        assert!(migrate_encryption(&path, None, None).is_err());
+        assert_eq!(read(&path, Some(&key)).unwrap(), "hello");
+        assert!(!Utf8PathBuf::from(format!("{path}.migrating")).exists());
+    }
+
+    #[test]
+    fn key_files_hold_a_hex_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = Utf8PathBuf::from_path_buf(dir.path().join("key")).unwrap();
+        let DatabaseKey::Raw(generated) = DatabaseKey::generate_key_file(&path).unwrap() else {
+            panic!("generated keys are raw");
+        };
+        let DatabaseKey::Raw(read) = DatabaseKey::read_key_file(&path).unwrap() else {
+            panic!("key files hold raw keys");
+        };
+        assert_eq!(generated, read);
+        // An existing key file is never overwritten.
+        assert!(DatabaseKey::generate_key_file(&path).is_err());
+
+        fs::write(&path, "not a key").unwrap();
+        assert!(DatabaseKey::read_key_file(&path).is_err());
+    }
+}
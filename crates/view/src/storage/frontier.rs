@@ -0,0 +1,285 @@
+use anyhow::Context as _;
+use ibc_types::core::commitment::{MerklePath, MerkleProof, MerkleRoot};
+use penumbra_chain::params::FmdParameters;
+use penumbra_fee::GasPrices;
+use penumbra_ibc::IBC_PROOF_SPECS;
+use penumbra_proto::{
+    core::component::sct::v1alpha1::{
+        query_service_client::QueryServiceClient as SctQueryServiceClient, SctFrontierRequest,
+        SctFrontierResponse,
+    },
+    util::tendermint_proxy::v1alpha1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, AbciQueryRequest,
+        GetBlockByHeightRequest,
+    },
+    DomainType,
+};
+use penumbra_sct::state_key as sct_state_key;
+use penumbra_tct as tct;
+use prost::Message as _;
+use tct::storage::{InMemory, Updates};
+use url::Url;
+
+/// A snapshot of the frontier of the state commitment tree, from which a new view database can
+/// start synchronizing instead of scanning every block since genesis.
+pub struct SctFrontier {
+    /// The height of the last block included in the tree.
+    pub height: u64,
+    /// The tree, with every commitment forgotten.
+    pub tree: tct::Tree,
+    /// The gas prices in effect at `height`, which would otherwise only be learned from blocks
+    /// before the frontier.
+    pub gas_prices: GasPrices,
+    /// The FMD parameters in effect at `height`, which would otherwise only be learned from the
+    /// genesis block.
+    pub fmd_parameters: FmdParameters,
+}
+
+impl SctFrontier {
+    /// Fetch the frontier served by `node` for a wallet created at `birthday`.
+    ///
+    /// The node serves the frontier recorded at the last epoch boundary before `birthday`, and its
+    /// root is checked against the anchor the chain recorded at that height, which must be proven
+    /// against the app hash committed in a block header. Returns `None` if no epoch has ended
+    /// before `birthday`, in which case synchronization must start from genesis.
+    pub async fn fetch(node: Url, chain_id: String, birthday: u64) -> anyhow::Result<Option<Self>> {
+        let mut client = SctQueryServiceClient::connect(node.to_string()).await?;
+        let response = match client
+            .sct_frontier(SctFrontierRequest {
+                chain_id,
+                height: birthday,
+            })
+            .await
+        {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == tonic::Code::NotFound => {
+                tracing::debug!(?birthday, "no sct frontier available before birthday");
+                return Ok(None);
+            }
+            Err(status) => return Err(status.into()),
+        };
+
+        let frontier = Self::from_response(response, birthday)?;
+        if let Some(frontier) = &frontier {
+            frontier.check_anchor(fetch_anchor(&node, frontier.height).await?)?;
+            tracing::info!(height = ?frontier.height, root = %frontier.tree.root(), "fetched sct frontier");
+        }
+        Ok(frontier)
+    }
+
+    /// Check that the root of the frontier is the anchor the chain recorded at its height.
+    fn check_anchor(&self, anchor: tct::Root) -> anyhow::Result<()> {
+        if self.tree.root() != anchor {
+            anyhow::bail!(
+                "sct frontier at height {} has root {}, but the chain recorded anchor {anchor}",
+                self.height,
+                self.tree.root()
+            );
+        }
+        Ok(())
+    }
+
+    /// Parse the frontier served for a wallet created at `birthday`.
+    ///
+    /// Returns `None` if the frontier was recorded without the chain state needed to start from
+    /// it, by a node predating its inclusion.
+    fn from_response(response: SctFrontierResponse, birthday: u64) -> anyhow::Result<Option<Self>> {
+        let height = response.height;
+        if height >= birthday {
+            anyhow::bail!(
+                "node served an sct frontier at height {height}, which is not before the birthday height {birthday}"
+            );
+        }
+
+        let (Some(gas_prices), Some(fmd_parameters)) =
+            (response.gas_prices, response.fmd_parameters)
+        else {
+            tracing::warn!(
+                ?height,
+                "sct frontier is missing gas prices or fmd parameters, synchronizing from genesis"
+            );
+            return Ok(None);
+        };
+
+        let updates: Updates = bincode::deserialize(&response.frontier)
+            .context("could not deserialize sct frontier")?;
+        let mut store = InMemory::new();
+        for update in updates {
+            update.apply(&mut store)?;
+        }
+        let tree = tct::Tree::from_reader(&mut store)?;
+
+        Ok(Some(Self {
+            height,
+            tree,
+            gas_prices: gas_prices.try_into()?,
+            fmd_parameters: fmd_parameters.try_into()?,
+        }))
+    }
+}
+
+/// Fetch the anchor the chain recorded at `height`, proven against the app hash of the latest
+/// state the node has committed to in a block header.
+async fn fetch_anchor(node: &Url, height: u64) -> anyhow::Result<tct::Root> {
+    let mut client = TendermintProxyServiceClient::connect(node.to_string()).await?;
+    let key = sct_state_key::anchor_by_height(height);
+    let response = client
+        .abci_query(AbciQueryRequest {
+            data: key.clone().into_bytes(),
+            path: "state/key".to_string(),
+            // Query the latest state, since the node may not keep the state at `height` itself.
+            height: 0,
+            prove: true,
+        })
+        .await?
+        .into_inner();
+    if response.value.is_empty() {
+        anyhow::bail!("chain recorded no anchor at height {height}");
+    }
+
+    let proof = MerkleProof {
+        proofs: response
+            .proof_ops
+            .with_context(|| format!("node did not prove the anchor at height {height}"))?
+            .ops
+            .into_iter()
+            .map(|op| ics23::CommitmentProof::decode(op.data.as_slice()))
+            .collect::<Result<_, _>>()?,
+    };
+
+    // The state after the queried block is committed to as the app hash of the next block.
+    let header = client
+        .get_block_by_height(GetBlockByHeightRequest {
+            height: response.height + 1,
+        })
+        .await?
+        .into_inner()
+        .block
+        .and_then(|block| block.header)
+        .with_context(|| format!("no header for block {}", response.height + 1))?;
+
+    // Anchors are kept in the main store, so they are proven by a single proof.
+    proof
+        .verify_membership(
+            &IBC_PROOF_SPECS[..1],
+            MerkleRoot {
+                hash: header.app_hash,
+            },
+            MerklePath {
+                key_path: vec![key],
+            },
+            response.value.clone(),
+            0,
+        )
+        .with_context(|| format!("could not verify the anchor at height {height}"))?;
+
+    tct::Root::decode(response.value.as_slice())
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_tct::{storage::StoredPosition, Forgotten, StateCommitment, Witness};
+
+    use super::*;
+
+    fn response(height: u64, tree: &tct::Tree) -> SctFrontierResponse {
+        let updates = tree
+            .updates(StoredPosition::default(), Forgotten::default())
+            .collect::<Updates>();
+        SctFrontierResponse {
+            height,
+            frontier: bincode::serialize(&updates).unwrap(),
+            gas_prices: Some(
+                GasPrices {
+                    block_space_price: 1,
+                    compact_block_space_price: 2,
+                    verification_price: 3,
+                    execution_price: 4,
+                }
+                .into(),
+            ),
+            fmd_parameters: Some(
+                FmdParameters {
+                    precision_bits: 4,
+                    as_of_block_height: 7,
+                }
+                .into(),
+            ),
+        }
+    }
+
+    fn tree() -> tct::Tree {
+        let mut tree = tct::Tree::new();
+        for i in 0..3u64 {
+            tree.insert(
+                Witness::Forget,
+                StateCommitment::try_from([i as u8; 32]).unwrap(),
+            )
+            .unwrap();
+            tree.end_block().unwrap();
+        }
+        tree.end_epoch().unwrap();
+        tree
+    }
+
+    #[test]
+    fn parses_frontier() {
+        let tree = tree();
+        let frontier = SctFrontier::from_response(response(10, &tree), 20)
+            .unwrap()
+            .expect("frontier is complete");
+
+        assert_eq!(frontier.height, 10);
+        assert_eq!(frontier.tree.root(), tree.root());
+        assert_eq!(frontier.tree.position(), tree.position());
+        assert_eq!(frontier.gas_prices.execution_price, 4);
+        assert_eq!(frontier.fmd_parameters.precision_bits, 4);
+        assert_eq!(frontier.fmd_parameters.as_of_block_height, 7);
+    }
+
+    #[test]
+    fn rejects_frontier_after_birthday() {
+        let tree = tree();
+        assert!(SctFrontier::from_response(response(20, &tree), 20).is_err());
+        assert!(SctFrontier::from_response(response(21, &tree), 20).is_err());
+    }
+
+    #[test]
+    fn incomplete_frontier_falls_back_to_genesis() {
+        let tree = tree();
+
+        let mut missing_gas_prices = response(10, &tree);
+        missing_gas_prices.gas_prices = None;
+        assert!(SctFrontier::from_response(missing_gas_prices, 20)
+            .unwrap()
+            .is_none());
+
+        let mut missing_fmd_parameters = response(10, &tree);
+        missing_fmd_parameters.fmd_parameters = None;
+        assert!(SctFrontier::from_response(missing_fmd_parameters, 20)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn rejects_frontier_with_mismatched_anchor() {
+        let tree = tree();
+        let frontier = SctFrontier::from_response(response(10, &tree), 20)
+            .unwrap()
+            .expect("frontier is complete");
+        assert!(frontier.check_anchor(tree.root()).is_ok());
+
+        let mut other = tree.clone();
+        other
+            .insert(Witness::Forget, StateCommitment::try_from([9; 32]).unwrap())
+            .unwrap();
+        assert!(frontier.check_anchor(other.root()).is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_frontier() {
+        let mut malformed = response(10, &tree());
+        malformed.frontier = vec![0xff; 3];
+        assert!(SctFrontier::from_response(malformed, 20).is_err());
+    }
+}
@@ -0,0 +1,175 @@
+//! Migrations of view databases created with older schemata to the current one.
+
+use anyhow::Context;
+use once_cell::sync::Lazy;
+use penumbra_keys::FullViewingKey;
+use penumbra_proto::DomainType;
+use r2d2_sqlite::rusqlite::Connection;
+use sha2::{Digest, Sha256};
+
+use super::SCHEMA_HASH;
+
+/// The hash of the schema used before a view database could track several wallets.
+static SINGLE_WALLET_SCHEMA_HASH: Lazy<String> = Lazy::new(|| {
+    hex::encode(Sha256::digest(include_str!(
+        "migrations/single_wallet_schema.sql"
+    )))
+});
+
+/// Migrates a database with the schema with the given hash to the current schema, in place.
+///
+/// Returns `false`, leaving the database untouched, if there is no migration from its schema, in
+/// which case it must be reset and resynchronized instead.
+pub(super) fn migrate(conn: &mut Connection, schema_hash: &str) -> anyhow::Result<bool> {
+    if schema_hash != *SINGLE_WALLET_SCHEMA_HASH {
+        return Ok(false);
+    }
+
+    let tx = conn.transaction()?;
+
+    let fvk_bytes: Vec<u8> = tx
+        .query_row("SELECT bytes FROM full_viewing_key", (), |row| {
+            row.get("bytes")
+        })
+        .context("failed to read the full viewing key of a single-wallet view database")?;
+    let wallet_id = FullViewingKey::decode(fvk_bytes.as_slice())?.wallet_id();
+
+    tx.execute(
+        "CREATE TEMPORARY TABLE migration_wallet AS SELECT ?1 AS wallet_id",
+        [wallet_id.0.to_vec()],
+    )?;
+    tx.execute_batch(include_str!("migrations/single_wallet_to_multi_wallet.sql"))
+        .context("failed to migrate single-wallet view database")?;
+    tx.execute("DROP TABLE temp.migration_wallet", ())?;
+
+    tx.execute("UPDATE schema_hash SET schema_hash = ?1", [&*SCHEMA_HASH])?;
+    tx.execute(
+        "UPDATE client_version SET client_version = ?1",
+        [env!("CARGO_PKG_VERSION")],
+    )?;
+
+    tx.commit()?;
+
+    tracing::info!(%wallet_id, "migrated single-wallet view database");
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use penumbra_keys::test_keys;
+
+    use super::*;
+
+    /// Returns the definitions of every table and index in the database.
+    fn schema(conn: &Connection) -> BTreeSet<(String, String)> {
+        conn.prepare("SELECT name, sql FROM sqlite_master WHERE sql IS NOT NULL")
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    fn single_wallet_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(include_str!("migrations/single_wallet_schema.sql"))
+            .unwrap();
+        conn.execute(
+            "INSERT INTO schema_hash (schema_hash) VALUES (?1)",
+            [&*SINGLE_WALLET_SCHEMA_HASH],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO client_version (client_version) VALUES ('0.0.0')",
+            (),
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO full_viewing_key (bytes) VALUES (?1)",
+            [test_keys::FULL_VIEWING_KEY.encode_to_vec()],
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn migrates_single_wallet_database() {
+        let mut conn = single_wallet_db();
+        conn.execute(
+            "INSERT INTO tx (tx_hash, tx_bytes, block_height) VALUES (X'01', X'02', 3)",
+            (),
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO spendable_notes
+                (note_commitment, nullifier, position, height_created, address_index, source)
+                VALUES (X'0a', X'0b', 1, 3, X'00', X'01')",
+            (),
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO swaps
+                (swap_commitment, swap, position, nullifier, output_data, source)
+                VALUES (X'1a', X'1b', 2, X'1c', X'1d', X'01')",
+            (),
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO positions (position_id, position_state, trading_pair)
+                VALUES (X'2a', 'opened', 'pair')",
+            (),
+        )
+        .unwrap();
+
+        assert!(migrate(&mut conn, &SINGLE_WALLET_SCHEMA_HASH).unwrap());
+
+        // The migrated database has exactly the current schema.
+        let fresh = Connection::open_in_memory().unwrap();
+        fresh.execute_batch(include_str!("schema.sql")).unwrap();
+        assert_eq!(schema(&conn), schema(&fresh));
+
+        let schema_hash: String = conn
+            .query_row("SELECT schema_hash FROM schema_hash", (), |row| row.get(0))
+            .unwrap();
+        assert_eq!(schema_hash, *SCHEMA_HASH);
+
+        // Everything the database knew about belongs to its wallet.
+        let wallet_id = test_keys::FULL_VIEWING_KEY.wallet_id().0.to_vec();
+        let fvk_wallet: Vec<u8> = conn
+            .query_row("SELECT wallet_id FROM full_viewing_keys", (), |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(fvk_wallet, wallet_id);
+        for table in ["wallet_tx", "spendable_notes", "swaps", "positions"] {
+            let wallets: Vec<Vec<u8>> = conn
+                .prepare(&format!("SELECT wallet_id FROM {table}"))
+                .unwrap()
+                .query_map([], |row| row.get(0))
+                .unwrap()
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert_eq!(wallets, vec![wallet_id.clone()], "wallets in {table}");
+        }
+        let height_created: i64 = conn
+            .query_row(
+                "SELECT height_created FROM spendable_notes WHERE note_commitment = X'0a'",
+                (),
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(height_created, 3);
+    }
+
+    #[test]
+    fn unknown_schemata_are_not_migrated() {
+        let mut conn = single_wallet_db();
+        let before = schema(&conn);
+
+        assert!(!migrate(&mut conn, "not a known schema hash").unwrap());
+        assert_eq!(schema(&conn), before);
+    }
+}
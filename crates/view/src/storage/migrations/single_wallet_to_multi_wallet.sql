@@ -0,0 +1,136 @@
+-- Migrates a view database tracking a single wallet to the schema that can track several,
+-- attributing everything it knows about to its wallet, whose ID is the only row of the
+-- temporary table `migration_wallet`.
+
+-- the full viewing keys of the wallets tracked by this database
+CREATE TABLE full_viewing_keys (
+    wallet_id               BLOB PRIMARY KEY NOT NULL,
+    bytes                   BLOB NOT NULL
+);
+INSERT INTO full_viewing_keys (wallet_id, bytes)
+    SELECT (SELECT wallet_id FROM migration_wallet), bytes FROM full_viewing_key;
+DROP TABLE full_viewing_key;
+
+-- the wallets to which each known transaction is relevant
+CREATE TABLE wallet_tx (
+    wallet_id               BLOB NOT NULL,
+    tx_hash                 BLOB NOT NULL,
+    PRIMARY KEY (wallet_id, tx_hash)
+);
+INSERT INTO wallet_tx (wallet_id, tx_hash)
+    SELECT (SELECT wallet_id FROM migration_wallet), tx_hash FROM tx;
+
+DROP INDEX spendable_notes_by_nullifier_idx;
+DROP INDEX spendable_notes_by_source_idx;
+DROP INDEX spendable_notes_idx;
+ALTER TABLE spendable_notes RENAME TO legacy_spendable_notes;
+
+-- Minimal data required for balance tracking
+-- Meant to represent notes which have been accepted into the note set
+CREATE TABLE spendable_notes (
+    note_commitment         BLOB PRIMARY KEY NOT NULL,
+    -- the wallet whose full viewing key detected the note
+    wallet_id               BLOB NOT NULL,
+    -- the nullifier for this note, used to detect when it is spent
+    nullifier               BLOB NOT NULL,
+    -- the position of the note in the state commitment tree
+    position                BIGINT NOT NULL,
+    -- the height at which the note was created
+    height_created          BIGINT NOT NULL,
+    -- precomputed decryption of the diversifier
+    address_index           BLOB NOT NULL,
+    -- the source of the note (a tx hash or structured data jammed into one)
+    source                  BLOB NOT NULL,
+    -- null if unspent, otherwise spent at height_spent
+    height_spent            BIGINT
+);
+INSERT INTO spendable_notes (
+    note_commitment,
+    wallet_id,
+    nullifier,
+    position,
+    height_created,
+    address_index,
+    source,
+    height_spent
+)
+    SELECT
+        note_commitment,
+        (SELECT wallet_id FROM migration_wallet),
+        nullifier,
+        position,
+        height_created,
+        address_index,
+        source,
+        height_spent
+    FROM legacy_spendable_notes;
+DROP TABLE legacy_spendable_notes;
+
+CREATE INDEX spendable_notes_by_nullifier_idx ON spendable_notes (
+    nullifier
+);
+
+CREATE INDEX spendable_notes_by_source_idx ON spendable_notes (
+    source
+);
+
+-- general purpose note queries
+CREATE INDEX spendable_notes_idx ON spendable_notes (
+    wallet_id,
+    address_index,
+    height_created,
+    height_spent       -- null if unspent, so spent/unspent is first
+);
+
+DROP INDEX swaps_nullifier_idx;
+ALTER TABLE swaps RENAME TO legacy_swaps;
+
+-- This table records the mapping from swap commitments to swap plaintexts.
+-- For now we just store the swap plaintexts as a blob.
+CREATE TABLE swaps (
+    swap_commitment         BLOB PRIMARY KEY NOT NULL,
+    wallet_id               BLOB NOT NULL,
+    swap                    BLOB NOT NULL,
+    position                BIGINT NOT NULL,
+    nullifier               BLOB NOT NULL,
+    output_data             BLOB NOT NULL,
+    height_claimed          BIGINT,
+    source                  BLOB NOT NULL
+);
+INSERT INTO swaps (
+    swap_commitment,
+    wallet_id,
+    swap,
+    position,
+    nullifier,
+    output_data,
+    height_claimed,
+    source
+)
+    SELECT
+        swap_commitment,
+        (SELECT wallet_id FROM migration_wallet),
+        swap,
+        position,
+        nullifier,
+        output_data,
+        height_claimed,
+        source
+    FROM legacy_swaps;
+DROP TABLE legacy_swaps;
+
+CREATE INDEX swaps_nullifier_idx ON swaps (nullifier);
+
+ALTER TABLE positions RENAME TO legacy_positions;
+
+CREATE TABLE positions (
+     wallet_id              BLOB NOT NULL,
+     position_id            BLOB NOT NULL,
+     position_state         TEXT NOT NULL,
+     trading_pair           TEXT NOT NULL,
+     PRIMARY KEY (wallet_id, position_id)
+);
+INSERT INTO positions (wallet_id, position_id, position_state, trading_pair)
+    SELECT (SELECT wallet_id FROM migration_wallet), position_id, position_state, trading_pair
+    FROM legacy_positions;
+DROP TABLE legacy_positions;
@@ -13,10 +13,15 @@ CREATE TABLE community_pool_params (bytes BLOB NOT NULL);
 CREATE TABLE fee_params (bytes BLOB NOT NULL);
 CREATE TABLE distributions_params (bytes BLOB NOT NULL);
 CREATE TABLE fmd_parameters (bytes BLOB NOT NULL);
-CREATE TABLE full_viewing_key (bytes BLOB NOT NULL);
 CREATE TABLE sync_height (height BIGINT NOT NULL);
 CREATE TABLE gas_prices (bytes BLOB NOT NULL);
 
+-- the full viewing keys of the wallets tracked by this database
+CREATE TABLE full_viewing_keys (
+    wallet_id               BLOB PRIMARY KEY NOT NULL,
+    bytes                   BLOB NOT NULL
+);
+
 -- used for storing a cache of known assets
 CREATE TABLE assets (
     asset_id                BLOB PRIMARY KEY NOT NULL,
@@ -61,6 +66,13 @@ CREATE TABLE tx (
     return_address          BLOB
 );
 
+-- the wallets to which each known transaction is relevant
+CREATE TABLE wallet_tx (
+    wallet_id               BLOB NOT NULL,
+    tx_hash                 BLOB NOT NULL,
+    PRIMARY KEY (wallet_id, tx_hash)
+);
+
 -- This table just records the mapping from note commitments to note plaintexts.
 -- This is also used as a way to give advice about out-of-band notes during scanning,
 -- by allowing the user to add notes to the database before they are scanned.
@@ -83,6 +95,8 @@ CREATE INDEX notes_idx ON notes (
 -- Meant to represent notes which have been accepted into the note set
 CREATE TABLE spendable_notes (
     note_commitment         BLOB PRIMARY KEY NOT NULL,
+    -- the wallet whose full viewing key detected the note
+    wallet_id               BLOB NOT NULL,
     -- the nullifier for this note, used to detect when it is spent
     nullifier               BLOB NOT NULL,
     -- the position of the note in the state commitment tree
@@ -107,6 +121,7 @@ CREATE INDEX spendable_notes_by_source_idx ON spendable_notes (
 
 -- general purpose note queries
 CREATE INDEX spendable_notes_idx ON spendable_notes (
+    wallet_id,
     address_index,
     height_created,
     height_spent       -- null if unspent, so spent/unspent is first
@@ -116,6 +131,7 @@ CREATE INDEX spendable_notes_idx ON spendable_notes (
 -- For now we just store the swap plaintexts as a blob.
 CREATE TABLE swaps (
     swap_commitment         BLOB PRIMARY KEY NOT NULL,
+    wallet_id               BLOB NOT NULL,
     swap                    BLOB NOT NULL,
     position                BIGINT NOT NULL,
     nullifier               BLOB NOT NULL,
@@ -127,7 +143,9 @@ CREATE TABLE swaps (
 CREATE INDEX swaps_nullifier_idx ON swaps (nullifier);
 
 CREATE TABLE positions (
-     position_id            BLOB PRIMARY KEY NOT NULL,
+     wallet_id              BLOB NOT NULL,
+     position_id            BLOB NOT NULL,
      position_state         TEXT NOT NULL,
-     trading_pair           TEXT NOT NULL
+     trading_pair           TEXT NOT NULL,
+     PRIMARY KEY (wallet_id, position_id)
 );
@@ -1,10 +1,10 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, sync::Arc};
 
 use penumbra_chain::params::FmdParameters;
 use penumbra_compact_block::{CompactBlock, StatePayload};
 use penumbra_dex::swap::{SwapPayload, SwapPlaintext};
 use penumbra_fee::GasPrices;
-use penumbra_keys::FullViewingKey;
+use penumbra_keys::{keys::WalletId, FullViewingKey};
 use penumbra_sct::Nullifier;
 use penumbra_shielded_pool::{Note, NotePayload};
 use penumbra_tct::{self as tct, StateCommitment};
@@ -17,6 +17,8 @@ use crate::{SpendableNoteRecord, Storage, SwapRecord};
 pub struct FilteredBlock {
     pub new_notes: BTreeMap<StateCommitment, SpendableNoteRecord>,
     pub new_swaps: BTreeMap<StateCommitment, SwapRecord>,
+    /// The wallet each of the new notes and swaps belongs to.
+    pub wallets: BTreeMap<StateCommitment, WalletId>,
     pub spent_nullifiers: Vec<Nullifier>,
     pub height: u64,
     pub fmd_parameters: Option<FmdParameters>,
@@ -26,7 +28,7 @@ pub struct FilteredBlock {
 
 #[tracing::instrument(skip_all, fields(height = %height))]
 pub async fn scan_block(
-    fvk: &FullViewingKey,
+    fvks: &Arc<[FullViewingKey]>,
     state_commitment_tree: &mut tct::Tree,
     CompactBlock {
         height,
@@ -44,24 +46,32 @@ pub async fn scan_block(
     }: CompactBlock,
     storage: &Storage,
 ) -> anyhow::Result<FilteredBlock> {
-    // Trial-decrypt a note with our own specific viewing key
-    let trial_decrypt_note = |note_payload: NotePayload| -> tokio::task::JoinHandle<Option<Note>> {
-        // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
-        // need this so the task is 'static and not dependent on key lifetime
-        let fvk2 = fvk.clone();
-        tokio::spawn(
-            async move { note_payload.trial_decrypt(&fvk2) }.instrument(tracing::Span::current()),
-        )
-    };
-    // Trial-decrypt a swap with our own specific viewing key
+    // Trial-decrypt a note with each of our viewing keys, returning the index of the key that
+    // decrypted it
+    let trial_decrypt_note =
+        |note_payload: NotePayload| -> tokio::task::JoinHandle<Option<(usize, Note)>> {
+            let fvks = fvks.clone();
+            tokio::spawn(
+                async move {
+                    fvks.iter().enumerate().find_map(|(index, fvk)| {
+                        note_payload.trial_decrypt(fvk).map(|note| (index, note))
+                    })
+                }
+                .instrument(tracing::Span::current()),
+            )
+        };
+    // Trial-decrypt a swap with each of our viewing keys, returning the index of the key that
+    // decrypted it
     let trial_decrypt_swap =
-        |swap_payload: SwapPayload| -> tokio::task::JoinHandle<Option<SwapPlaintext>> {
-            // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
-            // need this so the task is 'static and not dependent on key lifetime
-            let fvk2 = fvk.clone();
+        |swap_payload: SwapPayload| -> tokio::task::JoinHandle<Option<(usize, SwapPlaintext)>> {
+            let fvks = fvks.clone();
             tokio::spawn(
-                async move { swap_payload.trial_decrypt(&fvk2) }
-                    .instrument(tracing::Span::current()),
+                async move {
+                    fvks.iter().enumerate().find_map(|(index, fvk)| {
+                        swap_payload.trial_decrypt(fvk).map(|swap| (index, swap))
+                    })
+                }
+                .instrument(tracing::Span::current()),
             )
         };
 
@@ -84,23 +94,32 @@ pub async fn scan_block(
             StatePayload::RolledUp { commitment, .. } => unknown_commitments.push(*commitment),
         }
     }
-    // Having started trial decryption in the background, ask the Storage for scanning advice:
-    let mut note_advice = storage.scan_advice(unknown_commitments).await?;
+    // Having started trial decryption in the background, ask the Storage for scanning advice,
+    // attributing each advised note to the wallet whose viewing key controls its address:
+    let mut note_advice = BTreeMap::new();
+    for (commitment, note) in storage.scan_advice(unknown_commitments).await? {
+        if let Some(index) = fvks
+            .iter()
+            .position(|fvk| fvk.address_index(&note.address()).is_some())
+        {
+            note_advice.insert(commitment, (index, note));
+        }
+    }
     for decryption in note_decryptions {
-        if let Some(note) = decryption
+        if let Some((index, note)) = decryption
             .await
             .expect("able to join tokio note decryption handle")
         {
-            note_advice.insert(note.commit(), note);
+            note_advice.insert(note.commit(), (index, note));
         }
     }
     let mut swap_advice = BTreeMap::new();
     for decryption in swap_decryptions {
-        if let Some(swap) = decryption
+        if let Some((index, swap)) = decryption
             .await
             .expect("able to join tokio swap decryption handle")
         {
-            swap_advice.insert(swap.swap_commitment(), swap);
+            swap_advice.insert(swap.swap_commitment(), (index, swap));
         }
     }
 
@@ -108,6 +127,8 @@ pub async fn scan_block(
     let mut new_notes = BTreeMap::new();
     // Newly detected claimable swaps.
     let mut new_swaps = BTreeMap::new();
+    // The wallets owning the newly detected notes and swaps.
+    let mut wallets = BTreeMap::new();
 
     if note_advice.is_empty() && swap_advice.is_empty() {
         // If there are no notes we care about in this block, just insert the block root into the
@@ -127,7 +148,9 @@ pub async fn scan_block(
                 note_advice.get(payload.commitment()),
                 swap_advice.get(payload.commitment()),
             ) {
-                (Some(note), None) => {
+                (Some((index, note)), None) => {
+                    let fvk = &fvks[*index];
+
                     // Keep track of this commitment for later witnessing
                     let position = state_commitment_tree
                         .insert(tct::Witness::Keep, *payload.commitment())
@@ -151,8 +174,11 @@ pub async fn scan_block(
                             source,
                         },
                     );
+                    wallets.insert(*payload.commitment(), fvk.wallet_id());
                 }
-                (None, Some(swap)) => {
+                (None, Some((index, swap))) => {
+                    let fvk = &fvks[*index];
+
                     // Keep track of this commitment for later witnessing
                     let position = state_commitment_tree
                         .insert(tct::Witness::Keep, *payload.commitment())
@@ -191,6 +217,7 @@ pub async fn scan_block(
                             height_claimed: None,
                         },
                     );
+                    wallets.insert(*payload.commitment(), fvk.wallet_id());
                 }
                 (None, None) => {
                     // Don't remember this commitment; it wasn't ours
@@ -230,6 +257,7 @@ pub async fn scan_block(
     let result = FilteredBlock {
         new_notes,
         new_swaps,
+        wallets,
         spent_nullifiers: filtered_nullifiers,
         height,
         fmd_parameters,
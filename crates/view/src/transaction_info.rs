@@ -1,3 +1,4 @@
+use penumbra_proto::view::v1alpha1 as pb;
 use penumbra_transaction::{
     txhash::TransactionId, Transaction, TransactionPerspective, TransactionView,
 };
@@ -15,3 +16,29 @@ pub struct TransactionInfo {
     // A precomputed transaction view of `transaction` from `perspective`, included for convenience of clients that don't have support for viewing transactions on their own.
     pub view: TransactionView,
 }
+
+impl TryFrom<pb::TransactionInfo> for TransactionInfo {
+    type Error = anyhow::Error;
+
+    fn try_from(v: pb::TransactionInfo) -> Result<Self, Self::Error> {
+        Ok(TransactionInfo {
+            height: v.height,
+            id: v
+                .id
+                .ok_or_else(|| anyhow::anyhow!("missing id"))?
+                .try_into()?,
+            transaction: v
+                .transaction
+                .ok_or_else(|| anyhow::anyhow!("missing transaction"))?
+                .try_into()?,
+            perspective: v
+                .perspective
+                .ok_or_else(|| anyhow::anyhow!("missing perspective"))?
+                .try_into()?,
+            view: v
+                .view
+                .ok_or_else(|| anyhow::anyhow!("missing view"))?
+                .try_into()?,
+        })
+    }
+}
@@ -7,7 +7,7 @@ use std::{
 use anyhow::Context;
 use penumbra_compact_block::CompactBlock;
 use penumbra_dex::lp::{position, LpNft};
-use penumbra_keys::FullViewingKey;
+use penumbra_keys::{keys::WalletId, FullViewingKey};
 use penumbra_proto::{
     self as proto,
     core::{
@@ -27,24 +27,27 @@ use penumbra_proto::{
 use penumbra_sct::{CommitmentSource, Nullifier};
 use penumbra_transaction::Transaction;
 use proto::core::app::v1alpha1::TransactionsByHeightRequest;
+use rand_core::OsRng;
 use tokio::sync::{watch, RwLock};
 use tonic::transport::Channel;
 use url::Url;
 
 use crate::{
+    metrics,
     sync::{scan_block, FilteredBlock},
-    Storage,
+    ChaffPolicy, Notification, NotificationEvent, Storage,
 };
 
 pub struct Worker {
     storage: Storage,
     sct: Arc<RwLock<penumbra_tct::Tree>>,
-    fvk: FullViewingKey, // TODO: notifications (see TODOs on ViewService)
+    fvks: Arc<[FullViewingKey]>,
     error_slot: Arc<Mutex<Option<anyhow::Error>>>,
     sync_height_tx: watch::Sender<u64>,
     /// Tonic channel used to create GRPC clients.
     channel: Channel,
     node: Url,
+    chaff: ChaffPolicy,
 }
 
 impl Worker {
@@ -57,6 +60,7 @@ impl Worker {
     pub async fn new(
         storage: Storage,
         node: Url,
+        chaff: ChaffPolicy,
     ) -> Result<
         (
             Self,
@@ -66,7 +70,7 @@ impl Worker {
         ),
         anyhow::Error,
     > {
-        let fvk = storage.full_viewing_key().await?;
+        let fvks = storage.full_viewing_keys().await?.into();
 
         // Create a shared, in-memory SCT.
         let sct = Arc::new(RwLock::new(storage.state_commitment_tree().await?));
@@ -84,15 +88,18 @@ impl Worker {
             .await
             .with_context(|| "could not connect to grpc server")?;
 
+        metrics::gauge!(metrics::VIEW_CHAFF_RATE, chaff.rate());
+
         Ok((
             Self {
                 storage,
                 sct: sct.clone(),
-                fvk,
+                fvks,
                 error_slot: error_slot.clone(),
                 sync_height_tx,
                 channel,
                 node,
+                chaff,
             },
             sct,
             error_slot,
@@ -100,10 +107,12 @@ impl Worker {
         ))
     }
 
+    /// Fetches the transactions relevant to the scanned block, along with the wallets each of
+    /// them is relevant to.
     pub async fn fetch_transactions(
         &self,
         filtered_block: &mut FilteredBlock,
-    ) -> anyhow::Result<Vec<Transaction>> {
+    ) -> anyhow::Result<Vec<(Transaction, BTreeSet<WalletId>)>> {
         let spent_nullifiers = filtered_block
             .spent_nullifiers
             .iter()
@@ -122,9 +131,18 @@ impl Worker {
             )
             .any(|source| matches!(source, CommitmentSource::Transaction { .. }));
 
-        // Only make a block request if we detected transactions in the FilteredBlock.
-        // TODO: in the future, we could perform chaff downloads.
+        // Only make a block request if we detected transactions in the FilteredBlock, except
+        // for chaff downloads, which hide which blocks are relevant from the node.
         if spent_nullifiers.is_empty() && !has_tx_sources {
+            if self.chaff.sample(&mut OsRng) {
+                tracing::debug!(
+                    height = filtered_block.height,
+                    "fetching chaff transaction data"
+                );
+                // Fetch exactly as for a real download, then discard the result.
+                fetch_transactions(self.channel.clone(), filtered_block.height).await?;
+                metrics::increment_counter!(metrics::VIEW_CHAFF_TRANSACTION_FETCHES_TOTAL);
+            }
             return Ok(Vec::new());
         }
 
@@ -135,6 +153,12 @@ impl Worker {
 
         let all_transactions =
             fetch_transactions(self.channel.clone(), filtered_block.height).await?;
+        metrics::increment_counter!(metrics::VIEW_TRANSACTION_FETCHES_TOTAL);
+
+        let nullifier_wallets = self
+            .storage
+            .nullifier_wallets(spent_nullifiers.iter().cloned().collect())
+            .await?;
 
         let mut transactions = Vec::new();
 
@@ -142,17 +166,19 @@ impl Worker {
             let tx_id = tx.id().0;
 
             let mut relevant = false;
+            let mut wallets = BTreeSet::new();
 
-            if tx
-                .spent_nullifiers()
-                .any(|nf| spent_nullifiers.contains(&nf))
-            {
-                // The transaction is relevant, it spends one of our nullifiers.
-                relevant = true;
+            for nf in tx.spent_nullifiers() {
+                if spent_nullifiers.contains(&nf) {
+                    // The transaction is relevant, it spends one of our nullifiers.
+                    relevant = true;
+                    wallets.extend(nullifier_wallets.get(&nf).copied());
+                }
             }
 
             // Rehydrate commitment sources.
             for commitment in tx.state_commitments() {
+                wallets.extend(filtered_block.wallets.get(&commitment).copied());
                 filtered_block
                     .new_notes
                     .entry(commitment)
@@ -170,7 +196,7 @@ impl Worker {
             }
 
             if relevant {
-                transactions.push(tx);
+                transactions.push((tx, wallets));
             }
         }
 
@@ -229,13 +255,15 @@ impl Worker {
             // Lock the SCT only while processing this block.
             let mut sct_guard = self.sct.write().await;
 
+            let is_epoch_end = block.epoch_root.is_some();
+
             if !block.requires_scanning() {
                 // Optimization: if the block is empty, seal the in-memory SCT,
                 // and skip touching the database:
                 sct_guard.end_block()?;
                 // We also need to end the epoch, since if there are no funding streams, then an
                 // epoch boundary won't necessarily require scanning:
-                if block.epoch_root.is_some() {
+                if is_epoch_end {
                     sct_guard
                         .end_epoch()
                         .expect("ending the epoch must succeed");
@@ -246,14 +274,26 @@ impl Worker {
             } else {
                 // Otherwise, scan the block and commit its changes:
                 let mut filtered_block =
-                    scan_block(&self.fvk, &mut sct_guard, block, &self.storage).await?;
+                    scan_block(&self.fvks, &mut sct_guard, block, &self.storage).await?;
 
                 // Download any transactions we detected.
                 let transactions = self.fetch_transactions(&mut filtered_block).await?;
 
+                // Position state changes, to notify subscribers of once the block is recorded.
+                let mut position_notifications = Vec::new();
+                let mut notify_position = |wallets: &BTreeSet<WalletId>, position_id, state| {
+                    for wallet_id in wallets {
+                        position_notifications.push(Notification {
+                            wallet_id: *wallet_id,
+                            height,
+                            event: NotificationEvent::PositionStateChanged { position_id, state },
+                        });
+                    }
+                };
+
                 // LPNFT asset IDs won't be known to the chain, so we need to pre-populate them in the local
                 // registry based on transaction contents.
-                for transaction in &transactions {
+                for (transaction, wallets) in &transactions {
                     for action in transaction.actions() {
                         match action {
                             penumbra_transaction::Action::PositionOpen(position_open) => {
@@ -281,10 +321,14 @@ impl Worker {
                                 let denom = lp_nft.denom();
                                 self.storage.record_asset(denom).await?;
 
-                                // Record the position itself
-                                self.storage
-                                    .record_position(position_open.position.clone())
-                                    .await?;
+                                // Record the position itself, for every wallet the
+                                // transaction is relevant to
+                                for wallet_id in wallets {
+                                    self.storage
+                                        .record_position(*wallet_id, position_open.position.clone())
+                                        .await?;
+                                }
+                                notify_position(wallets, position_id, position::State::Opened);
                             }
                             penumbra_transaction::Action::PositionClose(position_close) => {
                                 let position_id = position_close.position_id;
@@ -293,6 +337,7 @@ impl Worker {
                                 self.storage
                                     .update_position(position_id, position::State::Closed)
                                     .await?;
+                                notify_position(wallets, position_id, position::State::Closed);
                             }
                             penumbra_transaction::Action::PositionWithdraw(position_withdraw) => {
                                 let position_id = position_withdraw.position_id;
@@ -301,6 +346,7 @@ impl Worker {
                                 self.storage
                                     .update_position(position_id, position::State::Withdrawn)
                                     .await?;
+                                notify_position(wallets, position_id, position::State::Withdrawn);
                             }
                             penumbra_transaction::Action::PositionRewardClaim(position_claim) => {
                                 let position_id = position_claim.position_id;
@@ -309,6 +355,7 @@ impl Worker {
                                 self.storage
                                     .update_position(position_id, position::State::Claimed)
                                     .await?;
+                                notify_position(wallets, position_id, position::State::Claimed);
                             }
                             _ => (),
                         };
@@ -366,6 +413,17 @@ impl Worker {
                     .await?;
                 // Notify all watchers of the new height we just recorded.
                 self.sync_height_tx.send(filtered_block.height)?;
+                for notification in position_notifications {
+                    self.storage.notify(notification);
+                }
+            }
+
+            if is_epoch_end {
+                // The SCT has already moved on to the next epoch.
+                if let Some(position) = sct_guard.position() {
+                    self.notify_claimable_undelegations(height, position.epoch().into())
+                        .await?;
+                }
             }
             #[cfg(feature = "sct-divergence-check")]
             sct_divergence_check(self.channel.clone(), height, sct_guard.root()).await?;
@@ -383,6 +441,28 @@ impl Worker {
         Ok(())
     }
 
+    /// Notifies subscribers of the unbonding tokens which become claimable at the start of the
+    /// given epoch.
+    async fn notify_claimable_undelegations(
+        &self,
+        height: u64,
+        epoch_index: u64,
+    ) -> anyhow::Result<()> {
+        for (wallet_id, note_record) in self
+            .storage
+            .unbonding_notes_claimable_at(epoch_index)
+            .await?
+        {
+            self.storage.notify(Notification {
+                wallet_id,
+                height,
+                event: NotificationEvent::UndelegationClaimable(note_record),
+            });
+        }
+
+        Ok(())
+    }
+
     pub async fn run(mut self) -> anyhow::Result<()> {
         self.run_inner().await.map_err(|e| {
             tracing::info!(?e, "view worker error");
@@ -5,6 +5,7 @@ pub mod build;
 pub mod error;
 pub mod keys;
 pub mod note_record;
+pub mod payment_request;
 pub mod planner;
 pub mod storage;
 pub mod swap_record;
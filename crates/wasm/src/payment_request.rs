@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use penumbra_asset::asset;
+use penumbra_keys::Address;
+use penumbra_proto::core::{asset::v1alpha1 as pb_asset, keys::v1alpha1 as pb_keys};
+use penumbra_transaction::PaymentRequest;
+
+use crate::error::WasmResult;
+use crate::utils;
+
+/// The contents of a payment request URI, as exchanged with JavaScript.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentRequestJs {
+    pub address: pb_keys::Address,
+    pub value: Option<pb_asset::Value>,
+    pub memo: Option<String>,
+    pub expiry_height: Option<u64>,
+}
+
+/// Encode a payment request as a `penumbra:` URI
+/// Arguments:
+///     address: `bech32 string`
+///     value: `Option<pb::Value>`
+///     memo: `Option<String>`
+///     expiry_height: `Option<u64>`
+/// Returns: `String`
+#[wasm_bindgen]
+pub fn encode_payment_request(
+    address: &str,
+    value: JsValue,
+    memo: Option<String>,
+    expiry_height: Option<u64>,
+) -> WasmResult<String> {
+    utils::set_panic_hook();
+
+    let value: Option<pb_asset::Value> = serde_wasm_bindgen::from_value(value)?;
+    let request = PaymentRequest {
+        address: Address::from_str(address)?,
+        value: value.map(TryInto::try_into).transpose()?,
+        memo,
+        expiry_height,
+    };
+    Ok(request.to_uri(&asset::Cache::with_known_assets()))
+}
+
+/// Decode a `penumbra:` payment request URI
+/// Arguments:
+///     uri: `String`
+/// Returns: `PaymentRequestJs`
+#[wasm_bindgen]
+pub fn decode_payment_request(uri: &str) -> WasmResult<JsValue> {
+    utils::set_panic_hook();
+
+    let request = PaymentRequest::from_str(uri)?;
+    let result = serde_wasm_bindgen::to_value(&PaymentRequestJs {
+        address: request.address.into(),
+        value: request.value.map(Into::into),
+        memo: request.memo,
+        expiry_height: request.expiry_height,
+    })?;
+    Ok(result)
+}
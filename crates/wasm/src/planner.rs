@@ -21,8 +21,8 @@ use penumbra_dex::{
 };
 use penumbra_fee::{Fee, GasPrices};
 use penumbra_governance::{
-    proposal_state::Outcome as ProposalOutcome, DelegatorVotePlan, Proposal, ProposalDepositClaim,
-    ProposalSubmit, ProposalWithdraw, ValidatorVote, Vote,
+    proposal_state::Outcome as ProposalOutcome, Ballot, DelegatorVotePlan, Proposal,
+    ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote,
 };
 use penumbra_ibc::IbcRelay;
 use penumbra_keys::Address;
@@ -31,7 +31,7 @@ use penumbra_proto::core::keys::v1alpha1::AddressIndex;
 use penumbra_proto::view::v1alpha1::{NotesForVotingRequest, NotesRequest};
 use penumbra_shielded_pool::{Ics20Withdrawal, Note, OutputPlan, SpendPlan};
 use penumbra_stake::{rate::RateData, validator};
-use penumbra_stake::{IdentityKey, UndelegateClaimPlan};
+use penumbra_stake::{IdentityKey, Penalty, RedelegateClaim, UndelegateClaimPlan};
 use penumbra_tct as tct;
 use penumbra_transaction::gas::GasCost;
 use penumbra_transaction::{
@@ -58,7 +58,7 @@ struct VoteIntent {
     start_block_height: u64,
     start_position: tct::Position,
     rate_data: BTreeMap<IdentityKey, RateData>,
-    vote: Vote,
+    vote: Ballot,
 }
 
 impl<R: RngCore + CryptoRng> Debug for Planner<R> {
@@ -107,6 +107,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
                     address_index: source.clone(),
                     amount_to_spend: Some(amount.into()),
                     include_spent: false,
+                    wallet_id: None,
                 })
                 .collect(),
             self.vote_intents
@@ -120,6 +121,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
                     )| NotesForVotingRequest {
                         votable_at_height: *start_block_height,
                         address_index: source.clone(),
+                        wallet_id: None,
                     },
                 )
                 .collect(),
@@ -284,6 +286,49 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Add a redelegation to this transaction.
+    ///
+    /// Moves `delegation_amount` of the source validator's delegation tokens,
+    /// described by `from_rate_data`, to the validator described by `to_rate_data`.
+    pub fn redelegate(
+        &mut self,
+        delegation_amount: Amount,
+        from_rate_data: RateData,
+        to_rate_data: RateData,
+    ) -> &mut Self {
+        let redelegation = from_rate_data
+            .build_redelegate(&to_rate_data, delegation_amount)
+            .into();
+        self.action(redelegation);
+        self
+    }
+
+    /// Add a redelegation claim to this transaction.
+    ///
+    /// Converts `escrowed_amount` of the redelegation tokens produced by a
+    /// redelegation in the epoch with index `start_epoch_index` into the
+    /// destination validator's delegation tokens, less the `penalty` applied to
+    /// the source validator over the redelegation's unbonding period.
+    pub fn redelegate_claim(
+        &mut self,
+        from_validator_identity: IdentityKey,
+        to_validator_identity: IdentityKey,
+        start_epoch_index: u64,
+        penalty: Penalty,
+        escrowed_amount: Amount,
+    ) -> &mut Self {
+        let claim = RedelegateClaim::new(
+            from_validator_identity,
+            to_validator_identity,
+            start_epoch_index,
+            penalty,
+            escrowed_amount,
+        )
+        .into();
+        self.action(claim);
+        self
+    }
+
     /// Add an undelegate claim to this transaction.
     pub fn undelegate_claim(&mut self, claim_plan: UndelegateClaimPlan) -> &mut Self {
         self.action(ActionPlan::UndelegateClaim(claim_plan));
@@ -349,6 +394,9 @@ impl<R: RngCore + CryptoRng> Planner<R> {
 
     /// Vote with all possible vote weight on a given proposal.
     ///
+    /// If `vote` is a weighted vote, the voting power of each note is split between the options in
+    /// proportion to its weights.
+    ///
     /// Voting twice on the same proposal in the same planner will overwrite the previous vote.
     pub fn delegator_vote(
         &mut self,
@@ -356,7 +404,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         start_block_height: u64,
         start_position: tct::Position,
         start_rate_data: BTreeMap<IdentityKey, RateData>,
-        vote: Vote,
+        vote: Ballot,
     ) -> &mut Self {
         self.vote_intents.insert(
             proposal,
@@ -379,7 +427,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         &mut self,
         proposal: u64,
         start_position: tct::Position,
-        vote: Vote,
+        vote: Ballot,
         note: Note,
         position: tct::Position,
         unbonded_amount: Amount,
@@ -112,28 +112,15 @@ pub fn witness(transaction_plan: JsValue, stored_tree: JsValue) -> WasmResult<Js
         )
         .collect();
 
-    let anchor = sct.root();
-
-    // Obtain an auth path for each requested note commitment
-
-    let auth_paths = note_commitments
-        .iter()
-        .map(|nc| {
-            sct.witness(*nc)
-                .ok_or(anyhow!("note commitment is in the SCT"))
-        })
-        .collect::<Result<Vec<Proof>, anyhow::Error>>()?;
+    // Obtain an auth path for each requested note commitment, relative to the same root
+    let batch = sct
+        .witness_batch(note_commitments)
+        .ok_or(anyhow!("note commitment is not in the SCT"))?;
 
     // Release the read lock on the SCT
     drop(sct);
 
-    let mut witness_data = WitnessData {
-        anchor,
-        state_commitment_proofs: auth_paths
-            .into_iter()
-            .map(|proof| (proof.commitment(), proof))
-            .collect(),
-    };
+    let mut witness_data = WitnessData::from(batch);
 
     // Now we need to augment the witness data with dummy proofs such that
     // note commitments corresponding to dummy spends also have proofs.
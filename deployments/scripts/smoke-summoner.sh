@@ -105,6 +105,11 @@ cargo run --quiet --release --bin pcli -- --home /tmp/account1 ceremony contribu
 echo "Exporting keys..."
 cargo run --quiet --release --bin summonerd -- export --storage-dir /tmp/summonerd --target-dir ./crates/crypto/proof-params/src/gen
 
+echo "Exporting and verifying the ceremony transcript..."
+mkdir -p /tmp/summonerd/export
+cargo run --quiet --release --bin summonerd -- export --storage-dir /tmp/summonerd --target-dir /tmp/summonerd/export --transcript
+cargo run --quiet --release --bin summonerd -- verify --transcript-dir /tmp/summonerd/export/transcript
+
 # We have a set of tests in the pcli crate that generate and verify proofs using the
 # proving keys present in the `penumbra-proof-params` crate.
 echo "Check tests pass using the new proving keys..."
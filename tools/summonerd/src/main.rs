@@ -6,6 +6,7 @@ mod phase;
 mod queue;
 mod server;
 mod storage;
+mod transcript;
 mod web;
 
 use anyhow::Result;
@@ -42,6 +43,9 @@ use crate::phase::Phase1;
 use crate::phase::Phase2;
 use crate::phase::PhaseMarker;
 use crate::queue::ParticipantQueue;
+use crate::transcript::{
+    export_transcript, mismatched_embedded_keys, verify_transcript, CIRCUIT_NAMES,
+};
 use crate::web::web_app;
 use crate::{penumbra_knower::PenumbraKnower, server::CoordinatorService};
 use penumbra_proof_setup::all::{Phase1CeremonyCRS, Phase1RawCeremonyCRS};
@@ -74,6 +78,11 @@ The provided subcommands should be run in the following order:
 
 Each of these stages should be triggered manually by a human
 operating the orchestration.
+
+Anyone can check the outcome of the ceremony against its transcript,
+as written by `summonerd export --transcript`, with:
+
+    summonerd verify --transcript-dir <DIR>
 "#;
 
 #[derive(Debug, Parser)]
@@ -151,6 +160,19 @@ enum Command {
         #[clap(long, display_order = 200)]
         /// Directory for storing the exported ceremony output.
         target_dir: Utf8PathBuf,
+        #[clap(long, display_order = 300)]
+        /// Also write the transcript of the ceremony, with every contribution, to
+        /// `<TARGET_DIR>/transcript`.
+        transcript: bool,
+    },
+    /// Verify the transcript of a ceremony, and that it produced the embedded proof parameters.
+    Verify {
+        #[clap(long, display_order = 100)]
+        /// Directory holding the transcript, as written by `export --transcript`.
+        transcript_dir: Utf8PathBuf,
+        #[clap(long, display_order = 200)]
+        /// Filepath to the expected phase 1 root. Defaults to the one `generate-phase1` creates.
+        phase1_root: Option<Utf8PathBuf>,
     },
 }
 
@@ -277,6 +299,7 @@ impl Opt {
             Command::Export {
                 storage_dir,
                 target_dir,
+                transcript,
             } => {
                 let storage =
                     Storage::load_or_initialize(Config::default(), ceremony_db(&storage_dir))
@@ -297,18 +320,34 @@ impl Opt {
                     None => anyhow::bail!("Please run phase2 before this command 8^)"),
                 };
                 let pks = combine(&phase1_crs, &phase2_crs, &aux);
-                let names = [
-                    "spend",
-                    "output",
-                    "delegator_vote",
-                    "convert",
-                    "swap",
-                    "swapclaim",
-                    "nullifier_derivation",
-                ];
-                for i in 0..7 {
-                    write_params(target_dir.as_path(), names[i], &pks[i], &pks[i].vk)?;
+                for (name, pk) in CIRCUIT_NAMES.iter().zip(pks.iter()) {
+                    write_params(target_dir.as_path(), name, pk, &pk.vk)?;
+                }
+                if transcript {
+                    export_transcript(&storage, &target_dir.join("transcript")).await?;
+                }
+                Ok(())
+            }
+            Command::Verify {
+                transcript_dir,
+                phase1_root,
+            } => {
+                let expected_root = match phase1_root {
+                    Some(path) => Phase1RawCeremonyCRS::unchecked_from_protobuf(
+                        CeremonyCrs::decode(fs::read(path)?.as_slice())?,
+                    )?
+                    .assume_valid(),
+                    None => Phase1CeremonyCRS::root()?,
+                };
+                let pks = verify_transcript(&transcript_dir, &expected_root)?;
+                let mismatched = mismatched_embedded_keys(&pks);
+                if !mismatched.is_empty() {
+                    anyhow::bail!(
+                        "the transcript is valid, but its keys differ from the embedded ones for: {}",
+                        mismatched.join(", ")
+                    );
                 }
+                println!("The transcript is valid, and produced the embedded proof parameters.");
                 Ok(())
             }
         }
@@ -1,9 +1,9 @@
 use anyhow::Result;
 use camino::Utf8Path;
 use penumbra_asset::STAKING_TOKEN_ASSET_ID;
-use penumbra_keys::{Address, FullViewingKey};
+use penumbra_keys::{keys::WalletId, Address, FullViewingKey};
 use penumbra_num::Amount;
-use penumbra_view::{Storage, ViewService};
+use penumbra_view::{ChaffPolicy, Storage, ViewService};
 use url::Url;
 
 /// Knows things about a running penumbra system, requires internet connectivity
@@ -13,6 +13,7 @@ pub struct PenumbraKnower {
     // we get the specific information we need, as this will get populated
     // by the view service.
     storage: Storage,
+    wallet_id: WalletId,
     // Not sure if storing this is necessary, but seems like a good idea to avoid things getting
     // dropped on the floor
     _view: ViewService,
@@ -27,16 +28,23 @@ impl PenumbraKnower {
         fvk: &FullViewingKey,
         node: Url,
     ) -> Result<Self> {
-        let storage = Storage::load_or_initialize(Some(storage_path), fvk, node.clone()).await?;
-        let view = ViewService::new(storage.clone(), node).await?;
+        let storage = Storage::load_or_initialize(
+            Some(storage_path),
+            std::slice::from_ref(fvk),
+            node.clone(),
+            None,
+        )
+        .await?;
+        let view = ViewService::new(storage.clone(), node, ChaffPolicy::default()).await?;
         Ok(Self {
+            wallet_id: fvk.wallet_id(),
             storage,
             _view: view,
         })
     }
 
     pub async fn total_amount_sent_to_me(&self, by: &Address) -> Result<Amount> {
-        let notes = self.storage.notes_by_sender(by).await?;
+        let notes = self.storage.notes_by_sender(self.wallet_id, by).await?;
         let what_i_want = STAKING_TOKEN_ASSET_ID.to_owned();
         let mut total = Amount::zero();
         for note in &notes {
@@ -345,8 +345,42 @@ impl Storage {
         Ok(out)
     }
 
+    /// Get the slots of the contributions made in a phase, in order, excluding its root.
+    pub async fn contribution_slots(&self, marker: PhaseMarker) -> Result<Vec<u64>> {
+        let mut conn = self.pool.get()?;
+        let tx = conn.transaction()?;
+        let query = match marker {
+            PhaseMarker::P1 => {
+                "SELECT slot FROM phase1_contributions WHERE NOT is_root ORDER BY slot"
+            }
+            PhaseMarker::P2 => {
+                "SELECT slot FROM phase2_contributions WHERE NOT is_root ORDER BY slot"
+            }
+        };
+        let mut stmt = tx.prepare(query)?;
+        let slots = stmt
+            .query_map([], |row| row.get::<usize, u64>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(slots)
+    }
+
+    /// Get the encoded contribution made in a phase at a given slot.
+    pub async fn contribution_data(&self, marker: PhaseMarker, slot: u64) -> Result<Vec<u8>> {
+        let mut conn = self.pool.get()?;
+        let tx = conn.transaction()?;
+        let query = match marker {
+            PhaseMarker::P1 => {
+                "SELECT contribution_or_crs FROM phase1_contribution_data WHERE slot = ?1"
+            }
+            PhaseMarker::P2 => {
+                "SELECT contribution_or_crs FROM phase2_contribution_data WHERE slot = ?1"
+            }
+        };
+        let data = tx.query_row(query, [slot], |row| row.get::<usize, Vec<u8>>(0))?;
+        Ok(data)
+    }
+
     /// Get Phase 1 root.
-    #[allow(dead_code)]
     pub async fn phase1_root(&self) -> Result<Phase1CeremonyCRS> {
         let mut conn = self.pool.get()?;
         let tx = conn.transaction()?;
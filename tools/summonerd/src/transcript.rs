@@ -0,0 +1,186 @@
+//! Exporting and independently verifying the transcript of a ceremony.
+//!
+//! A transcript is a directory holding the phase 1 root, and every contribution accepted in
+//! each phase, encoded as they were submitted:
+//!
+//! ```text
+//! transcript/
+//!     phase1_root.bin
+//!     phase1/000001.bin
+//!     phase1/000002.bin
+//!     ...
+//!     phase2/000001.bin
+//!     ...
+//! ```
+//!
+//! Contributions are named by their slot, and replayed in that order when verifying.
+use std::fs;
+
+use anyhow::{Context, Result};
+use ark_groth16::ProvingKey;
+use camino::{Utf8Path, Utf8PathBuf};
+use decaf377::Bls12_377;
+use penumbra_proof_params::{ProvingKeyExt, VerifyingKeyExt};
+use penumbra_proof_setup::all::{
+    combine, transition, Phase1CeremonyCRS, Phase1RawCeremonyCRS, Phase1RawCeremonyContribution,
+    Phase2RawCeremonyContribution,
+};
+use penumbra_proto::tools::summoning::v1alpha1::{
+    participate_request::Contribution as PBContribution, CeremonyCrs,
+};
+use penumbra_proto::Message;
+
+use crate::phase::PhaseMarker;
+use crate::storage::Storage;
+
+/// The name of each circuit, in the order the ceremony produces their keys.
+pub const CIRCUIT_NAMES: [&str; 7] = [
+    "spend",
+    "output",
+    "delegator_vote",
+    "convert",
+    "swap",
+    "swapclaim",
+    "nullifier_derivation",
+];
+
+/// The ids of the proving and verification keys embedded in `penumbra-proof-params`, in the
+/// order of [`CIRCUIT_NAMES`].
+const EMBEDDED_KEY_IDS: [(&str, &str); 7] = [
+    (
+        penumbra_proof_params::spend::PROVING_KEY_ID,
+        penumbra_proof_params::spend::VERIFICATION_KEY_ID,
+    ),
+    (
+        penumbra_proof_params::output::PROVING_KEY_ID,
+        penumbra_proof_params::output::VERIFICATION_KEY_ID,
+    ),
+    (
+        penumbra_proof_params::delegator_vote::PROVING_KEY_ID,
+        penumbra_proof_params::delegator_vote::VERIFICATION_KEY_ID,
+    ),
+    (
+        penumbra_proof_params::convert::PROVING_KEY_ID,
+        penumbra_proof_params::convert::VERIFICATION_KEY_ID,
+    ),
+    (
+        penumbra_proof_params::swap::PROVING_KEY_ID,
+        penumbra_proof_params::swap::VERIFICATION_KEY_ID,
+    ),
+    (
+        penumbra_proof_params::swapclaim::PROVING_KEY_ID,
+        penumbra_proof_params::swapclaim::VERIFICATION_KEY_ID,
+    ),
+    (
+        penumbra_proof_params::nullifier_derivation::PROVING_KEY_ID,
+        penumbra_proof_params::nullifier_derivation::VERIFICATION_KEY_ID,
+    ),
+];
+
+const PHASE1_ROOT_FILE: &str = "phase1_root.bin";
+
+fn phase_dir(transcript_dir: &Utf8Path, marker: PhaseMarker) -> Utf8PathBuf {
+    match marker {
+        PhaseMarker::P1 => transcript_dir.join("phase1"),
+        PhaseMarker::P2 => transcript_dir.join("phase2"),
+    }
+}
+
+/// Write the transcript of the ceremony in `storage` to `transcript_dir`.
+pub async fn export_transcript(storage: &Storage, transcript_dir: &Utf8Path) -> Result<()> {
+    fs::create_dir_all(transcript_dir)?;
+    let root: CeremonyCrs = storage.phase1_root().await?.try_into()?;
+    fs::write(transcript_dir.join(PHASE1_ROOT_FILE), root.encode_to_vec())?;
+
+    for marker in [PhaseMarker::P1, PhaseMarker::P2] {
+        let dir = phase_dir(transcript_dir, marker);
+        fs::create_dir_all(&dir)?;
+        let slots = storage.contribution_slots(marker).await?;
+        tracing::info!(?marker, count = slots.len(), "exporting contributions");
+        // Contributions are large, so write them out one at a time.
+        for slot in slots {
+            let data = storage.contribution_data(marker, slot).await?;
+            fs::write(dir.join(format!("{slot:06}.bin")), data)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The contributions of a phase in a transcript, in order.
+fn contribution_files(transcript_dir: &Utf8Path, marker: PhaseMarker) -> Result<Vec<Utf8PathBuf>> {
+    let dir = phase_dir(transcript_dir, marker);
+    let mut files = dir
+        .read_dir_utf8()
+        .with_context(|| format!("could not read contributions from {dir}"))?
+        .map(|entry| Ok(entry?.into_path()))
+        .collect::<Result<Vec<_>>>()?;
+    files.retain(|path| path.extension() == Some("bin"));
+    // The slots are zero-padded, so the files sort in the order of the contributions.
+    files.sort();
+    Ok(files)
+}
+
+/// Replay the ceremony recorded in `transcript_dir`, starting from `expected_root`, and return
+/// the proving keys it produced.
+///
+/// Every contribution is checked to be valid, and to build on the one before it.
+pub fn verify_transcript(
+    transcript_dir: &Utf8Path,
+    expected_root: &Phase1CeremonyCRS,
+) -> Result<[ProvingKey<Bls12_377>; 7]> {
+    let root_path = transcript_dir.join(PHASE1_ROOT_FILE);
+    let root_bytes = fs::read(&root_path).with_context(|| format!("could not read {root_path}"))?;
+    let root = Phase1RawCeremonyCRS::try_from(CeremonyCrs::decode(root_bytes.as_slice())?)?
+        // The root is the starting point of the ceremony, and is checked by comparison instead.
+        .assume_valid();
+    if &root != expected_root {
+        anyhow::bail!("the phase 1 root in the transcript is not the expected one");
+    }
+
+    let mut phase1_crs = root;
+    for path in contribution_files(transcript_dir, PhaseMarker::P1)? {
+        let contribution = Phase1RawCeremonyContribution::try_from(PBContribution::decode(
+            fs::read(&path)?.as_slice(),
+        )?)
+        .with_context(|| format!("could not decode phase 1 contribution {path}"))?
+        .validate()
+        .with_context(|| format!("phase 1 contribution {path} is invalid"))?;
+        if !contribution.is_linked_to(&phase1_crs) {
+            anyhow::bail!("phase 1 contribution {path} does not build on the previous one");
+        }
+        tracing::info!(%path, "verified phase 1 contribution");
+        phase1_crs = contribution.new_elements();
+    }
+
+    let (aux, phase2_root) = transition(&phase1_crs)?;
+
+    let mut phase2_crs = phase2_root.clone();
+    for path in contribution_files(transcript_dir, PhaseMarker::P2)? {
+        let contribution = Phase2RawCeremonyContribution::try_from(PBContribution::decode(
+            fs::read(&path)?.as_slice(),
+        )?)
+        .with_context(|| format!("could not decode phase 2 contribution {path}"))?
+        .validate(&phase2_root)
+        .with_context(|| format!("phase 2 contribution {path} is invalid"))?;
+        if !contribution.is_linked_to(&phase2_crs) {
+            anyhow::bail!("phase 2 contribution {path} does not build on the previous one");
+        }
+        tracing::info!(%path, "verified phase 2 contribution");
+        phase2_crs = contribution.new_elements();
+    }
+
+    Ok(combine(&phase1_crs, &phase2_crs, &aux))
+}
+
+/// Check that `pks` are the keys embedded in `penumbra-proof-params`, returning the names of
+/// the circuits whose keys differ.
+pub fn mismatched_embedded_keys(pks: &[ProvingKey<Bls12_377>; 7]) -> Vec<&'static str> {
+    CIRCUIT_NAMES
+        .iter()
+        .zip(EMBEDDED_KEY_IDS.iter())
+        .zip(pks.iter())
+        .filter(|((_, (pk_id, vk_id)), pk)| pk.debug_id() != *pk_id || pk.vk.debug_id() != *vk_id)
+        .map(|((name, _), _)| *name)
+        .collect()
+}